@@ -1,23 +1,77 @@
 use crate::core::msg::{ExecuteMsg, InitMsg, MigrateMsg, QueryMsg};
+use crate::execute::accept_admin::{accept_admin, AcceptAdminV1};
 use crate::execute::add_asset_definition::{add_asset_definition, AddAssetDefinitionV1};
 use crate::execute::add_asset_verifier::{add_asset_verifier, AddAssetVerifierV1};
+use crate::execute::add_registered_asset_verifier::{
+    add_registered_asset_verifier, AddRegisteredAssetVerifierV1,
+};
 use crate::execute::bind_contract_alias::{bind_contract_alias, BindContractAliasV1};
+use crate::execute::cancel_admin_proposal::{cancel_admin_proposal, CancelAdminProposalV1};
+use crate::execute::cancel_timelocked_change::{
+    cancel_timelocked_change, CancelTimelockedChangeV1,
+};
 use crate::execute::delete_asset_definition::{delete_asset_definition, DeleteAssetDefinitionV1};
+use crate::execute::execute_timelocked_change::{
+    execute_timelocked_change, ExecuteTimelockedChangeV1,
+};
+use crate::execute::grant_role::{grant_role, GrantRoleV1};
 use crate::execute::onboard_asset::{onboard_asset, OnboardAssetV1};
+use crate::execute::post_verifier_bond::{post_verifier_bond, PostVerifierBondV1};
+use crate::execute::propose_admin::{propose_admin, ProposeAdminV1};
+use crate::execute::queue_timelocked_change::{queue_timelocked_change, QueueTimelockedChangeV1};
+use crate::execute::register_verifier::{register_verifier, RegisterVerifierV1};
+use crate::execute::remove_asset_verifier::{remove_asset_verifier, RemoveAssetVerifierV1};
+use crate::execute::revoke_role::{revoke_role, RevokeRoleV1};
+use crate::execute::schedule_asset_verifier_update::{
+    schedule_asset_verifier_update, ScheduleAssetVerifierUpdateV1,
+};
+use crate::execute::self_update_asset_verifier::{
+    self_update_asset_verifier, SelfUpdateAssetVerifierV1,
+};
+use crate::execute::slash_verifier_bond::{slash_verifier_bond, SlashVerifierBondV1};
 use crate::execute::toggle_asset_definition::{toggle_asset_definition, ToggleAssetDefinitionV1};
+use crate::execute::toggle_asset_verifier::{toggle_asset_verifier, ToggleAssetVerifierV1};
 use crate::execute::update_access_routes::{update_access_routes, UpdateAccessRoutesV1};
 use crate::execute::update_asset_definition::{update_asset_definition, UpdateAssetDefinitionV1};
 use crate::execute::update_asset_verifier::{update_asset_verifier, UpdateAssetVerifierV1};
+use crate::execute::update_pause_status::{update_pause_status, UpdatePauseStatusV1};
+use crate::execute::update_protocol_fee::{update_protocol_fee, UpdateProtocolFeeV1};
+use crate::execute::update_timelock_delay::{update_timelock_delay, UpdateTimelockDelayV1};
+use crate::execute::update_verifier_bond_settings::{
+    update_verifier_bond_settings, UpdateVerifierBondSettingsV1,
+};
+use crate::execute::update_verifier_self_update_bounds::{
+    update_verifier_self_update_bounds, UpdateVerifierSelfUpdateBoundsV1,
+};
 use crate::execute::verify_asset::{verify_asset, VerifyAssetV1};
+use crate::execute::withdraw_fees::{withdraw_fees, WithdrawFeesV1};
+use crate::execute::withdraw_treasury::{withdraw_treasury, WithdrawTreasuryV1};
+use crate::execute::withdraw_verifier_bond::{withdraw_verifier_bond, WithdrawVerifierBondV1};
 use crate::instantiate::init_contract::init_contract;
 use crate::migrate::migrate_contract::migrate_contract;
+use crate::query::query_accrued_fees::query_accrued_fees;
 use crate::query::query_asset_definition::query_asset_definition;
+use crate::query::query_asset_definition_family::query_asset_definition_family;
+use crate::query::query_asset_definition_history::query_asset_definition_history;
+use crate::query::query_asset_definition_version::query_asset_definition_version;
 use crate::query::query_asset_definitions::query_asset_definitions;
 use crate::query::query_asset_scope_attribute::query_asset_scope_attribute;
+use crate::query::query_can_onboard::query_can_onboard;
+use crate::query::query_pause_status::query_pause_status;
+use crate::query::query_roles::query_roles;
+use crate::query::query_scheduled_verifier_updates::query_scheduled_verifier_updates;
 use crate::query::query_state::query_state;
+use crate::query::query_statistics::query_statistics;
+use crate::query::query_timelocked_changes::query_timelocked_changes;
+use crate::query::query_treasury_balance::query_treasury_balance;
+use crate::query::query_verifier::query_verifier;
+use crate::query::query_verifier_bond::query_verifier_bond;
+use crate::query::query_verifier_registration::query_verifier_registration;
+use crate::query::query_verifier_self_update_bounds::query_verifier_self_update_bounds;
 use crate::query::query_version::query_version;
 use crate::service::asset_meta_service::AssetMetaService;
 use crate::util::aliases::{AssetResult, DepsC, DepsMutC, EntryPointResponse};
+use crate::util::contract_helpers::check_execute_msg_not_paused;
 use crate::validation::validate_execute_msg::validate_execute_msg;
 use crate::validation::validate_init_msg::validate_init_msg;
 use cosmwasm_std::{entry_point, Binary, Env, MessageInfo};
@@ -56,13 +110,12 @@ pub fn instantiate(
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
-/// * `_env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.  Unused by this
-/// function, but required by cosmwasm for successful query entrypoint.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the query.
 /// * `msg` A custom query message enum defined by this contract to allow multiple different results
 /// to be determined for this route.
 #[entry_point]
-pub fn query(deps: DepsC, _env: Env, msg: QueryMsg) -> AssetResult<Binary> {
+pub fn query(deps: DepsC, env: Env, msg: QueryMsg) -> AssetResult<Binary> {
     match msg {
         QueryMsg::QueryAssetDefinition { qualifier } => {
             query_asset_definition(&deps, qualifier.to_asset_qualifier()?)
@@ -72,7 +125,51 @@ pub fn query(deps: DepsC, _env: Env, msg: QueryMsg) -> AssetResult<Binary> {
             query_asset_scope_attribute(&deps, identifier.to_asset_identifier()?)
         }
         QueryMsg::QueryState {} => query_state(&deps),
+        QueryMsg::QueryCanOnboard {
+            identifier,
+            asset_type,
+            verifier_address,
+            sender,
+        } => query_can_onboard(
+            &deps,
+            &env,
+            identifier.to_asset_identifier()?,
+            asset_type,
+            verifier_address,
+            sender,
+        ),
+        QueryMsg::QueryStatistics {} => query_statistics(&deps),
+        QueryMsg::QueryTreasuryBalance {} => query_treasury_balance(&deps),
+        QueryMsg::QueryVerifier { address } => query_verifier(&deps, address),
         QueryMsg::QueryVersion {} => query_version(&deps),
+        QueryMsg::QueryAccruedFees { address } => query_accrued_fees(&deps, address),
+        QueryMsg::QueryScheduledVerifierUpdates { asset_type } => {
+            query_scheduled_verifier_updates(&deps, asset_type)
+        }
+        QueryMsg::QueryRoles { role } => query_roles(&deps, role),
+        QueryMsg::QueryTimelockedChanges {} => query_timelocked_changes(&deps),
+        QueryMsg::QueryPauseStatus {} => query_pause_status(&deps),
+        QueryMsg::QueryVerifierSelfUpdateBounds {
+            asset_type,
+            verifier_address,
+        } => query_verifier_self_update_bounds(&deps, asset_type, verifier_address),
+        QueryMsg::QueryVerifierRegistration { verifier_address } => {
+            query_verifier_registration(&deps, verifier_address)
+        }
+        QueryMsg::QueryVerifierBond {
+            asset_type,
+            verifier_address,
+        } => query_verifier_bond(&deps, asset_type, verifier_address),
+        QueryMsg::QueryAssetDefinitionHistory { asset_type } => {
+            query_asset_definition_history(&deps, asset_type)
+        }
+        QueryMsg::QueryAssetDefinitionVersion {
+            asset_type,
+            version,
+        } => query_asset_definition_version(&deps, asset_type, version),
+        QueryMsg::QueryAssetDefinitionFamily { asset_type } => {
+            query_asset_definition_family(&deps, asset_type)
+        }
     }
 }
 
@@ -96,14 +193,18 @@ pub fn query(deps: DepsC, _env: Env, msg: QueryMsg) -> AssetResult<Binary> {
 pub fn execute(deps: DepsMutC, env: Env, info: MessageInfo, msg: ExecuteMsg) -> EntryPointResponse {
     // Ensure the execute message is properly formatted before doing anything
     validate_execute_msg(&msg)?;
+    // Reject requests for paused processes before any handler logic runs
+    check_execute_msg_not_paused(&deps.as_ref(), &msg)?;
     match msg {
         ExecuteMsg::OnboardAsset { .. } => onboard_asset(
             AssetMetaService::new(deps),
+            env,
             info,
             OnboardAssetV1::from_execute_msg(msg)?,
         ),
         ExecuteMsg::VerifyAsset { .. } => verify_asset(
             AssetMetaService::new(deps),
+            env,
             info,
             VerifyAssetV1::from_execute_msg(msg)?,
         ),
@@ -113,18 +214,33 @@ pub fn execute(deps: DepsMutC, env: Env, info: MessageInfo, msg: ExecuteMsg) ->
             info,
             AddAssetDefinitionV1::from_execute_msg(msg)?,
         ),
-        ExecuteMsg::UpdateAssetDefinition { .. } => {
-            update_asset_definition(deps, info, UpdateAssetDefinitionV1::from_execute_msg(msg)?)
-        }
-        ExecuteMsg::ToggleAssetDefinition { .. } => {
-            toggle_asset_definition(deps, info, ToggleAssetDefinitionV1::from_execute_msg(msg)?)
-        }
+        ExecuteMsg::UpdateAssetDefinition { .. } => update_asset_definition(
+            deps,
+            env,
+            info,
+            UpdateAssetDefinitionV1::from_execute_msg(msg)?,
+        ),
+        ExecuteMsg::ToggleAssetDefinition { .. } => toggle_asset_definition(
+            deps,
+            env,
+            info,
+            ToggleAssetDefinitionV1::from_execute_msg(msg)?,
+        ),
         ExecuteMsg::AddAssetVerifier { .. } => {
-            add_asset_verifier(deps, info, AddAssetVerifierV1::from_execute_msg(msg)?)
-        }
-        ExecuteMsg::UpdateAssetVerifier { .. } => {
-            update_asset_verifier(deps, info, UpdateAssetVerifierV1::from_execute_msg(msg)?)
+            add_asset_verifier(deps, env, info, AddAssetVerifierV1::from_execute_msg(msg)?)
         }
+        ExecuteMsg::UpdateAssetVerifier { .. } => update_asset_verifier(
+            deps,
+            env,
+            info,
+            UpdateAssetVerifierV1::from_execute_msg(msg)?,
+        ),
+        ExecuteMsg::ScheduleAssetVerifierUpdate { .. } => schedule_asset_verifier_update(
+            deps,
+            env,
+            info,
+            ScheduleAssetVerifierUpdateV1::from_execute_msg(msg)?,
+        ),
         ExecuteMsg::UpdateAccessRoutes { .. } => update_access_routes(
             AssetMetaService::new(deps),
             info,
@@ -133,9 +249,104 @@ pub fn execute(deps: DepsMutC, env: Env, info: MessageInfo, msg: ExecuteMsg) ->
         ExecuteMsg::BindContractAlias { .. } => {
             bind_contract_alias(deps, env, info, BindContractAliasV1::from_execute_msg(msg)?)
         }
-        ExecuteMsg::DeleteAssetDefinition { .. } => {
-            delete_asset_definition(deps, info, DeleteAssetDefinitionV1::from_execute_msg(msg)?)
+        ExecuteMsg::DeleteAssetDefinition { .. } => delete_asset_definition(
+            deps,
+            env,
+            info,
+            DeleteAssetDefinitionV1::from_execute_msg(msg)?,
+        ),
+        ExecuteMsg::WithdrawTreasury { .. } => {
+            withdraw_treasury(deps, info, WithdrawTreasuryV1::from_execute_msg(msg)?)
+        }
+        ExecuteMsg::UpdateProtocolFee { .. } => {
+            update_protocol_fee(deps, info, UpdateProtocolFeeV1::from_execute_msg(msg)?)
+        }
+        ExecuteMsg::WithdrawFees {} => {
+            withdraw_fees(deps, info, WithdrawFeesV1::from_execute_msg(msg)?)
+        }
+        ExecuteMsg::GrantRole { .. } => grant_role(deps, info, GrantRoleV1::from_execute_msg(msg)?),
+        ExecuteMsg::RevokeRole { .. } => {
+            revoke_role(deps, info, RevokeRoleV1::from_execute_msg(msg)?)
+        }
+        ExecuteMsg::ProposeAdmin { .. } => {
+            propose_admin(deps, info, ProposeAdminV1::from_execute_msg(msg)?)
+        }
+        ExecuteMsg::AcceptAdmin {} => {
+            accept_admin(deps, info, AcceptAdminV1::from_execute_msg(msg)?)
+        }
+        ExecuteMsg::CancelAdminProposal {} => {
+            cancel_admin_proposal(deps, info, CancelAdminProposalV1::from_execute_msg(msg)?)
+        }
+        ExecuteMsg::UpdateTimelockDelay { .. } => {
+            update_timelock_delay(deps, info, UpdateTimelockDelayV1::from_execute_msg(msg)?)
         }
+        ExecuteMsg::QueueTimelockedChange { .. } => queue_timelocked_change(
+            deps,
+            env,
+            info,
+            QueueTimelockedChangeV1::from_execute_msg(msg)?,
+        ),
+        ExecuteMsg::ExecuteTimelockedChange { .. } => execute_timelocked_change(
+            deps,
+            env,
+            info,
+            ExecuteTimelockedChangeV1::from_execute_msg(msg)?,
+        ),
+        ExecuteMsg::CancelTimelockedChange { .. } => {
+            cancel_timelocked_change(deps, info, CancelTimelockedChangeV1::from_execute_msg(msg)?)
+        }
+        ExecuteMsg::UpdatePauseStatus { .. } => {
+            update_pause_status(deps, info, UpdatePauseStatusV1::from_execute_msg(msg)?)
+        }
+        ExecuteMsg::UpdateVerifierSelfUpdateBounds { .. } => update_verifier_self_update_bounds(
+            deps,
+            info,
+            UpdateVerifierSelfUpdateBoundsV1::from_execute_msg(msg)?,
+        ),
+        ExecuteMsg::SelfUpdateAssetVerifier { .. } => self_update_asset_verifier(
+            deps,
+            env,
+            info,
+            SelfUpdateAssetVerifierV1::from_execute_msg(msg)?,
+        ),
+        ExecuteMsg::RemoveAssetVerifier { .. } => remove_asset_verifier(
+            AssetMetaService::new(deps),
+            env,
+            info,
+            RemoveAssetVerifierV1::from_execute_msg(msg)?,
+        ),
+        ExecuteMsg::ToggleAssetVerifier { .. } => toggle_asset_verifier(
+            deps,
+            env,
+            info,
+            ToggleAssetVerifierV1::from_execute_msg(msg)?,
+        ),
+        ExecuteMsg::RegisterVerifier { .. } => {
+            register_verifier(deps, info, RegisterVerifierV1::from_execute_msg(msg)?)
+        }
+        ExecuteMsg::AddRegisteredAssetVerifier { .. } => add_registered_asset_verifier(
+            deps,
+            env,
+            info,
+            AddRegisteredAssetVerifierV1::from_execute_msg(msg)?,
+        ),
+        ExecuteMsg::PostVerifierBond { .. } => {
+            post_verifier_bond(deps, info, PostVerifierBondV1::from_execute_msg(msg)?)
+        }
+        ExecuteMsg::SlashVerifierBond { .. } => {
+            slash_verifier_bond(deps, info, SlashVerifierBondV1::from_execute_msg(msg)?)
+        }
+        ExecuteMsg::WithdrawVerifierBond { .. } => withdraw_verifier_bond(
+            deps,
+            env,
+            info,
+            WithdrawVerifierBondV1::from_execute_msg(msg)?,
+        ),
+        ExecuteMsg::UpdateVerifierBondSettings { .. } => update_verifier_bond_settings(
+            deps,
+            info,
+            UpdateVerifierBondSettingsV1::from_execute_msg(msg)?,
+        ),
     }
 }
 
@@ -153,8 +364,8 @@ pub fn execute(deps: DepsMutC, env: Env, info: MessageInfo, msg: ExecuteMsg) ->
 /// * msg` A custom migrate message enum defined by this contract to allow multiple different
 /// results of invoking the migrate endpoint.
 #[entry_point]
-pub fn migrate(deps: DepsMutC, _env: Env, msg: MigrateMsg) -> EntryPointResponse {
+pub fn migrate(deps: DepsMutC, env: Env, msg: MigrateMsg) -> EntryPointResponse {
     match msg {
-        MigrateMsg::ContractUpgrade { options } => migrate_contract(deps, options),
+        MigrateMsg::ContractUpgrade { options } => migrate_contract(deps, env, options),
     }
 }
@@ -87,7 +87,8 @@ pub enum ContractError {
         /// The bech32 scope specification address listed on the scope provided during onboarding.
         scope_spec_address: String,
         /// The bech32 scope specification address listed in the [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2)
-        /// stored for the given asset type.
+        /// stored for the given asset type.  If the definition accepts multiple scope specifications,
+        /// they are all listed, separated by commas.
         expected_scope_spec_address: String,
     },
 
@@ -100,6 +101,14 @@ pub enum ContractError {
         asset_type: String,
     },
 
+    /// Occurs when a request is made for a process that has been halted via the contract's
+    /// [PauseStatus](super::types::pause_status::PauseStatus).
+    #[error("Contract operation [{operation}] is currently paused")]
+    ContractPaused {
+        /// The name of the paused process.
+        operation: String,
+    },
+
     /// Denotes that an existing [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
     /// has the same [address](super::types::verifier_detail::VerifierDetailV2::address) property
     /// as the provided [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2) to be
@@ -115,6 +124,33 @@ pub enum ContractError {
         msg: String,
     },
 
+    /// Occurs when a withdrawal from the contract treasury requests more funds than the treasury
+    /// currently holds.
+    #[error("Insufficient treasury balance: requested [{requested}], but only [{available}] is available")]
+    InsufficientTreasuryBalance {
+        /// The funds that were requested for withdrawal.
+        requested: String,
+        /// The funds currently held by the treasury in the requested denom.
+        available: String,
+    },
+
+    /// This error occurs when a verifier is selected during the onboarding process for an
+    /// [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2) that requires a
+    /// [minimum_verifier_bond](super::types::asset_definition::AssetDefinitionV2::minimum_verifier_bond),
+    /// and the verifier has not posted a large enough bond.
+    #[error("Verifier [{verifier_address}] has a bond of [{bonded}] for asset type [{asset_type}], but a bond of [{required}] is required")]
+    InsufficientVerifierBond {
+        /// The bech32 address of the verifier.
+        verifier_address: String,
+        /// The [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type) selected
+        /// during onboarding.
+        asset_type: String,
+        /// The bond that the verifier has posted for the asset type.
+        bonded: String,
+        /// The minimum bond required by the asset definition.
+        required: String,
+    },
+
     /// Indicates that a bech32 address was provided that does not meet proper specifications for the
     /// given scenario.
     #[error("Invalid address provided [{address}]: {explanation}")]
@@ -125,6 +161,17 @@ pub enum ContractError {
         explanation: String,
     },
 
+    /// Occurs when a change to an asset definition's [parent_asset_type](super::types::asset_definition::AssetDefinitionV2::parent_asset_type)
+    /// would produce an invalid hierarchy, like a parent that does not exist or a cycle of asset
+    /// types that are each other's ancestors.
+    #[error("Invalid hierarchy for asset type [{asset_type}]: {explanation}")]
+    InvalidAssetTypeHierarchy {
+        /// The asset type of the definition that would produce the invalid hierarchy.
+        asset_type: String,
+        /// A message further explaining the issue.
+        explanation: String,
+    },
+
     /// An error that can occur during a migration that indicates that an incorrect stored contract
     /// code was attempted to be provided for a migration.
     #[error("Current contract name [{current_contract}] does not match provided migration name [{migration_contract}]")]
@@ -194,6 +241,16 @@ pub enum ContractError {
         explanation: String,
     },
 
+    /// Occurs when the total cost of onboarding an asset, including any protocol fee, exceeds the
+    /// maximum that the requestor indicated they were willing to pay.
+    #[error("Onboarding cost of [{required}] exceeds the provided maximum of [{maximum}]")]
+    OnboardingCostExceedsMaximum {
+        /// The total funds required for onboarding.
+        required: String,
+        /// The maximum funds that the requestor was willing to pay.
+        maximum: String,
+    },
+
     /// An error that occurs when a unique key is violated during an attempt to add new data to the
     /// contract's internal storage.  Reference: [state](super::state).
     #[error("Existing record found: {explanation}")]
@@ -210,6 +267,24 @@ pub enum ContractError {
         explanation: String,
     },
 
+    /// Occurs when a queued [TimelockedChange](super::types::timelocked_change::TimelockedChange)
+    /// is executed before its delay has elapsed.
+    #[error("Timelocked change [{change_id}] cannot be executed until block height [{executable_at_height}]")]
+    TimelockNotElapsed {
+        /// The unique identifier of the queued change.
+        change_id: u64,
+        /// The first block height at which the change can be executed.
+        executable_at_height: u64,
+    },
+
+    /// Occurs when a sensitive configuration change is requested directly while the contract's
+    /// timelock is enabled.  The change must instead be queued as a [TimelockedChange](super::types::timelocked_change::TimelockedChange).
+    #[error("Message of type [{message_type}] must be queued as a timelocked change while the timelock is enabled")]
+    TimelockRequired {
+        /// The type of message that was rejected.
+        message_type: String,
+    },
+
     /// A generic error that occurs when an address attempts to perform an operation in the contract
     /// that it does not have the permission to.
     #[error("Unauthorized: {explanation}")]
@@ -275,6 +350,54 @@ pub enum ContractError {
         /// during onboarding.
         asset_type: String,
     },
+
+    /// This error occurs when a verifier attempts to withdraw its bond before the unbonding period
+    /// that began with its withdrawal request has elapsed.
+    #[error("Bond for verifier [{verifier_address}] for asset type [{asset_type}] is unbonding until block height [{unlock_height}]")]
+    VerifierBondUnbonding {
+        /// The bech32 address of the verifier.
+        verifier_address: String,
+        /// The [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type) for
+        /// which the bond was posted.
+        asset_type: String,
+        /// The block height at which the bond can be withdrawn.
+        unlock_height: u64,
+    },
+
+    /// This error occurs when a [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
+    /// that has been disabled is selected during the onboarding process.
+    #[error("Verifier [{verifier_address}] is currently disabled for asset type [{asset_type}]")]
+    VerifierDisabled {
+        /// The bech32 address of the disabled verifier.
+        verifier_address: String,
+        /// The [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type) selected
+        /// during onboarding.
+        asset_type: String,
+    },
+
+    /// This error occurs when a verifier is removed from an [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2)
+    /// while assets are still awaiting its verification, and no [PendingAssetStrategy](super::types::pending_asset_strategy::PendingAssetStrategy)
+    /// was provided to handle them.
+    #[error("Verifier [{verifier_address}] has [{pending_asset_count}] asset(s) pending verification for asset type [{asset_type}]")]
+    VerifierHasPendingAssets {
+        /// The bech32 address of the verifier.
+        verifier_address: String,
+        /// The [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type) from
+        /// which the verifier was being removed.
+        asset_type: String,
+        /// The number of assets awaiting verification by the verifier.
+        pending_asset_count: usize,
+    },
+
+    /// Occurs when a route that manages a single asset type is provided a verifier detail with an
+    /// [entity_detail](super::types::verifier_detail::VerifierDetailV2::entity_detail) that differs
+    /// from the verifier's [VerifierRegistration](super::types::verifier_registration::VerifierRegistration),
+    /// which owns that value for every asset type.
+    #[error("Verifier [{verifier_address}] is registered, so its entity detail can only be changed with the RegisterVerifier route")]
+    VerifierRegistrationConflict {
+        /// The bech32 address of the registered verifier.
+        verifier_address: String,
+    },
 }
 impl ContractError {
     /// Constructs an instance of the [GenericError](self::ContractError::GenericError) variant,
@@ -1,6 +1,15 @@
 use crate::core::types::asset_definition::AssetDefinitionInputV2;
+use crate::core::types::contract_role::ContractRole;
+use crate::core::types::pending_asset_strategy::PendingAssetStrategy;
+use crate::core::types::protocol_fee::ProtocolFee;
+use crate::core::types::scheduled_verifier_update::EffectiveAt;
 use crate::core::types::serialized_enum::SerializedEnum;
+use crate::core::types::timelocked_change::TimelockedChange;
+use crate::core::types::verifier_bond_settings::VerifierBondSettings;
 use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::core::types::verifier_registration::VerifierRegistration;
+use crate::core::types::verifier_self_update_bounds::VerifierSelfUpdateBounds;
+use cosmwasm_std::{Coin, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -60,10 +69,126 @@ pub enum QueryMsg {
     /// the contract behaves.  They reflect the values created at instantiation and potentially modified during migration.  It
     /// responds with a [StateV2](super::state::StateV2) struct value.
     QueryState {},
+    /// This route can be used to determine whether or not an [OnboardAsset](self::ExecuteMsg::OnboardAsset) request would
+    /// succeed, without spending any fees.  Every check that onboarding performs is run in a read-only manner, and every failure
+    /// is reported at once instead of only the first.  It responds with a [QueryCanOnboardResponse](crate::query::query_can_onboard::QueryCanOnboardResponse)
+    /// struct value.
+    QueryCanOnboard {
+        /// Expects an [AssetIdentifier](super::types::asset_identifier::AssetIdentifier)-compatible
+        /// [SerializedEnum](super::types::serialized_enum::SerializedEnum).
+        identifier: SerializedEnum,
+        /// The [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type) that the asset would be onboarded as.
+        asset_type: String,
+        /// The bech32 address of the Verifier Account that would be requested during onboarding.
+        verifier_address: String,
+        /// The bech32 address of the account that would send the onboarding request.
+        sender: String,
+    },
+    /// This route can be used to retrieve the counters that the contract maintains for onboardings, retries, approvals and
+    /// denials, both contract-wide and per asset type and verifier, as well as the total fees paid out per denom.  It responds
+    /// with a [QueryStatisticsResponse](crate::query::query_statistics::QueryStatisticsResponse) struct value.
+    QueryStatistics {},
+    /// This route can be used to retrieve the funds currently held in the contract treasury, per denom.  These funds do not
+    /// include onboarding fees held in escrow for pending verifications.  It responds with a [QueryTreasuryBalanceResponse](crate::query::query_treasury_balance::QueryTreasuryBalanceResponse)
+    /// struct value.
+    QueryTreasuryBalance {},
+    /// This route can be used to retrieve every [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2) asset type
+    /// in which a verifier is registered, alongside the [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2) configured
+    /// for each.  This allows verifiers to audit their configuration without inspecting every asset definition.  The response
+    /// also includes the verifier's [VerifierMetrics](super::types::verifier_metrics::VerifierMetrics), which track its approvals,
+    /// denials, verification speed in blocks and currently pending assets.  It responds
+    /// with a [QueryVerifierResponse](crate::query::query_verifier::QueryVerifierResponse) struct value.
+    QueryVerifier {
+        /// The bech32 address of the verifier to search for.
+        address: String,
+    },
     /// This route can be used to retrieve the internal contract version information.  It elucidates the current version of the
     /// contract that was derived through instantiation or the most recent code migration.  It responds with a [VersionInfoV1](crate::migrate::version_info::VersionInfoV1)
     /// struct value.
     QueryVersion {},
+    /// This route can be used to retrieve the fees credited to an address that have not yet been collected using the
+    /// [WithdrawFees](self::ExecuteMsg::WithdrawFees) route.  It responds with a [QueryAccruedFeesResponse](crate::query::query_accrued_fees::QueryAccruedFeesResponse)
+    /// struct value.
+    QueryAccruedFees {
+        /// The bech32 address for which to fetch accrued fees.
+        address: String,
+    },
+    /// This route can be used to retrieve every [ScheduledVerifierUpdate](super::types::scheduled_verifier_update::ScheduledVerifierUpdate)
+    /// that has not yet been applied.  It responds with a [QueryScheduledVerifierUpdatesResponse](crate::query::query_scheduled_verifier_updates::QueryScheduledVerifierUpdatesResponse)
+    /// struct value.
+    QueryScheduledVerifierUpdates {
+        /// If provided, only updates for this asset type are returned.
+        asset_type: Option<String>,
+    },
+    /// This route can be used to retrieve the addresses that have been granted each [ContractRole](super::types::contract_role::ContractRole).
+    /// It responds with a [QueryRolesResponse](crate::query::query_roles::QueryRolesResponse) struct value.
+    QueryRoles {
+        /// If provided, only the members of this role are returned.
+        role: Option<ContractRole>,
+    },
+    /// This route can be used to retrieve every queued [TimelockedChange](super::types::timelocked_change::TimelockedChange)
+    /// that has not yet been executed or cancelled, alongside the contract's current timelock delay.
+    /// It responds with a [QueryTimelockedChangesResponse](crate::query::query_timelocked_changes::QueryTimelockedChangesResponse)
+    /// struct value.
+    QueryTimelockedChanges {},
+    /// This route can be used to retrieve the flags that denote which of the contract's processes
+    /// are currently halted.  It responds with a [PauseStatus](super::types::pause_status::PauseStatus)
+    /// struct value.
+    QueryPauseStatus {},
+    /// This route can be used to retrieve the limits set by the admin on the changes that a verifier
+    /// can make to its own [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2).
+    /// It responds with a [VerifierSelfUpdateBounds](super::types::verifier_self_update_bounds::VerifierSelfUpdateBounds)
+    /// struct value.
+    QueryVerifierSelfUpdateBounds {
+        /// The asset type of the definition containing the verifier.
+        asset_type: String,
+        /// The bech32 address of the verifier.
+        verifier_address: String,
+    },
+    /// This route can be used to retrieve the global [VerifierRegistration](super::types::verifier_registration::VerifierRegistration)
+    /// of a verifier.  It responds with the registration, or null if the verifier has never been
+    /// registered.
+    QueryVerifierRegistration {
+        /// The bech32 address of the verifier.
+        verifier_address: String,
+    },
+    /// This route can be used to retrieve the collateral that a verifier has bonded for an asset
+    /// type with the [PostVerifierBond](self::ExecuteMsg::PostVerifierBond) route.  It responds
+    /// with a [Coin](cosmwasm_std::Coin), or null if the verifier has no bond for the asset type.
+    QueryVerifierBond {
+        /// The asset type for which the bond was posted.
+        asset_type: String,
+        /// The bech32 address of the verifier.
+        verifier_address: String,
+    },
+    /// This route can be used to retrieve every version of an asset definition.  Each time an
+    /// asset definition is changed or deleted, its previous values are kept as a version.  It
+    /// responds with a list of [AssetDefinitionVersion](super::types::asset_definition_version::AssetDefinitionVersion)
+    /// values in ascending version order, ending with the definition's current values if it still
+    /// exists.
+    QueryAssetDefinitionHistory {
+        /// The asset type of the definition.
+        asset_type: String,
+    },
+    /// This route can be used to retrieve a single version of an asset definition, like the version
+    /// recorded on an asset's [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)
+    /// when it was onboarded.  It responds with an [AssetDefinitionVersion](super::types::asset_definition_version::AssetDefinitionVersion),
+    /// or null if the version does not exist.
+    QueryAssetDefinitionVersion {
+        /// The asset type of the definition.
+        asset_type: String,
+        /// The version number to fetch.
+        version: u64,
+    },
+    /// This route can be used to retrieve an asset definition along with the definitions of every
+    /// asset type that descends from it through [parent_asset_type](super::types::asset_definition::AssetDefinitionV2::parent_asset_type),
+    /// like all of the asset types in a mortgage family.  Each returned definition includes the
+    /// values that it inherits from its ancestors.  It responds with a [QueryAssetDefinitionsResponse](crate::query::query_asset_definitions::QueryAssetDefinitionsResponse)
+    /// struct value.
+    QueryAssetDefinitionFamily {
+        /// The asset type at the top of the family.
+        asset_type: String,
+    },
 }
 
 /// Defines all routes in which the contract can be executed.  These are all handled directly in
@@ -100,6 +225,12 @@ pub enum ExecuteMsg {
         /// Note: Access routes can specify a [name](super::types::access_route::AccessRoute::name)
         /// parameter, as well, to indicate the reason for the route, but this is entirely optional.
         access_routes: Option<Vec<AccessRoute>>,
+        /// An optional upper bound, in nhash, on the total amount the requestor is willing to pay
+        /// for onboarding, including any protocol fee.  If the cost configured for the verifier has
+        /// grown above this value, the request will be rejected.  When provided, any amount of nhash
+        /// between the required cost and this maximum may be sent, and the excess will be refunded.
+        #[serde(default)]
+        max_onboarding_cost: Option<Uint128>,
     },
     /// This route is specifically designed to allow a Verifier specified in the [AssetScopeAttribute](super::types::asset_scope_attribute::AssetScopeAttribute)
     /// of a [Provenance Metadata Scope](https://docs.provenance.io/modules/metadata-module#scope-data-structures) to indicate to
@@ -130,12 +261,13 @@ pub enum ExecuteMsg {
         /// interaction.
         access_routes: Option<Vec<AccessRoute>>,
     },
-    /// __This route is only accessible to the contract's admin address.__  This route allows a new [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2)
+    /// __This route is only accessible to the contract's admin address or an address holding the [DefinitionManager](super::types::contract_role::ContractRole::DefinitionManager) role.__  This route allows a new [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2)
     /// value to be added to the contract's internal storage.  These asset definitions dictate which asset types are allowed to
     /// be onboarded, as well as which verifiers are tied to each asset type.  Each added asset definition must be unique in
     /// two criteria:
     /// * Its [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type) value must not yet be registered in a different asset definition.
     /// * Its [scope_spec_address](super::types::asset_definition::AssetDefinitionV2::scope_spec_address) (entered as a [ScopeSpecIdentifier](super::types::scope_spec_identifier::ScopeSpecIdentifier))
+    /// and any [additional_scope_spec_addresses](super::types::asset_definition::AssetDefinitionV2::additional_scope_spec_addresses)
     /// must also be unique across asset definitions.
     /// Additionally, all added asset definitions must refer to an existing [Provenance Metadata Scope Specification](https://docs.provenance.io/modules/metadata-module#scope-specification).
     AddAssetDefinition {
@@ -143,7 +275,7 @@ pub enum ExecuteMsg {
         /// values.  The execution route converts the incoming value to an asset definition.
         asset_definition: AssetDefinitionInputV2,
     },
-    /// __This route is only accessible to the contract's admin address.__ This route allows an existing [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2)
+    /// __This route is only accessible to the contract's admin address or an address holding the [DefinitionManager](super::types::contract_role::ContractRole::DefinitionManager) role.__ This route allows an existing [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2)
     /// value to be updated.  It works by matching the input's [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type) to an existing asset definition and overwriting the
     /// existing values.  If no asset definition exists for the given type, the request will be rejected.  Contract validation
     /// ensures that after the update, all scope specification addresses contained in asset definitions remain unique, as well.
@@ -152,7 +284,7 @@ pub enum ExecuteMsg {
         /// values.  The execution route converts the incoming value to an asset definition.
         asset_definition: AssetDefinitionInputV2,
     },
-    /// __This route is only accessible to the contract's admin address.__ This route toggles an existing [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2)
+    /// __This route is only accessible to the contract's admin address or an address holding the [DefinitionManager](super::types::contract_role::ContractRole::DefinitionManager) role.__ This route toggles an existing [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2)
     /// from enabled to disabled, or disabled to enabled.  When disabled, an asset definition will no longer allow new assets to
     /// be onboarded to the contract.  Existing assets already onboarded to the contract and in pending status will still be
     /// allowed to be verified, but new values will be rejected.  This same functionality could be achieved with an invocation of
@@ -168,7 +300,7 @@ pub enum ExecuteMsg {
         /// the asset definition is in the intended state during the execution of the route.
         expected_result: bool,
     },
-    /// __This route is only accessible to the contract's admin address.__ This route adds a new [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
+    /// __This route is only accessible to the contract's admin address or an address holding the [VerifierManager](super::types::contract_role::ContractRole::VerifierManager) role.__ This route adds a new [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
     /// to an existing [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2).  This route is intended to register new verifiers
     /// without the bulky requirements of the [UpdateAssetDefinition](self::ExecuteMsg::UpdateAssetDefinition) execution route.  This route will reject verifiers added
     /// with addresses that match any other verifiers on the target asset definition.
@@ -182,7 +314,7 @@ pub enum ExecuteMsg {
         /// parameter, or the request will be rejected.
         verifier: VerifierDetailV2,
     },
-    /// __This route is only accessible to the contract's admin address.__ This route updates an existing [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
+    /// __This route is only accessible to the contract's admin address or an address holding the [VerifierManager](super::types::contract_role::ContractRole::VerifierManager) role.__ This route updates an existing [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
     /// in an existing [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2).  This route is intended to be used when the values
     /// of a single verifier detail need to change, but not the entire asset definition.  The request will be rejected if the
     /// referenced asset definition is not present within the contract, or if a verifier does not exist within the asset
@@ -197,7 +329,25 @@ pub enum ExecuteMsg {
         /// value, or the request will be rejected.
         verifier: VerifierDetailV2,
     },
-    /// __This route is only accessible to the contract's admin address OR to the owner of the access routes being updated.__
+    /// __This route is only accessible to the contract's admin address or an address holding the [VerifierManager](super::types::contract_role::ContractRole::VerifierManager) role.__ This route schedules a
+    /// replacement for an existing [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
+    /// that takes effect at a future block height or time, giving integrators notice before fees
+    /// change.  The update is applied during the first onboarding for the asset type that occurs
+    /// after it becomes effective.  The request will be rejected if the referenced asset definition
+    /// or verifier does not exist, or if the effective point has already been reached.
+    ScheduleAssetVerifierUpdate {
+        /// The type of asset for which the [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2) will be updated. This
+        /// must refer to an existing [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2)'s [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type)
+        /// value, or the request will be rejected.
+        asset_type: String,
+        /// The updated verifier detail.  An existing verifier detail within the target asset
+        /// definition must have a matching [address](super::types::verifier_detail::VerifierDetailV2::address)
+        /// value, or the request will be rejected.
+        verifier: VerifierDetailV2,
+        /// The block height or time at which the update takes effect.
+        effective_at: EffectiveAt,
+    },
+    /// __This route is only accessible to the contract's admin address, an address holding the [AccessRouteAdmin](super::types::contract_role::ContractRole::AccessRouteAdmin) role, OR to the owner of the access routes being updated.__
     /// This route will swap all existing access routes for a specific owner for a specific scope to the provided values. These
     /// access routes either correspond to those created during the onboarding process, or those created during the verification
     /// process.
@@ -225,7 +375,7 @@ pub enum ExecuteMsg {
         /// The name to bind to the contract.  Ex: `assetclassificationalias.pb`.
         alias_name: String,
     },
-    /// __This route is only accessible to the contract's admin address.__ When an [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2)
+    /// __This route is only accessible to the contract's admin address or an address holding the [DefinitionManager](super::types::contract_role::ContractRole::DefinitionManager) role.__ When an [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2)
     /// is erroneously added with an incorrect asset type, the scope specification address is unable
     /// to be used, as it is another unique key of the asset definition.  This route facilitates the
     /// removal of bad data.
@@ -239,6 +389,215 @@ pub enum ExecuteMsg {
         /// existing [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2).
         qualifier: SerializedEnum,
     },
+    /// __This route is only accessible to the contract's admin address or an address holding the [TreasuryManager](super::types::contract_role::ContractRole::TreasuryManager) role.__ Sends funds that have been
+    /// retained in the contract treasury to a recipient.  Funds are only retained in the treasury when
+    /// a [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2) specifies a
+    /// [remainder_destination](super::types::verifier_detail::VerifierDetailV2::remainder_destination)
+    /// of [ContractTreasury](super::types::fee_remainder_destination::FeeRemainderDestination::ContractTreasury).
+    /// Onboarding fees held in escrow for pending verifications can never be withdrawn.
+    WithdrawTreasury {
+        /// The funds to withdraw.  This amount must not exceed the treasury's balance for its denom.
+        amount: Coin,
+        /// The bech32 address of the account that will receive the withdrawn funds.
+        recipient: String,
+    },
+    /// __This route is only accessible to the contract's admin address or an address holding the [TreasuryManager](super::types::contract_role::ContractRole::TreasuryManager) role.__ Sets or removes the
+    /// [protocol fee](super::types::protocol_fee::ProtocolFee) charged to onboarding accounts in
+    /// addition to the selected verifier's onboarding cost.  Assets that are already pending
+    /// verification retain the protocol fee that was charged when they were onboarded.
+    UpdateProtocolFee {
+        /// The new protocol fee.  If omitted, the protocol fee is removed and onboarding only
+        /// requires the verifier's onboarding cost.
+        protocol_fee: Option<ProtocolFee>,
+    },
+    /// Sends all fees credited to the sender's address to the sender.  Fees are only credited to
+    /// addresses, rather than sent immediately, when a [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
+    /// enables [accrue_fees](super::types::verifier_detail::VerifierDetailV2::accrue_fees).  The
+    /// request will be rejected if the sender has no accrued fees.
+    WithdrawFees {},
+    /// __This route is only accessible to the contract's admin address.__ Grants a [ContractRole](super::types::contract_role::ContractRole)
+    /// to an address, allowing it to use the routes guarded by that role without being the admin.
+    GrantRole {
+        /// The role to grant.
+        role: ContractRole,
+        /// The bech32 address that will receive the role.
+        address: String,
+    },
+    /// __This route is only accessible to the contract's admin address.__ Revokes a [ContractRole](super::types::contract_role::ContractRole)
+    /// from an address that currently holds it.
+    RevokeRole {
+        /// The role to revoke.
+        role: ContractRole,
+        /// The bech32 address that will lose the role.
+        address: String,
+    },
+    /// __This route is only accessible to the contract's admin address.__ Proposes a new admin
+    /// for the contract.  The current admin retains control until the proposed address sends an
+    /// [AcceptAdmin](self::ExecuteMsg::AcceptAdmin) message, which prevents control from being
+    /// handed to a mistyped address.  Proposing again replaces any pending proposal.
+    ProposeAdmin {
+        /// The bech32 address proposed to become the new admin.
+        address: String,
+    },
+    /// __This route is only accessible to the pending admin address.__ Accepts a proposal created
+    /// by [ProposeAdmin](self::ExecuteMsg::ProposeAdmin), making the sender the contract's admin.
+    AcceptAdmin {},
+    /// __This route is only accessible to the contract's admin address.__ Cancels a proposal created
+    /// by [ProposeAdmin](self::ExecuteMsg::ProposeAdmin), so the proposed address can no longer
+    /// accept control of the contract.
+    CancelAdminProposal {},
+    /// __This route is only accessible to the contract's admin address.__ Sets the number of blocks
+    /// that a [TimelockedChange](super::types::timelocked_change::TimelockedChange) must wait after
+    /// being queued before it can be executed.  While the timelock is enabled, [UpdateAssetVerifier](self::ExecuteMsg::UpdateAssetVerifier),
+    /// [DeleteAssetDefinition](self::ExecuteMsg::DeleteAssetDefinition) and protocol fee increases
+    /// must be queued via [QueueTimelockedChange](self::ExecuteMsg::QueueTimelockedChange).
+    /// Enabling or lengthening the timelock takes effect immediately, but shortening or disabling
+    /// it must also be queued.
+    UpdateTimelockDelay {
+        /// The new delay, in blocks.  If omitted, the timelock is disabled.
+        delay_blocks: Option<u64>,
+    },
+    /// __This route is only accessible to the contract's admin address or an address holding the role that governs the queued change.__
+    /// Queues a [TimelockedChange](super::types::timelocked_change::TimelockedChange) that can be
+    /// executed via [ExecuteTimelockedChange](self::ExecuteMsg::ExecuteTimelockedChange) once the
+    /// current timelock delay has elapsed.
+    QueueTimelockedChange {
+        /// The change to queue.
+        change: TimelockedChange,
+    },
+    /// __This route is only accessible to the contract's admin address or an address holding the role that governs the queued change.__
+    /// Applies a queued [TimelockedChange](super::types::timelocked_change::TimelockedChange)
+    /// whose delay has elapsed.
+    ExecuteTimelockedChange {
+        /// The unique identifier assigned to the change when it was queued.
+        change_id: u64,
+    },
+    /// __This route is only accessible to the contract's admin address or an address holding the role that governs the queued change.__
+    /// Removes a queued [TimelockedChange](super::types::timelocked_change::TimelockedChange)
+    /// without applying it.
+    CancelTimelockedChange {
+        /// The unique identifier assigned to the change when it was queued.
+        change_id: u64,
+    },
+    /// __This route is only accessible to the contract's admin address or an address holding the `pauser` role.__
+    /// Halts or resumes the onboarding, verification and access route update processes.  Requests
+    /// for a paused process are rejected until it is resumed.  At least one flag must be provided,
+    /// and omitted flags keep their current value.
+    UpdatePauseStatus {
+        /// If provided, pauses (true) or resumes (false) the [OnboardAsset](self::ExecuteMsg::OnboardAsset) route.
+        onboarding: Option<bool>,
+        /// If provided, pauses (true) or resumes (false) the [VerifyAsset](self::ExecuteMsg::VerifyAsset) route.
+        verification: Option<bool>,
+        /// If provided, pauses (true) or resumes (false) the [UpdateAccessRoutes](self::ExecuteMsg::UpdateAccessRoutes) route.
+        access_routes: Option<bool>,
+    },
+    /// __This route is only accessible to the contract's admin address or an address holding the `verifier_manager` role.__
+    /// Sets the limits on the changes that a verifier can make to its own [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
+    /// using the [SelfUpdateAssetVerifier](self::ExecuteMsg::SelfUpdateAssetVerifier) route.
+    UpdateVerifierSelfUpdateBounds {
+        /// The asset type of the definition containing the verifier.
+        asset_type: String,
+        /// The bech32 address of the verifier to which the bounds apply.
+        verifier_address: String,
+        /// The new bounds, which replace any existing bounds for the verifier.
+        bounds: VerifierSelfUpdateBounds,
+    },
+    /// __This route is only accessible to the verifier being updated.__ Allows a verifier to
+    /// replace its own [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2) within
+    /// the [VerifierSelfUpdateBounds](super::types::verifier_self_update_bounds::VerifierSelfUpdateBounds)
+    /// set by the admin, without requiring the admin to use the [UpdateAssetVerifier](self::ExecuteMsg::UpdateAssetVerifier)
+    /// route.
+    SelfUpdateAssetVerifier {
+        /// The asset type of the definition containing the verifier.
+        asset_type: String,
+        /// The replacement verifier detail.  Its address must match the sender's address.
+        verifier: VerifierDetailV2,
+    },
+    /// __This route is only accessible to the contract's admin address or an address holding the `verifier_manager` role.__
+    /// Removes an existing [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2) from
+    /// an [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2).  The last
+    /// verifier of an asset definition cannot be removed.
+    RemoveAssetVerifier {
+        /// The asset type of the definition from which the verifier will be removed.
+        asset_type: String,
+        /// The bech32 address of the verifier to remove.
+        verifier_address: String,
+        /// Defines how assets that are still awaiting verification by the verifier are handled.
+        /// If omitted, the request is rejected when any such assets exist.
+        pending_asset_strategy: Option<PendingAssetStrategy>,
+    },
+    /// __This route is only accessible to the contract's admin address or an address holding the `verifier_manager` role.__
+    /// Toggles a single [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2) within
+    /// an [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2) from enabled to
+    /// disabled, or disabled to enabled.  When disabled, the verifier can no longer be selected when
+    /// onboarding new assets, but assets already awaiting verification by it can still be verified.
+    ToggleAssetVerifier {
+        /// The asset type of the definition containing the verifier.
+        asset_type: String,
+        /// The bech32 address of the verifier to toggle.
+        verifier_address: String,
+        /// The value of [enabled](super::types::verifier_detail::VerifierDetailV2::enabled) after
+        /// the toggle takes place.  This value is required to ensure that multiple toggles executed
+        /// in succession will only be honored if the verifier is in the intended state during the
+        /// execution of the route.
+        expected_result: bool,
+    },
+    /// __This route is only accessible to the contract's admin address or an address holding the `verifier_manager` role.__
+    /// Creates or replaces the global [VerifierRegistration](super::types::verifier_registration::VerifierRegistration)
+    /// of a verifier.  The registration is copied into the [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2)
+    /// of every [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2) that uses the
+    /// verifier, except for the fees of verifier details that [override the registered fees](super::types::verifier_detail::VerifierDetailV2::overrides_registered_fees).
+    RegisterVerifier {
+        /// The registration to store for the verifier.
+        registration: VerifierRegistration,
+    },
+    /// __This route is only accessible to the contract's admin address or an address holding the `verifier_manager` role.__
+    /// Adds a [VerifierDetailV2](super::types::verifier_detail::VerifierDetailV2) built from an
+    /// existing [VerifierRegistration](super::types::verifier_registration::VerifierRegistration)
+    /// to an existing [AssetDefinitionV2](super::types::asset_definition::AssetDefinitionV2).  This
+    /// route will reject verifiers that already exist on the target asset definition.
+    AddRegisteredAssetVerifier {
+        /// The asset type of the definition to which the verifier will be added.
+        asset_type: String,
+        /// The bech32 address of the registered verifier.
+        verifier_address: String,
+    },
+    /// __This route is only accessible to a verifier within the target asset definition.__ Adds the
+    /// single coin sent with the request to the verifier's collateral bond for the asset type.  The
+    /// bond is held by the contract, and asset types with a [minimum_verifier_bond](super::types::asset_definition::AssetDefinitionV2::minimum_verifier_bond)
+    /// only accept onboarding requests for verifiers that have bonded at least that amount.
+    PostVerifierBond {
+        /// The asset type of the definition for which the bond is posted.
+        asset_type: String,
+    },
+    /// __This route is only accessible to the contract's admin address or an address holding the
+    /// verifier_manager role.__ Removes funds from a verifier's collateral bond and sends them to
+    /// the [slash_beneficiary](super::types::verifier_bond_settings::VerifierBondSettings::slash_beneficiary)
+    /// configured by the admin, or retains them in the contract treasury if none is configured.
+    SlashVerifierBond {
+        /// The asset type for which the bond was posted.
+        asset_type: String,
+        /// The bech32 address of the verifier whose bond is slashed.
+        verifier_address: String,
+        /// The funds to remove from the bond.  Must be in the bond's denom, and must not exceed the
+        /// bond's amount.
+        amount: Coin,
+    },
+    /// __This route is only accessible to a verifier that is no longer within the target asset definition.__
+    /// Returns the verifier's remaining collateral bond for the asset type.  Bonds cannot be
+    /// withdrawn while the verifier can still verify assets of the type, ensuring that they remain
+    /// available for slashing.  The first request begins an unbonding period, and the bond is only
+    /// returned by a request made after the period elapses.
+    WithdrawVerifierBond {
+        /// The asset type for which the bond was posted.
+        asset_type: String,
+    },
+    /// __This route is only accessible to the contract's admin address.__ Replaces the contract-wide
+    /// [VerifierBondSettings](super::types::verifier_bond_settings::VerifierBondSettings).
+    UpdateVerifierBondSettings {
+        /// The new settings, which replace the existing settings.
+        settings: VerifierBondSettings,
+    },
 }
 
 /// The struct used to migrate the contract from one code instance to another.  Utilized in the core
@@ -1,6 +1,20 @@
 use crate::core::types::asset_definition::AssetDefinitionV2;
+use crate::core::types::asset_definition_version::{
+    AssetDefinitionVersion, AssetDefinitionVersionReference,
+};
 use crate::core::types::asset_qualifier::AssetQualifier;
+use crate::core::types::contract_role::ContractRole;
+use crate::core::types::fee_destination::FeeDestinationV2;
+use crate::core::types::onboarding_statistics::OnboardingStatistics;
+use crate::core::types::pause_status::PauseStatus;
+use crate::core::types::protocol_fee::ProtocolFee;
+use crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate;
+use crate::core::types::timelocked_change::{QueuedChange, TimelockedChange};
+use crate::core::types::verifier_bond_settings::VerifierBondSettings;
 use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::core::types::verifier_metrics::VerifierMetrics;
+use crate::core::types::verifier_registration::VerifierRegistration;
+use crate::core::types::verifier_self_update_bounds::VerifierSelfUpdateBounds;
 use crate::{
     core::msg::InitMsg,
     util::{
@@ -8,7 +22,7 @@ use crate::{
         traits::{OptionExtensions, ResultExtensions},
     },
 };
-use cosmwasm_std::{Addr, Storage};
+use cosmwasm_std::{Addr, BlockInfo, Coin, Order, Storage, Uint128};
 use cosmwasm_storage::{
     bucket, bucket_read, singleton, singleton_read, Bucket, ReadonlyBucket, ReadonlySingleton,
     Singleton,
@@ -22,6 +36,28 @@ use super::error::ContractError;
 pub static STATE_V2_KEY: &[u8] = b"state_v2";
 pub static ASSET_META_KEY: &[u8] = b"asset_meta";
 pub static LATEST_VERIFIER_DETAIL_KEY: &[u8] = b"latest_verifier_detail";
+pub static CONTRACT_STATISTICS_KEY: &[u8] = b"contract_statistics";
+pub static ASSET_TYPE_STATISTICS_KEY: &[u8] = b"asset_type_statistics";
+pub static VERIFIER_STATISTICS_KEY: &[u8] = b"verifier_statistics";
+pub static FEES_PAID_KEY: &[u8] = b"fees_paid";
+pub static TREASURY_BALANCES_KEY: &[u8] = b"treasury_balances";
+pub static LATEST_PROTOCOL_FEE_KEY: &[u8] = b"latest_protocol_fee";
+pub static ACCRUED_FEES_KEY: &[u8] = b"accrued_fees";
+pub static REQUESTOR_ONBOARDING_COUNTS_KEY: &[u8] = b"requestor_onboarding_counts";
+pub static SCHEDULED_VERIFIER_UPDATES_KEY: &[u8] = b"scheduled_verifier_updates";
+pub static ROLE_MEMBERS_KEY: &[u8] = b"role_members";
+pub static QUEUED_CHANGES_KEY: &[u8] = b"queued_changes";
+pub static QUEUED_CHANGE_SEQUENCE_KEY: &[u8] = b"queued_change_sequence";
+pub static VERIFIER_SELF_UPDATE_BOUNDS_KEY: &[u8] = b"verifier_self_update_bounds";
+pub static VERIFIER_REGISTRATIONS_KEY: &[u8] = b"verifier_registrations";
+pub static VERIFIER_METRICS_KEY: &[u8] = b"verifier_metrics";
+pub static LATEST_ONBOARDING_HEIGHT_KEY: &[u8] = b"latest_onboarding_height";
+pub static VERIFIER_BONDS_KEY: &[u8] = b"verifier_bonds";
+pub static VERIFIER_BOND_UNLOCK_HEIGHTS_KEY: &[u8] = b"verifier_bond_unlock_heights";
+pub static PENDING_VERIFICATION_SCOPES_KEY: &[u8] = b"pending_verification_scopes";
+pub static ASSET_DEFINITION_VERSIONS_KEY: &[u8] = b"asset_definition_versions";
+pub static ASSET_DEFINITION_HISTORY_KEY: &[u8] = b"asset_definition_history";
+pub static ADDITIONAL_SCOPE_SPECS_KEY: &[u8] = b"additional_scope_specs";
 
 /// Stores the main configurations for the contract internally.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -32,13 +68,38 @@ pub struct StateV2 {
     /// The Provenance Blockchain bech32 address that maintains primary control over the contract.
     /// This address is derived from the sender of the initial contract instantiation, and is the
     /// only address that can access administrative execution routes in the contract.  It can be
-    /// changed during migrations.
+    /// changed during migrations, or by a proposed admin accepting a transfer of control.
     pub admin: Addr,
     /// A boolean value allowing for less restrictions to be placed on certain functionalities
     /// across the contract's execution processes.  Notably, this disables a check during the
     /// onboarding process to determine if onboarded scopes include underlying record values.  This
     /// should never be set to true in a mainnet environment.
     pub is_test: bool,
+    /// An optional fee charged to the onboarding account in addition to the selected verifier's
+    /// onboarding cost.  It can only be changed by the admin, and is paid out to its collector
+    /// when verification occurs.
+    #[serde(default)]
+    pub protocol_fee: Option<ProtocolFee>,
+    /// The address proposed by the current admin to take over control of the contract.  The
+    /// address only becomes the admin once it accepts the proposal, which prevents control from
+    /// being handed to an address that cannot sign transactions.
+    #[serde(default)]
+    pub pending_admin: Option<Addr>,
+    /// The number of blocks that a [TimelockedChange](super::types::timelocked_change::TimelockedChange)
+    /// must wait after being queued before it can be executed.  While this value is set, sensitive
+    /// configuration changes cannot be made directly, giving requestors and verifiers notice before
+    /// they take effect.  A None value disables the timelock.
+    #[serde(default)]
+    pub timelock_delay: Option<u64>,
+    /// Denotes which of the contract's state-changing processes are currently halted.  Requests
+    /// for a paused process are rejected by the [execute](crate::contract::execute) entry point
+    /// before they reach their handler.
+    #[serde(default)]
+    pub pause_status: PauseStatus,
+    /// The contract-wide configuration for verifier collateral bonds, like the account that
+    /// receives slashed funds.
+    #[serde(default)]
+    pub verifier_bond_settings: VerifierBondSettings,
 }
 impl StateV2 {
     /// Constructs a new instance of this struct for the instantiation process.
@@ -54,6 +115,11 @@ impl StateV2 {
             base_contract_name: msg.base_contract_name,
             admin,
             is_test: msg.is_test.unwrap_or(false),
+            protocol_fee: None,
+            pending_admin: None,
+            timelock_delay: None,
+            pause_status: PauseStatus::default(),
+            verifier_bond_settings: VerifierBondSettings::default(),
         }
     }
 }
@@ -81,7 +147,9 @@ pub fn config_read_v2(storage: &dyn Storage) -> ReadonlySingleton<StateV2> {
 /// that saves cannot include duplicate scope specs.
 /// If it becomes a requirement in the future that we have duplicate scope specs,
 /// we will need to swap to a MultiIndex, and a lot of the lookups in the contract
-/// will fall apart.
+/// will fall apart.  An index can only derive a single key from each definition, so
+/// [additional_scope_spec_addresses](super::types::asset_definition::AssetDefinitionV2::additional_scope_spec_addresses)
+/// are tracked separately in the [additional_scope_specs_store](self::additional_scope_specs_store).
 pub struct AssetDefinitionIndexesV2<'a> {
     scope_spec: UniqueIndex<'a, String, AssetDefinitionV2>,
 }
@@ -108,8 +176,9 @@ pub fn asset_definitions_v2<'a>(
 
 /// Inserts a new asset definition into storage. If a value already exists, an error will be returned.
 /// Note: Asset definitions must contain a unique [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type)
-/// value, as well as a unique [scope_spec_address](super::types::asset_definition::AssetDefinitionV2::scope_spec_address).
-/// Either unique constraint being violated will return an error.
+/// value, as well as a unique [scope_spec_address](super::types::asset_definition::AssetDefinitionV2::scope_spec_address)
+/// and unique [additional_scope_spec_addresses](super::types::asset_definition::AssetDefinitionV2::additional_scope_spec_addresses).
+/// Any unique constraint being violated will return an error.
 ///
 /// # Parameters
 ///
@@ -118,6 +187,7 @@ pub fn insert_asset_definition_v2(
     storage: &mut dyn Storage,
     definition: &AssetDefinitionV2,
 ) -> AssetResult<()> {
+    let definition = &remove_registered_verifier_values(storage, definition)?;
     let state = asset_definitions_v2();
     let key = &definition.storage_key();
     if let Ok(existing_def) = state.load(storage, key) {
@@ -129,16 +199,21 @@ pub fn insert_asset_definition_v2(
         }
             .to_err()
     } else {
+        check_additional_scope_specs_available(storage, definition)?;
+        check_asset_type_hierarchy(storage, definition)?;
         // At this point, we know there is no old data available, so we can safely call the replace function and
         // specify None for the old_data param.
         state
             .replace(storage, key, definition.to_some(), None)
-            .map_err(ContractError::Std)
+            .map_err(ContractError::Std)?;
+        save_additional_scope_specs(storage, definition)
     }
 }
 
 /// Replaces an existing asset definition in state with the provided value.  If no value exists for
-/// the given definition, an error will be returned.  Note: IndexedMap (the type [asset_definitions_v2](self::asset_definitions_v2)
+/// the given definition, an error will be returned.  If the provided value differs from the
+/// existing definition, the existing definition is kept as an [AssetDefinitionVersion](super::types::asset_definition_version::AssetDefinitionVersion)
+/// and the definition's version is incremented.  Note: IndexedMap (the type [asset_definitions_v2](self::asset_definitions_v2)
 /// function returns) provides a really nice update() function that allows two branches (one for
 /// success and one for failure to find) that seems ideal for this functionality, but it requires a
 /// non-reference version of the data to be used. This requires that the provided definition must be
@@ -149,13 +224,20 @@ pub fn insert_asset_definition_v2(
 /// * `storage` A mutable reference to the contract's internal storage.
 /// * `definition` The asset definition to replace by matching on its [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type)
 /// property.
+/// * `block_height` The block height at which the replacement occurs.
+/// * `actor` The bech32 address of the account responsible for the replacement.
 pub fn replace_asset_definition_v2(
     storage: &mut dyn Storage,
     definition: &AssetDefinitionV2,
+    block_height: u64,
+    actor: &str,
 ) -> AssetResult<()> {
+    let definition = &remove_registered_verifier_values(storage, definition)?;
     let state = asset_definitions_v2();
     let key = &definition.storage_key();
     if let Ok(existing_def) = state.load(storage, key) {
+        check_additional_scope_specs_available(storage, definition)?;
+        check_asset_type_hierarchy(storage, definition)?;
         // The documentation for the save() function in IndexedMap recommends calling replace() directly after
         // loading the data, because it's needed for an update and happens internally anyway
         state
@@ -165,7 +247,14 @@ pub fn replace_asset_definition_v2(
                 definition.to_some(),
                 (&existing_def).to_some(),
             )
-            .map_err(ContractError::Std)
+            .map_err(ContractError::Std)?;
+        remove_additional_scope_specs(storage, &existing_def);
+        save_additional_scope_specs(storage, definition)?;
+        // Replacing a definition with identical values does not produce a new version
+        if existing_def != *definition {
+            record_asset_definition_version(storage, existing_def, block_height, actor)?;
+        }
+        Ok(())
     } else {
         ContractError::RecordNotFound {
             explanation: format!(
@@ -173,222 +262,2222 @@ pub fn replace_asset_definition_v2(
                 &definition.asset_type
             ),
         }
-        .to_err()
+        .to_err()
+    }
+}
+
+/// Finds an existing asset definition in state by checking against the provided asset type,
+/// returning an Option that reflects whether or not the definition exists.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `asset_type` The unique name key [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type)
+/// for the requested asset definition.
+pub fn may_load_asset_definition_v2_by_type<S: Into<String>>(
+    storage: &dyn Storage,
+    asset_type: S,
+) -> AssetResult<Option<AssetDefinitionV2>> {
+    asset_definitions_v2()
+        // Coerce to lowercase to match how stored values are keyed
+        .may_load(storage, asset_type.into().to_lowercase().as_bytes())
+        .map_err(ContractError::Std)?
+        .map(|definition| apply_verifier_registrations(storage, definition))
+        .transpose()
+}
+
+/// Finds an existing asset definition by asset type, or returns an error if no definition is found.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `asset_type` The unique name key [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type)
+/// for the requested asset definition.
+pub fn load_asset_definition_v2_by_type<S: Into<String>>(
+    storage: &dyn Storage,
+    asset_type: S,
+) -> AssetResult<AssetDefinitionV2> {
+    let asset_type = asset_type.into();
+    if let Some(asset_definition) = may_load_asset_definition_v2_by_type(storage, &asset_type)? {
+        asset_definition.to_ok()
+    } else {
+        ContractError::RecordNotFound {
+            explanation: format!("no asset definition existed for asset type {}", asset_type,),
+        }
+        .to_err()
+    }
+}
+
+/// Finds an existing asset definition in state by checking against the provided scope spec address,
+/// returning an Option that reflects whether or not the definition exists.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `scope_spec_address` The unique address key [scope_spec_address](super::types::asset_definition::AssetDefinitionV2::scope_spec_address),
+/// or one of the [additional_scope_spec_addresses](super::types::asset_definition::AssetDefinitionV2::additional_scope_spec_addresses),
+/// for the requested asset definition.
+pub fn may_load_asset_definition_v2_by_scope_spec<S: Into<String>>(
+    storage: &dyn Storage,
+    scope_spec_address: S,
+) -> AssetResult<Option<AssetDefinitionV2>> {
+    // Coerce to lowercase to match how stored values are keyed
+    let spec_addr = scope_spec_address.into().to_lowercase();
+    if let Some((_, definition)) = asset_definitions_v2()
+        .idx
+        .scope_spec
+        .item(storage, spec_addr.clone())
+        .map_err(ContractError::Std)?
+    {
+        return apply_verifier_registrations(storage, definition)?
+            .to_some()
+            .to_ok();
+    }
+    match additional_scope_specs_store_ro(storage).may_load(spec_addr.as_bytes())? {
+        Some(asset_type) => may_load_asset_definition_v2_by_type(storage, asset_type),
+        None => Ok(None),
+    }
+}
+
+/// Finds an existing asset definition by scope spec address, or returns an error if no definition is
+/// found.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `scope_spec_address` The unique address key [scope_spec_address](super::types::asset_definition::AssetDefinitionV2::scope_spec_address)
+/// for the requested asset definition.
+pub fn load_asset_definition_v2_by_scope_spec<S: Into<String>>(
+    storage: &dyn Storage,
+    scope_spec_address: S,
+) -> AssetResult<AssetDefinitionV2> {
+    let scope_spec_address = scope_spec_address.into();
+    if let Some(asset_definition) =
+        may_load_asset_definition_v2_by_scope_spec(storage, &scope_spec_address)?
+    {
+        asset_definition.to_ok()
+    } else {
+        ContractError::RecordNotFound {
+            explanation: format!(
+                "no asset definition existed for scope spec address {}",
+                scope_spec_address
+            ),
+        }
+        .to_err()
+    }
+}
+
+/// Attempts to delete an existing asset definition by asset type.  Returns an error if the
+/// definition does not exist or if the deletion fails.  Returns the asset type of the deleted
+/// definition on a successful deletion.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `qualifier` An asset qualifier that can identify the [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
+/// to delete.
+/// * `block_height` The block height at which the deletion occurs.
+/// * `actor` The bech32 address of the account responsible for the deletion.
+pub fn delete_asset_definition_v2_by_qualifier(
+    storage: &mut dyn Storage,
+    qualifier: &AssetQualifier,
+    block_height: u64,
+    actor: &str,
+) -> AssetResult<String> {
+    let existing_definition = match qualifier {
+        AssetQualifier::AssetType(asset_type) => {
+            load_asset_definition_v2_by_type(storage, asset_type)
+        }
+        AssetQualifier::ScopeSpecAddress(scope_spec_address) => {
+            load_asset_definition_v2_by_scope_spec(storage, scope_spec_address)
+        }
+    }?;
+    let existing_asset_type = existing_definition.asset_type.clone();
+    // Children resolve their inherited values through their parent, so a parent must outlive them
+    if let Some(child) = asset_definitions_v2()
+        .range(storage, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, definition)| definition))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .find(|definition| {
+            definition
+                .parent_asset_type
+                .as_ref()
+                .map(|parent| parent.to_lowercase() == existing_asset_type.to_lowercase())
+                .unwrap_or(false)
+        })
+    {
+        return ContractError::InvalidAssetTypeHierarchy {
+            asset_type: existing_asset_type,
+            explanation: format!(
+                "asset type [{}] must be deleted or reassigned before its parent can be deleted",
+                child.asset_type
+            ),
+        }
+        .to_err();
+    }
+    asset_definitions_v2().remove(storage, existing_asset_type.to_lowercase().as_bytes())?;
+    remove_additional_scope_specs(storage, &existing_definition);
+    // The deleted definition's final version is kept, and its version number is never reused by a
+    // definition later added for the same asset type
+    record_asset_definition_version(storage, existing_definition, block_height, actor)?;
+    Ok(existing_asset_type)
+}
+
+/// Fetches a cosmwasm storage bucket that holds the asset type that owns each of the asset
+/// definitions' [additional_scope_spec_addresses](super::types::asset_definition::AssetDefinitionV2::additional_scope_spec_addresses),
+/// keyed on lowercase scope spec address.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn additional_scope_specs_store(storage: &mut dyn Storage) -> Bucket<'_, String> {
+    bucket(storage, ADDITIONAL_SCOPE_SPECS_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage bucket that holds the asset type that owns each of the
+/// asset definitions' [additional_scope_spec_addresses](super::types::asset_definition::AssetDefinitionV2::additional_scope_spec_addresses),
+/// keyed on lowercase scope spec address.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn additional_scope_specs_store_ro(storage: &dyn Storage) -> ReadonlyBucket<'_, String> {
+    bucket_read(storage, ADDITIONAL_SCOPE_SPECS_KEY)
+}
+
+/// Ensures that none of the scope spec addresses of the given definition are owned by a different
+/// asset definition.  Conflicts between two primary [scope_spec_address](super::types::asset_definition::AssetDefinitionV2::scope_spec_address)
+/// values are left to the unique index in [asset_definitions_v2](self::asset_definitions_v2).
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `definition` The asset definition that is about to be saved.
+fn check_additional_scope_specs_available(
+    storage: &dyn Storage,
+    definition: &AssetDefinitionV2,
+) -> AssetResult<()> {
+    let asset_type = definition.asset_type.to_lowercase();
+    for scope_spec_address in definition.get_scope_spec_addresses() {
+        let owner = match additional_scope_specs_store_ro(storage)
+            .may_load(scope_spec_address.to_lowercase().as_bytes())?
+        {
+            Some(owner) => owner.to_some(),
+            None if scope_spec_address != definition.scope_spec_address => {
+                may_load_asset_definition_v2_by_scope_spec(storage, scope_spec_address)?
+                    .map(|owner| owner.asset_type.to_lowercase())
+            }
+            None => None,
+        };
+        if let Some(owner) = owner.filter(|owner| owner != &asset_type) {
+            return ContractError::RecordAlreadyExists {
+                explanation: format!(
+                    "unique constraints violated! scope spec address [{}] is already used by asset type [{}]",
+                    scope_spec_address, owner
+                ),
+            }
+            .to_err();
+        }
+    }
+    Ok(())
+}
+
+/// Records the given definition as the owner of each of its [additional_scope_spec_addresses](super::types::asset_definition::AssetDefinitionV2::additional_scope_spec_addresses).
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `definition` The asset definition that was saved.
+fn save_additional_scope_specs(
+    storage: &mut dyn Storage,
+    definition: &AssetDefinitionV2,
+) -> AssetResult<()> {
+    let asset_type = definition.asset_type.to_lowercase();
+    let mut store = additional_scope_specs_store(storage);
+    for scope_spec_address in &definition.additional_scope_spec_addresses {
+        store.save(scope_spec_address.to_lowercase().as_bytes(), &asset_type)?;
+    }
+    Ok(())
+}
+
+/// Removes the ownership records of each of the given definition's [additional_scope_spec_addresses](super::types::asset_definition::AssetDefinitionV2::additional_scope_spec_addresses).
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `definition` The asset definition that was replaced or deleted.
+fn remove_additional_scope_specs(storage: &mut dyn Storage, definition: &AssetDefinitionV2) {
+    let mut store = additional_scope_specs_store(storage);
+    for scope_spec_address in &definition.additional_scope_spec_addresses {
+        store.remove(scope_spec_address.to_lowercase().as_bytes());
+    }
+}
+
+/// Fetches a cosmwasm storage bucket that holds the current version number of each asset
+/// definition, keyed on lowercase asset type.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn asset_definition_versions_store(storage: &mut dyn Storage) -> Bucket<'_, u64> {
+    bucket(storage, ASSET_DEFINITION_VERSIONS_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage bucket that holds the current version number of each asset
+/// definition, keyed on lowercase asset type.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn asset_definition_versions_store_ro(storage: &dyn Storage) -> ReadonlyBucket<'_, u64> {
+    bucket_read(storage, ASSET_DEFINITION_VERSIONS_KEY)
+}
+
+/// Loads the current version number of the asset definition for an asset type.  Asset definitions
+/// that have never been changed are at version 1.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type of the definition.
+pub fn load_asset_definition_version(storage: &dyn Storage, asset_type: &str) -> AssetResult<u64> {
+    asset_definition_versions_store_ro(storage)
+        .may_load(asset_type.to_lowercase().as_bytes())?
+        .unwrap_or(1)
+        .to_ok()
+}
+
+/// Fetches a cosmwasm storage bucket that holds every replaced [AssetDefinitionVersion](crate::core::types::asset_definition_version::AssetDefinitionVersion)
+/// of a single asset type, keyed on version number.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the definition.
+pub fn asset_definition_history_store<'a>(
+    storage: &'a mut dyn Storage,
+    asset_type: &str,
+) -> Bucket<'a, AssetDefinitionVersion> {
+    Bucket::multilevel(
+        storage,
+        &[
+            ASSET_DEFINITION_HISTORY_KEY,
+            asset_type.to_lowercase().as_bytes(),
+        ],
+    )
+}
+
+/// Fetches a read-only cosmwasm storage bucket that holds every replaced [AssetDefinitionVersion](crate::core::types::asset_definition_version::AssetDefinitionVersion)
+/// of a single asset type, keyed on version number.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type of the definition.
+pub fn asset_definition_history_store_ro<'a>(
+    storage: &'a dyn Storage,
+    asset_type: &str,
+) -> ReadonlyBucket<'a, AssetDefinitionVersion> {
+    ReadonlyBucket::multilevel(
+        storage,
+        &[
+            ASSET_DEFINITION_HISTORY_KEY,
+            asset_type.to_lowercase().as_bytes(),
+        ],
+    )
+}
+
+/// Keeps the previous values of a changed or deleted asset definition as a replaced version, and
+/// advances the asset type's current version number.  Registered verifier values are resolved
+/// before the version is kept, so it records the values in effect at the time of the change.
+/// Returns the new current version number.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `previous_definition` The asset definition's values before the change or deletion.
+/// * `block_height` The block height at which the change or deletion occurs.
+/// * `actor` The bech32 address of the account responsible for the change or deletion.
+fn record_asset_definition_version(
+    storage: &mut dyn Storage,
+    previous_definition: AssetDefinitionV2,
+    block_height: u64,
+    actor: &str,
+) -> AssetResult<u64> {
+    let previous_definition = apply_verifier_registrations(storage, previous_definition)?;
+    let asset_type = previous_definition.asset_type.clone();
+    let version = load_asset_definition_version(storage, &asset_type)?;
+    asset_definition_history_store(storage, &asset_type).save(
+        &version.to_be_bytes(),
+        &AssetDefinitionVersion::replaced(version, previous_definition, block_height, actor),
+    )?;
+    asset_definition_versions_store(storage)
+        .save(asset_type.to_lowercase().as_bytes(), &(version + 1))?;
+    (version + 1).to_ok()
+}
+
+/// Loads a single version of the asset definition for an asset type.  The current version is
+/// loaded from the asset definition itself, and earlier versions from its history.  Returns None if
+/// no such version exists.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type of the definition.
+/// * `version` The version number to load.
+pub fn may_load_asset_definition_version(
+    storage: &dyn Storage,
+    asset_type: &str,
+    version: u64,
+) -> AssetResult<Option<AssetDefinitionVersion>> {
+    if let Some(replaced_version) =
+        asset_definition_history_store_ro(storage, asset_type).may_load(&version.to_be_bytes())?
+    {
+        return replaced_version.to_some().to_ok();
+    }
+    if version != load_asset_definition_version(storage, asset_type)? {
+        return Ok(None);
+    }
+    may_load_asset_definition_v2_by_type(storage, asset_type)?
+        .map(|definition| AssetDefinitionVersion::current(version, definition))
+        .to_ok()
+}
+
+/// Loads every version of the asset definition for an asset type, in ascending version order.  The
+/// asset definition's current values are included as the final version if the definition has not
+/// been deleted.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type of the definition.
+pub fn load_asset_definition_history(
+    storage: &dyn Storage,
+    asset_type: &str,
+) -> AssetResult<Vec<AssetDefinitionVersion>> {
+    let mut history = asset_definition_history_store_ro(storage, asset_type)
+        .range(None, None, Order::Ascending)
+        .map(|result| result.map(|(_, version)| version))
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(definition) = may_load_asset_definition_v2_by_type(storage, asset_type)? {
+        history.push(AssetDefinitionVersion::current(
+            load_asset_definition_version(storage, asset_type)?,
+            definition,
+        ));
+    }
+    history.to_ok()
+}
+
+/// Fetches a mutable reference to the latest verifier detail storage from a [DepsMutC](crate::util::aliases::DepsMutC).
+/// This storage exists for the purpose of maintaining an accurate record of the [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
+/// each scope has when it is onboarded.  As those values can technically change between the time at
+/// which an asset is onboarded versus when it is verified, it is important that the values are
+/// stored until verification is complete in order to generate the correct fees to match the
+/// onboarding cost that is held in contract escrow during onboarding.
+///
+/// The other reason for this value being stored in contract storage versus on the scope attribute
+/// itself is because attributes written to the Provenance Blockchain have a set size limit.  Storing
+/// the entire [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2) on the
+/// attribute is a massive bloat to its size and can cause rejected attribute writes in certain
+/// circumstances.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn latest_verifier_detail_store(storage: &mut dyn Storage) -> Bucket<VerifierDetailV2> {
+    bucket(storage, LATEST_VERIFIER_DETAIL_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage bucket instance for loading verifier details for scope
+/// attributes.  This storage exists for the purpose of maintaining
+/// an accurate record of the VerifierDetailV2 each scope has when it is onboarded.  As those values
+/// can technically change between the time at which an asset is onboarded versus when it is verified,
+/// it is important that the values are stored until verification is complete in order to generate
+/// the correct fees to match the onboarding cost that is held in contract escrow during onboarding.
+///
+/// The other reason for this value being stored in contract storage versus on the scope attribute
+/// itself is because attributes written to the Provenance Blockchain have a set size limit.  Storing
+/// the entire [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2) on the
+/// attribute is a massive bloat to its size and can cause rejected attribute writes in certain
+/// circumstances.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn latest_verifier_detail_store_ro(storage: &dyn Storage) -> ReadonlyBucket<VerifierDetailV2> {
+    bucket_read(storage, LATEST_VERIFIER_DETAIL_KEY)
+}
+
+/// Inserts a verifier detail into the contract's storage, leveraging the
+/// [latest_verifier_detail_store](self::latest_verifier_detail_store) function.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `scope_address` The scope address of the asset for which to store the detail.  This serves
+/// as the primary key for this bucket.
+/// * `verifier_detail` The verifier detail to store.  This represents the latest version of the
+/// verifier detail that was used when the asset was onboarded into the contract for verification.
+pub fn insert_latest_verifier_detail<S: Into<String>>(
+    storage: &mut dyn Storage,
+    scope_address: S,
+    verifier_detail: &VerifierDetailV2,
+) -> AssetResult<()> {
+    latest_verifier_detail_store(storage)
+        .save(scope_address.into().as_bytes(), verifier_detail)
+        .map_err(ContractError::Std)
+}
+
+/// Deletes an existing [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
+/// from the contract's internal latest verifier detail storage.  This leverages the
+/// [latest_verifier_detail_store](self::latest_verifier_detail_store) function.  This function is
+/// important, as contract storage should not retain any bloat.  All verifier details should be
+/// deleted after a verification has been completed successfully, as they are a historical record
+/// that is not relevant.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `scope_address` The scope address of the asset for which to store the detail.  This serves
+/// as the primary key for this bucket.
+pub fn delete_latest_verifier_detail<S: Into<String>>(
+    storage: &mut dyn Storage,
+    scope_address: S,
+) -> AssetResult<()> {
+    latest_verifier_detail_store(storage).remove(scope_address.into().as_bytes());
+    Ok(())
+}
+
+/// Fetches a cosmwasm storage bucket that indexes the assets awaiting verification by a single
+/// verifier within a single asset type, keyed on scope address.  The index allows the assets of one
+/// verifier to be found without scanning every asset that is awaiting verification.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type for which the assets were onboarded.
+/// * `verifier_address` The bech32 address of the verifier assigned to the assets.
+pub fn pending_verification_scopes_store<'a>(
+    storage: &'a mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) -> Bucket<'a, bool> {
+    Bucket::multilevel(
+        storage,
+        &[
+            PENDING_VERIFICATION_SCOPES_KEY,
+            asset_type.to_lowercase().as_bytes(),
+            verifier_address.as_bytes(),
+        ],
+    )
+}
+
+/// Fetches a read-only cosmwasm storage bucket that indexes the assets awaiting verification by a
+/// single verifier within a single asset type, keyed on scope address.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type for which the assets were onboarded.
+/// * `verifier_address` The bech32 address of the verifier assigned to the assets.
+pub fn pending_verification_scopes_store_ro<'a>(
+    storage: &'a dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) -> ReadonlyBucket<'a, bool> {
+    ReadonlyBucket::multilevel(
+        storage,
+        &[
+            PENDING_VERIFICATION_SCOPES_KEY,
+            asset_type.to_lowercase().as_bytes(),
+            verifier_address.as_bytes(),
+        ],
+    )
+}
+
+/// Records that an asset is awaiting verification by a verifier.  Must be called whenever an asset
+/// is onboarded or reassigned to a verifier.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type for which the asset was onboarded.
+/// * `verifier_address` The bech32 address of the verifier assigned to the asset.
+/// * `scope_address` The scope address of the asset.
+pub fn insert_pending_verification_scope(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+    scope_address: &str,
+) -> AssetResult<()> {
+    pending_verification_scopes_store(storage, asset_type, verifier_address)
+        .save(scope_address.as_bytes(), &true)
+        .map_err(ContractError::Std)
+}
+
+/// Removes an asset from the assets awaiting verification by a verifier.  Must be called whenever an
+/// asset is verified, refunded, or reassigned away from the verifier.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type for which the asset was onboarded.
+/// * `verifier_address` The bech32 address of the verifier previously assigned to the asset.
+/// * `scope_address` The scope address of the asset.
+pub fn delete_pending_verification_scope(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+    scope_address: &str,
+) {
+    pending_verification_scopes_store(storage, asset_type, verifier_address)
+        .remove(scope_address.as_bytes());
+}
+
+/// Lists the scope address of every asset of an asset type that is awaiting verification by the
+/// given verifier, in ascending order.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type for which the assets were onboarded.
+/// * `verifier_address` The bech32 address of the verifier for which to find assets.
+pub fn load_pending_verification_scope_addresses(
+    storage: &dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<Vec<String>> {
+    pending_verification_scopes_store_ro(storage, asset_type, verifier_address)
+        .range(None, None, Order::Ascending)
+        .map(|result| {
+            let (scope_address, _) = result?;
+            String::from_utf8(scope_address).map_err(|e| ContractError::generic(e.to_string()))
+        })
+        .collect()
+}
+
+/// Fetches a cosmwasm storage singleton that tracks [OnboardingStatistics](crate::core::types::onboarding_statistics::OnboardingStatistics)
+/// for all onboarding and verification processes in the contract.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn contract_statistics(storage: &mut dyn Storage) -> Singleton<'_, OnboardingStatistics> {
+    singleton(storage, CONTRACT_STATISTICS_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage singleton that tracks [OnboardingStatistics](crate::core::types::onboarding_statistics::OnboardingStatistics)
+/// for all onboarding and verification processes in the contract.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn contract_statistics_read(
+    storage: &dyn Storage,
+) -> ReadonlySingleton<'_, OnboardingStatistics> {
+    singleton_read(storage, CONTRACT_STATISTICS_KEY)
+}
+
+/// Fetches a cosmwasm storage bucket that tracks [OnboardingStatistics](crate::core::types::onboarding_statistics::OnboardingStatistics)
+/// keyed on the lowercase asset type, matching the case-insensitive keys of asset definitions.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn asset_type_statistics_store(storage: &mut dyn Storage) -> Bucket<'_, OnboardingStatistics> {
+    bucket(storage, ASSET_TYPE_STATISTICS_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage bucket that tracks [OnboardingStatistics](crate::core::types::onboarding_statistics::OnboardingStatistics)
+/// keyed on the lowercase asset type.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn asset_type_statistics_store_ro(
+    storage: &dyn Storage,
+) -> ReadonlyBucket<'_, OnboardingStatistics> {
+    bucket_read(storage, ASSET_TYPE_STATISTICS_KEY)
+}
+
+/// Fetches a cosmwasm storage bucket that tracks [OnboardingStatistics](crate::core::types::onboarding_statistics::OnboardingStatistics)
+/// keyed on verifier address.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn verifier_statistics_store(storage: &mut dyn Storage) -> Bucket<'_, OnboardingStatistics> {
+    bucket(storage, VERIFIER_STATISTICS_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage bucket that tracks [OnboardingStatistics](crate::core::types::onboarding_statistics::OnboardingStatistics)
+/// keyed on verifier address.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn verifier_statistics_store_ro(
+    storage: &dyn Storage,
+) -> ReadonlyBucket<'_, OnboardingStatistics> {
+    bucket_read(storage, VERIFIER_STATISTICS_KEY)
+}
+
+/// Fetches a cosmwasm storage bucket that tracks the total amount of fees paid out by the contract,
+/// keyed on denom.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn fees_paid_store(storage: &mut dyn Storage) -> Bucket<'_, Uint128> {
+    bucket(storage, FEES_PAID_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage bucket that tracks the total amount of fees paid out by the
+/// contract, keyed on denom.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn fees_paid_store_ro(storage: &dyn Storage) -> ReadonlyBucket<'_, Uint128> {
+    bucket_read(storage, FEES_PAID_KEY)
+}
+
+/// Increments the contract-wide, asset type, and verifier [OnboardingStatistics](crate::core::types::onboarding_statistics::OnboardingStatistics)
+/// to reflect a completed onboarding.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type that was onboarded.
+/// * `verifier_address` The bech32 address of the verifier that was selected during onboarding.
+/// * `is_retry` Whether or not the onboarding was for an asset that was previously denied.
+pub fn record_onboarding_statistics<S1: Into<String>, S2: Into<String>>(
+    storage: &mut dyn Storage,
+    asset_type: S1,
+    verifier_address: S2,
+    is_retry: bool,
+) -> AssetResult<()> {
+    update_statistics(storage, asset_type, verifier_address, |statistics| {
+        statistics.record_onboarding(is_retry)
+    })
+}
+
+/// Increments the contract-wide, asset type, and verifier [OnboardingStatistics](crate::core::types::onboarding_statistics::OnboardingStatistics)
+/// to reflect a completed verification.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type that was verified.
+/// * `verifier_address` The bech32 address of the verifier that performed verification.
+/// * `success` Whether or not the verifier approved the asset.
+pub fn record_verification_statistics<S1: Into<String>, S2: Into<String>>(
+    storage: &mut dyn Storage,
+    asset_type: S1,
+    verifier_address: S2,
+    success: bool,
+) -> AssetResult<()> {
+    update_statistics(storage, asset_type, verifier_address, |statistics| {
+        statistics.record_verification(success)
+    })
+}
+
+/// Adds each provided coin to the running total of fees paid out by the contract for its denom.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `fees` All funds that were paid out by the contract.
+pub fn record_fees_paid(storage: &mut dyn Storage, fees: &[Coin]) -> AssetResult<()> {
+    let mut store = fees_paid_store(storage);
+    for fee in fees {
+        store.update(fee.denom.as_bytes(), |total| -> AssetResult<Uint128> {
+            total
+                .unwrap_or_default()
+                .checked_add(fee.amount)
+                .map_err(|e| ContractError::Std(e.into()))
+        })?;
+    }
+    Ok(())
+}
+
+/// Fetches a cosmwasm storage bucket that tracks the funds held in the contract treasury, keyed on
+/// denom.  These funds are tracked separately from the contract's bank balance, which also includes
+/// onboarding fees held in escrow until verification completes.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn treasury_balances_store(storage: &mut dyn Storage) -> Bucket<'_, Uint128> {
+    bucket(storage, TREASURY_BALANCES_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage bucket that tracks the funds held in the contract treasury,
+/// keyed on denom.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn treasury_balances_store_ro(storage: &dyn Storage) -> ReadonlyBucket<'_, Uint128> {
+    bucket_read(storage, TREASURY_BALANCES_KEY)
+}
+
+/// Adds funds to the contract treasury's tracked balance for the coin's denom.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `funds` The funds that have been retained by the contract.
+pub fn deposit_to_treasury(storage: &mut dyn Storage, funds: &Coin) -> AssetResult<()> {
+    treasury_balances_store(storage).update(
+        funds.denom.as_bytes(),
+        |balance| -> AssetResult<Uint128> {
+            balance
+                .unwrap_or_default()
+                .checked_add(funds.amount)
+                .map_err(|e| ContractError::Std(e.into()))
+        },
+    )?;
+    Ok(())
+}
+
+/// Removes funds from the contract treasury's tracked balance for the coin's denom, producing an
+/// [InsufficientTreasuryBalance](crate::core::error::ContractError::InsufficientTreasuryBalance)
+/// error if the treasury does not hold enough funds.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `funds` The funds to remove from the treasury.
+pub fn withdraw_from_treasury(storage: &mut dyn Storage, funds: &Coin) -> AssetResult<()> {
+    treasury_balances_store(storage).update(
+        funds.denom.as_bytes(),
+        |balance| -> AssetResult<Uint128> {
+            let balance = balance.unwrap_or_default();
+            balance.checked_sub(funds.amount).map_err(|_| {
+                ContractError::InsufficientTreasuryBalance {
+                    requested: funds.to_string(),
+                    available: format!("{}{}", balance, funds.denom),
+                }
+            })
+        },
+    )?;
+    Ok(())
+}
+
+/// Fetches a cosmwasm storage bucket that tracks the number of assets each requestor has onboarded
+/// with a single verifier, keyed on requestor address.  These counts determine which of the
+/// verifier's [pricing_tiers](crate::core::types::verifier_detail::VerifierDetailV2::pricing_tiers)
+/// applies to a requestor.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `verifier_address` The bech32 address of the verifier for which counts are tracked.
+pub fn requestor_onboarding_counts_store<'a>(
+    storage: &'a mut dyn Storage,
+    verifier_address: &str,
+) -> Bucket<'a, u64> {
+    Bucket::multilevel(
+        storage,
+        &[REQUESTOR_ONBOARDING_COUNTS_KEY, verifier_address.as_bytes()],
+    )
+}
+
+/// Fetches a read-only cosmwasm storage bucket that tracks the number of assets each requestor has
+/// onboarded with a single verifier, keyed on requestor address.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `verifier_address` The bech32 address of the verifier for which counts are tracked.
+pub fn requestor_onboarding_counts_store_ro<'a>(
+    storage: &'a dyn Storage,
+    verifier_address: &str,
+) -> ReadonlyBucket<'a, u64> {
+    ReadonlyBucket::multilevel(
+        storage,
+        &[REQUESTOR_ONBOARDING_COUNTS_KEY, verifier_address.as_bytes()],
+    )
+}
+
+/// Loads the number of assets a requestor has onboarded with a verifier, defaulting to zero if the
+/// requestor has never onboarded with the verifier.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `verifier_address` The bech32 address of the verifier selected during onboarding.
+/// * `requestor_address` The bech32 address of the account that requested onboarding.
+pub fn load_requestor_onboarding_count(
+    storage: &dyn Storage,
+    verifier_address: &str,
+    requestor_address: &str,
+) -> AssetResult<u64> {
+    requestor_onboarding_counts_store_ro(storage, verifier_address)
+        .may_load(requestor_address.as_bytes())?
+        .unwrap_or_default()
+        .to_ok()
+}
+
+/// Increments the number of assets a requestor has onboarded with a verifier.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `verifier_address` The bech32 address of the verifier selected during onboarding.
+/// * `requestor_address` The bech32 address of the account that requested onboarding.
+pub fn increment_requestor_onboarding_count(
+    storage: &mut dyn Storage,
+    verifier_address: &str,
+    requestor_address: &str,
+) -> AssetResult<()> {
+    requestor_onboarding_counts_store(storage, verifier_address)
+        .update(requestor_address.as_bytes(), |count| -> AssetResult<u64> {
+            (count.unwrap_or_default() + 1).to_ok()
+        })?;
+    Ok(())
+}
+
+/// Fetches a cosmwasm storage bucket that holds every [ScheduledVerifierUpdate](crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate)
+/// that has not yet been applied, keyed on asset type.  Updates for each asset type are stored in
+/// the order in which they were scheduled.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn scheduled_verifier_updates_store(
+    storage: &mut dyn Storage,
+) -> Bucket<'_, Vec<ScheduledVerifierUpdate>> {
+    bucket(storage, SCHEDULED_VERIFIER_UPDATES_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage bucket that holds every [ScheduledVerifierUpdate](crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate)
+/// that has not yet been applied, keyed on asset type.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn scheduled_verifier_updates_store_ro(
+    storage: &dyn Storage,
+) -> ReadonlyBucket<'_, Vec<ScheduledVerifierUpdate>> {
+    bucket_read(storage, SCHEDULED_VERIFIER_UPDATES_KEY)
+}
+
+/// Loads every unapplied [ScheduledVerifierUpdate](crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate)
+/// for an asset type, in the order in which they were scheduled.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type for which to load updates.
+pub fn load_scheduled_verifier_updates(
+    storage: &dyn Storage,
+    asset_type: &str,
+) -> AssetResult<Vec<ScheduledVerifierUpdate>> {
+    scheduled_verifier_updates_store_ro(storage)
+        .may_load(asset_type.as_bytes())?
+        .unwrap_or_default()
+        .to_ok()
+}
+
+/// Appends a [ScheduledVerifierUpdate](crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate)
+/// to the updates stored for its asset type.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `update` The update to store.
+pub fn insert_scheduled_verifier_update(
+    storage: &mut dyn Storage,
+    update: &ScheduledVerifierUpdate,
+) -> AssetResult<()> {
+    scheduled_verifier_updates_store(storage).update(
+        update.asset_type.as_bytes(),
+        |updates| -> AssetResult<Vec<ScheduledVerifierUpdate>> {
+            let mut updates = updates.unwrap_or_default();
+            updates.push(update.to_owned());
+            updates.to_ok()
+        },
+    )?;
+    Ok(())
+}
+
+/// Removes and returns every [ScheduledVerifierUpdate](crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate)
+/// for an asset type that has become effective by the given block, retaining all updates that are
+/// still upcoming.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type for which to take effective updates.
+/// * `block` The block information for the current transaction.
+pub fn take_effective_scheduled_verifier_updates(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    block: &BlockInfo,
+) -> AssetResult<Vec<ScheduledVerifierUpdate>> {
+    let (effective, upcoming): (Vec<_>, Vec<_>) =
+        load_scheduled_verifier_updates(storage, asset_type)?
+            .into_iter()
+            .partition(|update| update.effective_at.has_passed(block));
+    if !effective.is_empty() {
+        if upcoming.is_empty() {
+            delete_scheduled_verifier_updates(storage, asset_type);
+        } else {
+            scheduled_verifier_updates_store(storage).save(asset_type.as_bytes(), &upcoming)?;
+        }
+    }
+    effective.to_ok()
+}
+
+/// Removes every [ScheduledVerifierUpdate](crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate)
+/// for an asset type.  This should be done when the asset type's definition is deleted.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type for which to remove all updates.
+pub fn delete_scheduled_verifier_updates(storage: &mut dyn Storage, asset_type: &str) {
+    scheduled_verifier_updates_store(storage).remove(asset_type.as_bytes());
+}
+
+/// Removes every [ScheduledVerifierUpdate](crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate)
+/// targeting a single verifier within an asset type, retaining the updates for all other verifiers.
+/// This should be done when the verifier is removed from the asset type's definition.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type for which to remove the verifier's updates.
+/// * `verifier_address` The bech32 address of the removed verifier.
+pub fn delete_scheduled_verifier_updates_for_verifier(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<()> {
+    let remaining = load_scheduled_verifier_updates(storage, asset_type)?
+        .into_iter()
+        .filter(|update| update.verifier.address != verifier_address)
+        .collect::<Vec<ScheduledVerifierUpdate>>();
+    if remaining.is_empty() {
+        delete_scheduled_verifier_updates(storage, asset_type);
+    } else {
+        scheduled_verifier_updates_store(storage).save(asset_type.as_bytes(), &remaining)?;
+    }
+    Ok(())
+}
+
+/// Fetches a cosmwasm storage bucket that holds the protocol fee charged to each scope during
+/// onboarding, keyed on scope address.  The fee is stored as a [FeeDestinationV2](crate::core::types::fee_destination::FeeDestinationV2)
+/// targeting the protocol fee collector, and is denominated in the onboarding denom of the scope's
+/// latest verifier detail.  Like the latest verifier detail, this ensures that the fee held in
+/// escrow is the fee that is paid out, even if the protocol fee changes before verification.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn latest_protocol_fee_store(storage: &mut dyn Storage) -> Bucket<'_, FeeDestinationV2> {
+    bucket(storage, LATEST_PROTOCOL_FEE_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage bucket that holds the protocol fee charged to each scope
+/// during onboarding, keyed on scope address.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn latest_protocol_fee_store_ro(storage: &dyn Storage) -> ReadonlyBucket<'_, FeeDestinationV2> {
+    bucket_read(storage, LATEST_PROTOCOL_FEE_KEY)
+}
+
+/// Inserts the protocol fee charged during a scope's onboarding into the contract's storage,
+/// leveraging the [latest_protocol_fee_store](self::latest_protocol_fee_store) function.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `scope_address` The scope address of the asset for which to store the fee.  This serves as
+/// the primary key for this bucket.
+/// * `protocol_fee` The collector and amount of the fee that was charged.
+pub fn insert_latest_protocol_fee<S: Into<String>>(
+    storage: &mut dyn Storage,
+    scope_address: S,
+    protocol_fee: &FeeDestinationV2,
+) -> AssetResult<()> {
+    latest_protocol_fee_store(storage)
+        .save(scope_address.into().as_bytes(), protocol_fee)
+        .map_err(ContractError::Std)
+}
+
+/// Deletes the protocol fee charged during a scope's onboarding from the contract's storage.  This
+/// should be done after verification, when the fee has been paid out to its collector.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `scope_address` The scope address of the asset for which the fee was stored.
+pub fn delete_latest_protocol_fee<S: Into<String>>(
+    storage: &mut dyn Storage,
+    scope_address: S,
+) -> AssetResult<()> {
+    latest_protocol_fee_store(storage).remove(scope_address.into().as_bytes());
+    Ok(())
+}
+
+/// Fetches a cosmwasm storage bucket that tracks the fees credited to a single recipient address,
+/// keyed on denom.  Fees are credited here instead of being sent immediately when a verifier
+/// elects to [accrue fees](crate::core::types::verifier_detail::VerifierDetailV2::accrue_fees).
+/// These funds are held by the contract until the recipient withdraws them.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `address` The bech32 address of the account to which the fees are owed.
+pub fn accrued_fees_store<'a>(storage: &'a mut dyn Storage, address: &str) -> Bucket<'a, Uint128> {
+    Bucket::multilevel(storage, &[ACCRUED_FEES_KEY, address.as_bytes()])
+}
+
+/// Fetches a read-only cosmwasm storage bucket that tracks the fees credited to a single recipient
+/// address, keyed on denom.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `address` The bech32 address of the account to which the fees are owed.
+pub fn accrued_fees_store_ro<'a>(
+    storage: &'a dyn Storage,
+    address: &str,
+) -> ReadonlyBucket<'a, Uint128> {
+    ReadonlyBucket::multilevel(storage, &[ACCRUED_FEES_KEY, address.as_bytes()])
+}
+
+/// Credits funds to a recipient's accrued fee balance for the coin's denom.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `address` The bech32 address of the account to which the fees are owed.
+/// * `funds` The funds to credit.
+pub fn accrue_fees(storage: &mut dyn Storage, address: &str, funds: &Coin) -> AssetResult<()> {
+    accrued_fees_store(storage, address).update(
+        funds.denom.as_bytes(),
+        |balance| -> AssetResult<Uint128> {
+            balance
+                .unwrap_or_default()
+                .checked_add(funds.amount)
+                .map_err(|e| ContractError::Std(e.into()))
+        },
+    )?;
+    Ok(())
+}
+
+/// Loads every nonzero accrued fee balance owed to a recipient, sorted by denom.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `address` The bech32 address of the account to which the fees are owed.
+pub fn load_accrued_fees(storage: &dyn Storage, address: &str) -> AssetResult<Vec<Coin>> {
+    accrued_fees_store_ro(storage, address)
+        .range(None, None, Order::Ascending)
+        .map(|result| {
+            let (denom, amount) = result?;
+            Coin {
+                denom: String::from_utf8(denom)
+                    .map_err(|e| ContractError::generic(e.to_string()))?,
+                amount,
+            }
+            .to_ok()
+        })
+        .filter(|result| !matches!(result, Ok(coin) if coin.amount.is_zero()))
+        .collect()
+}
+
+/// Removes every accrued fee balance owed to a recipient, returning the removed funds.  The caller
+/// is responsible for sending the returned funds to the recipient.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `address` The bech32 address of the account to which the fees are owed.
+pub fn withdraw_accrued_fees(storage: &mut dyn Storage, address: &str) -> AssetResult<Vec<Coin>> {
+    let balances = load_accrued_fees(storage, address)?;
+    let mut store = accrued_fees_store(storage, address);
+    for balance in &balances {
+        store.remove(balance.denom.as_bytes());
+    }
+    balances.to_ok()
+}
+
+/// Fetches a cosmwasm storage bucket that holds the bech32 addresses that have been granted each
+/// [ContractRole](crate::core::types::contract_role::ContractRole), keyed on the role's name.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn role_members_store(storage: &mut dyn Storage) -> Bucket<'_, Vec<String>> {
+    bucket(storage, ROLE_MEMBERS_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage bucket that holds the bech32 addresses that have been
+/// granted each [ContractRole](crate::core::types::contract_role::ContractRole), keyed on the
+/// role's name.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn role_members_store_ro(storage: &dyn Storage) -> ReadonlyBucket<'_, Vec<String>> {
+    bucket_read(storage, ROLE_MEMBERS_KEY)
+}
+
+/// Loads every bech32 address that has been granted a [ContractRole](crate::core::types::contract_role::ContractRole),
+/// sorted alphabetically.  The contract's admin is not included, despite implicitly holding every
+/// role.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `role` The role for which to load members.
+pub fn load_role_members(storage: &dyn Storage, role: &ContractRole) -> AssetResult<Vec<String>> {
+    role_members_store_ro(storage)
+        .may_load(role.name().as_bytes())?
+        .unwrap_or_default()
+        .to_ok()
+}
+
+/// Determines if a bech32 address has been granted a [ContractRole](crate::core::types::contract_role::ContractRole).
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `role` The role to check.
+/// * `address` The bech32 address to check.
+pub fn has_role(storage: &dyn Storage, role: &ContractRole, address: &str) -> AssetResult<bool> {
+    load_role_members(storage, role)?
+        .iter()
+        .any(|member| member == address)
+        .to_ok()
+}
+
+/// Grants a [ContractRole](crate::core::types::contract_role::ContractRole) to a bech32 address.
+/// Returns a [RecordAlreadyExists](crate::core::error::ContractError::RecordAlreadyExists) error
+/// if the address already holds the role.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `role` The role to grant.
+/// * `address` The bech32 address that will receive the role.
+pub fn insert_role_member(
+    storage: &mut dyn Storage,
+    role: &ContractRole,
+    address: &str,
+) -> AssetResult<()> {
+    let mut members = load_role_members(storage, role)?;
+    if members.iter().any(|member| member == address) {
+        return ContractError::RecordAlreadyExists {
+            explanation: format!("address [{}] already holds role [{}]", address, role),
+        }
+        .to_err();
+    }
+    members.push(address.to_string());
+    members.sort();
+    role_members_store(storage).save(role.name().as_bytes(), &members)?;
+    Ok(())
+}
+
+/// Revokes a [ContractRole](crate::core::types::contract_role::ContractRole) from a bech32
+/// address.  Returns a [NotFound](crate::core::error::ContractError::NotFound) error if the address
+/// does not hold the role.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `role` The role to revoke.
+/// * `address` The bech32 address that will lose the role.
+pub fn remove_role_member(
+    storage: &mut dyn Storage,
+    role: &ContractRole,
+    address: &str,
+) -> AssetResult<()> {
+    let mut members = load_role_members(storage, role)?;
+    if !members.iter().any(|member| member == address) {
+        return ContractError::NotFound {
+            explanation: format!("address [{}] does not hold role [{}]", address, role),
+        }
+        .to_err();
+    }
+    members.retain(|member| member != address);
+    if members.is_empty() {
+        role_members_store(storage).remove(role.name().as_bytes());
+    } else {
+        role_members_store(storage).save(role.name().as_bytes(), &members)?;
+    }
+    Ok(())
+}
+
+/// Fetches a cosmwasm storage bucket that holds every [QueuedChange](crate::core::types::timelocked_change::QueuedChange)
+/// that has not yet been executed or cancelled, keyed on the big-endian bytes of its change id.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn queued_changes_store(storage: &mut dyn Storage) -> Bucket<'_, QueuedChange> {
+    bucket(storage, QUEUED_CHANGES_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage bucket that holds every [QueuedChange](crate::core::types::timelocked_change::QueuedChange)
+/// that has not yet been executed or cancelled, keyed on the big-endian bytes of its change id.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn queued_changes_store_ro(storage: &dyn Storage) -> ReadonlyBucket<'_, QueuedChange> {
+    bucket_read(storage, QUEUED_CHANGES_KEY)
+}
+
+/// Queues a [TimelockedChange](crate::core::types::timelocked_change::TimelockedChange), assigning
+/// it the next available change id.  Change ids are never reused, even after a change is executed
+/// or cancelled.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `change` The change to queue.
+/// * `queued_by` The bech32 address of the account queueing the change.
+/// * `height` The block height of the current transaction.
+/// * `delay_blocks` The number of blocks that must pass before the change can be executed.
+pub fn insert_queued_change(
+    storage: &mut dyn Storage,
+    change: TimelockedChange,
+    queued_by: Addr,
+    height: u64,
+    delay_blocks: u64,
+) -> AssetResult<QueuedChange> {
+    let change_id = singleton_read::<u64>(storage, QUEUED_CHANGE_SEQUENCE_KEY)
+        .may_load()?
+        .unwrap_or_default()
+        + 1;
+    singleton(storage, QUEUED_CHANGE_SEQUENCE_KEY).save(&change_id)?;
+    let queued_change = QueuedChange::new(change_id, change, queued_by, height, delay_blocks);
+    queued_changes_store(storage).save(&change_id.to_be_bytes(), &queued_change)?;
+    queued_change.to_ok()
+}
+
+/// Loads a [QueuedChange](crate::core::types::timelocked_change::QueuedChange) by its change id.
+/// Returns a [NotFound](crate::core::error::ContractError::NotFound) error if no queued change
+/// exists with the id.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `change_id` The unique identifier of the queued change.
+pub fn load_queued_change(storage: &dyn Storage, change_id: u64) -> AssetResult<QueuedChange> {
+    match queued_changes_store_ro(storage).may_load(&change_id.to_be_bytes())? {
+        Some(queued_change) => queued_change.to_ok(),
+        None => ContractError::NotFound {
+            explanation: format!("no queued change exists with id [{}]", change_id),
+        }
+        .to_err(),
+    }
+}
+
+/// Loads every [QueuedChange](crate::core::types::timelocked_change::QueuedChange), in the order
+/// in which they were queued.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn load_queued_changes(storage: &dyn Storage) -> AssetResult<Vec<QueuedChange>> {
+    queued_changes_store_ro(storage)
+        .range(None, None, Order::Ascending)
+        .map(|result| result.map(|(_, queued_change)| queued_change))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ContractError::Std)
+}
+
+/// Removes a [QueuedChange](crate::core::types::timelocked_change::QueuedChange).  This should be
+/// done when the change is executed or cancelled.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `change_id` The unique identifier of the queued change.
+pub fn delete_queued_change(storage: &mut dyn Storage, change_id: u64) {
+    queued_changes_store(storage).remove(&change_id.to_be_bytes());
+}
+
+/// Fetches a cosmwasm storage bucket that holds the [VerifierSelfUpdateBounds](crate::core::types::verifier_self_update_bounds::VerifierSelfUpdateBounds)
+/// set by the admin for each verifier within a single asset type, keyed on verifier address.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the definition containing the verifiers.
+pub fn verifier_self_update_bounds_store<'a>(
+    storage: &'a mut dyn Storage,
+    asset_type: &str,
+) -> Bucket<'a, VerifierSelfUpdateBounds> {
+    Bucket::multilevel(
+        storage,
+        &[VERIFIER_SELF_UPDATE_BOUNDS_KEY, asset_type.as_bytes()],
+    )
+}
+
+/// Fetches a read-only cosmwasm storage bucket that holds the [VerifierSelfUpdateBounds](crate::core::types::verifier_self_update_bounds::VerifierSelfUpdateBounds)
+/// set by the admin for each verifier within a single asset type, keyed on verifier address.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type of the definition containing the verifiers.
+pub fn verifier_self_update_bounds_store_ro<'a>(
+    storage: &'a dyn Storage,
+    asset_type: &str,
+) -> ReadonlyBucket<'a, VerifierSelfUpdateBounds> {
+    ReadonlyBucket::multilevel(
+        storage,
+        &[VERIFIER_SELF_UPDATE_BOUNDS_KEY, asset_type.as_bytes()],
+    )
+}
+
+/// Loads the [VerifierSelfUpdateBounds](crate::core::types::verifier_self_update_bounds::VerifierSelfUpdateBounds)
+/// for a verifier within an asset type, defaulting to the most restrictive onboarding cost bounds
+/// if the admin has never set any.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type of the definition containing the verifier.
+/// * `verifier_address` The bech32 address of the verifier.
+pub fn load_verifier_self_update_bounds(
+    storage: &dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<VerifierSelfUpdateBounds> {
+    verifier_self_update_bounds_store_ro(storage, asset_type)
+        .may_load(verifier_address.as_bytes())?
+        .unwrap_or_default()
+        .to_ok()
+}
+
+/// Saves the [VerifierSelfUpdateBounds](crate::core::types::verifier_self_update_bounds::VerifierSelfUpdateBounds)
+/// for a verifier within an asset type, replacing any existing bounds.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the definition containing the verifier.
+/// * `verifier_address` The bech32 address of the verifier.
+/// * `bounds` The bounds to store.
+pub fn insert_verifier_self_update_bounds(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+    bounds: &VerifierSelfUpdateBounds,
+) -> AssetResult<()> {
+    verifier_self_update_bounds_store(storage, asset_type)
+        .save(verifier_address.as_bytes(), bounds)?;
+    Ok(())
+}
+
+/// Removes the [VerifierSelfUpdateBounds](crate::core::types::verifier_self_update_bounds::VerifierSelfUpdateBounds)
+/// stored for a verifier within an asset type.  This should be done when the verifier is removed
+/// from the asset definition.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the definition that contained the verifier.
+/// * `verifier_address` The bech32 address of the verifier.
+pub fn delete_verifier_self_update_bounds(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) {
+    verifier_self_update_bounds_store(storage, asset_type).remove(verifier_address.as_bytes());
+}
+
+/// Fetches a cosmwasm storage bucket that holds the collateral bonded by each verifier within a
+/// single asset type, keyed on verifier address.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the definition for which the bonds were posted.
+pub fn verifier_bonds_store<'a>(
+    storage: &'a mut dyn Storage,
+    asset_type: &str,
+) -> Bucket<'a, Coin> {
+    Bucket::multilevel(
+        storage,
+        &[VERIFIER_BONDS_KEY, asset_type.to_lowercase().as_bytes()],
+    )
+}
+
+/// Fetches a read-only cosmwasm storage bucket that holds the collateral bonded by each verifier
+/// within a single asset type, keyed on verifier address.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type of the definition for which the bonds were posted.
+pub fn verifier_bonds_store_ro<'a>(
+    storage: &'a dyn Storage,
+    asset_type: &str,
+) -> ReadonlyBucket<'a, Coin> {
+    ReadonlyBucket::multilevel(
+        storage,
+        &[VERIFIER_BONDS_KEY, asset_type.to_lowercase().as_bytes()],
+    )
+}
+
+/// Loads the collateral bonded by a verifier within an asset type, if any has been posted.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type of the definition for which the bond was posted.
+/// * `verifier_address` The bech32 address of the verifier.
+pub fn may_load_verifier_bond(
+    storage: &dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<Option<Coin>> {
+    verifier_bonds_store_ro(storage, asset_type)
+        .may_load(verifier_address.as_bytes())?
+        .to_ok()
+}
+
+/// Adds funds to the collateral bonded by a verifier within an asset type, returning the new total
+/// bond.  A bond can only be held in a single denom, so funds in a denom that differs from the
+/// existing bond are rejected.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the definition for which the bond is posted.
+/// * `verifier_address` The bech32 address of the verifier.
+/// * `funds` The funds to add to the bond.
+pub fn deposit_verifier_bond(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+    funds: &Coin,
+) -> AssetResult<Coin> {
+    verifier_bonds_store(storage, asset_type).update(
+        verifier_address.as_bytes(),
+        |bond| -> AssetResult<Coin> {
+            match bond {
+                Some(bond) if bond.denom != funds.denom => ContractError::InvalidFunds(format!(
+                    "the existing bond is held in [{}], but funds were provided in [{}]",
+                    bond.denom, funds.denom
+                ))
+                .to_err(),
+                Some(bond) => Coin {
+                    amount: bond
+                        .amount
+                        .checked_add(funds.amount)
+                        .map_err(|e| ContractError::Std(e.into()))?,
+                    denom: bond.denom,
+                }
+                .to_ok(),
+                None => funds.to_owned().to_ok(),
+            }
+        },
+    )
+}
+
+/// Removes funds from the collateral bonded by a verifier within an asset type, returning the
+/// remaining bond.  The bond is deleted from storage entirely if no funds remain.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the definition for which the bond was posted.
+/// * `verifier_address` The bech32 address of the verifier.
+/// * `funds` The funds to remove from the bond.
+pub fn deduct_verifier_bond(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+    funds: &Coin,
+) -> AssetResult<Coin> {
+    let bond = match may_load_verifier_bond(storage, asset_type, verifier_address)? {
+        Some(bond) => bond,
+        None => {
+            return ContractError::NotFound {
+                explanation: format!(
+                    "no bond exists for verifier {} for asset type {}",
+                    verifier_address, asset_type
+                ),
+            }
+            .to_err()
+        }
+    };
+    if bond.denom != funds.denom || bond.amount < funds.amount {
+        return ContractError::InvalidFunds(format!(
+            "cannot remove [{}] from a bond of [{}]",
+            funds, bond
+        ))
+        .to_err();
+    }
+    let remaining_bond = Coin {
+        amount: bond.amount - funds.amount,
+        denom: bond.denom,
+    };
+    if remaining_bond.amount.is_zero() {
+        verifier_bonds_store(storage, asset_type).remove(verifier_address.as_bytes());
+        delete_verifier_bond_unlock_height(storage, asset_type, verifier_address);
+    } else {
+        verifier_bonds_store(storage, asset_type)
+            .save(verifier_address.as_bytes(), &remaining_bond)?;
+    }
+    remaining_bond.to_ok()
+}
+
+/// Fetches a cosmwasm storage bucket that holds the block height at which each verifier's bond
+/// within a single asset type finishes unbonding, keyed on verifier address.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the definition for which the bonds were posted.
+pub fn verifier_bond_unlock_heights_store<'a>(
+    storage: &'a mut dyn Storage,
+    asset_type: &str,
+) -> Bucket<'a, u64> {
+    Bucket::multilevel(
+        storage,
+        &[
+            VERIFIER_BOND_UNLOCK_HEIGHTS_KEY,
+            asset_type.to_lowercase().as_bytes(),
+        ],
+    )
+}
+
+/// Fetches a read-only cosmwasm storage bucket that holds the block height at which each
+/// verifier's bond within a single asset type finishes unbonding, keyed on verifier address.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type of the definition for which the bonds were posted.
+pub fn verifier_bond_unlock_heights_store_ro<'a>(
+    storage: &'a dyn Storage,
+    asset_type: &str,
+) -> ReadonlyBucket<'a, u64> {
+    ReadonlyBucket::multilevel(
+        storage,
+        &[
+            VERIFIER_BOND_UNLOCK_HEIGHTS_KEY,
+            asset_type.to_lowercase().as_bytes(),
+        ],
+    )
+}
+
+/// Loads the block height at which a verifier's bond within an asset type finishes unbonding, if
+/// the verifier has requested its withdrawal.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type of the definition for which the bond was posted.
+/// * `verifier_address` The bech32 address of the verifier.
+pub fn may_load_verifier_bond_unlock_height(
+    storage: &dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<Option<u64>> {
+    verifier_bond_unlock_heights_store_ro(storage, asset_type)
+        .may_load(verifier_address.as_bytes())?
+        .to_ok()
+}
+
+/// Stores the block height at which a verifier's bond within an asset type finishes unbonding.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the definition for which the bond was posted.
+/// * `verifier_address` The bech32 address of the verifier.
+/// * `unlock_height` The block height at which the bond can be withdrawn.
+pub fn save_verifier_bond_unlock_height(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+    unlock_height: u64,
+) -> AssetResult<()> {
+    verifier_bond_unlock_heights_store(storage, asset_type)
+        .save(verifier_address.as_bytes(), &unlock_height)?
+        .to_ok()
+}
+
+/// Removes the unbonding block height of a verifier's bond within an asset type, if one exists.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_type` The asset type of the definition for which the bond was posted.
+/// * `verifier_address` The bech32 address of the verifier.
+pub fn delete_verifier_bond_unlock_height(
+    storage: &mut dyn Storage,
+    asset_type: &str,
+    verifier_address: &str,
+) {
+    verifier_bond_unlock_heights_store(storage, asset_type).remove(verifier_address.as_bytes());
+}
+
+/// Fetches a cosmwasm storage bucket that holds the global [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration)
+/// of each verifier, keyed on verifier address.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn verifier_registrations_store(storage: &mut dyn Storage) -> Bucket<'_, VerifierRegistration> {
+    bucket(storage, VERIFIER_REGISTRATIONS_KEY)
+}
+
+/// Fetches a read-only cosmwasm storage bucket that holds the global [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration)
+/// of each verifier, keyed on verifier address.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn verifier_registrations_store_ro(
+    storage: &dyn Storage,
+) -> ReadonlyBucket<'_, VerifierRegistration> {
+    bucket_read(storage, VERIFIER_REGISTRATIONS_KEY)
+}
+
+/// Finds the [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration)
+/// of a verifier, returning None if the verifier has never been registered.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `verifier_address` The bech32 address of the verifier.
+pub fn may_load_verifier_registration(
+    storage: &dyn Storage,
+    verifier_address: &str,
+) -> AssetResult<Option<VerifierRegistration>> {
+    verifier_registrations_store_ro(storage)
+        .may_load(verifier_address.as_bytes())?
+        .to_ok()
+}
+
+/// Loads the [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration)
+/// of a verifier, returning a [NotFound](crate::core::error::ContractError::NotFound) error if the
+/// verifier has never been registered.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `verifier_address` The bech32 address of the verifier.
+pub fn load_verifier_registration(
+    storage: &dyn Storage,
+    verifier_address: &str,
+) -> AssetResult<VerifierRegistration> {
+    match may_load_verifier_registration(storage, verifier_address)? {
+        Some(registration) => registration.to_ok(),
+        None => ContractError::NotFound {
+            explanation: format!("no registration exists for verifier {}", verifier_address),
+        }
+        .to_err(),
+    }
+}
+
+/// Saves the [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration)
+/// of a verifier, replacing any existing registration.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `registration` The registration to store, keyed on its address.
+pub fn insert_verifier_registration(
+    storage: &mut dyn Storage,
+    registration: &VerifierRegistration,
+) -> AssetResult<()> {
+    verifier_registrations_store(storage).save(registration.address.as_bytes(), registration)?;
+    Ok(())
+}
+
+/// Loads every [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2) that
+/// contains a verifier with the given address, ordered by asset type.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `verifier_address` The bech32 address of the verifier.
+pub fn load_asset_definitions_v2_by_verifier(
+    storage: &dyn Storage,
+    verifier_address: &str,
+) -> AssetResult<Vec<AssetDefinitionV2>> {
+    asset_definitions_v2()
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(|result| match result {
+            Ok((_, definition))
+                if definition
+                    .verifiers
+                    .iter()
+                    .any(|verifier| verifier.address == verifier_address) =>
+            {
+                apply_verifier_registrations(storage, definition).to_some()
+            }
+            Ok(_) => None,
+            Err(e) => ContractError::Std(e).to_err().to_some(),
+        })
+        .collect()
+}
+
+/// Produces the values of an asset definition after inheriting from each of its ancestors, as
+/// described by [inherit_from](crate::core::types::asset_definition::AssetDefinitionV2::inherit_from).
+/// Definitions without a [parent_asset_type](crate::core::types::asset_definition::AssetDefinitionV2::parent_asset_type)
+/// are returned unchanged.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `definition` The stored values of the asset definition to resolve.
+pub fn resolve_asset_definition_v2(
+    storage: &dyn Storage,
+    definition: AssetDefinitionV2,
+) -> AssetResult<AssetDefinitionV2> {
+    match &definition.parent_asset_type {
+        Some(parent_asset_type) => {
+            let parent = resolve_asset_definition_v2(
+                storage,
+                load_asset_definition_v2_by_type(storage, parent_asset_type)?,
+            )?;
+            apply_verifier_registrations(storage, definition.inherit_from(&parent))
+        }
+        None => apply_verifier_registrations(storage, definition),
+    }
+}
+
+/// Resolves the values of each [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
+/// in an asset definition that are owned by the verifier's [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration),
+/// as described by [apply_to](crate::core::types::verifier_registration::VerifierRegistration::apply_to).
+/// Verifiers without a registration are left unchanged.  Every function that loads asset
+/// definitions uses this, so the registry is the only source of these values.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `definition` The stored values of the asset definition.
+pub fn apply_verifier_registrations(
+    storage: &dyn Storage,
+    mut definition: AssetDefinitionV2,
+) -> AssetResult<AssetDefinitionV2> {
+    for verifier in definition.verifiers.iter_mut() {
+        if let Some(registration) = may_load_verifier_registration(storage, &verifier.address)? {
+            registration.apply_to(verifier);
+        }
+    }
+    definition.to_ok()
+}
+
+/// Produces the value of an asset definition that is written to storage.  Each registered
+/// verifier's entity detail is removed, and its fees are replaced with the registered fees unless
+/// it overrides them, because these values are always resolved from the registry by
+/// [apply_verifier_registrations](self::apply_verifier_registrations).
+///
+/// # Parameters
+///
+/// * `storage` A reference to the contract's internal storage.
+/// * `definition` The asset definition that will be stored.
+fn remove_registered_verifier_values(
+    storage: &dyn Storage,
+    definition: &AssetDefinitionV2,
+) -> AssetResult<AssetDefinitionV2> {
+    let mut definition = definition.to_owned();
+    for verifier in definition.verifiers.iter_mut() {
+        if let Some(registration) = may_load_verifier_registration(storage, &verifier.address)? {
+            registration.apply_to(verifier);
+            verifier.entity_detail = None;
+        }
+    }
+    definition.to_ok()
+}
+
+/// Loads the current version number of each ancestor of an asset definition in the asset type
+/// hierarchy, starting with its parent.  Definitions without a [parent_asset_type](crate::core::types::asset_definition::AssetDefinitionV2::parent_asset_type)
+/// produce an empty list.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `definition` The asset definition for which to load the ancestors' versions.
+pub fn load_ancestor_asset_definition_versions(
+    storage: &dyn Storage,
+    definition: &AssetDefinitionV2,
+) -> AssetResult<Vec<AssetDefinitionVersionReference>> {
+    let mut versions = vec![];
+    let mut parent_asset_type = definition.parent_asset_type.to_owned();
+    while let Some(asset_type) = parent_asset_type {
+        let parent = load_asset_definition_v2_by_type(storage, &asset_type)?;
+        versions.push(AssetDefinitionVersionReference::new(
+            &parent.asset_type,
+            load_asset_definition_version(storage, &parent.asset_type)?,
+        ));
+        parent_asset_type = parent.parent_asset_type;
+    }
+    versions.to_ok()
+}
+
+/// Loads the asset definition for an asset type, followed by the definitions of all of its
+/// descendants in the asset type hierarchy.  Each definition is resolved with [resolve_asset_definition_v2](self::resolve_asset_definition_v2),
+/// so the returned values include everything inherited from ancestors.  Returns an empty list if
+/// no definition exists for the asset type.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type at the top of the family.
+pub fn load_asset_definition_v2_family(
+    storage: &dyn Storage,
+    asset_type: &str,
+) -> AssetResult<Vec<AssetDefinitionV2>> {
+    load_unresolved_asset_definition_v2_family(storage, asset_type)?
+        .into_iter()
+        .map(|definition| resolve_asset_definition_v2(storage, definition))
+        .collect()
+}
+
+/// Loads the asset definition that owns the verifiers used by an asset type.  This is the asset
+/// type's own definition unless it has no verifiers, in which case it is the nearest ancestor in
+/// the asset type hierarchy that has verifiers, as described by [inherit_from](crate::core::types::asset_definition::AssetDefinitionV2::inherit_from).
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type for which to find the owner of the verifiers.
+pub fn load_verifier_owning_asset_definition_v2(
+    storage: &dyn Storage,
+    asset_type: &str,
+) -> AssetResult<AssetDefinitionV2> {
+    let mut definition = load_asset_definition_v2_by_type(storage, asset_type)?;
+    while definition.verifiers.is_empty() {
+        match &definition.parent_asset_type {
+            Some(parent_asset_type) => {
+                definition = load_asset_definition_v2_by_type(storage, parent_asset_type)?;
+            }
+            None => break,
+        }
+    }
+    definition.to_ok()
+}
+
+/// Loads an asset type, followed by each of its descendants in the asset type hierarchy that
+/// inherits its verifiers.  Descendants that define their own verifiers, and their descendants,
+/// are excluded.  Returns an empty list if no definition exists for the asset type.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type that owns the verifiers.
+pub fn load_verifier_inheriting_asset_types(
+    storage: &dyn Storage,
+    asset_type: &str,
+) -> AssetResult<Vec<String>> {
+    let mut asset_types: Vec<String> = vec![];
+    // The family is ordered by generation, so each parent is checked before its children
+    for (index, definition) in load_unresolved_asset_definition_v2_family(storage, asset_type)?
+        .into_iter()
+        .enumerate()
+    {
+        let inherits = index == 0
+            || (definition.verifiers.is_empty()
+                && definition
+                    .parent_asset_type
+                    .as_ref()
+                    .map(|parent| {
+                        asset_types
+                            .iter()
+                            .any(|asset_type| asset_type.to_lowercase() == parent.to_lowercase())
+                    })
+                    .unwrap_or(false));
+        if inherits {
+            asset_types.push(definition.asset_type);
+        }
     }
+    asset_types.to_ok()
 }
 
-/// Finds an existing asset definition in state by checking against the provided asset type,
-/// returning an Option that reflects whether or not the definition exists.
+/// Loads the stored values of an asset definition and all of its descendants, in the order
+/// described by [load_asset_definition_v2_family](self::load_asset_definition_v2_family), without
+/// resolving any inherited values.
 ///
 /// # Parameters
 ///
-/// * `storage` A reference to the contract's internal storage.
-/// * `asset_type` The unique name key [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type)
-/// for the requested asset definition.
-pub fn may_load_asset_definition_v2_by_type<S: Into<String>>(
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `asset_type` The asset type at the top of the family.
+fn load_unresolved_asset_definition_v2_family(
     storage: &dyn Storage,
-    asset_type: S,
-) -> AssetResult<Option<AssetDefinitionV2>> {
-    asset_definitions_v2()
-        // Coerce to lowercase to match how stored values are keyed
-        .may_load(storage, asset_type.into().to_lowercase().as_bytes())
-        .map_err(ContractError::Std)
+    asset_type: &str,
+) -> AssetResult<Vec<AssetDefinitionV2>> {
+    let root = match may_load_asset_definition_v2_by_type(storage, asset_type)? {
+        Some(root) => root,
+        None => return Ok(vec![]),
+    };
+    let mut remaining = asset_definitions_v2()
+        .range(storage, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, definition)| definition))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut family = vec![root];
+    let mut index = 0;
+    // Each pass collects the children of one family member, so every generation is appended after
+    // the generation before it
+    while index < family.len() {
+        let parent_asset_type = family[index].asset_type.to_lowercase();
+        let (children, others): (Vec<_>, Vec<_>) = remaining.into_iter().partition(|definition| {
+            definition
+                .parent_asset_type
+                .as_ref()
+                .map(|parent| parent.to_lowercase() == parent_asset_type)
+                .unwrap_or(false)
+        });
+        family.extend(children);
+        remaining = others;
+        index += 1;
+    }
+    family.to_ok()
 }
 
-/// Finds an existing asset definition by asset type, or returns an error if no definition is found.
+/// Ensures that the [parent_asset_type](crate::core::types::asset_definition::AssetDefinitionV2::parent_asset_type)
+/// of the given definition refers to an existing asset definition, and that saving the definition
+/// would not make it its own ancestor.
 ///
 /// # Parameters
 ///
 /// * `storage` A reference to the contract's internal storage.
-/// * `asset_type` The unique name key [asset_type](super::types::asset_definition::AssetDefinitionV2::asset_type)
-/// for the requested asset definition.
-pub fn load_asset_definition_v2_by_type<S: Into<String>>(
+/// * `definition` The asset definition that is about to be saved.
+fn check_asset_type_hierarchy(
     storage: &dyn Storage,
-    asset_type: S,
-) -> AssetResult<AssetDefinitionV2> {
-    let asset_type = asset_type.into();
-    if let Some(asset_definition) = may_load_asset_definition_v2_by_type(storage, &asset_type)? {
-        asset_definition.to_ok()
-    } else {
-        ContractError::RecordNotFound {
-            explanation: format!("no asset definition existed for asset type {}", asset_type,),
+    definition: &AssetDefinitionV2,
+) -> AssetResult<()> {
+    let asset_type = definition.asset_type.to_lowercase();
+    let mut ancestor_asset_type = definition.parent_asset_type.clone();
+    while let Some(current_asset_type) = ancestor_asset_type {
+        if current_asset_type.to_lowercase() == asset_type {
+            return ContractError::InvalidAssetTypeHierarchy {
+                asset_type: definition.asset_type.clone(),
+                explanation: "an asset type cannot be its own ancestor".to_string(),
+            }
+            .to_err();
         }
-        .to_err()
+        ancestor_asset_type =
+            match may_load_asset_definition_v2_by_type(storage, &current_asset_type)? {
+                Some(ancestor) => ancestor.parent_asset_type,
+                None => {
+                    return ContractError::InvalidAssetTypeHierarchy {
+                        asset_type: definition.asset_type.clone(),
+                        explanation: format!(
+                            "ancestor asset type [{}] does not exist",
+                            current_asset_type
+                        ),
+                    }
+                    .to_err()
+                }
+            };
     }
+    Ok(())
 }
 
-/// Finds an existing asset definition in state by checking against the provided scope spec address,
-/// returning an Option that reflects whether or not the definition exists.
+/// Fetches a cosmwasm storage bucket that tracks [VerifierMetrics](crate::core::types::verifier_metrics::VerifierMetrics)
+/// keyed on verifier address.
 ///
 /// # Parameters
 ///
-/// * `storage` A reference to the contract's internal storage.
-/// * `scope_spec_address` The unique address key [scope_spec_address](super::types::asset_definition::AssetDefinitionV2::scope_spec_address)
-/// for the requested asset definition.
-pub fn may_load_asset_definition_v2_by_scope_spec<S: Into<String>>(
-    storage: &dyn Storage,
-    scope_spec_address: S,
-) -> AssetResult<Option<AssetDefinitionV2>> {
-    // Coerce to lowercase to match how stored values are keyed
-    let spec_addr = scope_spec_address.into().to_lowercase();
-    asset_definitions_v2()
-        .idx
-        .scope_spec
-        .item(storage, spec_addr)
-        .map(|option| option.map(|(_, def)| def))
-        .map_err(ContractError::Std)
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn verifier_metrics_store(storage: &mut dyn Storage) -> Bucket<'_, VerifierMetrics> {
+    bucket(storage, VERIFIER_METRICS_KEY)
 }
 
-/// Finds an existing asset definition by scope spec address, or returns an error if no definition is
-/// found.
+/// Fetches a read-only cosmwasm storage bucket that tracks [VerifierMetrics](crate::core::types::verifier_metrics::VerifierMetrics)
+/// keyed on verifier address.
 ///
 /// # Parameters
 ///
-/// * `storage` A reference to the contract's internal storage.
-/// * `scope_spec_address` The unique address key [scope_spec_address](super::types::asset_definition::AssetDefinitionV2::scope_spec_address)
-/// for the requested asset definition.
-pub fn load_asset_definition_v2_by_scope_spec<S: Into<String>>(
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+pub fn verifier_metrics_store_ro(storage: &dyn Storage) -> ReadonlyBucket<'_, VerifierMetrics> {
+    bucket_read(storage, VERIFIER_METRICS_KEY)
+}
+
+/// Loads the [VerifierMetrics](crate::core::types::verifier_metrics::VerifierMetrics) for a
+/// verifier.  A verifier that has never had an asset onboarded to it receives the default, empty
+/// metrics.
+///
+/// # Parameters
+///
+/// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
+/// [DepsMutC](crate::util::aliases::DepsMutC).
+/// * `verifier_address` The bech32 address of the verifier.
+pub fn load_verifier_metrics(
     storage: &dyn Storage,
-    scope_spec_address: S,
-) -> AssetResult<AssetDefinitionV2> {
-    let scope_spec_address = scope_spec_address.into();
-    if let Some(asset_definition) =
-        may_load_asset_definition_v2_by_scope_spec(storage, &scope_spec_address)?
-    {
-        asset_definition.to_ok()
-    } else {
-        ContractError::RecordNotFound {
-            explanation: format!(
-                "no asset definition existed for scope spec address {}",
-                scope_spec_address
-            ),
-        }
-        .to_err()
-    }
+    verifier_address: &str,
+) -> AssetResult<VerifierMetrics> {
+    verifier_metrics_store_ro(storage)
+        .may_load(verifier_address.as_bytes())?
+        .unwrap_or_default()
+        .to_ok()
 }
 
-/// Attempts to delete an existing asset definition by asset type.  Returns an error if the
-/// definition does not exist or if the deletion fails.  Returns the asset type of the deleted
-/// definition on a successful deletion.
+/// Applies a change to the [VerifierMetrics](crate::core::types::verifier_metrics::VerifierMetrics)
+/// of a verifier, starting from the default metrics if none are stored yet.
 ///
 /// # Parameters
 ///
 /// * `storage` A mutable reference to the contract's internal storage.
-/// * `qualifier` An asset qualifier that can identify the [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
-/// to delete.
-pub fn delete_asset_definition_v2_by_qualifier(
+/// * `verifier_address` The bech32 address of the verifier.
+/// * `update_fn` The change to apply to the verifier's metrics.
+pub fn update_verifier_metrics<F: FnOnce(&mut VerifierMetrics)>(
     storage: &mut dyn Storage,
-    qualifier: &AssetQualifier,
-) -> AssetResult<String> {
-    let existing_asset_type = match qualifier {
-        AssetQualifier::AssetType(asset_type) => {
-            load_asset_definition_v2_by_type(storage, asset_type)
-        }
-        AssetQualifier::ScopeSpecAddress(scope_spec_address) => {
-            load_asset_definition_v2_by_scope_spec(storage, scope_spec_address)
-        }
-    }?
-    .asset_type;
-    asset_definitions_v2().remove(storage, existing_asset_type.to_lowercase().as_bytes())?;
-    Ok(existing_asset_type)
+    verifier_address: &str,
+    update_fn: F,
+) -> AssetResult<()> {
+    verifier_metrics_store(storage).update(
+        verifier_address.as_bytes(),
+        |metrics| -> AssetResult<VerifierMetrics> {
+            let mut metrics = metrics.unwrap_or_default();
+            update_fn(&mut metrics);
+            metrics.to_ok()
+        },
+    )?;
+    Ok(())
 }
 
-/// Fetches a mutable reference to the latest verifier detail storage from a [DepsMutC](crate::util::aliases::DepsMutC).
-/// This storage exists for the purpose of maintaining an accurate record of the [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
-/// each scope has when it is onboarded.  As those values can technically change between the time at
-/// which an asset is onboarded versus when it is verified, it is important that the values are
-/// stored until verification is complete in order to generate the correct fees to match the
-/// onboarding cost that is held in contract escrow during onboarding.
-///
-/// The other reason for this value being stored in contract storage versus on the scope attribute
-/// itself is because attributes written to the Provenance Blockchain have a set size limit.  Storing
-/// the entire [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2) on the
-/// attribute is a massive bloat to its size and can cause rejected attribute writes in certain
-/// circumstances.
+/// Fetches a cosmwasm storage bucket that holds the block height at which each scope awaiting
+/// verification was onboarded, keyed on scope address.  These values are used to measure how long
+/// verifiers take to process assets in their [VerifierMetrics](crate::core::types::verifier_metrics::VerifierMetrics).
 ///
 /// # Parameters
 ///
 /// * `storage` A mutable reference to the contract's internal storage.
-pub fn latest_verifier_detail_store(storage: &mut dyn Storage) -> Bucket<VerifierDetailV2> {
-    bucket(storage, LATEST_VERIFIER_DETAIL_KEY)
+pub fn latest_onboarding_height_store(storage: &mut dyn Storage) -> Bucket<'_, u64> {
+    bucket(storage, LATEST_ONBOARDING_HEIGHT_KEY)
 }
 
-/// Fetches a read-only cosmwasm storage bucket instance for loading verifier details for scope
-/// attributes.  This storage exists for the purpose of maintaining
-/// an accurate record of the VerifierDetailV2 each scope has when it is onboarded.  As those values
-/// can technically change between the time at which an asset is onboarded versus when it is verified,
-/// it is important that the values are stored until verification is complete in order to generate
-/// the correct fees to match the onboarding cost that is held in contract escrow during onboarding.
-///
-/// The other reason for this value being stored in contract storage versus on the scope attribute
-/// itself is because attributes written to the Provenance Blockchain have a set size limit.  Storing
-/// the entire [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2) on the
-/// attribute is a massive bloat to its size and can cause rejected attribute writes in certain
-/// circumstances.
+/// Fetches a read-only cosmwasm storage bucket that holds the block height at which each scope
+/// awaiting verification was onboarded, keyed on scope address.
 ///
 /// # Parameters
 ///
 /// * `storage` A reference to the storage from a [DepsC](crate::util::aliases::DepsC) or
 /// [DepsMutC](crate::util::aliases::DepsMutC).
-pub fn latest_verifier_detail_store_ro(storage: &dyn Storage) -> ReadonlyBucket<VerifierDetailV2> {
-    bucket_read(storage, LATEST_VERIFIER_DETAIL_KEY)
+pub fn latest_onboarding_height_store_ro(storage: &dyn Storage) -> ReadonlyBucket<'_, u64> {
+    bucket_read(storage, LATEST_ONBOARDING_HEIGHT_KEY)
 }
 
-/// Inserts a verifier detail into the contract's storage, leveraging the
-/// [latest_verifier_detail_store](self::latest_verifier_detail_store) function.
+/// Removes the onboarding block height stored for a scope, returning it if it was present.  This
+/// should be done when the scope is no longer awaiting verification.
 ///
 /// # Parameters
 ///
 /// * `storage` A mutable reference to the contract's internal storage.
-/// * `scope_address` The scope address of the asset for which to store the detail.  This serves
-/// as the primary key for this bucket.
-/// * `verifier_detail` The verifier detail to store.  This represents the latest version of the
-/// verifier detail that was used when the asset was onboarded into the contract for verification.
-pub fn insert_latest_verifier_detail<S: Into<String>>(
+/// * `scope_address` The scope address of the asset for which the height was stored.
+pub fn take_latest_onboarding_height(
     storage: &mut dyn Storage,
-    scope_address: S,
-    verifier_detail: &VerifierDetailV2,
-) -> AssetResult<()> {
-    latest_verifier_detail_store(storage)
-        .save(scope_address.into().as_bytes(), verifier_detail)
-        .map_err(ContractError::Std)
+    scope_address: &str,
+) -> AssetResult<Option<u64>> {
+    let height = latest_onboarding_height_store_ro(storage).may_load(scope_address.as_bytes())?;
+    latest_onboarding_height_store(storage).remove(scope_address.as_bytes());
+    height.to_ok()
 }
 
-/// Deletes an existing [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
-/// from the contract's internal latest verifier detail storage.  This leverages the
-/// [latest_verifier_detail_store](self::latest_verifier_detail_store) function.  This function is
-/// important, as contract storage should not retain any bloat.  All verifier details should be
-/// deleted after a verification has been completed successfully, as they are a historical record
-/// that is not relevant.
-///
-/// # Parameters
-///
-/// * `storage` A mutable reference to the contract's internal storage.
-/// * `scope_address` The scope address of the asset for which to store the detail.  This serves
-/// as the primary key for this bucket.
-pub fn delete_latest_verifier_detail<S: Into<String>>(
+fn update_statistics<S1: Into<String>, S2: Into<String>, F: Fn(&mut OnboardingStatistics)>(
     storage: &mut dyn Storage,
-    scope_address: S,
+    asset_type: S1,
+    verifier_address: S2,
+    update_fn: F,
 ) -> AssetResult<()> {
-    latest_verifier_detail_store(storage).remove(scope_address.into().as_bytes());
+    let updater = |statistics: Option<OnboardingStatistics>| -> AssetResult<OnboardingStatistics> {
+        let mut statistics = statistics.unwrap_or_default();
+        update_fn(&mut statistics);
+        statistics.to_ok()
+    };
+    // Singletons cannot be updated before they are initialized, so the contract-wide value must
+    // be loaded and saved manually
+    let totals = updater(contract_statistics_read(storage).may_load()?)?;
+    contract_statistics(storage).save(&totals)?;
+    // Requestors may reference an asset type in any casing, so the counters are keyed on the
+    // lowercase asset type to avoid splitting them across casings
+    asset_type_statistics_store(storage)
+        .update(asset_type.into().to_lowercase().as_bytes(), updater)?;
+    verifier_statistics_store(storage).update(verifier_address.into().as_bytes(), updater)?;
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use cosmwasm_std::coin;
     use provwasm_mocks::mock_dependencies;
 
     use crate::core::error::ContractError;
     use crate::core::state::{
         delete_asset_definition_v2_by_qualifier, delete_latest_verifier_detail,
-        insert_asset_definition_v2, insert_latest_verifier_detail, latest_verifier_detail_store_ro,
+        delete_pending_verification_scope, insert_asset_definition_v2,
+        insert_latest_verifier_detail, insert_pending_verification_scope,
+        latest_verifier_detail_store_ro, load_asset_definition_history,
         load_asset_definition_v2_by_scope_spec, load_asset_definition_v2_by_type,
+        load_asset_definition_version, load_pending_verification_scope_addresses,
         may_load_asset_definition_v2_by_scope_spec, may_load_asset_definition_v2_by_type,
-        replace_asset_definition_v2,
+        may_load_asset_definition_version, replace_asset_definition_v2,
+        resolve_asset_definition_v2,
     };
     use crate::core::types::asset_definition::AssetDefinitionV2;
+    use crate::core::types::asset_definition_version::AssetDefinitionVersion;
     use crate::core::types::asset_qualifier::AssetQualifier;
+    use crate::testutil::test_constants::DEFAULT_ADMIN_ADDRESS;
     use crate::testutil::test_utilities::get_default_verifier_detail;
+    use crate::util::traits::OptionExtensions;
 
     #[test]
     fn test_insert_asset_definition() {
@@ -430,7 +2519,9 @@ mod tests {
     fn test_replace_asset_definition() {
         let mut deps = mock_dependencies(&[]);
         let mut def = AssetDefinitionV2::new("heloc", "heloc-scope-spec", vec![]);
-        let error = replace_asset_definition_v2(deps.as_mut().storage, &def).unwrap_err();
+        let error =
+            replace_asset_definition_v2(deps.as_mut().storage, &def, 12345, DEFAULT_ADMIN_ADDRESS)
+                .unwrap_err();
         match error {
             ContractError::RecordNotFound { explanation } => {
                 assert_eq!(
@@ -443,7 +2534,7 @@ mod tests {
         insert_asset_definition_v2(deps.as_mut().storage, &def)
             .expect("insert should work correctly");
         def.scope_spec_address = "new-spec-address".to_string();
-        replace_asset_definition_v2(deps.as_mut().storage, &def)
+        replace_asset_definition_v2(deps.as_mut().storage, &def, 12345, DEFAULT_ADMIN_ADDRESS)
             .expect("update should work correctly");
         let loaded_asset_definition =
             load_asset_definition_v2_by_type(deps.as_ref().storage, &def.asset_type)
@@ -454,6 +2545,253 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_replace_asset_definition_records_versions() {
+        let mut deps = mock_dependencies(&[]);
+        let original_def = AssetDefinitionV2::new("heloc", "heloc-scope-spec", vec![]);
+        insert_asset_definition_v2(deps.as_mut().storage, &original_def)
+            .expect("insert should work correctly");
+        assert_eq!(
+            1,
+            load_asset_definition_version(deps.as_ref().storage, "heloc").unwrap(),
+            "a newly inserted asset definition should start at version 1",
+        );
+        replace_asset_definition_v2(
+            deps.as_mut().storage,
+            &original_def,
+            12345,
+            DEFAULT_ADMIN_ADDRESS,
+        )
+        .expect("an identical replace should work correctly");
+        assert_eq!(
+            1,
+            load_asset_definition_version(deps.as_ref().storage, "heloc").unwrap(),
+            "replacing an asset definition with identical values should not create a new version",
+        );
+        let updated_def = AssetDefinitionV2 {
+            enabled: false,
+            ..original_def.clone()
+        };
+        replace_asset_definition_v2(
+            deps.as_mut().storage,
+            &updated_def,
+            12346,
+            DEFAULT_ADMIN_ADDRESS,
+        )
+        .expect("update should work correctly");
+        assert_eq!(
+            vec![
+                AssetDefinitionVersion::replaced(
+                    1,
+                    original_def.clone(),
+                    12346,
+                    DEFAULT_ADMIN_ADDRESS
+                ),
+                AssetDefinitionVersion::current(2, updated_def.clone()),
+            ],
+            load_asset_definition_history(deps.as_ref().storage, "HELOC").unwrap(),
+            "the previous values should be kept as version 1 and the new values should be version 2",
+        );
+        delete_asset_definition_v2_by_qualifier(
+            deps.as_mut().storage,
+            &AssetQualifier::asset_type("heloc"),
+            12347,
+            "other-admin",
+        )
+        .expect("delete should work correctly");
+        assert!(
+            may_load_asset_definition_version(deps.as_ref().storage, "heloc", 3)
+                .unwrap()
+                .is_none(),
+            "no current version should exist after the asset definition is deleted",
+        );
+        insert_asset_definition_v2(deps.as_mut().storage, &original_def)
+            .expect("re-adding the asset definition should work correctly");
+        assert_eq!(
+            vec![
+                AssetDefinitionVersion::replaced(
+                    1,
+                    original_def.clone(),
+                    12346,
+                    DEFAULT_ADMIN_ADDRESS
+                ),
+                AssetDefinitionVersion::replaced(2, updated_def, 12347, "other-admin"),
+                AssetDefinitionVersion::current(3, original_def),
+            ],
+            load_asset_definition_history(deps.as_ref().storage, "heloc").unwrap(),
+            "a re-added asset definition should continue the numbering of its deleted versions",
+        );
+    }
+
+    #[test]
+    fn test_additional_scope_spec_addresses() {
+        let mut deps = mock_dependencies(&[]);
+        let heloc = AssetDefinitionV2 {
+            additional_scope_spec_addresses: vec!["heloc-scope-spec-v2".to_string()],
+            ..AssetDefinitionV2::new("heloc", "heloc-scope-spec", vec![])
+        };
+        insert_asset_definition_v2(deps.as_mut().storage, &heloc)
+            .expect("the heloc definition should insert without error");
+        assert_eq!(
+            heloc,
+            load_asset_definition_v2_by_scope_spec(deps.as_ref().storage, "HELOC-SCOPE-SPEC-V2")
+                .expect("the definition should load by its additional scope spec address"),
+            "the additional scope spec address should resolve to the heloc definition, regardless of casing",
+        );
+        let mortgage = AssetDefinitionV2 {
+            additional_scope_spec_addresses: vec!["heloc-scope-spec-v2".to_string()],
+            ..AssetDefinitionV2::new("mortgage", "mortgage-scope-spec", vec![])
+        };
+        assert!(
+            matches!(
+                insert_asset_definition_v2(deps.as_mut().storage, &mortgage).unwrap_err(),
+                ContractError::RecordAlreadyExists { .. },
+            ),
+            "a definition reusing another definition's additional scope spec address should be rejected",
+        );
+        let mortgage = AssetDefinitionV2::new("mortgage", "heloc-scope-spec-v2", vec![]);
+        assert!(
+            matches!(
+                insert_asset_definition_v2(deps.as_mut().storage, &mortgage).unwrap_err(),
+                ContractError::RecordAlreadyExists { .. },
+            ),
+            "a definition using another definition's additional scope spec address as its primary scope spec should be rejected",
+        );
+        let mortgage = AssetDefinitionV2 {
+            additional_scope_spec_addresses: vec!["heloc-scope-spec".to_string()],
+            ..AssetDefinitionV2::new("mortgage", "mortgage-scope-spec", vec![])
+        };
+        assert!(
+            matches!(
+                insert_asset_definition_v2(deps.as_mut().storage, &mortgage).unwrap_err(),
+                ContractError::RecordAlreadyExists { .. },
+            ),
+            "a definition using another definition's primary scope spec address as an additional scope spec should be rejected",
+        );
+        let updated_heloc = AssetDefinitionV2 {
+            additional_scope_spec_addresses: vec!["heloc-scope-spec-v3".to_string()],
+            ..heloc
+        };
+        replace_asset_definition_v2(
+            deps.as_mut().storage,
+            &updated_heloc,
+            12345,
+            DEFAULT_ADMIN_ADDRESS,
+        )
+        .expect("the heloc definition should be replaced without error");
+        assert!(
+            may_load_asset_definition_v2_by_scope_spec(
+                deps.as_ref().storage,
+                "heloc-scope-spec-v2"
+            )
+            .unwrap()
+            .is_none(),
+            "a removed additional scope spec address should no longer resolve to a definition",
+        );
+        assert_eq!(
+            updated_heloc,
+            load_asset_definition_v2_by_scope_spec(deps.as_ref().storage, "heloc-scope-spec-v3")
+                .expect("the definition should load by its new additional scope spec address"),
+            "the new additional scope spec address should resolve to the heloc definition",
+        );
+        delete_asset_definition_v2_by_qualifier(
+            deps.as_mut().storage,
+            &AssetQualifier::scope_spec_address("heloc-scope-spec-v3"),
+            12346,
+            DEFAULT_ADMIN_ADDRESS,
+        )
+        .expect("the heloc definition should be deleted by its additional scope spec address");
+        let mortgage = AssetDefinitionV2 {
+            additional_scope_spec_addresses: vec!["heloc-scope-spec-v3".to_string()],
+            ..AssetDefinitionV2::new("mortgage", "mortgage-scope-spec", vec![])
+        };
+        insert_asset_definition_v2(deps.as_mut().storage, &mortgage).expect(
+            "the additional scope spec address of a deleted definition should be available for reuse",
+        );
+    }
+
+    #[test]
+    fn test_asset_type_hierarchy() {
+        let mut deps = mock_dependencies(&[]);
+        let mortgage = AssetDefinitionV2 {
+            minimum_verifier_bond: coin(100, "nhash").to_some(),
+            ..AssetDefinitionV2::new(
+                "mortgage",
+                "mortgage-scope-spec",
+                vec![get_default_verifier_detail()],
+            )
+        };
+        let heloc = AssetDefinitionV2 {
+            parent_asset_type: "MORTGAGE".to_string().to_some(),
+            ..AssetDefinitionV2::new("heloc", "heloc-scope-spec", vec![])
+        };
+        assert!(
+            matches!(
+                insert_asset_definition_v2(deps.as_mut().storage, &heloc).unwrap_err(),
+                ContractError::InvalidAssetTypeHierarchy { .. },
+            ),
+            "a definition should not be inserted before its parent exists",
+        );
+        insert_asset_definition_v2(deps.as_mut().storage, &mortgage)
+            .expect("the mortgage definition should insert without error");
+        insert_asset_definition_v2(deps.as_mut().storage, &heloc)
+            .expect("the heloc definition should insert after its parent exists");
+        assert_eq!(
+            AssetDefinitionV2 {
+                verifiers: mortgage.verifiers.clone(),
+                minimum_verifier_bond: mortgage.minimum_verifier_bond.clone(),
+                ..heloc.clone()
+            },
+            resolve_asset_definition_v2(deps.as_ref().storage, heloc.clone())
+                .expect("the heloc definition should resolve without error"),
+            "the heloc definition should inherit the values it leaves empty from its parent",
+        );
+        let cyclical_mortgage = AssetDefinitionV2 {
+            parent_asset_type: "heloc".to_string().to_some(),
+            ..mortgage.clone()
+        };
+        assert!(
+            matches!(
+                replace_asset_definition_v2(
+                    deps.as_mut().storage,
+                    &cyclical_mortgage,
+                    12345,
+                    DEFAULT_ADMIN_ADDRESS,
+                )
+                .unwrap_err(),
+                ContractError::InvalidAssetTypeHierarchy { .. },
+            ),
+            "a definition should not be able to become a descendant of its own child",
+        );
+        assert!(
+            matches!(
+                delete_asset_definition_v2_by_qualifier(
+                    deps.as_mut().storage,
+                    &AssetQualifier::asset_type("mortgage"),
+                    12345,
+                    DEFAULT_ADMIN_ADDRESS,
+                )
+                .unwrap_err(),
+                ContractError::InvalidAssetTypeHierarchy { .. },
+            ),
+            "a definition should not be deleted while it has children",
+        );
+        delete_asset_definition_v2_by_qualifier(
+            deps.as_mut().storage,
+            &AssetQualifier::asset_type("heloc"),
+            12345,
+            DEFAULT_ADMIN_ADDRESS,
+        )
+        .expect("the childless heloc definition should be deleted without error");
+        delete_asset_definition_v2_by_qualifier(
+            deps.as_mut().storage,
+            &AssetQualifier::asset_type("mortgage"),
+            12345,
+            DEFAULT_ADMIN_ADDRESS,
+        )
+        .expect("the mortgage definition should be deleted once it has no children");
+    }
+
     #[test]
     fn test_may_load_asset_definition_by_type() {
         let mut deps = mock_dependencies(&[]);
@@ -574,6 +2912,8 @@ mod tests {
         delete_asset_definition_v2_by_qualifier(
             deps.as_mut().storage,
             &AssetQualifier::asset_type(&def.asset_type),
+            12345,
+            DEFAULT_ADMIN_ADDRESS,
         )
         .expect("expected the deletion to succeed");
         let err = load_asset_definition_v2_by_type(deps.as_ref().storage, &def.asset_type)
@@ -617,6 +2957,8 @@ mod tests {
         let err = delete_asset_definition_v2_by_qualifier(
             deps.as_mut().storage,
             &AssetQualifier::asset_type("fake-type"),
+            12345,
+            DEFAULT_ADMIN_ADDRESS,
         )
         .expect_err("expected an error to occur when attempting to delete a missing asset type");
         assert!(
@@ -647,6 +2989,8 @@ mod tests {
         delete_asset_definition_v2_by_qualifier(
             deps.as_mut().storage,
             &AssetQualifier::scope_spec_address(&def.scope_spec_address),
+            12345,
+            DEFAULT_ADMIN_ADDRESS,
         )
         .expect("expected the deletion to succeed");
         let err = load_asset_definition_v2_by_type(deps.as_ref().storage, &def.asset_type)
@@ -690,6 +3034,8 @@ mod tests {
         let err = delete_asset_definition_v2_by_qualifier(
             deps.as_mut().storage,
             &AssetQualifier::scope_spec_address("fake-scope-spec-address"),
+            12345,
+            DEFAULT_ADMIN_ADDRESS,
         )
         .expect_err(
             "expected an error to occur when attempting to delete by a missing scope spec address",
@@ -748,4 +3094,36 @@ mod tests {
             "expected an attempt to load a verifier detail after it is deleted to provide a None option",
         );
     }
+
+    #[test]
+    fn test_pending_verification_scopes_are_indexed_by_asset_type_and_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        for (asset_type, verifier_address, scope_address) in [
+            ("heloc", "verifier-a", "scope-2"),
+            ("HELOC", "verifier-a", "scope-1"),
+            ("heloc", "verifier-b", "scope-3"),
+            ("mortgage", "verifier-a", "scope-4"),
+        ] {
+            insert_pending_verification_scope(
+                deps.as_mut().storage,
+                asset_type,
+                verifier_address,
+                scope_address,
+            )
+            .expect("indexing the pending scope should succeed");
+        }
+        assert_eq!(
+            vec!["scope-1".to_string(), "scope-2".to_string()],
+            load_pending_verification_scope_addresses(deps.as_ref().storage, "Heloc", "verifier-a")
+                .expect("loading the pending scopes should succeed"),
+            "only the scopes of the verifier and asset type should be listed, regardless of the casing of the asset type",
+        );
+        delete_pending_verification_scope(deps.as_mut().storage, "heloc", "verifier-a", "scope-1");
+        assert_eq!(
+            vec!["scope-2".to_string()],
+            load_pending_verification_scope_addresses(deps.as_ref().storage, "heloc", "verifier-a")
+                .expect("loading the pending scopes should succeed"),
+            "a deleted scope should no longer be listed",
+        );
+    }
 }
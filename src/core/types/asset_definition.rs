@@ -1,6 +1,9 @@
+use cosmwasm_std::Coin;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::core::types::fee_discount::FeeDiscount;
+use crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate;
 use crate::core::types::serialized_enum::SerializedEnum;
 use crate::core::types::verifier_detail::VerifierDetailV2;
 use crate::{
@@ -26,6 +29,29 @@ pub struct AssetDefinitionV2 {
     /// Indicates whether or not the asset definition is enabled for use in the contract.  If disabled,
     /// requests to onboard assets of this type will be rejected.
     pub enabled: bool,
+    /// Requestor addresses that are charged a reduced onboarding cost, regardless of the verifier
+    /// they select.  A discount defined on the selected verifier's [fee_discounts](super::verifier_detail::VerifierDetailV2::fee_discounts)
+    /// takes precedence over these values.
+    #[serde(default)]
+    pub fee_discounts: Vec<FeeDiscount>,
+    /// The bond that a verifier must have posted for this asset type before assets can be onboarded
+    /// with it.  Higher-value asset types can use this value to ensure that their verifiers have
+    /// collateral at stake that the admin can slash.  If omitted, no bond is required.
+    #[serde(default)]
+    pub minimum_verifier_bond: Option<Coin>,
+    /// Links to scope specifications, beyond the [scope_spec_address](self::AssetDefinitionV2::scope_spec_address),
+    /// that also define this asset type.  This allows multiple revisions of a scope specification
+    /// to be onboarded as the same asset type, like during a migration from one spec version to the
+    /// next.  Like the primary scope spec address, each value must be unique across all instances
+    /// persisted in contract storage.
+    #[serde(default)]
+    pub additional_scope_spec_addresses: Vec<String>,
+    /// The asset type of a parent definition, allowing related asset types to be grouped into a
+    /// family.  A child definition inherits its parent's verifiers, fee discounts and minimum
+    /// verifier bond whenever it leaves those values empty, and is only enabled while its parent
+    /// is enabled.  Inherited values are resolved through each ancestor in turn.
+    #[serde(default)]
+    pub parent_asset_type: Option<String>,
 }
 impl AssetDefinitionV2 {
     /// Constructs a new instance of AssetDefinitionV2, setting enabled to `true` by default.
@@ -45,6 +71,111 @@ impl AssetDefinitionV2 {
             scope_spec_address: scope_spec_address.into(),
             verifiers,
             enabled: true,
+            fee_discounts: vec![],
+            minimum_verifier_bond: None,
+            additional_scope_spec_addresses: vec![],
+            parent_asset_type: None,
+        }
+    }
+
+    /// Produces the values of this definition after inheriting from its parent definition.  Any
+    /// verifiers, fee discounts or minimum verifier bond left empty in this definition are taken
+    /// from the parent, and the result is only enabled if both definitions are enabled.  The
+    /// parent should already have inherited from its own ancestors.
+    ///
+    /// # Parameters
+    ///
+    /// * `parent` The resolved definition of this definition's parent asset type.
+    pub fn inherit_from(mut self, parent: &AssetDefinitionV2) -> Self {
+        if self.verifiers.is_empty() {
+            self.verifiers = parent.verifiers.to_owned();
+        }
+        if self.fee_discounts.is_empty() {
+            self.fee_discounts = parent.fee_discounts.to_owned();
+        }
+        if self.minimum_verifier_bond.is_none() {
+            self.minimum_verifier_bond = parent.minimum_verifier_bond.to_owned();
+        }
+        self.enabled = self.enabled && parent.enabled;
+        self
+    }
+
+    /// Produces every scope specification address that defines this asset type, starting with the
+    /// primary [scope_spec_address](self::AssetDefinitionV2::scope_spec_address).
+    pub fn get_scope_spec_addresses(&self) -> Vec<&str> {
+        std::iter::once(&self.scope_spec_address)
+            .chain(self.additional_scope_spec_addresses.iter())
+            .map(|address| address.as_str())
+            .collect()
+    }
+
+    /// Determines if a scope with the given specification address can be onboarded as this asset
+    /// type.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope_spec_address` The bech32 address of the scope's specification.
+    pub fn accepts_scope_spec(&self, scope_spec_address: &str) -> bool {
+        self.get_scope_spec_addresses()
+            .into_iter()
+            .any(|address| address == scope_spec_address)
+    }
+
+    /// Replaces each verifier targeted by the given scheduled updates with the update's verifier
+    /// detail, in order.  Updates targeting a verifier that no longer exists in this asset
+    /// definition are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// * `updates` The effective updates to apply.
+    pub fn apply_scheduled_verifier_updates(&mut self, updates: &[ScheduledVerifierUpdate]) {
+        for update in updates {
+            if let Some(verifier) = self
+                .verifiers
+                .iter_mut()
+                .find(|verifier| verifier.address == update.verifier.address)
+            {
+                *verifier = update.verifier.to_owned();
+            }
+        }
+    }
+
+    /// Determines the discount, in basis points, that applies to a requestor that selects the given
+    /// verifier.  Discounts defined on the verifier take precedence over discounts defined on this
+    /// asset definition.
+    ///
+    /// # Parameters
+    ///
+    /// * `verifier` The verifier detail selected by the requestor.
+    /// * `requestor_address` The bech32 address of the account requesting onboarding.
+    pub fn get_fee_discount_basis_points(
+        &self,
+        verifier: &VerifierDetailV2,
+        requestor_address: &str,
+    ) -> Option<u16> {
+        verifier
+            .fee_discounts
+            .iter()
+            .chain(self.fee_discounts.iter())
+            .find(|discount| discount.requestor_address == requestor_address)
+            .map(|discount| discount.discount_basis_points)
+    }
+
+    /// Produces the verifier detail that governs the fees charged to a requestor, after applying
+    /// any discount found by [get_fee_discount_basis_points](self::AssetDefinitionV2::get_fee_discount_basis_points).
+    ///
+    /// # Parameters
+    ///
+    /// * `verifier` The verifier detail selected by the requestor.
+    /// * `requestor_address` The bech32 address of the account requesting onboarding.
+    pub fn get_discounted_verifier_detail(
+        &self,
+        verifier: &VerifierDetailV2,
+        requestor_address: &str,
+    ) -> VerifierDetailV2 {
+        match self.get_fee_discount_basis_points(verifier, requestor_address) {
+            Some(discount_basis_points) => verifier.apply_fee_discount(discount_basis_points),
+            None => verifier.to_owned(),
         }
     }
 
@@ -92,6 +223,25 @@ pub struct AssetDefinitionInputV2 {
     /// will be bound to the contract.  For example, if the base name is "pb" and the asset type is
     /// "myasset," the resulting bound name would be "myasset.pb".
     pub bind_name: Option<bool>,
+    /// Requestor addresses that are charged a reduced onboarding cost, regardless of the verifier
+    /// they select.
+    #[serde(default)]
+    pub fee_discounts: Vec<FeeDiscount>,
+    /// The bond that a verifier must have posted for this asset type before assets can be onboarded
+    /// with it.  If omitted, no bond is required.
+    #[serde(default)]
+    pub minimum_verifier_bond: Option<Coin>,
+    /// Links to scope specifications, beyond the `scope_spec_identifier`, that also define this
+    /// asset type.  Each is a serialized version of a [ScopeSpecIdentifier](super::scope_spec_identifier::ScopeSpecIdentifier),
+    /// and must be unique across all instances persisted in contract storage, or requests to add
+    /// will be rejected.
+    #[serde(default)]
+    pub additional_scope_spec_identifiers: Vec<SerializedEnum>,
+    /// The asset type of a parent definition, from which the verifiers, fee discounts and minimum
+    /// verifier bond are inherited whenever they are left empty.  The parent definition must
+    /// already exist.
+    #[serde(default)]
+    pub parent_asset_type: Option<String>,
 }
 impl AssetDefinitionInputV2 {
     /// Constructs a new instance of this struct.
@@ -123,12 +273,78 @@ impl AssetDefinitionInputV2 {
             verifiers,
             enabled,
             bind_name,
+            fee_discounts: vec![],
+            minimum_verifier_bond: None,
+            additional_scope_spec_identifiers: vec![],
+            parent_asset_type: None,
         }
     }
 
+    /// Replaces the [fee_discounts](self::AssetDefinitionInputV2::fee_discounts) of this input,
+    /// returning the modified instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `fee_discounts` Requestor addresses that are charged a reduced onboarding cost.
+    pub fn with_fee_discounts(mut self, fee_discounts: Vec<FeeDiscount>) -> Self {
+        self.fee_discounts = fee_discounts;
+        self
+    }
+
+    /// Replaces the [minimum_verifier_bond](self::AssetDefinitionInputV2::minimum_verifier_bond) of
+    /// this input, returning the modified instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `minimum_verifier_bond` The bond that a verifier must have posted for the asset type.
+    pub fn with_minimum_verifier_bond(mut self, minimum_verifier_bond: Option<Coin>) -> Self {
+        self.minimum_verifier_bond = minimum_verifier_bond;
+        self
+    }
+
+    /// Replaces the [additional_scope_spec_identifiers](self::AssetDefinitionInputV2::additional_scope_spec_identifiers)
+    /// of this input, returning the modified instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `additional_scope_spec_identifiers` Links to scope specifications that also define the
+    /// asset type.
+    pub fn with_additional_scope_spec_identifiers(
+        mut self,
+        additional_scope_spec_identifiers: Vec<SerializedEnum>,
+    ) -> Self {
+        self.additional_scope_spec_identifiers = additional_scope_spec_identifiers;
+        self
+    }
+
+    /// Replaces the [parent_asset_type](self::AssetDefinitionInputV2::parent_asset_type) of this
+    /// input, returning the modified instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `parent_asset_type` The asset type of the parent definition.
+    pub fn with_parent_asset_type<S: Into<String>>(mut self, parent_asset_type: Option<S>) -> Self {
+        self.parent_asset_type = parent_asset_type.map(|asset_type| asset_type.into());
+        self
+    }
+
+    /// Converts each of the [additional_scope_spec_identifiers](self::AssetDefinitionInputV2::additional_scope_spec_identifiers)
+    /// into a string scope spec address.
+    fn get_additional_scope_spec_addresses(&self) -> AssetResult<Vec<String>> {
+        self.additional_scope_spec_identifiers
+            .iter()
+            .map(|identifier| {
+                identifier
+                    .to_scope_spec_identifier()?
+                    .get_scope_spec_address()
+            })
+            .collect()
+    }
+
     /// Moves this struct into an instance of [AssetDefinitionV2](self::AssetDefinitionV2), converting
     /// the contained `scope_spec_identifier` enum value into a string scope spec address.
     pub fn into_asset_definition(self) -> AssetResult<AssetDefinitionV2> {
+        let additional_scope_spec_addresses = self.get_additional_scope_spec_addresses()?;
         AssetDefinitionV2 {
             asset_type: self.asset_type,
             scope_spec_address: self
@@ -137,6 +353,10 @@ impl AssetDefinitionInputV2 {
                 .get_scope_spec_address()?,
             verifiers: self.verifiers,
             enabled: self.enabled.unwrap_or(true),
+            fee_discounts: self.fee_discounts,
+            minimum_verifier_bond: self.minimum_verifier_bond,
+            additional_scope_spec_addresses,
+            parent_asset_type: self.parent_asset_type,
         }
         .to_ok()
     }
@@ -152,6 +372,10 @@ impl AssetDefinitionInputV2 {
                 .get_scope_spec_address()?,
             verifiers: self.verifiers.clone(),
             enabled: self.enabled.unwrap_or(true),
+            fee_discounts: self.fee_discounts.clone(),
+            minimum_verifier_bond: self.minimum_verifier_bond.clone(),
+            additional_scope_spec_addresses: self.get_additional_scope_spec_addresses()?,
+            parent_asset_type: self.parent_asset_type.clone(),
         }
         .to_ok()
     }
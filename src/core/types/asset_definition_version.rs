@@ -0,0 +1,92 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::asset_definition::AssetDefinitionV2;
+
+/// A numbered version of an [AssetDefinitionV2](super::asset_definition::AssetDefinitionV2).  Each
+/// time an asset definition is changed or deleted, its previous values are kept as a version in
+/// contract storage, allowing the verifiers and fees that applied when an asset was onboarded to
+/// be determined from the [asset_definition_version](super::asset_scope_attribute::AssetScopeAttribute::asset_definition_version)
+/// on its scope attribute.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AssetDefinitionVersion {
+    /// The version number of the asset definition.  Asset definitions start at version 1, and each
+    /// change increments the version by one.
+    pub version: u64,
+    /// The values of the asset definition during this version.
+    pub asset_definition: AssetDefinitionV2,
+    /// The block height at which this version was replaced by a change or deletion.  Not set for
+    /// the asset definition's current version.
+    pub replaced_at_height: Option<u64>,
+    /// The bech32 address of the account that replaced this version.  Changes that the contract
+    /// applies on its own, like scheduled verifier updates and migrations, are attributed to the
+    /// contract's address.  Not set for the asset definition's current version.
+    pub replaced_by: Option<String>,
+}
+impl AssetDefinitionVersion {
+    /// Constructs a new instance of this struct for the current version of an asset definition.
+    ///
+    /// # Parameters
+    ///
+    /// * `version` The version number of the asset definition.
+    /// * `asset_definition` The current values of the asset definition.
+    pub fn current(version: u64, asset_definition: AssetDefinitionV2) -> Self {
+        AssetDefinitionVersion {
+            version,
+            asset_definition,
+            replaced_at_height: None,
+            replaced_by: None,
+        }
+    }
+
+    /// Constructs a new instance of this struct for a version of an asset definition that has been
+    /// changed or deleted.
+    ///
+    /// # Parameters
+    ///
+    /// * `version` The version number of the asset definition.
+    /// * `asset_definition` The values of the asset definition before it was replaced.
+    /// * `replaced_at_height` The block height at which the version was replaced.
+    /// * `replaced_by` The bech32 address of the account that replaced the version.
+    pub fn replaced<S: Into<String>>(
+        version: u64,
+        asset_definition: AssetDefinitionV2,
+        replaced_at_height: u64,
+        replaced_by: S,
+    ) -> Self {
+        AssetDefinitionVersion {
+            version,
+            asset_definition,
+            replaced_at_height: Some(replaced_at_height),
+            replaced_by: Some(replaced_by.into()),
+        }
+    }
+}
+
+/// Identifies a single version of the asset definition for an asset type.  Used to record which
+/// version of each ancestor in the asset type hierarchy was in effect when a scope was onboarded as
+/// a child asset type, because changes to an ancestor also change the values that its descendants
+/// inherit.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AssetDefinitionVersionReference {
+    /// The asset type of the definition.
+    pub asset_type: String,
+    /// The version number of the asset definition.
+    pub version: u64,
+}
+impl AssetDefinitionVersionReference {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type of the definition.
+    /// * `version` The version number of the asset definition.
+    pub fn new<S: Into<String>>(asset_type: S, version: u64) -> Self {
+        AssetDefinitionVersionReference {
+            asset_type: asset_type.into(),
+            version,
+        }
+    }
+}
@@ -14,7 +14,8 @@ use crate::{
 
 use super::{
     access_definition::AccessDefinition, access_route::AccessRoute,
-    asset_identifier::AssetIdentifier, asset_onboarding_status::AssetOnboardingStatus,
+    asset_definition_version::AssetDefinitionVersionReference, asset_identifier::AssetIdentifier,
+    asset_onboarding_status::AssetOnboardingStatus,
     asset_verification_result::AssetVerificationResult,
 };
 
@@ -51,6 +52,20 @@ pub struct AssetScopeAttribute {
     /// All provided access definitions are stored in the attribute for external consumers, and can
     /// be externally manipulated by admin routes or verification tasks.
     pub access_definitions: Vec<AccessDefinition>,
+    /// The version of the [AssetDefinitionV2](super::asset_definition::AssetDefinitionV2) that was
+    /// in effect when the scope was onboarded.  Allows the verifiers and fees that applied to the
+    /// onboarding to be retrieved from the asset definition's version history.  Not set for scopes
+    /// onboarded before asset definition versions were tracked.
+    #[serde(default)]
+    pub asset_definition_version: Option<u64>,
+    /// The version of each ancestor of the asset definition in the asset type hierarchy that was
+    /// in effect when the scope was onboarded, starting with its parent.  Because a child asset type
+    /// inherits values from its ancestors, these versions are needed alongside the
+    /// [asset_definition_version](self::AssetScopeAttribute::asset_definition_version) to determine
+    /// the verifiers and fees that applied to the onboarding.  Empty for asset types without a
+    /// parent.
+    #[serde(default)]
+    pub ancestor_asset_definition_versions: Vec<AssetDefinitionVersionReference>,
 }
 impl AssetScopeAttribute {
     /// Constructs a new instance of AssetScopeAttribute from the input params
@@ -112,6 +127,8 @@ impl AssetScopeAttribute {
             latest_verifier_detail: None,
             latest_verification_result: None,
             access_definitions,
+            asset_definition_version: None,
+            ancestor_asset_definition_versions: vec![],
         }
         .to_ok()
     }
@@ -0,0 +1,51 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Defines a permission that can be granted to an address, allowing it to use a group of execution
+/// routes that would otherwise only be accessible to the contract's admin.  The contract's admin
+/// implicitly holds every role, and is the only address that can grant or revoke them.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractRole {
+    /// Allows the management of [AssetDefinitionV2s](super::asset_definition::AssetDefinitionV2):
+    /// adding, updating, toggling and deleting them.
+    DefinitionManager,
+    /// Allows the management of the [VerifierDetailV2s](super::verifier_detail::VerifierDetailV2)
+    /// within each asset definition: adding, updating and scheduling updates for them, and slashing
+    /// their bonds.
+    VerifierManager,
+    /// Allows the modification of access routes owned by any address on an [AssetScopeAttribute](super::asset_scope_attribute::AssetScopeAttribute).
+    AccessRouteAdmin,
+    /// Allows withdrawals from the contract treasury and changes to the contract's [ProtocolFee](super::protocol_fee::ProtocolFee).
+    TreasuryManager,
+    /// Allows contract functionality to be paused and resumed.
+    Pauser,
+}
+impl ContractRole {
+    /// Lists every available role, in a stable order.
+    pub fn all() -> Vec<ContractRole> {
+        vec![
+            ContractRole::DefinitionManager,
+            ContractRole::VerifierManager,
+            ContractRole::AccessRouteAdmin,
+            ContractRole::TreasuryManager,
+            ContractRole::Pauser,
+        ]
+    }
+
+    /// Returns the snake_case name of the role, matching its serialized form.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContractRole::DefinitionManager => "definition_manager",
+            ContractRole::VerifierManager => "verifier_manager",
+            ContractRole::AccessRouteAdmin => "access_route_admin",
+            ContractRole::TreasuryManager => "treasury_manager",
+            ContractRole::Pauser => "pauser",
+        }
+    }
+}
+impl std::fmt::Display for ContractRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
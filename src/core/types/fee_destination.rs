@@ -1,4 +1,5 @@
 use crate::core::types::entity_detail::EntityDetail;
+use crate::util::constants::BASIS_POINTS_DENOMINATOR;
 use crate::util::traits::OptionExtensions;
 use cosmwasm_std::Uint128;
 use schemars::JsonSchema;
@@ -10,13 +11,31 @@ use serde::{Deserialize, Serialize};
 pub struct FeeDestinationV2 {
     /// The Provenance Blockchain bech32 address belonging to the account.
     pub address: String,
-    /// The amount to be distributed to this account from the designated total [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost) of the
-    /// containing [VerifierDetailV2](super::verifier_detail::VerifierDetailV2).  This number should
-    /// always sum with the other fee destinations to be less than or at most equal to the total
-    /// onboarding cost.
+    /// The amount to be distributed to this account from the cost that its list distributes within
+    /// the containing [VerifierDetailV2](super::verifier_detail::VerifierDetailV2): the
+    /// [completion fee](super::verifier_detail::VerifierDetailV2::get_completion_fee) for
+    /// [fee_destinations](super::verifier_detail::VerifierDetailV2::fee_destinations), or the
+    /// [intake_fee](super::verifier_detail::VerifierDetailV2::intake_fee) for
+    /// [intake_fee_destinations](super::verifier_detail::VerifierDetailV2::intake_fee_destinations).
+    /// This number should always sum with the other fee destinations in the same list to be less
+    /// than or at most equal to that cost.  This value must be zero when [fee_basis_points](self::FeeDestinationV2::fee_basis_points)
+    /// are specified.
+    #[serde(default)]
     pub fee_amount: Uint128,
     /// An optional set of fields that define the fee destination, including its name and home URL location.
     pub entity_detail: Option<EntityDetail>,
+    /// An alternative to [fee_amount](self::FeeDestinationV2::fee_amount) that expresses this
+    /// account's fee as a fraction of the same cost, in basis points (1/100th of a percent).  For
+    /// [fee_destinations](super::verifier_detail::VerifierDetailV2::fee_destinations), this is the
+    /// onboarding cost minus any intake fee, not the full onboarding cost.  The calculated amount
+    /// is always rounded down.
+    #[serde(default)]
+    pub fee_basis_points: Option<u16>,
+    /// If true, this account receives any amount lost to rounding down the fees of all fee
+    /// destinations that specify [fee_basis_points](self::FeeDestinationV2::fee_basis_points).
+    /// At most one fee destination within a verifier may set this value.
+    #[serde(default)]
+    pub receives_rounding_remainder: bool,
 }
 impl FeeDestinationV2 {
     /// Constructs a new instance of this struct.
@@ -31,6 +50,27 @@ impl FeeDestinationV2 {
             address: address.into(),
             fee_amount,
             entity_detail: None,
+            fee_basis_points: None,
+            receives_rounding_remainder: false,
+        }
+    }
+
+    /// Constructs a new instance of this struct that receives a fraction of the cost distributed by
+    /// its list, expressed in basis points.
+    ///
+    /// # Parameters
+    ///
+    /// * `address` The Provenance Blockchain bech32 address belonging to the account.
+    /// * `fee_basis_points` The fraction of the cost distributed by its list within the containing
+    /// [VerifierDetailV2](super::verifier_detail::VerifierDetailV2) to be distributed to this
+    /// account, in basis points.
+    pub fn new_basis_points<S: Into<String>>(address: S, fee_basis_points: u16) -> Self {
+        Self {
+            address: address.into(),
+            fee_amount: Uint128::zero(),
+            entity_detail: None,
+            fee_basis_points: fee_basis_points.to_some(),
+            receives_rounding_remainder: false,
         }
     }
 
@@ -52,6 +92,59 @@ impl FeeDestinationV2 {
             address: address.into(),
             fee_amount,
             entity_detail: entity_detail.to_some(),
+            fee_basis_points: None,
+            receives_rounding_remainder: false,
         }
     }
+
+    /// Marks this fee destination as the recipient of any amount lost to rounding basis point fees.
+    pub fn with_rounding_remainder(mut self) -> Self {
+        self.receives_rounding_remainder = true;
+        self
+    }
+
+    /// Calculates the amount that this account should receive, before any rounding remainder is
+    /// applied.  Basis point fees are always rounded down.
+    ///
+    /// # Parameters
+    ///
+    /// * `cost` The cost distributed by this account's list within the containing [VerifierDetailV2](super::verifier_detail::VerifierDetailV2).
+    pub fn calculate_fee_amount(&self, cost: Uint128) -> Uint128 {
+        match self.fee_basis_points {
+            Some(basis_points) => cost.multiply_ratio(basis_points, BASIS_POINTS_DENOMINATOR),
+            None => self.fee_amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint128;
+
+    use super::FeeDestinationV2;
+
+    #[test]
+    fn test_calculate_flat_fee_amount() {
+        let destination = FeeDestinationV2::new("address", Uint128::new(55));
+        assert_eq!(
+            Uint128::new(55),
+            destination.calculate_fee_amount(Uint128::new(100)),
+            "a flat fee should ignore the onboarding cost",
+        );
+    }
+
+    #[test]
+    fn test_calculate_basis_points_fee_amount() {
+        let destination = FeeDestinationV2::new_basis_points("address", 2500);
+        assert_eq!(
+            Uint128::new(250),
+            destination.calculate_fee_amount(Uint128::new(1000)),
+            "25% of 1000 should be 250",
+        );
+        assert_eq!(
+            Uint128::new(2),
+            destination.calculate_fee_amount(Uint128::new(11)),
+            "25% of 11 should round down to 2",
+        );
+    }
 }
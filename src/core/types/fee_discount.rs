@@ -0,0 +1,43 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::util::constants::BASIS_POINTS_DENOMINATOR;
+
+/// Reduces the onboarding cost charged to a specific requestor address.  Discounts can be defined
+/// on an [AssetDefinitionV2](super::asset_definition::AssetDefinitionV2), applying to all of its
+/// verifiers, or on a single [VerifierDetailV2](super::verifier_detail::VerifierDetailV2), which
+/// takes precedence over any discount defined on its asset definition.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FeeDiscount {
+    /// The Provenance Blockchain bech32 address of the account that sends onboarding requests.
+    pub requestor_address: String,
+    /// The fraction of the onboarding cost that is waived, in basis points (1/100th of a percent).
+    /// A value of 10000 waives the onboarding cost entirely.
+    pub discount_basis_points: u16,
+}
+impl FeeDiscount {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `requestor_address` The Provenance Blockchain bech32 address of the account that sends
+    /// onboarding requests.
+    /// * `discount_basis_points` The fraction of the onboarding cost that is waived, in basis points.
+    pub fn new<S: Into<String>>(requestor_address: S, discount_basis_points: u16) -> Self {
+        Self {
+            requestor_address: requestor_address.into(),
+            discount_basis_points,
+        }
+    }
+
+    /// Constructs a new instance of this struct that waives the onboarding cost entirely.
+    ///
+    /// # Parameters
+    ///
+    /// * `requestor_address` The Provenance Blockchain bech32 address of the account that sends
+    /// onboarding requests.
+    pub fn full_waiver<S: Into<String>>(requestor_address: S) -> Self {
+        Self::new(requestor_address, BASIS_POINTS_DENOMINATOR as u16)
+    }
+}
@@ -0,0 +1,19 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Defines where the portion of a verifier's [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost)
+/// that is not claimed by any [FeeDestinationV2](super::fee_destination::FeeDestinationV2) is
+/// sent after verification completes.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeRemainderDestination {
+    /// The remainder is sent to the verifier's own [address](super::verifier_detail::VerifierDetailV2::address).
+    /// This is the default behavior.
+    #[default]
+    Verifier,
+    /// The remainder is sent to the specified bech32 address.
+    Address(String),
+    /// The remainder is retained by the contract in its treasury, where it can only be withdrawn
+    /// by the contract's admin.
+    ContractTreasury,
+}
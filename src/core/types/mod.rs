@@ -6,6 +6,8 @@ pub mod access_definition;
 pub mod access_route;
 /// Defines a specific asset type associated with the contract.  Allows its specified type to be onboarded and verified.
 pub mod asset_definition;
+/// A numbered snapshot of an asset definition, kept each time the definition changes.
+pub mod asset_definition_version;
 /// An enum containing interchangeable values that can be used to define an asset (uuid or address).
 pub mod asset_identifier;
 /// An enum that denotes the various states that an [AssetScopeAttribute](self::asset_scope_attribute::AssetScopeAttribute) can have.
@@ -17,14 +19,42 @@ pub mod asset_qualifier;
 pub mod asset_scope_attribute;
 /// A simple wrapper for the result of a verification for a scope.
 pub mod asset_verification_result;
+/// Defines a permission that allows an address to use a group of otherwise admin-only routes.
+pub mod contract_role;
 /// Various fields describing an entity, which could be an organization, account, etc.
 pub mod entity_detail;
 /// Defines an external account designated as a recipient of funds during the verification process.
 pub mod fee_destination;
+/// Defines a reduction of the onboarding cost for a specific requestor address.
+pub mod fee_discount;
+/// Defines where the unclaimed portion of a verifier's onboarding cost is sent after verification.
+pub mod fee_remainder_destination;
+/// A collection of counters that track the usage of the onboarding and verification processes.
+pub mod onboarding_statistics;
+/// Defines which of the contract's state-changing processes are currently halted.
+pub mod pause_status;
+/// Defines how assets awaiting verification are handled when their verifier is removed.
+pub mod pending_asset_strategy;
+/// Defines an alternate onboarding cost that applies to requestors based on their onboarding volume.
+pub mod pricing_tier;
+/// Defines a contract-wide fee charged during onboarding in addition to the verifier's onboarding cost.
+pub mod protocol_fee;
+/// Defines a verifier detail change that takes effect at a future block height or time.
+pub mod scheduled_verifier_update;
 /// An enum containing interchangeable values that can be used to define a Provenance Blockchain Metadata Scope Specification.
 pub mod scope_spec_identifier;
 /// A simple struct that allows a type and value to be translated to some of the optional enums in the contract:
 /// [AssetIdentifier](self::asset_identifier::AssetIdentifier), [AssetQualifier](self::asset_qualifier::AssetQualifier), and [ScopeSpecIdentifier](self::scope_spec_identifier::ScopeSpecIdentifier).
 pub mod serialized_enum;
+/// Defines a sensitive configuration change that must wait out a delay before it can be applied.
+pub mod timelocked_change;
+/// The contract-wide configuration for verifier collateral bonds.
+pub mod verifier_bond_settings;
 /// Defines the fees and addresses for a single verifier account for an [AssetDefinitionV2](self::asset_definition::AssetDefinitionV2).
 pub mod verifier_detail;
+/// Tracks how quickly a verifier processes assets and how many assets are awaiting it.
+pub mod verifier_metrics;
+/// The global record of a verifier, shared by every [AssetDefinitionV2](self::asset_definition::AssetDefinitionV2) that uses it.
+pub mod verifier_registration;
+/// Defines the limits on the changes a verifier can make to its own verifier detail.
+pub mod verifier_self_update_bounds;
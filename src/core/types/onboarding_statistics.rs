@@ -0,0 +1,122 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A collection of counters that track the usage of the onboarding and verification processes.
+/// These values are maintained contract-wide, as well as per asset type and per verifier.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct OnboardingStatistics {
+    /// The total number of times an asset has been onboarded, including retries.
+    pub onboardings: u64,
+    /// The total number of times an asset has been re-onboarded after being denied by a verifier.
+    /// This value is a subset of the [onboardings](self::OnboardingStatistics::onboardings) counter.
+    pub retries: u64,
+    /// The total number of times a verifier has marked an asset as successfully verified.
+    pub approvals: u64,
+    /// The total number of times a verifier has rejected an asset.
+    pub denials: u64,
+}
+impl OnboardingStatistics {
+    /// Increments the counters that are relevant to an onboarding.
+    ///
+    /// # Parameters
+    ///
+    /// * `is_retry` Whether or not the onboarding was for an asset that was previously denied.
+    pub fn record_onboarding(&mut self, is_retry: bool) {
+        self.onboardings += 1;
+        if is_retry {
+            self.retries += 1;
+        }
+    }
+
+    /// Increments the counters that are relevant to a verification.
+    ///
+    /// # Parameters
+    ///
+    /// * `success` Whether or not the verifier approved the asset.
+    pub fn record_verification(&mut self, success: bool) {
+        if success {
+            self.approvals += 1;
+        } else {
+            self.denials += 1;
+        }
+    }
+
+    /// Adds every counter of another set of statistics to this one.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` The statistics to add to these statistics.
+    pub fn merge(&mut self, other: &OnboardingStatistics) {
+        self.onboardings += other.onboardings;
+        self.retries += other.retries;
+        self.approvals += other.approvals;
+        self.denials += other.denials;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OnboardingStatistics;
+
+    #[test]
+    fn test_record_onboarding() {
+        let mut statistics = OnboardingStatistics::default();
+        statistics.record_onboarding(false);
+        statistics.record_onboarding(true);
+        assert_eq!(
+            OnboardingStatistics {
+                onboardings: 2,
+                retries: 1,
+                approvals: 0,
+                denials: 0,
+            },
+            statistics,
+            "onboardings should count retries, and retries should only be counted when specified",
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut statistics = OnboardingStatistics {
+            onboardings: 3,
+            retries: 1,
+            approvals: 2,
+            denials: 0,
+        };
+        statistics.merge(&OnboardingStatistics {
+            onboardings: 1,
+            retries: 0,
+            approvals: 0,
+            denials: 1,
+        });
+        assert_eq!(
+            OnboardingStatistics {
+                onboardings: 4,
+                retries: 1,
+                approvals: 2,
+                denials: 1,
+            },
+            statistics,
+            "every counter should be added to the existing counters",
+        );
+    }
+
+    #[test]
+    fn test_record_verification() {
+        let mut statistics = OnboardingStatistics::default();
+        statistics.record_verification(true);
+        statistics.record_verification(false);
+        statistics.record_verification(false);
+        assert_eq!(
+            OnboardingStatistics {
+                onboardings: 0,
+                retries: 0,
+                approvals: 1,
+                denials: 2,
+            },
+            statistics,
+            "approvals and denials should be counted separately",
+        );
+    }
+}
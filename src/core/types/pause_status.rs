@@ -0,0 +1,65 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Denotes which of the contract's state-changing processes are currently halted.  Each flag can
+/// be toggled independently, allowing a single process to be stopped immediately when a bug or a
+/// compromised verifier is discovered, without disabling every [AssetDefinitionV2](super::asset_definition::AssetDefinitionV2).
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PauseStatus {
+    /// When true, [OnboardAsset](crate::core::msg::ExecuteMsg::OnboardAsset) requests are rejected.
+    #[serde(default)]
+    pub onboarding: bool,
+    /// When true, [VerifyAsset](crate::core::msg::ExecuteMsg::VerifyAsset) requests are rejected.
+    #[serde(default)]
+    pub verification: bool,
+    /// When true, [UpdateAccessRoutes](crate::core::msg::ExecuteMsg::UpdateAccessRoutes) requests
+    /// are rejected.
+    #[serde(default)]
+    pub access_routes: bool,
+}
+impl PauseStatus {
+    /// Lists the names of the processes that are currently paused, in a stable order.
+    pub fn paused_operations(&self) -> Vec<&'static str> {
+        let mut paused_operations = vec![];
+        if self.onboarding {
+            paused_operations.push("onboarding");
+        }
+        if self.verification {
+            paused_operations.push("verification");
+        }
+        if self.access_routes {
+            paused_operations.push("access_routes");
+        }
+        paused_operations
+    }
+}
+impl std::fmt::Display for PauseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let paused_operations = self.paused_operations();
+        if paused_operations.is_empty() {
+            write!(f, "none")
+        } else {
+            write!(f, "{}", paused_operations.join(","))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PauseStatus;
+
+    #[test]
+    fn test_pause_status_display() {
+        assert_eq!("none", PauseStatus::default().to_string());
+        assert_eq!(
+            "onboarding,access_routes",
+            PauseStatus {
+                onboarding: true,
+                verification: false,
+                access_routes: true,
+            }
+            .to_string(),
+        );
+    }
+}
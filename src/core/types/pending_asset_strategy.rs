@@ -0,0 +1,33 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Defines how assets that are still awaiting verification by a verifier are handled when that
+/// verifier is removed from an [AssetDefinitionV2](super::asset_definition::AssetDefinitionV2)
+/// with the [RemoveAssetVerifier](crate::core::msg::ExecuteMsg::RemoveAssetVerifier) route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PendingAssetStrategy {
+    /// Each pending asset is assigned to another verifier in the same asset definition.  The fees
+    /// held in escrow for the asset are unchanged, and are distributed to the new verifier's fee
+    /// destinations when it completes verification.  The new verifier must be enabled, and must hold
+    /// any bond that onboarding would require.
+    Reassign {
+        /// The bech32 address of the verifier that will take over the pending assets.
+        verifier_address: String,
+    },
+    /// The fees held in escrow for each pending asset are returned to the requestor, and the asset
+    /// is marked as [Denied](super::asset_onboarding_status::AssetOnboardingStatus::Denied) so that
+    /// it can be onboarded again with another verifier.  Intake fees were paid out at onboarding
+    /// time, and are not refunded.
+    Refund {},
+}
+impl std::fmt::Display for PendingAssetStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PendingAssetStrategy::Reassign { verifier_address } => {
+                write!(f, "reassign:{}", verifier_address)
+            }
+            PendingAssetStrategy::Refund {} => write!(f, "refund"),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Defines an alternate onboarding cost for a [VerifierDetailV2](super::verifier_detail::VerifierDetailV2)
+/// that applies once a requestor has onboarded a certain number of assets with that verifier.
+/// Requestors that have not reached any tier pay the verifier's standard [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PricingTier {
+    /// The number of assets that a requestor must have previously onboarded with the verifier
+    /// before this tier applies.  Retries of denied assets are not counted.
+    pub minimum_onboardings: u64,
+    /// The total amount charged to onboard with the verifier while this tier applies.
+    pub onboarding_cost: Uint128,
+}
+impl PricingTier {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `minimum_onboardings` The number of assets that a requestor must have previously onboarded
+    /// with the verifier before this tier applies.
+    /// * `onboarding_cost` The total amount charged to onboard with the verifier while this tier
+    /// applies.
+    pub fn new(minimum_onboardings: u64, onboarding_cost: Uint128) -> Self {
+        Self {
+            minimum_onboardings,
+            onboarding_cost,
+        }
+    }
+}
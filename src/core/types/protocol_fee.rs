@@ -0,0 +1,172 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::util::constants::BASIS_POINTS_DENOMINATOR;
+
+/// Defines how the amount of a [ProtocolFee](self::ProtocolFee) is derived for each onboarding.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProtocolFeeAmount {
+    /// A fixed amount that is charged for every onboarding, regardless of the verifier's cost.
+    Flat(Uint128),
+    /// A fraction of the selected verifier's [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost),
+    /// in basis points (1/100th of a percent).  The calculated amount is always rounded down.
+    BasisPoints(u16),
+}
+
+/// A contract-wide fee charged to the onboarding account on top of the selected verifier's
+/// [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost).  The fee is held
+/// in escrow alongside the onboarding cost, and is sent to the collector when verification occurs.
+/// The fee is always charged in the verifier's [onboarding_denom](super::verifier_detail::VerifierDetailV2::onboarding_denom).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ProtocolFee {
+    /// The Provenance Blockchain bech32 address of the account that receives the fee.
+    pub collector_address: String,
+    /// The manner in which the fee's amount is derived.
+    pub amount: ProtocolFeeAmount,
+}
+impl ProtocolFee {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `collector_address` The Provenance Blockchain bech32 address of the account that receives
+    /// the fee.
+    /// * `amount` The manner in which the fee's amount is derived.
+    pub fn new<S: Into<String>>(collector_address: S, amount: ProtocolFeeAmount) -> Self {
+        Self {
+            collector_address: collector_address.into(),
+            amount,
+        }
+    }
+
+    /// Determines the fee charged for an onboarding with the given verifier onboarding cost.
+    ///
+    /// # Parameters
+    ///
+    /// * `onboarding_cost` The [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost)
+    /// of the verifier selected during onboarding.
+    pub fn calculate_fee_amount(&self, onboarding_cost: Uint128) -> Uint128 {
+        match self.amount {
+            ProtocolFeeAmount::Flat(amount) => amount,
+            ProtocolFeeAmount::BasisPoints(basis_points) => {
+                onboarding_cost.multiply_ratio(basis_points as u128, BASIS_POINTS_DENOMINATOR)
+            }
+        }
+    }
+}
+
+/// Determines if replacing the current protocol fee with a new one could charge requestors more
+/// during onboarding.  A change between flat and basis point amounts is always considered an
+/// increase, as the two cannot be compared without knowing the verifier's onboarding cost.
+///
+/// # Parameters
+///
+/// * `current` The protocol fee currently stored in the contract's [state](crate::core::state::StateV2).
+/// * `new` The protocol fee that would replace it.
+pub fn is_protocol_fee_increase(current: &Option<ProtocolFee>, new: &Option<ProtocolFee>) -> bool {
+    match (current, new) {
+        (_, None) => false,
+        (None, Some(_)) => true,
+        (Some(current), Some(new)) => match (&current.amount, &new.amount) {
+            (ProtocolFeeAmount::Flat(current), ProtocolFeeAmount::Flat(new)) => new > current,
+            (ProtocolFeeAmount::BasisPoints(current), ProtocolFeeAmount::BasisPoints(new)) => {
+                new > current
+            }
+            _ => true,
+        },
+    }
+}
+impl std::fmt::Display for ProtocolFee {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.amount {
+            ProtocolFeeAmount::Flat(amount) => write!(f, "{}:{}", self.collector_address, amount),
+            ProtocolFeeAmount::BasisPoints(basis_points) => {
+                write!(f, "{}:{}bps", self.collector_address, basis_points)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint128;
+
+    use crate::util::traits::OptionExtensions;
+
+    use super::{is_protocol_fee_increase, ProtocolFee, ProtocolFeeAmount};
+
+    #[test]
+    fn test_flat_fee_ignores_onboarding_cost() {
+        let fee = ProtocolFee::new("collector", ProtocolFeeAmount::Flat(Uint128::new(25)));
+        assert_eq!(Uint128::new(25), fee.calculate_fee_amount(Uint128::zero()));
+        assert_eq!(
+            Uint128::new(25),
+            fee.calculate_fee_amount(Uint128::new(1000))
+        );
+    }
+
+    #[test]
+    fn test_basis_points_fee_rounds_down() {
+        let fee = ProtocolFee::new("collector", ProtocolFeeAmount::BasisPoints(250));
+        assert_eq!(
+            Uint128::new(25),
+            fee.calculate_fee_amount(Uint128::new(1000)),
+            "2.5% of 1000 should be 25",
+        );
+        assert_eq!(
+            Uint128::new(2),
+            fee.calculate_fee_amount(Uint128::new(119)),
+            "2.5% of 119 should be rounded down to 2",
+        );
+        assert_eq!(Uint128::zero(), fee.calculate_fee_amount(Uint128::zero()));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "collector:100",
+            ProtocolFee::new("collector", ProtocolFeeAmount::Flat(Uint128::new(100))).to_string(),
+        );
+        assert_eq!(
+            "collector:15bps",
+            ProtocolFee::new("collector", ProtocolFeeAmount::BasisPoints(15)).to_string(),
+        );
+    }
+
+    #[test]
+    fn test_is_protocol_fee_increase() {
+        let flat = |amount: u128| {
+            ProtocolFee::new("collector", ProtocolFeeAmount::Flat(Uint128::new(amount))).to_some()
+        };
+        let basis_points = |amount: u16| {
+            ProtocolFee::new("collector", ProtocolFeeAmount::BasisPoints(amount)).to_some()
+        };
+        assert!(
+            is_protocol_fee_increase(&None, &flat(1)),
+            "adding a protocol fee should be an increase",
+        );
+        assert!(
+            !is_protocol_fee_increase(&flat(10), &None),
+            "removing a protocol fee should not be an increase",
+        );
+        assert!(
+            is_protocol_fee_increase(&flat(10), &flat(11)),
+            "raising a flat fee should be an increase",
+        );
+        assert!(
+            !is_protocol_fee_increase(&flat(10), &flat(10)),
+            "keeping the same flat fee should not be an increase",
+        );
+        assert!(
+            !is_protocol_fee_increase(&basis_points(100), &basis_points(50)),
+            "lowering a basis point fee should not be an increase",
+        );
+        assert!(
+            is_protocol_fee_increase(&basis_points(100), &flat(1)),
+            "switching between amount types should be an increase",
+        );
+    }
+}
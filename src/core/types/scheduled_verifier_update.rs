@@ -0,0 +1,101 @@
+use cosmwasm_std::{BlockInfo, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::verifier_detail::VerifierDetailV2;
+
+/// Defines the point at which a [ScheduledVerifierUpdate](self::ScheduledVerifierUpdate) takes
+/// effect.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EffectiveAt {
+    /// The update takes effect for onboardings processed at or after this block height.
+    BlockHeight(u64),
+    /// The update takes effect for onboardings processed in blocks with a time at or after this
+    /// timestamp.
+    Time(Timestamp),
+}
+impl EffectiveAt {
+    /// Determines if the effective point has been reached by the given block.
+    ///
+    /// # Parameters
+    ///
+    /// * `block` The block information for the current transaction.
+    pub fn has_passed(&self, block: &BlockInfo) -> bool {
+        match self {
+            EffectiveAt::BlockHeight(height) => block.height >= *height,
+            EffectiveAt::Time(time) => block.time >= *time,
+        }
+    }
+}
+impl std::fmt::Display for EffectiveAt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EffectiveAt::BlockHeight(height) => write!(f, "height:{}", height),
+            EffectiveAt::Time(time) => write!(f, "time:{}", time.nanos()),
+        }
+    }
+}
+
+/// A [VerifierDetailV2](super::verifier_detail::VerifierDetailV2) that replaces an existing
+/// verifier in an [AssetDefinitionV2](super::asset_definition::AssetDefinitionV2) once a future
+/// block height or time is reached.  Updates are applied lazily, when the next onboarding for the
+/// asset type occurs after the update has become effective.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ScheduledVerifierUpdate {
+    /// The [asset_type](super::asset_definition::AssetDefinitionV2::asset_type) of the asset
+    /// definition that contains the verifier.
+    pub asset_type: String,
+    /// The verifier detail that will replace the existing verifier with a matching [address](super::verifier_detail::VerifierDetailV2::address).
+    pub verifier: VerifierDetailV2,
+    /// The point at which the update takes effect.
+    pub effective_at: EffectiveAt,
+}
+impl ScheduledVerifierUpdate {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type of the asset definition that contains the verifier.
+    /// * `verifier` The verifier detail that will replace the existing verifier.
+    /// * `effective_at` The point at which the update takes effect.
+    pub fn new<S: Into<String>>(
+        asset_type: S,
+        verifier: VerifierDetailV2,
+        effective_at: EffectiveAt,
+    ) -> Self {
+        Self {
+            asset_type: asset_type.into(),
+            verifier,
+            effective_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Timestamp;
+
+    use super::EffectiveAt;
+
+    #[test]
+    fn test_has_passed() {
+        let block = mock_env().block;
+        assert!(EffectiveAt::BlockHeight(block.height).has_passed(&block));
+        assert!(!EffectiveAt::BlockHeight(block.height + 1).has_passed(&block));
+        assert!(EffectiveAt::Time(block.time).has_passed(&block));
+        assert!(!EffectiveAt::Time(block.time.plus_seconds(1)).has_passed(&block));
+        assert!(EffectiveAt::Time(Timestamp::from_seconds(0)).has_passed(&block));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("height:15", EffectiveAt::BlockHeight(15).to_string());
+        assert_eq!(
+            "time:2000000000",
+            EffectiveAt::Time(Timestamp::from_seconds(2)).to_string(),
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use cosmwasm_std::Addr;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::util::traits::OptionExtensions;
+
+use super::asset_definition::AssetDefinitionInputV2;
+use super::contract_role::ContractRole;
+use super::pending_asset_strategy::PendingAssetStrategy;
+use super::protocol_fee::ProtocolFee;
+use super::serialized_enum::SerializedEnum;
+use super::verifier_detail::VerifierDetailV2;
+use super::verifier_registration::VerifierRegistration;
+
+/// A sensitive configuration change that, while the contract's [timelock_delay](crate::core::state::StateV2::timelock_delay)
+/// is enabled, must be queued and can only be applied once the delay has elapsed.  Each variant
+/// mirrors the fields of the [ExecuteMsg](crate::core::msg::ExecuteMsg) variant that it delays.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+pub enum TimelockedChange {
+    /// Delays an [UpdateAssetVerifier](crate::core::msg::ExecuteMsg::UpdateAssetVerifier) request.
+    UpdateAssetVerifier {
+        /// The type of asset for which the verifier will be updated.
+        asset_type: String,
+        /// The verifier detail that will replace the existing verifier with a matching address.
+        verifier: VerifierDetailV2,
+    },
+    /// Delays a [DeleteAssetDefinition](crate::core::msg::ExecuteMsg::DeleteAssetDefinition) request.
+    DeleteAssetDefinition {
+        /// Expects an [AssetQualifier](super::asset_qualifier::AssetQualifier)-compatible
+        /// [SerializedEnum](super::serialized_enum::SerializedEnum) that will map to an
+        /// existing [AssetDefinitionV2](super::asset_definition::AssetDefinitionV2).
+        qualifier: SerializedEnum,
+    },
+    /// Delays an [UpdateProtocolFee](crate::core::msg::ExecuteMsg::UpdateProtocolFee) request.
+    UpdateProtocolFee {
+        /// The new protocol fee, or None to remove the protocol fee.
+        protocol_fee: Option<ProtocolFee>,
+    },
+    /// Delays an [UpdateTimelockDelay](crate::core::msg::ExecuteMsg::UpdateTimelockDelay) request.
+    UpdateTimelockDelay {
+        /// The new delay, in blocks, or None to disable the timelock.
+        delay_blocks: Option<u64>,
+    },
+    /// Delays a [RegisterVerifier](crate::core::msg::ExecuteMsg::RegisterVerifier) request.
+    RegisterVerifier {
+        /// The registration to store for the verifier.
+        registration: VerifierRegistration,
+    },
+    /// Delays an [UpdateAssetDefinition](crate::core::msg::ExecuteMsg::UpdateAssetDefinition) request.
+    UpdateAssetDefinition {
+        /// An asset definition input value defining all of the updated [AssetDefinitionV2](super::asset_definition::AssetDefinitionV2)'s
+        /// values.
+        asset_definition: AssetDefinitionInputV2,
+    },
+    /// Delays a [RemoveAssetVerifier](crate::core::msg::ExecuteMsg::RemoveAssetVerifier) request.
+    RemoveAssetVerifier {
+        /// The type of asset from which the verifier will be removed.
+        asset_type: String,
+        /// The bech32 address of the verifier to remove.
+        verifier_address: String,
+        /// Defines how assets that are still awaiting verification by the verifier are handled
+        /// when the change is executed.
+        pending_asset_strategy: Option<PendingAssetStrategy>,
+    },
+}
+impl TimelockedChange {
+    /// The [ContractRole](super::contract_role::ContractRole) that allows an address other than
+    /// the admin to queue, execute and cancel this change.  A None value indicates that only the
+    /// admin can manage the change.
+    pub fn required_role(&self) -> Option<ContractRole> {
+        match self {
+            TimelockedChange::UpdateAssetVerifier { .. } => ContractRole::VerifierManager.to_some(),
+            TimelockedChange::DeleteAssetDefinition { .. } => {
+                ContractRole::DefinitionManager.to_some()
+            }
+            TimelockedChange::UpdateProtocolFee { .. } => ContractRole::TreasuryManager.to_some(),
+            TimelockedChange::UpdateTimelockDelay { .. } => None,
+            TimelockedChange::RegisterVerifier { .. } => ContractRole::VerifierManager.to_some(),
+            TimelockedChange::UpdateAssetDefinition { .. } => {
+                ContractRole::DefinitionManager.to_some()
+            }
+            TimelockedChange::RemoveAssetVerifier { .. } => ContractRole::VerifierManager.to_some(),
+        }
+    }
+
+    /// The name of the [ExecuteMsg](crate::core::msg::ExecuteMsg) variant that this change delays.
+    pub fn message_type(&self) -> &'static str {
+        match self {
+            TimelockedChange::UpdateAssetVerifier { .. } => "ExecuteMsg::UpdateAssetVerifier",
+            TimelockedChange::DeleteAssetDefinition { .. } => "ExecuteMsg::DeleteAssetDefinition",
+            TimelockedChange::UpdateProtocolFee { .. } => "ExecuteMsg::UpdateProtocolFee",
+            TimelockedChange::UpdateTimelockDelay { .. } => "ExecuteMsg::UpdateTimelockDelay",
+            TimelockedChange::RegisterVerifier { .. } => "ExecuteMsg::RegisterVerifier",
+            TimelockedChange::UpdateAssetDefinition { .. } => "ExecuteMsg::UpdateAssetDefinition",
+            TimelockedChange::RemoveAssetVerifier { .. } => "ExecuteMsg::RemoveAssetVerifier",
+        }
+    }
+}
+
+/// A [TimelockedChange](self::TimelockedChange) that has been queued and is awaiting execution or
+/// cancellation.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueuedChange {
+    /// The unique identifier assigned to the change when it was queued.
+    pub change_id: u64,
+    /// The change that will be applied on execution.
+    pub change: TimelockedChange,
+    /// The bech32 address of the account that queued the change.
+    pub queued_by: Addr,
+    /// The block height at which the change was queued.
+    pub queued_at_height: u64,
+    /// The first block height at which the change can be executed.
+    pub executable_at_height: u64,
+}
+impl QueuedChange {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `change_id` The unique identifier assigned to the change.
+    /// * `change` The change that will be applied on execution.
+    /// * `queued_by` The bech32 address of the account that queued the change.
+    /// * `queued_at_height` The block height at which the change was queued.
+    /// * `delay_blocks` The number of blocks that must pass before the change can be executed.
+    pub fn new(
+        change_id: u64,
+        change: TimelockedChange,
+        queued_by: Addr,
+        queued_at_height: u64,
+        delay_blocks: u64,
+    ) -> Self {
+        Self {
+            change_id,
+            change,
+            queued_by,
+            queued_at_height,
+            executable_at_height: queued_at_height.saturating_add(delay_blocks),
+        }
+    }
+
+    /// Determines if the change's delay has elapsed at the given block height.
+    ///
+    /// # Parameters
+    ///
+    /// * `height` The block height of the current transaction.
+    pub fn is_executable(&self, height: u64) -> bool {
+        height >= self.executable_at_height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Addr;
+
+    use super::{QueuedChange, TimelockedChange};
+
+    #[test]
+    fn test_queued_change_becomes_executable_after_delay() {
+        let change = QueuedChange::new(
+            1,
+            TimelockedChange::UpdateProtocolFee { protocol_fee: None },
+            Addr::unchecked("admin"),
+            100,
+            50,
+        );
+        assert_eq!(
+            150, change.executable_at_height,
+            "the executable height should be the queued height plus the delay",
+        );
+        assert!(
+            !change.is_executable(149),
+            "the change should not be executable before the delay elapses",
+        );
+        assert!(
+            change.is_executable(150),
+            "the change should be executable once the delay elapses",
+        );
+    }
+}
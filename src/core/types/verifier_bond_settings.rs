@@ -0,0 +1,69 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::util::constants::VERIFIER_BOND_UNBONDING_BLOCKS;
+
+/// The contract-wide configuration for verifier collateral bonds.  These values can only be
+/// changed by the admin, so the accounts that slash bonds never decide where the slashed funds go.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VerifierBondSettings {
+    /// The Provenance Blockchain bech32 address of the account that receives funds removed from a
+    /// bond by [SlashVerifierBond](crate::core::msg::ExecuteMsg::SlashVerifierBond).  When not set,
+    /// slashed funds are retained in the contract treasury.
+    #[serde(default)]
+    pub slash_beneficiary: Option<String>,
+    /// The number of blocks that a verifier's bond remains locked after the verifier requests its
+    /// withdrawal with [WithdrawVerifierBond](crate::core::msg::ExecuteMsg::WithdrawVerifierBond).
+    /// Withdrawals that have already begun keep the unlock height that was set when they began.
+    /// Defaults to [VERIFIER_BOND_UNBONDING_BLOCKS](crate::util::constants::VERIFIER_BOND_UNBONDING_BLOCKS).
+    #[serde(default = "default_unbonding_blocks")]
+    pub unbonding_blocks: u64,
+}
+impl VerifierBondSettings {
+    /// Constructs a new instance of this struct with the default unbonding period.
+    ///
+    /// # Parameters
+    ///
+    /// * `slash_beneficiary` The bech32 address of the account that receives slashed funds, or None
+    /// to retain them in the contract treasury.
+    pub fn new(slash_beneficiary: Option<String>) -> Self {
+        Self {
+            slash_beneficiary,
+            unbonding_blocks: default_unbonding_blocks(),
+        }
+    }
+
+    /// Replaces the [unbonding_blocks](self::VerifierBondSettings::unbonding_blocks) of these
+    /// settings.
+    ///
+    /// # Parameters
+    ///
+    /// * `unbonding_blocks` The number of blocks that a bond remains locked after its withdrawal
+    /// is requested.
+    pub fn with_unbonding_blocks(mut self, unbonding_blocks: u64) -> Self {
+        self.unbonding_blocks = unbonding_blocks;
+        self
+    }
+}
+impl Default for VerifierBondSettings {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+impl std::fmt::Display for VerifierBondSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "slash_beneficiary={}, unbonding_blocks={}",
+            self.slash_beneficiary.as_deref().unwrap_or("treasury"),
+            self.unbonding_blocks,
+        )
+    }
+}
+
+/// Supplies the default value of [unbonding_blocks](self::VerifierBondSettings::unbonding_blocks)
+/// for settings stored before the value was configurable.
+fn default_unbonding_blocks() -> u64 {
+    VERIFIER_BOND_UNBONDING_BLOCKS
+}
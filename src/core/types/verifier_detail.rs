@@ -1,9 +1,14 @@
 use crate::core::types::fee_destination::FeeDestinationV2;
+use crate::util::constants::BASIS_POINTS_DENOMINATOR;
+use crate::util::traits::OptionExtensions;
 use cosmwasm_std::Uint128;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::entity_detail::EntityDetail;
+use super::fee_discount::FeeDiscount;
+use super::fee_remainder_destination::FeeRemainderDestination;
+use super::pricing_tier::PricingTier;
 
 /// Defines the fees and addresses for a single verifier account for an [AssetDefinitionV2](super::asset_definition::AssetDefinitionV2).
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -15,14 +20,61 @@ pub struct VerifierDetailV2 {
     pub onboarding_cost: Uint128,
     /// The coin denomination used for this onboarding process.
     pub onboarding_denom: String,
-    /// Each account that should receive fees when onboarding a new scope to the contract.
-    /// All of these destinations' individual [fee_amount](super::fee_destination::FeeDestinationV2::fee_amount) properties
-    /// should sum to an amount less than or equal to the [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost).
+    /// Each account that should receive fees when a scope onboarded to the contract is verified.
+    /// All of these destinations' individual [fee_amount](super::fee_destination::FeeDestinationV2::fee_amount) properties,
+    /// or amounts derived from their [fee_basis_points](super::fee_destination::FeeDestinationV2::fee_basis_points), should sum to an amount less than or equal to the [completion fee](super::verifier_detail::VerifierDetailV2::get_completion_fee).
     /// Amounts not precisely equal in sum will cause this verifier detail to be considered invalid
     /// and rejected in requests that include it.
     pub fee_destinations: Vec<FeeDestinationV2>,
     /// An optional set of fields that define the verifier, including its name and home URL location.
     pub entity_detail: Option<EntityDetail>,
+    /// Where the portion of the [onboarding_cost](self::VerifierDetailV2::onboarding_cost) that is
+    /// not claimed by any of the [fee_destinations](self::VerifierDetailV2::fee_destinations) is
+    /// sent.  Defaults to the verifier's own address.
+    #[serde(default)]
+    pub remainder_destination: FeeRemainderDestination,
+    /// If true, fees owed to the verifier, its fee destinations and the protocol fee collector after
+    /// verification are credited to a ledger in contract storage instead of being sent immediately.  Each recipient can then
+    /// collect its balance using the [WithdrawFees](crate::core::msg::ExecuteMsg::WithdrawFees) route.
+    /// This avoids a bank send per recipient for every verification.
+    #[serde(default)]
+    pub accrue_fees: bool,
+    /// Requestor addresses that are charged a reduced onboarding cost when selecting this verifier.
+    /// These discounts take precedence over any [fee_discounts](super::asset_definition::AssetDefinitionV2::fee_discounts)
+    /// defined on the containing asset definition.
+    #[serde(default)]
+    pub fee_discounts: Vec<FeeDiscount>,
+    /// Alternate onboarding costs that apply based on the number of assets a requestor has already
+    /// onboarded with this verifier.  The tier with the highest [minimum_onboardings](super::pricing_tier::PricingTier::minimum_onboardings)
+    /// reached by the requestor is used.
+    #[serde(default)]
+    pub pricing_tiers: Vec<PricingTier>,
+    /// The pricing tier that was used to derive the [onboarding_cost](self::VerifierDetailV2::onboarding_cost)
+    /// of this verifier detail.  This value is only populated on the verifier detail stored for a
+    /// scope when it is onboarded, and is always empty in asset definitions.
+    #[serde(default)]
+    pub applied_pricing_tier: Option<PricingTier>,
+    /// The portion of the [onboarding_cost](self::VerifierDetailV2::onboarding_cost) that is paid
+    /// out as soon as a scope is onboarded, rather than when verification occurs.  This portion is
+    /// kept by its recipients even if the scope is never verified.  The remainder of the onboarding
+    /// cost is the completion fee, which is paid out at verification time.  Defaults to zero.
+    #[serde(default)]
+    pub intake_fee: Uint128,
+    /// Each account that should receive a portion of the [intake_fee](self::VerifierDetailV2::intake_fee)
+    /// when a scope is onboarded.  The portion of the intake fee not claimed by these destinations
+    /// is sent to the [remainder_destination](self::VerifierDetailV2::remainder_destination).
+    #[serde(default)]
+    pub intake_fee_destinations: Vec<FeeDestinationV2>,
+    /// Indicates whether or not the verifier can be selected during onboarding.  A disabled
+    /// verifier can still verify the assets that were onboarded before it was disabled.  Defaults
+    /// to true.
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+    /// If true, the onboarding cost, onboarding denom and fee destinations of this verifier detail
+    /// are specific to its asset type, and are not replaced when the verifier's global
+    /// [VerifierRegistration](super::verifier_registration::VerifierRegistration) changes.
+    #[serde(default)]
+    pub overrides_registered_fees: bool,
 }
 impl VerifierDetailV2 {
     /// Constructs a new instance of this struct.
@@ -47,23 +99,281 @@ impl VerifierDetailV2 {
             onboarding_denom: onboarding_denom.into(),
             fee_destinations,
             entity_detail,
+            remainder_destination: FeeRemainderDestination::default(),
+            accrue_fees: false,
+            fee_discounts: vec![],
+            pricing_tiers: vec![],
+            applied_pricing_tier: None,
+            intake_fee: Uint128::zero(),
+            intake_fee_destinations: vec![],
+            enabled: true,
+            overrides_registered_fees: false,
         }
     }
 
+    /// Replaces the [remainder_destination](self::VerifierDetailV2::remainder_destination) of this
+    /// verifier detail, returning the modified instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `remainder_destination` Where the unclaimed portion of the onboarding cost should be sent.
+    pub fn with_remainder_destination(
+        mut self,
+        remainder_destination: FeeRemainderDestination,
+    ) -> Self {
+        self.remainder_destination = remainder_destination;
+        self
+    }
+
+    /// Replaces the [accrue_fees](self::VerifierDetailV2::accrue_fees) value of this verifier
+    /// detail, returning the modified instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `accrue_fees` Whether or not fees should be credited to the contract's fee ledger instead
+    /// of being sent immediately after verification.
+    pub fn with_accrue_fees(mut self, accrue_fees: bool) -> Self {
+        self.accrue_fees = accrue_fees;
+        self
+    }
+
+    /// Replaces the [fee_discounts](self::VerifierDetailV2::fee_discounts) of this verifier detail,
+    /// returning the modified instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `fee_discounts` Requestor addresses that are charged a reduced onboarding cost.
+    pub fn with_fee_discounts(mut self, fee_discounts: Vec<FeeDiscount>) -> Self {
+        self.fee_discounts = fee_discounts;
+        self
+    }
+
+    /// Replaces the [pricing_tiers](self::VerifierDetailV2::pricing_tiers) of this verifier detail,
+    /// returning the modified instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `pricing_tiers` Alternate onboarding costs based on requestor onboarding volume.
+    pub fn with_pricing_tiers(mut self, pricing_tiers: Vec<PricingTier>) -> Self {
+        self.pricing_tiers = pricing_tiers;
+        self
+    }
+
+    /// Replaces the [intake_fee](self::VerifierDetailV2::intake_fee) and [intake_fee_destinations](self::VerifierDetailV2::intake_fee_destinations)
+    /// of this verifier detail, returning the modified instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `intake_fee` The portion of the onboarding cost paid out when a scope is onboarded.
+    /// * `intake_fee_destinations` Each account that should receive some of the intake fee.
+    pub fn with_intake_fee(
+        mut self,
+        intake_fee: Uint128,
+        intake_fee_destinations: Vec<FeeDestinationV2>,
+    ) -> Self {
+        self.intake_fee = intake_fee;
+        self.intake_fee_destinations = intake_fee_destinations;
+        self
+    }
+
+    /// Replaces the [overrides_registered_fees](self::VerifierDetailV2::overrides_registered_fees)
+    /// value of this verifier detail, returning the modified instance.
+    ///
+    /// # Parameters
+    ///
+    /// * `overrides_registered_fees` Whether or not the fees of this verifier detail should be
+    /// retained when the verifier's global registration changes.
+    pub fn with_overrides_registered_fees(mut self, overrides_registered_fees: bool) -> Self {
+        self.overrides_registered_fees = overrides_registered_fees;
+        self
+    }
+
+    /// Locates the pricing tier with the highest [minimum_onboardings](super::pricing_tier::PricingTier::minimum_onboardings)
+    /// that a requestor has reached, if any.
+    ///
+    /// # Parameters
+    ///
+    /// * `onboarding_count` The number of assets the requestor has previously onboarded with this
+    /// verifier.
+    pub fn get_pricing_tier(&self, onboarding_count: u64) -> Option<&PricingTier> {
+        self.pricing_tiers
+            .iter()
+            .filter(|tier| tier.minimum_onboardings <= onboarding_count)
+            .max_by_key(|tier| tier.minimum_onboardings)
+    }
+
+    /// Produces a copy of this verifier detail that reflects the pricing tier reached by a
+    /// requestor, recording the tier in [applied_pricing_tier](self::VerifierDetailV2::applied_pricing_tier).
+    /// The [onboarding_cost](self::VerifierDetailV2::onboarding_cost) is replaced by the tier's cost,
+    /// and the [intake_fee](self::VerifierDetailV2::intake_fee) and each flat [fee_amount](super::fee_destination::FeeDestinationV2::fee_amount)
+    /// are scaled by the ratio of the tier's cost to the standard cost, rounding down.  If no tier has been
+    /// reached, an unmodified copy is returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `onboarding_count` The number of assets the requestor has previously onboarded with this
+    /// verifier.
+    pub fn apply_pricing_tier(&self, onboarding_count: u64) -> Self {
+        let mut tiered = self.clone();
+        if let Some(tier) = self.get_pricing_tier(onboarding_count) {
+            if !self.onboarding_cost.is_zero() {
+                tiered.intake_fee = self
+                    .intake_fee
+                    .multiply_ratio(tier.onboarding_cost, self.onboarding_cost);
+                tiered
+                    .fee_destinations
+                    .iter_mut()
+                    .chain(tiered.intake_fee_destinations.iter_mut())
+                    .for_each(|destination| {
+                        destination.fee_amount = destination
+                            .fee_amount
+                            .multiply_ratio(tier.onboarding_cost, self.onboarding_cost);
+                    });
+            }
+            tiered.onboarding_cost = tier.onboarding_cost;
+            tiered.applied_pricing_tier = tier.to_owned().to_some();
+        }
+        tiered
+    }
+
+    /// Produces a copy of this verifier detail with its [onboarding_cost](self::VerifierDetailV2::onboarding_cost)
+    /// reduced by a discount, and its [intake_fee](self::VerifierDetailV2::intake_fee) and each flat
+    /// [fee_amount](super::fee_destination::FeeDestinationV2::fee_amount) in its fee destinations
+    /// scaled to match.  Basis point fee destinations scale naturally with
+    /// the onboarding cost.  All scaled amounts are rounded down, ensuring that the fee destinations
+    /// never claim more than the discounted onboarding cost.
+    ///
+    /// # Parameters
+    ///
+    /// * `discount_basis_points` The fraction of the onboarding cost to waive, in basis points.
+    pub fn apply_fee_discount(&self, discount_basis_points: u16) -> Self {
+        let remaining_basis_points =
+            BASIS_POINTS_DENOMINATOR.saturating_sub(discount_basis_points as u128);
+        let mut discounted = self.clone();
+        discounted.onboarding_cost = self
+            .onboarding_cost
+            .multiply_ratio(remaining_basis_points, BASIS_POINTS_DENOMINATOR);
+        discounted.intake_fee = self
+            .intake_fee
+            .multiply_ratio(remaining_basis_points, BASIS_POINTS_DENOMINATOR);
+        discounted
+            .fee_destinations
+            .iter_mut()
+            .chain(discounted.intake_fee_destinations.iter_mut())
+            .for_each(|destination| {
+                destination.fee_amount = destination
+                    .fee_amount
+                    .multiply_ratio(remaining_basis_points, BASIS_POINTS_DENOMINATOR);
+            });
+        discounted
+    }
+
+    /// Calculates the portion of the [onboarding_cost](self::VerifierDetailV2::onboarding_cost)
+    /// that is paid out when verification occurs, after the [intake_fee](self::VerifierDetailV2::intake_fee)
+    /// has been paid out during onboarding.
+    pub fn get_completion_fee(&self) -> Uint128 {
+        self.onboarding_cost.saturating_sub(self.intake_fee)
+    }
+
     /// Calculates a sum of all held [fee_destinations](self::VerifierDetailV2::fee_destinations)
-    /// respective [fee_amount](super::fee_destination::FeeDestinationV2::fee_amount) fields.
+    /// respective fee amounts, as derived by [get_fee_destination_amounts](self::VerifierDetailV2::get_fee_destination_amounts).
     ///
     pub fn get_fee_total(&self) -> u128 {
-        self.fee_destinations
+        self.get_fee_destination_amounts()
             .iter()
-            .map(|d| d.fee_amount.u128())
+            .map(|(_, amount)| amount)
             .sum::<u128>()
     }
+
+    /// Calculates the portion of the [completion fee](self::VerifierDetailV2::get_completion_fee)
+    /// that is not claimed by any [fee_destinations](self::VerifierDetailV2::fee_destinations).
+    /// This amount is distributed according to the [remainder_destination](self::VerifierDetailV2::remainder_destination).
+    pub fn get_fee_remainder(&self) -> u128 {
+        self.get_completion_fee()
+            .u128()
+            .saturating_sub(self.get_fee_total())
+    }
+
+    /// Pairs each held [fee_destination](self::VerifierDetailV2::fee_destinations) with the amount
+    /// it should receive from the [completion fee](self::VerifierDetailV2::get_completion_fee).
+    /// Fees specified in basis points are rounded down, and the sum lost to rounding is awarded to
+    /// the destination flagged with [receives_rounding_remainder](super::fee_destination::FeeDestinationV2::receives_rounding_remainder).
+    /// If no such destination exists, the rounding remainder is left undistributed.
+    pub fn get_fee_destination_amounts(&self) -> Vec<(&FeeDestinationV2, u128)> {
+        calculate_destination_amounts(&self.fee_destinations, self.get_completion_fee())
+    }
+
+    /// Calculates a sum of all held [intake_fee_destinations](self::VerifierDetailV2::intake_fee_destinations)
+    /// respective fee amounts, as derived by [get_intake_fee_destination_amounts](self::VerifierDetailV2::get_intake_fee_destination_amounts).
+    pub fn get_intake_fee_total(&self) -> u128 {
+        self.get_intake_fee_destination_amounts()
+            .iter()
+            .map(|(_, amount)| amount)
+            .sum::<u128>()
+    }
+
+    /// Calculates the portion of the [intake_fee](self::VerifierDetailV2::intake_fee) that is not
+    /// claimed by any [intake_fee_destinations](self::VerifierDetailV2::intake_fee_destinations).
+    /// This amount is distributed according to the [remainder_destination](self::VerifierDetailV2::remainder_destination).
+    pub fn get_intake_fee_remainder(&self) -> u128 {
+        self.intake_fee
+            .u128()
+            .saturating_sub(self.get_intake_fee_total())
+    }
+
+    /// Pairs each held [intake_fee_destination](self::VerifierDetailV2::intake_fee_destinations)
+    /// with the amount it should receive from the [intake_fee](self::VerifierDetailV2::intake_fee),
+    /// using the same rounding rules as [get_fee_destination_amounts](self::VerifierDetailV2::get_fee_destination_amounts).
+    pub fn get_intake_fee_destination_amounts(&self) -> Vec<(&FeeDestinationV2, u128)> {
+        calculate_destination_amounts(&self.intake_fee_destinations, self.intake_fee)
+    }
+}
+
+/// Supplies the default value of [enabled](self::VerifierDetailV2::enabled) for verifier details
+/// stored before the flag existed.
+fn enabled_by_default() -> bool {
+    true
+}
+
+/// Pairs each fee destination with the amount it should receive from a total cost.  Fees specified
+/// in basis points are rounded down, and the sum lost to rounding is awarded to the destination
+/// flagged with [receives_rounding_remainder](super::fee_destination::FeeDestinationV2::receives_rounding_remainder),
+/// if one exists.
+fn calculate_destination_amounts(
+    destinations: &[FeeDestinationV2],
+    cost: Uint128,
+) -> Vec<(&FeeDestinationV2, u128)> {
+    let total_basis_points = destinations
+        .iter()
+        .filter_map(|d| d.fee_basis_points)
+        .map(u128::from)
+        .sum::<u128>();
+    let mut amounts = destinations
+        .iter()
+        .map(|d| (d, d.calculate_fee_amount(cost).u128()))
+        .collect::<Vec<(&FeeDestinationV2, u128)>>();
+    let rounded_basis_point_total = amounts
+        .iter()
+        .filter(|(d, _)| d.fee_basis_points.is_some())
+        .map(|(_, amount)| amount)
+        .sum::<u128>();
+    let rounding_remainder = cost
+        .multiply_ratio(total_basis_points, BASIS_POINTS_DENOMINATOR)
+        .u128()
+        .saturating_sub(rounded_basis_point_total);
+    if let Some((_, amount)) = amounts
+        .iter_mut()
+        .find(|(d, _)| d.receives_rounding_remainder)
+    {
+        *amount += rounding_remainder;
+    }
+    amounts
 }
 
 #[cfg(test)]
 mod tests {
     use crate::core::types::fee_destination::FeeDestinationV2;
+    use crate::core::types::pricing_tier::PricingTier;
     use crate::core::types::verifier_detail::VerifierDetailV2;
     use crate::util::constants::NHASH;
     use cosmwasm_std::Uint128;
@@ -114,4 +424,184 @@ mod tests {
             "expected the fee total to be the sum of all fee destinations' fee amounts (10 + 20 + 30 + 40 + 50 + 60 = 210)",
         );
     }
+
+    #[test]
+    fn test_basis_point_fee_destinations_fee_total() {
+        let verifier = VerifierDetailV2::new(
+            "address",
+            Uint128::new(1000),
+            NHASH,
+            vec![
+                FeeDestinationV2::new_basis_points("fee-address-1", 2500),
+                FeeDestinationV2::new("fee-address-2", Uint128::new(100)),
+            ],
+            None,
+        );
+        assert_eq!(
+            350,
+            verifier.get_fee_total(),
+            "expected the fee total to combine basis point and flat fees (25% of 1000 + 100 = 350)",
+        );
+    }
+
+    #[test]
+    fn test_rounding_remainder_is_awarded_to_designated_destination() {
+        let verifier = VerifierDetailV2::new(
+            "address",
+            Uint128::new(10),
+            NHASH,
+            vec![
+                FeeDestinationV2::new_basis_points("fee-address-1", 3333),
+                FeeDestinationV2::new_basis_points("fee-address-2", 3333).with_rounding_remainder(),
+                FeeDestinationV2::new_basis_points("fee-address-3", 3334),
+            ],
+            None,
+        );
+        let amounts = verifier
+            .get_fee_destination_amounts()
+            .into_iter()
+            .map(|(d, amount)| (d.address.as_str(), amount))
+            .collect::<Vec<(&str, u128)>>();
+        assert_eq!(
+            vec![("fee-address-1", 3), ("fee-address-2", 4), ("fee-address-3", 3)],
+            amounts,
+            "each destination should be rounded down, with the lost amount awarded to the designated destination",
+        );
+        assert_eq!(
+            10,
+            verifier.get_fee_total(),
+            "the entire onboarding cost should be distributed when basis points sum to 100%",
+        );
+    }
+
+    #[test]
+    fn test_rounding_remainder_is_undistributed_without_designated_destination() {
+        let verifier = VerifierDetailV2::new(
+            "address",
+            Uint128::new(10),
+            NHASH,
+            vec![
+                FeeDestinationV2::new_basis_points("fee-address-1", 5050),
+                FeeDestinationV2::new_basis_points("fee-address-2", 4950),
+            ],
+            None,
+        );
+        assert_eq!(
+            9,
+            verifier.get_fee_total(),
+            "the rounding remainder should not be included in the fee total when no destination is designated to receive it",
+        );
+    }
+
+    #[test]
+    fn test_apply_fee_discount_scales_onboarding_cost_and_flat_fees() {
+        let verifier = VerifierDetailV2::new(
+            "address",
+            Uint128::new(1001),
+            NHASH,
+            vec![
+                FeeDestinationV2::new("fee-address-1", Uint128::new(501)),
+                FeeDestinationV2::new_basis_points("fee-address-2", 1000),
+            ],
+            None,
+        );
+        let discounted = verifier.apply_fee_discount(5000);
+        assert_eq!(
+            500,
+            discounted.onboarding_cost.u128(),
+            "the onboarding cost should be halved and rounded down",
+        );
+        assert_eq!(
+            250,
+            discounted.fee_destinations[0].fee_amount.u128(),
+            "flat fee amounts should be halved and rounded down",
+        );
+        assert_eq!(
+            Some(1000),
+            discounted.fee_destinations[1].fee_basis_points,
+            "basis point fee destinations should remain unchanged",
+        );
+        assert_eq!(
+            300,
+            discounted.get_fee_total(),
+            "the fee total should reflect the discounted onboarding cost",
+        );
+        let waived = verifier.apply_fee_discount(10000);
+        assert!(waived.onboarding_cost.is_zero());
+        assert_eq!(0, waived.get_fee_total());
+    }
+
+    #[test]
+    fn test_apply_pricing_tier_uses_highest_reached_tier() {
+        let verifier = VerifierDetailV2::new(
+            "address",
+            Uint128::new(1000),
+            NHASH,
+            vec![FeeDestinationV2::new("fee-address", Uint128::new(333))],
+            None,
+        )
+        .with_pricing_tiers(vec![
+            PricingTier::new(1000, Uint128::new(500)),
+            PricingTier::new(100, Uint128::new(800)),
+        ]);
+        assert_eq!(
+            verifier,
+            verifier.apply_pricing_tier(99),
+            "the verifier should be unchanged when no tier has been reached",
+        );
+        let tiered = verifier.apply_pricing_tier(999);
+        assert_eq!(800, tiered.onboarding_cost.u128());
+        assert_eq!(
+            266,
+            tiered.fee_destinations[0].fee_amount.u128(),
+            "flat fee amounts should be scaled to the tier's cost and rounded down",
+        );
+        assert_eq!(
+            Some(PricingTier::new(100, Uint128::new(800))),
+            tiered.applied_pricing_tier,
+            "the applied tier should be recorded",
+        );
+        let tiered = verifier.apply_pricing_tier(5000);
+        assert_eq!(
+            500,
+            tiered.onboarding_cost.u128(),
+            "the tier with the highest reached minimum should be used",
+        );
+    }
+    #[test]
+    fn test_intake_fee_is_excluded_from_completion_fee() {
+        let verifier = VerifierDetailV2::new(
+            "address",
+            Uint128::new(1000),
+            NHASH,
+            vec![FeeDestinationV2::new_basis_points("fee-address", 5000)],
+            None,
+        )
+        .with_intake_fee(
+            Uint128::new(400),
+            vec![FeeDestinationV2::new_basis_points("intake-address", 2500)],
+        );
+        assert_eq!(
+            600,
+            verifier.get_completion_fee().u128(),
+            "the completion fee should be the onboarding cost minus the intake fee",
+        );
+        assert_eq!(
+            300,
+            verifier.get_fee_total(),
+            "basis point fee destinations should be derived from the completion fee",
+        );
+        assert_eq!(
+            100,
+            verifier.get_intake_fee_total(),
+            "basis point intake fee destinations should be derived from the intake fee",
+        );
+        assert_eq!(300, verifier.get_intake_fee_remainder());
+        let discounted = verifier.apply_fee_discount(5000);
+        assert_eq!(
+            200,
+            discounted.intake_fee.u128(),
+            "the intake fee should be scaled by a fee discount",
+        );
+    }
 }
@@ -0,0 +1,119 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Measures the performance of a single verifier across every asset type in which it is
+/// registered.  Unlike [OnboardingStatistics](super::onboarding_statistics::OnboardingStatistics),
+/// which only count events, these values describe how quickly the verifier processes assets and how
+/// many assets are still waiting on it.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VerifierMetrics {
+    /// The total number of assets that the verifier has marked as successfully verified.
+    pub approvals: u64,
+    /// The total number of assets that the verifier has rejected.
+    pub denials: u64,
+    /// The number of verifications for which the block height of the asset's onboarding was known.
+    /// Only these verifications contribute to the block-based metrics.  Assets onboarded before
+    /// onboarding heights were recorded are excluded.
+    pub timed_verifications: u64,
+    /// The sum of the blocks elapsed between onboarding and verification for all timed verifications.
+    pub total_verification_blocks: u64,
+    /// The average number of blocks elapsed between onboarding and verification, rounded down.
+    pub average_verification_blocks: u64,
+    /// The largest number of blocks elapsed between onboarding and verification of a single asset.
+    pub max_verification_blocks: u64,
+    /// The number of assets currently awaiting verification by the verifier.
+    pub pending_assets: u64,
+}
+impl VerifierMetrics {
+    /// Counts a newly onboarded asset as awaiting verification by the verifier.
+    pub fn record_onboarding(&mut self) {
+        self.pending_assets += 1;
+    }
+
+    /// Records the outcome of a verification and removes the asset from the verifier's pending
+    /// assets.
+    ///
+    /// # Parameters
+    ///
+    /// * `success` Whether or not the verifier approved the asset.
+    /// * `elapsed_blocks` The number of blocks that passed between the asset's onboarding and its
+    /// verification, if the onboarding height is known.
+    pub fn record_verification(&mut self, success: bool, elapsed_blocks: Option<u64>) {
+        if success {
+            self.approvals += 1;
+        } else {
+            self.denials += 1;
+        }
+        if let Some(elapsed_blocks) = elapsed_blocks {
+            self.timed_verifications += 1;
+            self.total_verification_blocks = self
+                .total_verification_blocks
+                .saturating_add(elapsed_blocks);
+            self.average_verification_blocks =
+                self.total_verification_blocks / self.timed_verifications;
+            self.max_verification_blocks = self.max_verification_blocks.max(elapsed_blocks);
+        }
+        self.release_pending_asset();
+    }
+
+    /// Removes an asset from the verifier's pending assets without recording a verification, for
+    /// when the asset is refunded or reassigned to another verifier.
+    pub fn release_pending_asset(&mut self) {
+        // Assets onboarded before pending assets were tracked may be released without having been
+        // counted, so the count never drops below zero
+        self.pending_assets = self.pending_assets.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VerifierMetrics;
+
+    #[test]
+    fn test_record_onboarding() {
+        let mut metrics = VerifierMetrics::default();
+        metrics.record_onboarding();
+        metrics.record_onboarding();
+        assert_eq!(
+            2, metrics.pending_assets,
+            "each onboarding should add a pending asset"
+        );
+    }
+
+    #[test]
+    fn test_record_verification() {
+        let mut metrics = VerifierMetrics {
+            pending_assets: 3,
+            ..VerifierMetrics::default()
+        };
+        metrics.record_verification(true, Some(10));
+        metrics.record_verification(false, Some(5));
+        metrics.record_verification(true, None);
+        assert_eq!(
+            VerifierMetrics {
+                approvals: 2,
+                denials: 1,
+                timed_verifications: 2,
+                total_verification_blocks: 15,
+                average_verification_blocks: 7,
+                max_verification_blocks: 10,
+                pending_assets: 0,
+            },
+            metrics,
+            "verifications without a known onboarding height should only affect the counters",
+        );
+    }
+
+    #[test]
+    fn test_release_pending_asset_never_underflows() {
+        let mut metrics = VerifierMetrics::default();
+        metrics.release_pending_asset();
+        metrics.record_verification(false, None);
+        assert_eq!(
+            0, metrics.pending_assets,
+            "releasing an untracked asset should leave the pending count at zero",
+        );
+        assert_eq!(1, metrics.denials, "the denial should still be counted");
+    }
+}
@@ -0,0 +1,164 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::entity_detail::EntityDetail;
+use super::fee_destination::FeeDestinationV2;
+use super::verifier_detail::VerifierDetailV2;
+
+/// The global record of a verifier, shared by every [AssetDefinitionV2](super::asset_definition::AssetDefinitionV2)
+/// that references the verifier's address.  Asset definitions do not store these values for
+/// registered verifiers, and instead each [VerifierDetailV2](super::verifier_detail::VerifierDetailV2)
+/// resolves them from the registration by address whenever it is loaded, which prevents a verifier
+/// that serves many asset types from requiring a separate update for each.  A verifier is
+/// registered automatically the first time it is added to any asset type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VerifierRegistration {
+    /// The Provenance Blockchain bech32 address of the verifier account.
+    pub address: String,
+    /// An optional set of fields that define the verifier, including its name and home URL
+    /// location.  This value is always copied into every asset type that uses the verifier.
+    pub entity_detail: Option<EntityDetail>,
+    /// The default total amount charged to use the onboarding process with this verifier.
+    pub onboarding_cost: Uint128,
+    /// The default coin denomination used for the onboarding process.
+    pub onboarding_denom: String,
+    /// The default accounts that should receive fees when a scope onboarded with this verifier is
+    /// verified.
+    pub fee_destinations: Vec<FeeDestinationV2>,
+}
+impl VerifierRegistration {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `address` The Provenance Blockchain bech32 address of the verifier account.
+    /// * `entity_detail` An optional set of fields that define the verifier.
+    /// * `onboarding_cost` The default total amount charged to use the onboarding process.
+    /// * `onboarding_denom` The default coin denomination used for the onboarding process.
+    /// * `fee_destinations` The default accounts that should receive fees after verification.
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        address: S1,
+        entity_detail: Option<EntityDetail>,
+        onboarding_cost: Uint128,
+        onboarding_denom: S2,
+        fee_destinations: Vec<FeeDestinationV2>,
+    ) -> Self {
+        Self {
+            address: address.into(),
+            entity_detail,
+            onboarding_cost,
+            onboarding_denom: onboarding_denom.into(),
+            fee_destinations,
+        }
+    }
+
+    /// Creates a registration from the values held by an existing verifier detail.  Used when
+    /// populating the registry from asset definitions that existed before it.
+    ///
+    /// # Parameters
+    ///
+    /// * `verifier` The verifier detail from which to copy values.
+    pub fn from_verifier_detail(verifier: &VerifierDetailV2) -> Self {
+        Self::new(
+            &verifier.address,
+            verifier.entity_detail.clone(),
+            verifier.onboarding_cost,
+            &verifier.onboarding_denom,
+            verifier.fee_destinations.clone(),
+        )
+    }
+
+    /// Creates a new verifier detail that uses this registration's values, and the defaults for
+    /// all other fields.
+    pub fn to_verifier_detail(&self) -> VerifierDetailV2 {
+        VerifierDetailV2::new(
+            &self.address,
+            self.onboarding_cost,
+            &self.onboarding_denom,
+            self.fee_destinations.clone(),
+            self.entity_detail.clone(),
+        )
+    }
+
+    /// Determines if the fees of the given verifier detail match the fees of this registration.
+    ///
+    /// # Parameters
+    ///
+    /// * `verifier` The verifier detail to compare.
+    pub fn has_matching_fees(&self, verifier: &VerifierDetailV2) -> bool {
+        self.onboarding_cost == verifier.onboarding_cost
+            && self.onboarding_denom == verifier.onboarding_denom
+            && self.fee_destinations == verifier.fee_destinations
+    }
+
+    /// Copies this registration's values into a verifier detail with the same address.  Used to
+    /// resolve the values of a verifier detail whenever an asset definition is loaded.  The
+    /// entity detail is always copied, but the fees are left untouched if the verifier detail
+    /// [overrides_registered_fees](super::verifier_detail::VerifierDetailV2::overrides_registered_fees).
+    ///
+    /// # Parameters
+    ///
+    /// * `verifier` The verifier detail to modify.
+    pub fn apply_to(&self, verifier: &mut VerifierDetailV2) {
+        verifier.entity_detail = self.entity_detail.clone();
+        if !verifier.overrides_registered_fees {
+            verifier.onboarding_cost = self.onboarding_cost;
+            verifier.onboarding_denom = self.onboarding_denom.clone();
+            verifier.fee_destinations = self.fee_destinations.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint128;
+
+    use crate::core::types::entity_detail::EntityDetail;
+    use crate::core::types::fee_destination::FeeDestinationV2;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::util::traits::OptionExtensions;
+
+    use super::VerifierRegistration;
+
+    #[test]
+    fn test_apply_to() {
+        let registration = VerifierRegistration::new(
+            "verifier",
+            EntityDetail::new("name", "description", "home", "source").to_some(),
+            Uint128::new(200),
+            "nhash",
+            vec![FeeDestinationV2::new("fee", Uint128::new(100))],
+        );
+        let mut verifier =
+            VerifierDetailV2::new("verifier", Uint128::new(100), "nhash", vec![], None);
+        registration.apply_to(&mut verifier);
+        assert_eq!(
+            registration.to_verifier_detail(),
+            verifier,
+            "the registration's values should be copied into the verifier detail",
+        );
+        let mut overriding_verifier =
+            VerifierDetailV2::new("verifier", Uint128::new(100), "nhash", vec![], None)
+                .with_overrides_registered_fees(true);
+        registration.apply_to(&mut overriding_verifier);
+        assert_eq!(
+            registration.entity_detail, overriding_verifier.entity_detail,
+            "the entity detail should always be copied",
+        );
+        assert_eq!(
+            Uint128::new(100),
+            overriding_verifier.onboarding_cost,
+            "the onboarding cost should be retained when the verifier overrides the registered fees",
+        );
+        assert!(
+            overriding_verifier.fee_destinations.is_empty(),
+            "the fee destinations should be retained when the verifier overrides the registered fees",
+        );
+        assert!(
+            !registration.has_matching_fees(&overriding_verifier),
+            "the overriding verifier's fees should differ from the registration",
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::verifier_detail::VerifierDetailV2;
+
+/// Names a single property of a [VerifierDetailV2](super::verifier_detail::VerifierDetailV2), used
+/// to control which properties a verifier can change with the [SelfUpdateAssetVerifier](crate::core::msg::ExecuteMsg::SelfUpdateAssetVerifier)
+/// route.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VerifierDetailField {
+    /// The [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost) property.
+    OnboardingCost,
+    /// The [onboarding_denom](super::verifier_detail::VerifierDetailV2::onboarding_denom) property.
+    /// This property can never be changed by the verifier, because the onboarding cost cap is
+    /// expressed in the current denom.
+    OnboardingDenom,
+    /// The [fee_destinations](super::verifier_detail::VerifierDetailV2::fee_destinations) property.
+    FeeDestinations,
+    /// The [entity_detail](super::verifier_detail::VerifierDetailV2::entity_detail) property.
+    EntityDetail,
+    /// The [remainder_destination](super::verifier_detail::VerifierDetailV2::remainder_destination) property.
+    RemainderDestination,
+    /// The [accrue_fees](super::verifier_detail::VerifierDetailV2::accrue_fees) property.
+    AccrueFees,
+    /// The [fee_discounts](super::verifier_detail::VerifierDetailV2::fee_discounts) property.
+    FeeDiscounts,
+    /// The [pricing_tiers](super::verifier_detail::VerifierDetailV2::pricing_tiers) property.
+    /// This property can never be changed by the verifier, because tiers can charge more than the
+    /// capped onboarding cost.
+    PricingTiers,
+    /// The [applied_pricing_tier](super::verifier_detail::VerifierDetailV2::applied_pricing_tier)
+    /// property.  This property can never be changed by the verifier.
+    AppliedPricingTier,
+    /// The [intake_fee](super::verifier_detail::VerifierDetailV2::intake_fee) property.
+    IntakeFee,
+    /// The [intake_fee_destinations](super::verifier_detail::VerifierDetailV2::intake_fee_destinations) property.
+    IntakeFeeDestinations,
+    /// The [enabled](super::verifier_detail::VerifierDetailV2::enabled) property.  This property
+    /// can never be changed by the verifier, and is instead managed with the [ToggleAssetVerifier](crate::core::msg::ExecuteMsg::ToggleAssetVerifier)
+    /// route.
+    Enabled,
+    /// The [overrides_registered_fees](super::verifier_detail::VerifierDetailV2::overrides_registered_fees)
+    /// property.  This property can never be changed by the verifier.
+    OverridesRegisteredFees,
+}
+impl VerifierDetailField {
+    /// Returns the snake_case name of the field, matching its serialized form.
+    pub fn name(&self) -> &'static str {
+        match self {
+            VerifierDetailField::OnboardingCost => "onboarding_cost",
+            VerifierDetailField::OnboardingDenom => "onboarding_denom",
+            VerifierDetailField::FeeDestinations => "fee_destinations",
+            VerifierDetailField::EntityDetail => "entity_detail",
+            VerifierDetailField::RemainderDestination => "remainder_destination",
+            VerifierDetailField::AccrueFees => "accrue_fees",
+            VerifierDetailField::FeeDiscounts => "fee_discounts",
+            VerifierDetailField::PricingTiers => "pricing_tiers",
+            VerifierDetailField::AppliedPricingTier => "applied_pricing_tier",
+            VerifierDetailField::IntakeFee => "intake_fee",
+            VerifierDetailField::IntakeFeeDestinations => "intake_fee_destinations",
+            VerifierDetailField::Enabled => "enabled",
+            VerifierDetailField::OverridesRegisteredFees => "overrides_registered_fees",
+        }
+    }
+
+    /// Determines if the field can ever be changed by the verifier itself.  Fields that return
+    /// false can only be changed through the admin-controlled verifier routes.
+    pub fn is_self_updatable(&self) -> bool {
+        !matches!(
+            self,
+            VerifierDetailField::OnboardingDenom
+                | VerifierDetailField::PricingTiers
+                | VerifierDetailField::AppliedPricingTier
+                | VerifierDetailField::Enabled
+                | VerifierDetailField::OverridesRegisteredFees
+        )
+    }
+
+    /// Lists every field whose value differs between two verifier details, in declaration order.
+    /// The [address](super::verifier_detail::VerifierDetailV2::address) is not compared.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` The verifier detail as it is currently stored.
+    /// * `updated` The proposed replacement verifier detail.
+    pub fn changed_fields(
+        current: &VerifierDetailV2,
+        updated: &VerifierDetailV2,
+    ) -> Vec<VerifierDetailField> {
+        let mut changed_fields = vec![];
+        let mut check = |field: VerifierDetailField, changed: bool| {
+            if changed {
+                changed_fields.push(field);
+            }
+        };
+        check(
+            VerifierDetailField::OnboardingCost,
+            current.onboarding_cost != updated.onboarding_cost,
+        );
+        check(
+            VerifierDetailField::OnboardingDenom,
+            current.onboarding_denom != updated.onboarding_denom,
+        );
+        check(
+            VerifierDetailField::FeeDestinations,
+            current.fee_destinations != updated.fee_destinations,
+        );
+        check(
+            VerifierDetailField::EntityDetail,
+            current.entity_detail != updated.entity_detail,
+        );
+        check(
+            VerifierDetailField::RemainderDestination,
+            current.remainder_destination != updated.remainder_destination,
+        );
+        check(
+            VerifierDetailField::AccrueFees,
+            current.accrue_fees != updated.accrue_fees,
+        );
+        check(
+            VerifierDetailField::FeeDiscounts,
+            current.fee_discounts != updated.fee_discounts,
+        );
+        check(
+            VerifierDetailField::PricingTiers,
+            current.pricing_tiers != updated.pricing_tiers,
+        );
+        check(
+            VerifierDetailField::AppliedPricingTier,
+            current.applied_pricing_tier != updated.applied_pricing_tier,
+        );
+        check(
+            VerifierDetailField::IntakeFee,
+            current.intake_fee != updated.intake_fee,
+        );
+        check(
+            VerifierDetailField::IntakeFeeDestinations,
+            current.intake_fee_destinations != updated.intake_fee_destinations,
+        );
+        check(
+            VerifierDetailField::Enabled,
+            current.enabled != updated.enabled,
+        );
+        check(
+            VerifierDetailField::OverridesRegisteredFees,
+            current.overrides_registered_fees != updated.overrides_registered_fees,
+        );
+        changed_fields
+    }
+}
+impl std::fmt::Display for VerifierDetailField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The limits set by the admin on the changes that a verifier can make to its own [VerifierDetailV2](super::verifier_detail::VerifierDetailV2)
+/// within a single [AssetDefinitionV2](super::asset_definition::AssetDefinitionV2).  A verifier
+/// without stored bounds uses the default value, which allows every self-updatable field to change
+/// but prevents the onboarding cost from being raised.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VerifierSelfUpdateBounds {
+    /// The highest [onboarding_cost](super::verifier_detail::VerifierDetailV2::onboarding_cost)
+    /// that the verifier can set.  If omitted, the verifier can lower its onboarding cost, but
+    /// cannot raise it.
+    pub max_onboarding_cost: Option<Uint128>,
+    /// Fields that the verifier cannot change, in addition to those that are never
+    /// [self-updatable](self::VerifierDetailField::is_self_updatable).
+    #[serde(default)]
+    pub locked_fields: Vec<VerifierDetailField>,
+}
+impl VerifierSelfUpdateBounds {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_onboarding_cost` The highest onboarding cost that the verifier can set.
+    /// * `locked_fields` Fields that the verifier cannot change.
+    pub fn new(
+        max_onboarding_cost: Option<Uint128>,
+        locked_fields: Vec<VerifierDetailField>,
+    ) -> Self {
+        Self {
+            max_onboarding_cost,
+            locked_fields,
+        }
+    }
+
+    /// Determines if the verifier is allowed to change the given field.
+    ///
+    /// # Parameters
+    ///
+    /// * `field` The field to check.
+    pub fn allows_change_to(&self, field: &VerifierDetailField) -> bool {
+        field.is_self_updatable() && !self.locked_fields.contains(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint128;
+
+    use crate::core::types::entity_detail::EntityDetail;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{VerifierDetailField, VerifierSelfUpdateBounds};
+
+    #[test]
+    fn test_changed_fields() {
+        let current = VerifierDetailV2::new("verifier", Uint128::new(100), "nhash", vec![], None);
+        assert!(
+            VerifierDetailField::changed_fields(&current, &current.clone()).is_empty(),
+            "identical verifier details should have no changed fields",
+        );
+        let mut updated = current.clone();
+        updated.onboarding_cost = Uint128::new(150);
+        updated.onboarding_denom = "otherdenom".to_string();
+        updated.entity_detail =
+            EntityDetail::new("name", "description", "home", "source").to_some();
+        assert_eq!(
+            vec![
+                VerifierDetailField::OnboardingCost,
+                VerifierDetailField::OnboardingDenom,
+                VerifierDetailField::EntityDetail,
+            ],
+            VerifierDetailField::changed_fields(&current, &updated),
+            "each modified field should be listed in declaration order",
+        );
+    }
+
+    #[test]
+    fn test_allows_change_to() {
+        let bounds =
+            VerifierSelfUpdateBounds::new(None, vec![VerifierDetailField::FeeDestinations]);
+        assert!(bounds.allows_change_to(&VerifierDetailField::EntityDetail));
+        assert!(!bounds.allows_change_to(&VerifierDetailField::FeeDestinations));
+        assert!(!bounds.allows_change_to(&VerifierDetailField::OnboardingDenom));
+        assert!(!bounds.allows_change_to(&VerifierDetailField::PricingTiers));
+        assert!(!bounds.allows_change_to(&VerifierDetailField::Enabled));
+        assert!(!bounds.allows_change_to(&VerifierDetailField::OverridesRegisteredFees));
+    }
+}
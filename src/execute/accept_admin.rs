@@ -0,0 +1,241 @@
+use cosmwasm_std::{MessageInfo, Response};
+
+use crate::core::state::{config_read_v2, config_v2};
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::check_funds_are_empty,
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::AcceptAdmin](crate::core::msg::ExecuteMsg::AcceptAdmin)
+/// for ease of use in the underlying [accept_admin](self::accept_admin) function.  The message
+/// contains no fields, as the sender is always the address accepting control of the contract.
+#[derive(Clone, PartialEq)]
+pub struct AcceptAdminV1;
+impl AcceptAdminV1 {
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [AcceptAdmin](crate::core::msg::ExecuteMsg::AcceptAdmin)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<AcceptAdminV1> {
+        match msg {
+            ExecuteMsg::AcceptAdmin {} => AcceptAdminV1.to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::AcceptAdmin".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::AcceptAdmin](crate::core::msg::ExecuteMsg::AcceptAdmin)
+/// message is provided.  Completes an admin transfer started by [propose_admin](crate::execute::propose_admin::propose_admin).
+/// The sender must be the pending admin in the contract's [state](crate::core::state::StateV2),
+/// and becomes the admin once this succeeds.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `_msg` An instance of the accept admin v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn accept_admin(deps: DepsMutC, info: MessageInfo, _msg: AcceptAdminV1) -> EntryPointResponse {
+    check_funds_are_empty(&info)?;
+    let mut state = config_read_v2(deps.storage).load()?;
+    let pending_admin = match state.pending_admin {
+        Some(ref pending_admin) => pending_admin.clone(),
+        None => {
+            return ContractError::NotFound {
+                explanation: "no admin transfer has been proposed".to_string(),
+            }
+            .to_err();
+        }
+    };
+    if info.sender != pending_admin {
+        return ContractError::Unauthorized {
+            explanation: "only the pending admin can accept the admin proposal".to_string(),
+        }
+        .to_err();
+    }
+    let previous_admin = state.admin;
+    state.admin = pending_admin;
+    state.pending_admin = None;
+    config_v2(deps.storage).save(&state)?;
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("previous_admin", previous_admin.as_str());
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::AcceptAdmin)
+                .set_new_value(&state.admin)
+                .set_additional_metadata(&additional_metadata),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::config_read_v2;
+    use crate::execute::propose_admin::{propose_admin, ProposeAdminV1};
+    use crate::execute::update_protocol_fee::{update_protocol_fee, UpdateProtocolFeeV1};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS, DEFAULT_SENDER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, mock_info_with_nhash, single_attribute_for_key, test_instantiate_success,
+        InstArgs,
+    };
+    use crate::util::aliases::DepsMutC;
+    use crate::util::constants::{ADDITIONAL_METADATA_KEY, ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+
+    use super::{accept_admin, AcceptAdminV1};
+
+    #[test]
+    fn test_valid_accept_admin_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        propose_fee_address_as_admin(deps.as_mut());
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            ExecuteMsg::AcceptAdmin {},
+        )
+        .expect("accepting the admin proposal should succeed");
+        assert_eq!(
+            EventType::AcceptAdmin.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_FEE_ADDRESS,
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the new admin should be emitted",
+        );
+        assert_eq!(
+            format!("[previous_admin={}]", DEFAULT_ADMIN_ADDRESS),
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the previous admin should be emitted",
+        );
+        let state = config_read_v2(deps.as_ref().storage)
+            .load()
+            .expect("state should load");
+        assert_eq!(
+            DEFAULT_FEE_ADDRESS,
+            state.admin.as_str(),
+            "the pending admin should become the admin",
+        );
+        assert!(
+            state.pending_admin.is_none(),
+            "the pending admin should be cleared after acceptance",
+        );
+    }
+
+    #[test]
+    fn test_accepted_admin_replaces_previous_admin_access() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        propose_fee_address_as_admin(deps.as_mut());
+        accept_admin(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            AcceptAdminV1,
+        )
+        .expect("accepting the admin proposal should succeed");
+        let error = update_protocol_fee(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateProtocolFeeV1::new(None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the previous admin to lose access, but got: {:?}",
+            error,
+        );
+        update_protocol_fee(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            UpdateProtocolFeeV1::new(None),
+        )
+        .expect("the new admin should have access to admin routes");
+    }
+
+    #[test]
+    fn test_invalid_accept_admin_without_proposal() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = accept_admin(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            AcceptAdminV1,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected the not found error to be returned when no proposal exists, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_accept_admin_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        propose_fee_address_as_admin(deps.as_mut());
+        let error = accept_admin(
+            deps.as_mut(),
+            mock_info(DEFAULT_SENDER_ADDRESS, &[]),
+            AcceptAdminV1,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected only the pending admin to be able to accept, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_accept_admin_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        propose_fee_address_as_admin(deps.as_mut());
+        let error = accept_admin(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_FEE_ADDRESS, 150),
+            AcceptAdminV1,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+
+    fn propose_fee_address_as_admin(deps: DepsMutC) {
+        propose_admin(
+            deps,
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ProposeAdminV1::new(DEFAULT_FEE_ADDRESS),
+        )
+        .expect("proposing a new admin should succeed");
+    }
+}
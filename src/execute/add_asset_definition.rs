@@ -2,8 +2,10 @@ use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
 use crate::core::state::{config_read_v2, insert_asset_definition_v2};
 use crate::core::types::asset_definition::AssetDefinitionV2;
+use crate::core::types::contract_role::ContractRole;
+use crate::execute::register_verifier::register_verifier_details;
 use crate::util::aliases::{AssetResult, DepsMutC, EntryPointResponse};
-use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
+use crate::util::contract_helpers::{check_funds_are_empty, check_role};
 use crate::util::event_attributes::{EventAttributes, EventType};
 use crate::util::functions::generate_asset_attribute_name;
 use crate::util::traits::ResultExtensions;
@@ -67,11 +69,12 @@ pub fn add_asset_definition(
     deps: DepsMutC,
     env: Env,
     info: MessageInfo,
-    msg: AddAssetDefinitionV1,
+    mut msg: AddAssetDefinitionV1,
 ) -> EntryPointResponse {
     // Verify that the admin is making this call and no funds are provided
-    check_admin_only(&deps.as_ref(), &info)?;
+    check_role(&deps.as_ref(), &info, ContractRole::DefinitionManager)?;
     check_funds_are_empty(&info)?;
+    register_verifier_details(deps.storage, &mut msg.asset_definition)?;
     // The insert function includes its own checking to verify that the asset definition does not yet exist, and an error
     // will be returned if a duplicate is attempted
     insert_asset_definition_v2(deps.storage, &msg.asset_definition)?;
@@ -347,7 +350,8 @@ mod tests {
             TEST_ASSET_TYPE,
             ScopeSpecIdentifier::address(TEST_SCOPE_SPEC_ADDRESS).to_serialized_enum(),
             // Defining the verifier to be the same as the default values is fine, because
-            // it is realistic that different asset types might use the same verifiers
+            // it is realistic that different asset types might use the same verifiers.  Its fee
+            // destinations differ from the default verifier's registration, so they are overrides
             vec![VerifierDetailV2::new(
                 DEFAULT_VERIFIER_ADDRESS,
                 Uint128::new(1000),
@@ -357,7 +361,8 @@ mod tests {
                     Uint128::new(500),
                 )],
                 get_default_entity_detail().to_some(),
-            )],
+            )
+            .with_overrides_registered_fees(true)],
             None,
             None,
         );
@@ -1,12 +1,14 @@
 use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
 use crate::core::state::{load_asset_definition_v2_by_type, replace_asset_definition_v2};
+use crate::core::types::contract_role::ContractRole;
 use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::execute::register_verifier::register_verifier_detail;
 use crate::util::aliases::{AssetResult, DepsMutC, EntryPointResponse};
-use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
+use crate::util::contract_helpers::{check_funds_are_empty, check_role};
 use crate::util::event_attributes::{EventAttributes, EventType};
 use crate::util::traits::ResultExtensions;
-use cosmwasm_std::{MessageInfo, Response};
+use cosmwasm_std::{Env, MessageInfo, Response};
 
 /// A transformation of [ExecuteMsg::AddAssetVerifier](crate::core::msg::ExecuteMsg::AddAssetVerifier)
 /// for ease of use in the underlying [add_asset_verifier](self::add_asset_verifier) function.
@@ -71,16 +73,19 @@ impl AddAssetVerifierV1 {
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
 /// of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` An instance of the add asset verifier v1 struct, provided by conversion from an
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg).
 pub fn add_asset_verifier(
     deps: DepsMutC,
+    env: Env,
     info: MessageInfo,
-    msg: AddAssetVerifierV1,
+    mut msg: AddAssetVerifierV1,
 ) -> EntryPointResponse {
-    check_admin_only(&deps.as_ref(), &info)?;
+    check_role(&deps.as_ref(), &info, ContractRole::VerifierManager)?;
     check_funds_are_empty(&info)?;
     let mut asset_definition = load_asset_definition_v2_by_type(deps.storage, &msg.asset_type)?;
     // If the asset definition has any verifiers on it (only ever should be 1 max) with a matching
@@ -97,8 +102,14 @@ pub fn add_asset_verifier(
         .set_asset_type(&asset_definition.asset_type)
         .set_verifier(&msg.verifier.address);
     // Store the new verifier in the definition and save it to storage
+    register_verifier_detail(deps.storage, &mut msg.verifier)?;
     asset_definition.verifiers.push(msg.verifier);
-    replace_asset_definition_v2(deps.storage, &asset_definition)?;
+    replace_asset_definition_v2(
+        deps.storage,
+        &asset_definition,
+        env.block.height,
+        info.sender.as_str(),
+    )?;
     // Respond with emitted attributes
     Response::new().add_attributes(attributes).to_ok()
 }
@@ -109,9 +120,10 @@ mod tests {
     use crate::contract::execute;
     use crate::core::error::ContractError;
     use crate::core::msg::ExecuteMsg;
-    use crate::core::state::load_asset_definition_v2_by_type;
+    use crate::core::state::{load_asset_definition_v2_by_type, load_verifier_registration};
     use crate::core::types::fee_destination::FeeDestinationV2;
     use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::core::types::verifier_registration::VerifierRegistration;
     use crate::execute::add_asset_verifier::{add_asset_verifier, AddAssetVerifierV1};
     use crate::testutil::test_constants::{
         DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS,
@@ -183,6 +195,7 @@ mod tests {
         let msg = get_add_verifier();
         add_asset_verifier(
             deps.as_mut(),
+            mock_env(),
             mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
             msg.clone(),
         )
@@ -190,6 +203,26 @@ mod tests {
         test_default_verifier_was_added(&msg.verifier, &deps.as_ref());
     }
 
+    #[test]
+    fn test_valid_add_asset_verifier_registers_new_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let msg = get_add_verifier();
+        add_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
+            msg.clone(),
+        )
+        .expect("expected the add verifier function to return properly");
+        assert_eq!(
+            VerifierRegistration::from_verifier_detail(&msg.verifier),
+            load_verifier_registration(deps.as_ref().storage, TEST_VERIFIER_ADDRESS)
+                .expect("the added verifier should be registered"),
+            "the registration should use the values of the added verifier",
+        );
+    }
+
     #[test]
     fn test_invalid_add_asset_verifier_for_invalid_asset_type() {
         let mut deps = mock_dependencies(&[]);
@@ -240,6 +273,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let error = add_asset_verifier(
             deps.as_mut(),
+            mock_env(),
             mock_info("non-admin-person", &[]),
             get_add_verifier(),
         )
@@ -257,6 +291,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let error = add_asset_verifier(
             deps.as_mut(),
+            mock_env(),
             mock_info(DEFAULT_ADMIN_ADDRESS, &[coin(6900, "nhash")]),
             get_add_verifier(),
         )
@@ -274,6 +309,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let error = add_asset_verifier(
             deps.as_mut(),
+            mock_env(),
             mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
             AddAssetVerifierV1::new(
                 DEFAULT_ASSET_TYPE,
@@ -0,0 +1,293 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{
+    load_asset_definition_v2_by_type, load_verifier_registration, replace_asset_definition_v2,
+};
+use crate::core::types::contract_role::ContractRole;
+use crate::util::aliases::{AssetResult, DepsMutC, EntryPointResponse};
+use crate::util::contract_helpers::{check_funds_are_empty, check_role};
+use crate::util::event_attributes::{EventAttributes, EventType};
+use crate::util::traits::ResultExtensions;
+use cosmwasm_std::{Env, MessageInfo, Response};
+
+/// A transformation of [ExecuteMsg::AddRegisteredAssetVerifier](crate::core::msg::ExecuteMsg::AddRegisteredAssetVerifier)
+/// for ease of use in the underlying [add_registered_asset_verifier](self::add_registered_asset_verifier) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The type of asset, corresponding to the [asset_type](crate::core::types::asset_definition::AssetDefinitionV2::asset_type)
+/// value of an existing [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
+/// in contract storage.
+/// * `verifier_address` The bech32 address of a verifier with an existing [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration).
+#[derive(Clone, PartialEq)]
+pub struct AddRegisteredAssetVerifierV1 {
+    pub asset_type: String,
+    pub verifier_address: String,
+}
+impl AddRegisteredAssetVerifierV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The type of asset, corresponding to the [asset_type](crate::core::types::asset_definition::AssetDefinitionV2::asset_type)
+    /// value of an existing [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
+    /// in contract storage.
+    /// * `verifier_address` The bech32 address of a verifier with an existing [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration).
+    pub fn new<S1: Into<String>, S2: Into<String>>(asset_type: S1, verifier_address: S2) -> Self {
+        AddRegisteredAssetVerifierV1 {
+            asset_type: asset_type.into(),
+            verifier_address: verifier_address.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [AddRegisteredAssetVerifier](crate::core::msg::ExecuteMsg::AddRegisteredAssetVerifier)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<AddRegisteredAssetVerifierV1> {
+        match msg {
+            ExecuteMsg::AddRegisteredAssetVerifier {
+                asset_type,
+                verifier_address,
+            } => AddRegisteredAssetVerifierV1::new(asset_type, verifier_address).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::AddRegisteredAssetVerifier".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::AddRegisteredAssetVerifier](crate::core::msg::ExecuteMsg::AddRegisteredAssetVerifier)
+/// message is provided.  Adds a new [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
+/// built from a verifier's [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration)
+/// to an existing [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2) if no
+/// verifier exists with a matching address.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the add registered asset verifier v1 struct, provided by conversion
+/// from an [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn add_registered_asset_verifier(
+    deps: DepsMutC,
+    env: Env,
+    info: MessageInfo,
+    msg: AddRegisteredAssetVerifierV1,
+) -> EntryPointResponse {
+    check_role(&deps.as_ref(), &info, ContractRole::VerifierManager)?;
+    check_funds_are_empty(&info)?;
+    let registration = load_verifier_registration(deps.storage, &msg.verifier_address)?;
+    let mut asset_definition = load_asset_definition_v2_by_type(deps.storage, &msg.asset_type)?;
+    if asset_definition
+        .verifiers
+        .iter()
+        .any(|verifier| verifier.address == registration.address)
+    {
+        return ContractError::DuplicateVerifierProvided.to_err();
+    }
+    asset_definition
+        .verifiers
+        .push(registration.to_verifier_detail());
+    replace_asset_definition_v2(
+        deps.storage,
+        &asset_definition,
+        env.block.height,
+        info.sender.as_str(),
+    )?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::AddRegisteredAssetVerifier)
+                .set_asset_type(&asset_definition.asset_type)
+                .set_verifier(&registration.address),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Uint128;
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::load_asset_definition_v2_by_type;
+    use crate::core::types::verifier_registration::VerifierRegistration;
+    use crate::execute::register_verifier::{register_verifier, RegisterVerifierV1};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_entity_detail, mock_info_with_nhash, single_attribute_for_key,
+        test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::DepsMutC;
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NHASH, VERIFIER_ADDRESS_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{add_registered_asset_verifier, AddRegisteredAssetVerifierV1};
+
+    const REGISTERED_VERIFIER_ADDRESS: &str = "tp1g83pm46c8wxsnlra2ytruec7nuy95ttc8yy5n3";
+
+    #[test]
+    fn test_valid_add_registered_asset_verifier_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let registration = register_test_verifier(deps.as_mut());
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::AddRegisteredAssetVerifier {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                verifier_address: REGISTERED_VERIFIER_ADDRESS.to_string(),
+            },
+        )
+        .expect("adding the registered verifier should succeed");
+        assert_eq!(
+            EventType::AddRegisteredAssetVerifier.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            single_attribute_for_key(&response, ASSET_TYPE_KEY),
+            "the asset type should be emitted",
+        );
+        assert_eq!(
+            REGISTERED_VERIFIER_ADDRESS,
+            single_attribute_for_key(&response, VERIFIER_ADDRESS_KEY),
+            "the verifier address should be emitted",
+        );
+        let asset_definition =
+            load_asset_definition_v2_by_type(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .expect("the default asset definition should exist");
+        assert_eq!(
+            &registration.to_verifier_detail(),
+            asset_definition
+                .verifiers
+                .iter()
+                .find(|verifier| verifier.address == REGISTERED_VERIFIER_ADDRESS)
+                .expect("the registered verifier should be added to the asset definition"),
+            "the added verifier should use the registered values",
+        );
+    }
+
+    #[test]
+    fn test_invalid_add_registered_asset_verifier_for_unregistered_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = add_registered_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AddRegisteredAssetVerifierV1::new(DEFAULT_ASSET_TYPE, REGISTERED_VERIFIER_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected the not found error to be returned for an unregistered verifier, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_add_registered_asset_verifier_for_duplicate_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        register_verifier(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            RegisterVerifierV1::new(VerifierRegistration::new(
+                DEFAULT_VERIFIER_ADDRESS,
+                None,
+                Uint128::new(1000),
+                NHASH,
+                vec![],
+            )),
+        )
+        .expect("registering the default verifier should succeed");
+        let error = add_registered_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            AddRegisteredAssetVerifierV1::new(DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::DuplicateVerifierProvided),
+            "expected the duplicate verifier error to be returned, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_add_registered_asset_verifier_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        register_test_verifier(deps.as_mut());
+        let error = add_registered_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(REGISTERED_VERIFIER_ADDRESS, &[]),
+            AddRegisteredAssetVerifierV1::new(DEFAULT_ASSET_TYPE, REGISTERED_VERIFIER_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_add_registered_asset_verifier_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        register_test_verifier(deps.as_mut());
+        let error = add_registered_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            AddRegisteredAssetVerifierV1::new(DEFAULT_ASSET_TYPE, REGISTERED_VERIFIER_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+
+    fn register_test_verifier(deps: DepsMutC) -> VerifierRegistration {
+        let registration = VerifierRegistration::new(
+            REGISTERED_VERIFIER_ADDRESS,
+            get_default_entity_detail().to_some(),
+            Uint128::new(500),
+            NHASH,
+            vec![],
+        );
+        register_verifier(
+            deps,
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            RegisterVerifierV1::new(registration.clone()),
+        )
+        .expect("registering the verifier should succeed");
+        registration
+    }
+}
@@ -0,0 +1,201 @@
+use cosmwasm_std::{MessageInfo, Response};
+
+use crate::core::state::{config_read_v2, config_v2};
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_admin_only, check_funds_are_empty},
+        event_attributes::{EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::CancelAdminProposal](crate::core::msg::ExecuteMsg::CancelAdminProposal)
+/// for ease of use in the underlying [cancel_admin_proposal](self::cancel_admin_proposal) function.
+/// The message contains no fields, as only a single admin proposal can be pending at a time.
+#[derive(Clone, PartialEq)]
+pub struct CancelAdminProposalV1;
+impl CancelAdminProposalV1 {
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [CancelAdminProposal](crate::core::msg::ExecuteMsg::CancelAdminProposal)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<CancelAdminProposalV1> {
+        match msg {
+            ExecuteMsg::CancelAdminProposal {} => CancelAdminProposalV1.to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::CancelAdminProposal".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::CancelAdminProposal](crate::core::msg::ExecuteMsg::CancelAdminProposal)
+/// message is provided.  Clears the pending admin from the contract's [state](crate::core::state::StateV2),
+/// preventing the proposed address from accepting control of the contract.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `_msg` An instance of the cancel admin proposal v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn cancel_admin_proposal(
+    deps: DepsMutC,
+    info: MessageInfo,
+    _msg: CancelAdminProposalV1,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut state = config_read_v2(deps.storage).load()?;
+    let pending_admin = match state.pending_admin.take() {
+        Some(pending_admin) => pending_admin,
+        None => {
+            return ContractError::NotFound {
+                explanation: "no admin transfer has been proposed".to_string(),
+            }
+            .to_err();
+        }
+    };
+    config_v2(deps.storage).save(&state)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::CancelAdminProposal).set_new_value(pending_admin),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::config_read_v2;
+    use crate::execute::accept_admin::{accept_admin, AcceptAdminV1};
+    use crate::execute::propose_admin::{propose_admin, ProposeAdminV1};
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, mock_info_with_nhash, single_attribute_for_key, test_instantiate_success,
+        InstArgs,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+
+    use super::{cancel_admin_proposal, CancelAdminProposalV1};
+
+    #[test]
+    fn test_valid_cancel_admin_proposal_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        propose_admin(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ProposeAdminV1::new(DEFAULT_FEE_ADDRESS),
+        )
+        .expect("proposing a new admin should succeed");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::CancelAdminProposal {},
+        )
+        .expect("cancelling the admin proposal should succeed");
+        assert_eq!(
+            EventType::CancelAdminProposal.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_FEE_ADDRESS,
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the cancelled pending admin should be emitted",
+        );
+        assert!(
+            config_read_v2(deps.as_ref().storage)
+                .load()
+                .expect("state should load")
+                .pending_admin
+                .is_none(),
+            "the pending admin should be cleared",
+        );
+        let error = accept_admin(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            AcceptAdminV1,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected a cancelled proposal to no longer be acceptable, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_cancel_admin_proposal_without_proposal() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = cancel_admin_proposal(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            CancelAdminProposalV1,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected the not found error to be returned when no proposal exists, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_cancel_admin_proposal_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        propose_admin(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ProposeAdminV1::new(DEFAULT_FEE_ADDRESS),
+        )
+        .expect("proposing a new admin should succeed");
+        let error = cancel_admin_proposal(
+            deps.as_mut(),
+            mock_info(DEFAULT_FEE_ADDRESS, &[]),
+            CancelAdminProposalV1,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected only the admin to be able to cancel the proposal, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_cancel_admin_proposal_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = cancel_admin_proposal(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            CancelAdminProposalV1,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+}
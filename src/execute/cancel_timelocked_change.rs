@@ -0,0 +1,251 @@
+use cosmwasm_std::{MessageInfo, Response};
+
+use crate::core::state::{delete_queued_change, load_queued_change};
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_funds_are_empty, check_timelocked_change_access},
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::CancelTimelockedChange](crate::core::msg::ExecuteMsg::CancelTimelockedChange)
+/// for ease of use in the underlying [cancel_timelocked_change](self::cancel_timelocked_change) function.
+///
+/// # Parameters
+///
+/// * `change_id` The unique identifier of the queued change to cancel.
+#[derive(Clone, PartialEq)]
+pub struct CancelTimelockedChangeV1 {
+    pub change_id: u64,
+}
+impl CancelTimelockedChangeV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `change_id` The unique identifier of the queued change to cancel.
+    pub fn new(change_id: u64) -> Self {
+        CancelTimelockedChangeV1 { change_id }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [CancelTimelockedChange](crate::core::msg::ExecuteMsg::CancelTimelockedChange)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<CancelTimelockedChangeV1> {
+        match msg {
+            ExecuteMsg::CancelTimelockedChange { change_id } => {
+                CancelTimelockedChangeV1::new(change_id).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::CancelTimelockedChange".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::CancelTimelockedChange](crate::core::msg::ExecuteMsg::CancelTimelockedChange)
+/// message is provided.  Removes a [QueuedChange](crate::core::types::timelocked_change::QueuedChange)
+/// from the queue without applying it.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the cancel timelocked change v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn cancel_timelocked_change(
+    deps: DepsMutC,
+    info: MessageInfo,
+    msg: CancelTimelockedChangeV1,
+) -> EntryPointResponse {
+    let queued_change = load_queued_change(deps.storage, msg.change_id)?;
+    check_timelocked_change_access(&deps.as_ref(), &info, &queued_change.change)?;
+    check_funds_are_empty(&info)?;
+    delete_queued_change(deps.storage, queued_change.change_id);
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("message_type", queued_change.change.message_type());
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::CancelTimelockedChange)
+                .set_new_value(queued_change.change_id)
+                .set_additional_metadata(&additional_metadata),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::load_queued_changes;
+    use crate::core::types::contract_role::ContractRole;
+    use crate::core::types::timelocked_change::TimelockedChange;
+    use crate::execute::grant_role::{grant_role, GrantRoleV1};
+    use crate::execute::queue_timelocked_change::{
+        queue_timelocked_change, QueueTimelockedChangeV1,
+    };
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, mock_info_with_nhash, single_attribute_for_key, test_instantiate_success,
+        InstArgs,
+    };
+    use crate::util::constants::{ADDITIONAL_METADATA_KEY, ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+
+    use super::{cancel_timelocked_change, CancelTimelockedChangeV1};
+
+    #[test]
+    fn test_valid_cancel_timelocked_change_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        queue_timelocked_change(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            QueueTimelockedChangeV1::new(TimelockedChange::UpdateProtocolFee {
+                protocol_fee: None,
+            }),
+        )
+        .expect("queueing a change should succeed");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::CancelTimelockedChange { change_id: 1 },
+        )
+        .expect("cancelling a queued change should succeed");
+        assert_eq!(
+            EventType::CancelTimelockedChange.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            "1",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the cancelled change's id should be emitted",
+        );
+        assert_eq!(
+            "[message_type=ExecuteMsg::UpdateProtocolFee]",
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the cancelled change's type should be emitted",
+        );
+        assert!(
+            load_queued_changes(deps.as_ref().storage)
+                .expect("queued changes should load")
+                .is_empty(),
+            "the cancelled change should be removed from the queue",
+        );
+    }
+
+    #[test]
+    fn test_valid_cancel_timelocked_change_by_role_holder() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        grant_role(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            GrantRoleV1::new(ContractRole::TreasuryManager, DEFAULT_FEE_ADDRESS),
+        )
+        .expect("granting the role should succeed");
+        queue_timelocked_change(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            QueueTimelockedChangeV1::new(TimelockedChange::UpdateProtocolFee {
+                protocol_fee: None,
+            }),
+        )
+        .expect("queueing a change should succeed");
+        cancel_timelocked_change(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            CancelTimelockedChangeV1::new(1),
+        )
+        .expect("an account holding the governing role should be able to cancel the change");
+    }
+
+    #[test]
+    fn test_invalid_cancel_timelocked_change_for_missing_change() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = cancel_timelocked_change(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            CancelTimelockedChangeV1::new(1),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected the not found error to be returned for a missing change, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_cancel_timelocked_change_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        queue_timelocked_change(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            QueueTimelockedChangeV1::new(TimelockedChange::UpdateTimelockDelay {
+                delay_blocks: None,
+            }),
+        )
+        .expect("queueing a change should succeed");
+        let error = cancel_timelocked_change(
+            deps.as_mut(),
+            mock_info(DEFAULT_FEE_ADDRESS, &[]),
+            CancelTimelockedChangeV1::new(1),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected only the admin to be able to cancel a timelock delay change, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_cancel_timelocked_change_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        queue_timelocked_change(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            QueueTimelockedChangeV1::new(TimelockedChange::UpdateProtocolFee {
+                protocol_fee: None,
+            }),
+        )
+        .expect("queueing a change should succeed");
+        let error = cancel_timelocked_change(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            CancelTimelockedChangeV1::new(1),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+}
@@ -1,12 +1,15 @@
 use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
-use crate::core::state::delete_asset_definition_v2_by_qualifier;
+use crate::core::state::{
+    delete_asset_definition_v2_by_qualifier, delete_scheduled_verifier_updates,
+};
 use crate::core::types::asset_qualifier::AssetQualifier;
+use crate::core::types::contract_role::ContractRole;
 use crate::util::aliases::{AssetResult, DepsMutC, EntryPointResponse};
-use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
+use crate::util::contract_helpers::{check_funds_are_empty, check_role, check_timelock_disabled};
 use crate::util::event_attributes::{EventAttributes, EventType};
 use crate::util::traits::ResultExtensions;
-use cosmwasm_std::{MessageInfo, Response};
+use cosmwasm_std::{Env, MessageInfo, Response, Storage};
 
 /// A transformation of [ExecuteMsg::DeleteAssetDefinition](crate::core::msg::ExecuteMsg::DeleteAssetDefinition)
 /// for ease of use in the underlying [delete_asset_definition](self::delete_asset_definition) function.
@@ -55,28 +58,61 @@ impl DeleteAssetDefinitionV1 {
 /// dangerous, because existing assets in the onboarding process for an asset definition will start
 /// emitting errors when being verified or retried.  This should only ever be used on a definition
 /// that is guaranteed to be not in use and/or was erroneously added.
+/// While the contract's timelock is enabled, this route is rejected and the deletion must be
+/// queued as a [TimelockedChange](crate::core::types::timelocked_change::TimelockedChange) instead.
 ///
 /// # Parameters
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
 /// of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` An instance of the delete asset definition v1 struct, provided by conversion from an
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg).
 pub fn delete_asset_definition(
     deps: DepsMutC,
+    env: Env,
     info: MessageInfo,
     msg: DeleteAssetDefinitionV1,
 ) -> EntryPointResponse {
-    check_admin_only(&deps.as_ref(), &info)?;
+    check_role(&deps.as_ref(), &info, ContractRole::DefinitionManager)?;
     check_funds_are_empty(&info)?;
-    let deleted_asset_type = delete_asset_definition_v2_by_qualifier(deps.storage, &msg.qualifier)?;
+    check_timelock_disabled(&deps.as_ref(), "ExecuteMsg::DeleteAssetDefinition")?;
     Response::new()
-        .add_attributes(
-            EventAttributes::new(EventType::DeleteAssetDefinition)
-                .set_asset_type(deleted_asset_type),
-        )
+        .add_attributes(apply_delete_asset_definition(
+            deps.storage,
+            msg,
+            env.block.height,
+            info.sender.as_str(),
+        )?)
+        .to_ok()
+}
+
+/// Removes an asset definition without any authorization checks.  Used directly by
+/// [delete_asset_definition](self::delete_asset_definition) and by [execute_timelocked_change](crate::execute::execute_timelocked_change::execute_timelocked_change)
+/// once a queued deletion's delay has elapsed.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `msg` An instance of the delete asset definition v1 struct.
+/// * `block_height` The block height at which the change is applied.
+/// * `actor` The bech32 address of the account responsible for the change.
+pub fn apply_delete_asset_definition(
+    storage: &mut dyn Storage,
+    msg: DeleteAssetDefinitionV1,
+    block_height: u64,
+    actor: &str,
+) -> AssetResult<EventAttributes> {
+    let deleted_asset_type =
+        delete_asset_definition_v2_by_qualifier(storage, &msg.qualifier, block_height, actor)?;
+    // Scheduled verifier updates can never be applied without an asset definition, so they are
+    // removed alongside it
+    delete_scheduled_verifier_updates(storage, &deleted_asset_type);
+    EventAttributes::new(EventType::DeleteAssetDefinition)
+        .set_asset_type(deleted_asset_type)
         .to_ok()
 }
 
@@ -97,8 +133,8 @@ mod tests {
         DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SCOPE_SPEC_ADDRESS,
     };
     use crate::testutil::test_utilities::{
-        empty_mock_info, mock_info_with_funds, single_attribute_for_key, test_instantiate_success,
-        InstArgs,
+        empty_mock_info, mock_info_with_funds, single_attribute_for_key, test_enable_timelock,
+        test_instantiate_success, InstArgs,
     };
     use crate::util::constants::{ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY};
     use crate::util::event_attributes::EventType;
@@ -115,6 +151,7 @@ mod tests {
         );
         let response = delete_asset_definition(
             deps.as_mut(),
+            mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
             DeleteAssetDefinitionV1::new(AssetQualifier::asset_type(DEFAULT_ASSET_TYPE)),
         )
@@ -156,6 +193,7 @@ mod tests {
         );
         let response = delete_asset_definition(
             deps.as_mut(),
+            mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
             DeleteAssetDefinitionV1::new(AssetQualifier::scope_spec_address(
                 DEFAULT_SCOPE_SPEC_ADDRESS,
@@ -221,6 +259,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let err = delete_asset_definition(
             deps.as_mut(),
+            mock_env(),
             empty_mock_info("bad-actor"),
             DeleteAssetDefinitionV1::new(AssetQualifier::asset_type(DEFAULT_ASSET_TYPE)),
         )
@@ -240,6 +279,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let err = delete_asset_definition(
             deps.as_mut(),
+            mock_env(),
             mock_info_with_funds(DEFAULT_ADMIN_ADDRESS, &[coin(100, "coindollars")]),
             DeleteAssetDefinitionV1::new(AssetQualifier::asset_type(DEFAULT_ADMIN_ADDRESS)),
         )
@@ -251,12 +291,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_delete_asset_definition_failure_for_enabled_timelock() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        let err = delete_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            DeleteAssetDefinitionV1::new(AssetQualifier::asset_type(DEFAULT_ASSET_TYPE)),
+        )
+        .expect_err("expected an error to occur when the timelock is enabled");
+        assert!(
+            matches!(err, ContractError::TimelockRequired { .. }),
+            "expected a timelock required error to be emitted when the timelock is enabled, but got: {:?}",
+            err,
+        );
+        load_asset_definition_v2_by_type(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+            .expect("the asset definition should not be deleted when the request is rejected");
+    }
+
     #[test]
     fn test_delete_asset_definition_failure_for_missing_definition() {
         let mut deps = mock_dependencies(&[]);
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let err = delete_asset_definition(
             deps.as_mut(),
+            mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
             DeleteAssetDefinitionV1::new(AssetQualifier::asset_type("not real asset type")),
         )
@@ -268,6 +330,7 @@ mod tests {
         );
         let err = delete_asset_definition(
             deps.as_mut(),
+            mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
             DeleteAssetDefinitionV1::new(AssetQualifier::scope_spec_address("not real scope spec")),
         )
@@ -0,0 +1,537 @@
+use cosmwasm_std::{Env, MessageInfo, Response};
+
+use crate::core::state::{delete_queued_change, load_queued_change};
+use crate::core::types::timelocked_change::TimelockedChange;
+use crate::execute::delete_asset_definition::{
+    apply_delete_asset_definition, DeleteAssetDefinitionV1,
+};
+use crate::execute::register_verifier::{apply_register_verifier, RegisterVerifierV1};
+use crate::execute::remove_asset_verifier::{apply_remove_asset_verifier, RemoveAssetVerifierV1};
+use crate::execute::update_asset_definition::{
+    apply_update_asset_definition, UpdateAssetDefinitionV1,
+};
+use crate::execute::update_asset_verifier::{apply_update_asset_verifier, UpdateAssetVerifierV1};
+use crate::execute::update_protocol_fee::{apply_update_protocol_fee, UpdateProtocolFeeV1};
+use crate::execute::update_timelock_delay::{apply_update_timelock_delay, UpdateTimelockDelayV1};
+use crate::service::asset_meta_service::AssetMetaService;
+use crate::service::message_gathering_service::MessageGatheringService;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_funds_are_empty, check_timelocked_change_access},
+        event_attributes::EventAdditionalMetadata,
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::ExecuteTimelockedChange](crate::core::msg::ExecuteMsg::ExecuteTimelockedChange)
+/// for ease of use in the underlying [execute_timelocked_change](self::execute_timelocked_change) function.
+///
+/// # Parameters
+///
+/// * `change_id` The unique identifier of the queued change to execute.
+#[derive(Clone, PartialEq)]
+pub struct ExecuteTimelockedChangeV1 {
+    pub change_id: u64,
+}
+impl ExecuteTimelockedChangeV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `change_id` The unique identifier of the queued change to execute.
+    pub fn new(change_id: u64) -> Self {
+        ExecuteTimelockedChangeV1 { change_id }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [ExecuteTimelockedChange](crate::core::msg::ExecuteMsg::ExecuteTimelockedChange)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<ExecuteTimelockedChangeV1> {
+        match msg {
+            ExecuteMsg::ExecuteTimelockedChange { change_id } => {
+                ExecuteTimelockedChangeV1::new(change_id).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::ExecuteTimelockedChange".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::ExecuteTimelockedChange](crate::core::msg::ExecuteMsg::ExecuteTimelockedChange)
+/// message is provided.  Applies a [QueuedChange](crate::core::types::timelocked_change::QueuedChange)
+/// whose delay has elapsed and removes it from the queue.  The response contains the same
+/// attributes that the change's original route emits, with the change id included as additional
+/// metadata.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the execute timelocked change v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn execute_timelocked_change(
+    deps: DepsMutC,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteTimelockedChangeV1,
+) -> EntryPointResponse {
+    let queued_change = load_queued_change(deps.storage, msg.change_id)?;
+    check_timelocked_change_access(&deps.as_ref(), &info, &queued_change.change)?;
+    check_funds_are_empty(&info)?;
+    if !queued_change.is_executable(env.block.height) {
+        return ContractError::TimelockNotElapsed {
+            change_id: queued_change.change_id,
+            executable_at_height: queued_change.executable_at_height,
+        }
+        .to_err();
+    }
+    delete_queued_change(deps.storage, queued_change.change_id);
+    let mut messages = vec![];
+    let attributes = match queued_change.change {
+        TimelockedChange::UpdateAssetVerifier {
+            asset_type,
+            verifier,
+        } => apply_update_asset_verifier(
+            deps.storage,
+            UpdateAssetVerifierV1::new(asset_type, verifier),
+            env.block.height,
+            info.sender.as_str(),
+        )?,
+        TimelockedChange::DeleteAssetDefinition { qualifier } => apply_delete_asset_definition(
+            deps.storage,
+            DeleteAssetDefinitionV1::new(qualifier.to_asset_qualifier()?),
+            env.block.height,
+            info.sender.as_str(),
+        )?,
+        TimelockedChange::UpdateProtocolFee { protocol_fee } => {
+            apply_update_protocol_fee(deps.storage, UpdateProtocolFeeV1::new(protocol_fee))?
+        }
+        TimelockedChange::UpdateTimelockDelay { delay_blocks } => {
+            apply_update_timelock_delay(deps.storage, UpdateTimelockDelayV1::new(delay_blocks))?
+        }
+        TimelockedChange::RegisterVerifier { registration } => {
+            apply_register_verifier(deps.storage, RegisterVerifierV1::new(registration))?
+        }
+        TimelockedChange::UpdateAssetDefinition { asset_definition } => {
+            apply_update_asset_definition(
+                deps.storage,
+                UpdateAssetDefinitionV1::new(asset_definition.into_asset_definition()?),
+                env.block.height,
+                info.sender.as_str(),
+            )?
+        }
+        TimelockedChange::RemoveAssetVerifier {
+            asset_type,
+            verifier_address,
+            pending_asset_strategy,
+        } => {
+            // Reassigning or refunding pending assets requires the full asset service, which also
+            // gathers any refund messages
+            let service = AssetMetaService::new(deps);
+            let attributes = apply_remove_asset_verifier(
+                &service,
+                RemoveAssetVerifierV1::new(asset_type, verifier_address, pending_asset_strategy),
+                env.block.height,
+                info.sender.as_str(),
+            )?;
+            messages = service.get_messages();
+            attributes
+        }
+    };
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("change_id", queued_change.change_id.to_string());
+    Response::new()
+        .add_attributes(attributes.set_additional_metadata(&additional_metadata))
+        .add_messages(messages)
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{BankMsg, CosmosMsg, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::{
+        config_read_v2, load_asset_definition_v2_by_type, load_queued_changes,
+        load_verifier_registration,
+    };
+    use crate::core::types::asset_definition::AssetDefinitionInputV2;
+    use crate::core::types::asset_qualifier::AssetQualifier;
+    use crate::core::types::pending_asset_strategy::PendingAssetStrategy;
+    use crate::core::types::protocol_fee::{ProtocolFee, ProtocolFeeAmount};
+    use crate::core::types::timelocked_change::TimelockedChange;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::core::types::verifier_registration::VerifierRegistration;
+    use crate::execute::queue_timelocked_change::{
+        queue_timelocked_change, QueueTimelockedChangeV1,
+    };
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_FEE_ADDRESS, DEFAULT_ONBOARDING_COST,
+        DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, env_at_height, get_default_asset_definition_input,
+        get_default_verifier_detail, mock_info_with_nhash, setup_test_suite,
+        single_attribute_for_key, test_enable_timelock, test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::DepsMutC;
+    use crate::util::constants::{
+        ADDITIONAL_METADATA_KEY, ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY, NHASH,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{execute_timelocked_change, ExecuteTimelockedChangeV1};
+
+    const SECOND_VERIFIER_ADDRESS: &str = "tp1g83pm46c8wxsnlra2ytruec7nuy95ttc8yy5n3";
+
+    #[test]
+    fn test_valid_execute_timelocked_change_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        let protocol_fee =
+            ProtocolFee::new(DEFAULT_FEE_ADDRESS, ProtocolFeeAmount::BasisPoints(50));
+        queue_change(
+            deps.as_mut(),
+            TimelockedChange::UpdateProtocolFee {
+                protocol_fee: protocol_fee.clone().to_some(),
+            },
+        );
+        let response = execute(
+            deps.as_mut(),
+            env_at_height(mock_env().block.height + 100),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::ExecuteTimelockedChange { change_id: 1 },
+        )
+        .expect("executing a change after its delay should succeed");
+        assert_eq!(
+            EventType::UpdateProtocolFee.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the event type of the applied change should be emitted",
+        );
+        assert_eq!(
+            protocol_fee.to_string(),
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the applied change's attributes should be emitted",
+        );
+        assert_eq!(
+            "[change_id=1]",
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the executed change's id should be emitted",
+        );
+        assert_eq!(
+            protocol_fee.to_some(),
+            config_read_v2(deps.as_ref().storage)
+                .load()
+                .expect("state should load")
+                .protocol_fee,
+            "the queued protocol fee should be applied",
+        );
+        assert!(
+            load_queued_changes(deps.as_ref().storage)
+                .expect("queued changes should load")
+                .is_empty(),
+            "the executed change should be removed from the queue",
+        );
+    }
+
+    #[test]
+    fn test_valid_execute_timelocked_asset_definition_deletion() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        queue_change(
+            deps.as_mut(),
+            TimelockedChange::DeleteAssetDefinition {
+                qualifier: AssetQualifier::asset_type(DEFAULT_ASSET_TYPE).to_serialized_enum(),
+            },
+        );
+        execute_timelocked_change(
+            deps.as_mut(),
+            env_at_height(mock_env().block.height + 100),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteTimelockedChangeV1::new(1),
+        )
+        .expect("executing the deletion after its delay should succeed");
+        load_asset_definition_v2_by_type(deps.as_ref().storage, DEFAULT_ASSET_TYPE).unwrap_err();
+    }
+
+    #[test]
+    fn test_valid_execute_timelocked_asset_definition_update() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        let mut asset_definition = get_default_asset_definition_input();
+        asset_definition.enabled = false.to_some();
+        queue_change(
+            deps.as_mut(),
+            TimelockedChange::UpdateAssetDefinition { asset_definition },
+        );
+        execute_timelocked_change(
+            deps.as_mut(),
+            env_at_height(mock_env().block.height + 100),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteTimelockedChangeV1::new(1),
+        )
+        .expect("executing the update after its delay should succeed");
+        assert!(
+            !load_asset_definition_v2_by_type(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .expect("the asset definition should still exist")
+                .enabled,
+            "the queued update should be applied",
+        );
+    }
+
+    #[test]
+    fn test_valid_execute_timelocked_verifier_registration() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        let registration = VerifierRegistration::new(
+            DEFAULT_VERIFIER_ADDRESS,
+            None,
+            Uint128::new(2000),
+            NHASH,
+            vec![],
+        );
+        queue_change(
+            deps.as_mut(),
+            TimelockedChange::RegisterVerifier {
+                registration: registration.clone(),
+            },
+        );
+        execute_timelocked_change(
+            deps.as_mut(),
+            env_at_height(mock_env().block.height + 100),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteTimelockedChangeV1::new(1),
+        )
+        .expect("executing the registration after its delay should succeed");
+        assert_eq!(
+            registration,
+            load_verifier_registration(deps.as_ref().storage, DEFAULT_VERIFIER_ADDRESS)
+                .expect("the registration should be stored"),
+            "the queued registration should be applied",
+        );
+    }
+
+    #[test]
+    fn test_valid_execute_timelocked_asset_verifier_removal() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![AssetDefinitionInputV2 {
+                    verifiers: vec![
+                        get_default_verifier_detail(),
+                        VerifierDetailV2::new(
+                            SECOND_VERIFIER_ADDRESS,
+                            Uint128::new(DEFAULT_ONBOARDING_COST),
+                            NHASH,
+                            vec![],
+                            None,
+                        ),
+                    ],
+                    ..get_default_asset_definition_input()
+                }],
+                ..Default::default()
+            },
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed");
+        test_enable_timelock(deps.as_mut(), 100);
+        queue_change(
+            deps.as_mut(),
+            TimelockedChange::RemoveAssetVerifier {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
+                pending_asset_strategy: PendingAssetStrategy::Refund {}.to_some(),
+            },
+        );
+        let response = execute_timelocked_change(
+            deps.as_mut(),
+            env_at_height(mock_env().block.height + 100),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteTimelockedChangeV1::new(1),
+        )
+        .expect("executing the removal after its delay should succeed");
+        assert_eq!(
+            "[pending_asset_count=1], [pending_asset_strategy=refund], [change_id=1]",
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the pending asset handling and the executed change's id should be emitted",
+        );
+        assert!(
+            response.messages.iter().any(|msg| matches!(
+                &msg.msg,
+                CosmosMsg::Bank(BankMsg::Send { to_address, .. }) if to_address == DEFAULT_SENDER_ADDRESS
+            )),
+            "the escrowed onboarding fee should be refunded to the requestor",
+        );
+        assert_eq!(
+            vec![SECOND_VERIFIER_ADDRESS.to_string()],
+            load_asset_definition_v2_by_type(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .expect("the asset definition should load")
+                .verifiers
+                .into_iter()
+                .map(|verifier| verifier.address)
+                .collect::<Vec<_>>(),
+            "the queued removal should be applied",
+        );
+    }
+
+    #[test]
+    fn test_valid_execute_timelocked_delay_reduction() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        queue_change(
+            deps.as_mut(),
+            TimelockedChange::UpdateTimelockDelay { delay_blocks: None },
+        );
+        execute_timelocked_change(
+            deps.as_mut(),
+            env_at_height(mock_env().block.height + 100),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteTimelockedChangeV1::new(1),
+        )
+        .expect("executing the delay change after its delay should succeed");
+        assert!(
+            config_read_v2(deps.as_ref().storage)
+                .load()
+                .expect("state should load")
+                .timelock_delay
+                .is_none(),
+            "the timelock should be disabled",
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_timelocked_change_before_delay_elapses() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        queue_change(
+            deps.as_mut(),
+            TimelockedChange::UpdateProtocolFee { protocol_fee: None },
+        );
+        let error = execute_timelocked_change(
+            deps.as_mut(),
+            env_at_height(mock_env().block.height + 99),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteTimelockedChangeV1::new(1),
+        )
+        .unwrap_err();
+        match error {
+            ContractError::TimelockNotElapsed {
+                change_id,
+                executable_at_height,
+            } => {
+                assert_eq!(
+                    1, change_id,
+                    "the change id should be included in the error"
+                );
+                assert_eq!(
+                    mock_env().block.height + 100,
+                    executable_at_height,
+                    "the executable height should be included in the error",
+                );
+            }
+            _ => panic!(
+                "expected the timelock not elapsed error to be returned, but got: {:?}",
+                error
+            ),
+        }
+    }
+
+    #[test]
+    fn test_invalid_execute_timelocked_change_for_missing_change() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = execute_timelocked_change(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteTimelockedChangeV1::new(1),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected the not found error to be returned for a missing change, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_timelocked_change_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        queue_change(
+            deps.as_mut(),
+            TimelockedChange::UpdateProtocolFee { protocol_fee: None },
+        );
+        let error = execute_timelocked_change(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_FEE_ADDRESS, &[]),
+            ExecuteTimelockedChangeV1::new(1),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected an account without the governing role to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_execute_timelocked_change_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        queue_change(
+            deps.as_mut(),
+            TimelockedChange::UpdateProtocolFee { protocol_fee: None },
+        );
+        let error = execute_timelocked_change(
+            deps.as_mut(),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            ExecuteTimelockedChangeV1::new(1),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+
+    fn queue_change(deps: DepsMutC, change: TimelockedChange) {
+        queue_timelocked_change(
+            deps,
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            QueueTimelockedChangeV1::new(change),
+        )
+        .expect("queueing a change should succeed");
+    }
+}
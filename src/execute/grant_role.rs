@@ -0,0 +1,285 @@
+use cosmwasm_std::{MessageInfo, Response};
+
+use crate::core::state::insert_role_member;
+use crate::core::types::contract_role::ContractRole;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_admin_only, check_funds_are_empty},
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        scope_address_utils::bech32_string_to_addr,
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::GrantRole](crate::core::msg::ExecuteMsg::GrantRole)
+/// for ease of use in the underlying [grant_role](self::grant_role) function.
+///
+/// # Parameters
+///
+/// * `role` The role to grant.
+/// * `address` The bech32 address to which the role will be granted.
+#[derive(Clone, PartialEq)]
+pub struct GrantRoleV1 {
+    pub role: ContractRole,
+    pub address: String,
+}
+impl GrantRoleV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `role` The role to grant.
+    /// * `address` The bech32 address to which the role will be granted.
+    pub fn new<S: Into<String>>(role: ContractRole, address: S) -> Self {
+        GrantRoleV1 {
+            role,
+            address: address.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [GrantRole](crate::core::msg::ExecuteMsg::GrantRole)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<GrantRoleV1> {
+        match msg {
+            ExecuteMsg::GrantRole { role, address } => GrantRoleV1::new(role, address).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::GrantRole".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::GrantRole](crate::core::msg::ExecuteMsg::GrantRole)
+/// message is provided.  Grants a [ContractRole](crate::core::types::contract_role::ContractRole)
+/// to an address.  The address gains access to every route guarded by the role.  The request is rejected if the address already holds the role.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the grant role v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn grant_role(deps: DepsMutC, info: MessageInfo, msg: GrantRoleV1) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let address = bech32_string_to_addr(&msg.address)?;
+    insert_role_member(deps.storage, &msg.role, address.as_str())?;
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("address", address.as_str());
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::GrantRole)
+                .set_new_value(msg.role)
+                .set_additional_metadata(&additional_metadata),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::load_role_members;
+    use crate::core::types::contract_role::ContractRole;
+    use crate::execute::toggle_asset_definition::{
+        toggle_asset_definition, ToggleAssetDefinitionV1,
+    };
+    use crate::execute::update_protocol_fee::{update_protocol_fee, UpdateProtocolFeeV1};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_FEE_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, mock_info_with_nhash, single_attribute_for_key, test_instantiate_success,
+        InstArgs,
+    };
+    use crate::util::constants::{ADDITIONAL_METADATA_KEY, ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+
+    use super::{grant_role, GrantRoleV1};
+
+    #[test]
+    fn test_valid_grant_role_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::GrantRole {
+                role: ContractRole::DefinitionManager,
+                address: DEFAULT_FEE_ADDRESS.to_string(),
+            },
+        )
+        .expect("granting the role should succeed");
+        assert_eq!(
+            EventType::GrantRole.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            "definition_manager",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the granted role should be emitted",
+        );
+        assert_eq!(
+            format!("[address={}]", DEFAULT_FEE_ADDRESS),
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the address receiving the role should be emitted",
+        );
+        assert_eq!(
+            vec![DEFAULT_FEE_ADDRESS.to_string()],
+            load_role_members(deps.as_ref().storage, &ContractRole::DefinitionManager)
+                .expect("role members should load"),
+            "the address should be stored as a member of the role",
+        );
+    }
+
+    #[test]
+    fn test_granted_role_allows_access_to_guarded_routes_only() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, false),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the unauthorized error to be returned before the role is granted, but got: {:?}",
+            error,
+        );
+        grant_role(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            GrantRoleV1::new(ContractRole::DefinitionManager, DEFAULT_FEE_ADDRESS),
+        )
+        .expect("granting the role should succeed");
+        toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, false),
+        )
+        .expect("a definition manager should be able to toggle an asset definition");
+        let error = update_protocol_fee(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            UpdateProtocolFeeV1::new(None),
+        )
+        .unwrap_err();
+        match error {
+            ContractError::Unauthorized { explanation } => {
+                assert_eq!(
+                    "admin or treasury_manager role required", explanation,
+                    "the missing role should be described in the error",
+                );
+            }
+            _ => panic!(
+                "expected a definition manager to be rejected from a treasury route, but got: {:?}",
+                error
+            ),
+        }
+    }
+
+    #[test]
+    fn test_invalid_grant_role_for_existing_member() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        grant_role(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            GrantRoleV1::new(ContractRole::Pauser, DEFAULT_FEE_ADDRESS),
+        )
+        .expect("granting the role should succeed");
+        let error = grant_role(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            GrantRoleV1::new(ContractRole::Pauser, DEFAULT_FEE_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::RecordAlreadyExists { .. }),
+            "expected the record already exists error to be returned for a duplicate grant, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_grant_role_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        grant_role(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            GrantRoleV1::new(ContractRole::DefinitionManager, DEFAULT_FEE_ADDRESS),
+        )
+        .expect("granting the role should succeed");
+        let error = grant_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_FEE_ADDRESS, &[]),
+            GrantRoleV1::new(ContractRole::TreasuryManager, DEFAULT_FEE_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected only the admin to be able to grant roles, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_grant_role_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = grant_role(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            GrantRoleV1::new(ContractRole::Pauser, DEFAULT_FEE_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_grant_role_for_invalid_address() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::GrantRole {
+                role: ContractRole::Pauser,
+                address: "not-an-address".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMessageFields { .. }),
+            "expected the invalid message fields error to be returned for a bad address, but got: {:?}",
+            error,
+        );
+    }
+}
@@ -1,5 +1,9 @@
 //! Contains all execution routes used by the [contract file](crate::contract).
 
+/// Contains the functionality used by the [AcceptAdmin](crate::core::msg::ExecuteMsg::AcceptAdmin)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod accept_admin;
 /// Contains the functionality used by the [AddAssetDefinition](crate::core::msg::ExecuteMsg::AddAssetDefinition)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
@@ -8,22 +12,82 @@ pub mod add_asset_definition;
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod add_asset_verifier;
+/// Contains the functionality used by the [AddRegisteredAssetVerifier](crate::core::msg::ExecuteMsg::AddRegisteredAssetVerifier)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod add_registered_asset_verifier;
 /// Contains the functionality used by the [BindContractAlias](crate::core::msg::ExecuteMsg::BindContractAlias)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod bind_contract_alias;
+/// Contains the functionality used by the [CancelAdminProposal](crate::core::msg::ExecuteMsg::CancelAdminProposal)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod cancel_admin_proposal;
+/// Contains the functionality used by the [CancelTimelockedChange](crate::core::msg::ExecuteMsg::CancelTimelockedChange)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod cancel_timelocked_change;
 /// Contains the functionality used by the [DeleteAssetDefinition](crate::core::msg::ExecuteMsg::DeleteAssetDefinition)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod delete_asset_definition;
+/// Contains the functionality used by the [ExecuteTimelockedChange](crate::core::msg::ExecuteMsg::ExecuteTimelockedChange)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod execute_timelocked_change;
+/// Contains the functionality used by the [GrantRole](crate::core::msg::ExecuteMsg::GrantRole)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod grant_role;
 /// Contains the functionality used by the [OnboardAsset](crate::core::msg::ExecuteMsg::OnboardAsset)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod onboard_asset;
+/// Contains the functionality used by the [PostVerifierBond](crate::core::msg::ExecuteMsg::PostVerifierBond)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod post_verifier_bond;
+/// Contains the functionality used by the [ProposeAdmin](crate::core::msg::ExecuteMsg::ProposeAdmin)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod propose_admin;
+/// Contains the functionality used by the [QueueTimelockedChange](crate::core::msg::ExecuteMsg::QueueTimelockedChange)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod queue_timelocked_change;
+/// Contains the functionality used by the [RegisterVerifier](crate::core::msg::ExecuteMsg::RegisterVerifier)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod register_verifier;
+/// Contains the functionality used by the [RemoveAssetVerifier](crate::core::msg::ExecuteMsg::RemoveAssetVerifier)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod remove_asset_verifier;
+/// Contains the functionality used by the [RevokeRole](crate::core::msg::ExecuteMsg::RevokeRole)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod revoke_role;
+/// Contains the functionality used by the [ScheduleAssetVerifierUpdate](crate::core::msg::ExecuteMsg::ScheduleAssetVerifierUpdate)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod schedule_asset_verifier_update;
+/// Contains the functionality used by the [SelfUpdateAssetVerifier](crate::core::msg::ExecuteMsg::SelfUpdateAssetVerifier)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod self_update_asset_verifier;
+/// Contains the functionality used by the [SlashVerifierBond](crate::core::msg::ExecuteMsg::SlashVerifierBond)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod slash_verifier_bond;
 /// Contains the functionality used by the [ToggleAssetDefinition](crate::core::msg::ExecuteMsg::ToggleAssetDefinition)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod toggle_asset_definition;
+/// Contains the functionality used by the [ToggleAssetVerifier](crate::core::msg::ExecuteMsg::ToggleAssetVerifier)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod toggle_asset_verifier;
 /// Contains the functionality used by the [UpdateAccessRoutes](crate::core::msg::ExecuteMsg::UpdateAccessRoutes)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
@@ -36,7 +100,39 @@ pub mod update_asset_definition;
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod update_asset_verifier;
+/// Contains the functionality used by the [UpdatePauseStatus](crate::core::msg::ExecuteMsg::UpdatePauseStatus)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod update_pause_status;
+/// Contains the functionality used by the [UpdateProtocolFee](crate::core::msg::ExecuteMsg::UpdateProtocolFee)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod update_protocol_fee;
+/// Contains the functionality used by the [UpdateTimelockDelay](crate::core::msg::ExecuteMsg::UpdateTimelockDelay)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod update_timelock_delay;
+/// Contains the functionality used by the [UpdateVerifierBondSettings](crate::core::msg::ExecuteMsg::UpdateVerifierBondSettings)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod update_verifier_bond_settings;
+/// Contains the functionality used by the [UpdateVerifierSelfUpdateBounds](crate::core::msg::ExecuteMsg::UpdateVerifierSelfUpdateBounds)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod update_verifier_self_update_bounds;
 /// Contains the functionality used by the [VerifyAsset](crate::core::msg::ExecuteMsg::VerifyAsset)
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
 /// function.
 pub mod verify_asset;
+/// Contains the functionality used by the [WithdrawFees](crate::core::msg::ExecuteMsg::WithdrawFees)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod withdraw_fees;
+/// Contains the functionality used by the [WithdrawTreasury](crate::core::msg::ExecuteMsg::WithdrawTreasury)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod withdraw_treasury;
+/// Contains the functionality used by the [WithdrawVerifierBond](crate::core::msg::ExecuteMsg::WithdrawVerifierBond)
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg) variant when invoked via the [execute](crate::contract::execute)
+/// function.
+pub mod withdraw_verifier_bond;
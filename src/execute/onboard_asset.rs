@@ -1,17 +1,25 @@
 use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
-use crate::core::state::{config_read_v2, load_asset_definition_v2_by_type};
+use crate::core::state::{
+    config_read_v2, load_ancestor_asset_definition_versions, load_asset_definition_v2_by_type,
+    load_asset_definition_version, load_requestor_onboarding_count, replace_asset_definition_v2,
+    resolve_asset_definition_v2, take_effective_scheduled_verifier_updates,
+};
 use crate::core::types::access_route::AccessRoute;
 use crate::core::types::asset_identifier::AssetIdentifier;
 use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
 use crate::core::types::asset_scope_attribute::AssetScopeAttribute;
+use crate::core::types::fee_destination::FeeDestinationV2;
 use crate::service::asset_meta_repository::AssetMetaRepository;
 use crate::service::deps_manager::DepsManager;
 use crate::service::message_gathering_service::MessageGatheringService;
 use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::constants::NHASH;
+use crate::util::contract_helpers::{check_funds_are_empty, check_verifier_bond};
 use crate::util::event_attributes::{EventAttributes, EventType};
+use crate::util::functions::bank_send;
 use crate::util::traits::{OptionExtensions, ResultExtensions};
-use cosmwasm_std::{MessageInfo, Response};
+use cosmwasm_std::{Env, MessageInfo, Response, Uint128};
 use provwasm_std::ProvenanceQuerier;
 
 /// A transformation of [ExecuteMsg::OnboardAsset](crate::core::msg::ExecuteMsg::OnboardAsset)
@@ -32,12 +40,15 @@ use provwasm_std::ProvenanceQuerier;
 /// * `access_routes` A vector of access routes to be added to the generated [AssetScopeAttribute's](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
 /// [AccessDefinition](crate::core::types::access_definition::AccessDefinition) for the [Requestor](crate::core::types::access_definition::AccessDefinitionType::Requestor)
 /// entry.
+/// * `max_onboarding_cost` An optional upper bound on the total nhash the requestor is willing to
+/// pay for onboarding.  When provided, any overpayment up to this value is refunded.
 #[derive(Clone, Debug, PartialEq)]
 pub struct OnboardAssetV1 {
     pub identifier: AssetIdentifier,
     pub asset_type: String,
     pub verifier_address: String,
     pub access_routes: Vec<AccessRoute>,
+    pub max_onboarding_cost: Option<Uint128>,
 }
 impl OnboardAssetV1 {
     /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
@@ -55,11 +66,13 @@ impl OnboardAssetV1 {
                 asset_type,
                 verifier_address,
                 access_routes,
+                max_onboarding_cost,
             } => OnboardAssetV1 {
                 identifier: identifier.to_asset_identifier()?,
                 asset_type,
                 verifier_address,
                 access_routes: access_routes.unwrap_or_default(),
+                max_onboarding_cost,
             }
             .to_ok(),
             _ => ContractError::InvalidMessageType {
@@ -80,12 +93,15 @@ impl OnboardAssetV1 {
 /// * `repository` A helper collection of traits that allows complex lookups of scope values and
 /// emits messages to construct the process of onboarding as a collection of messages to produce
 /// in the function's result.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
 /// of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` An instance of the onboard asset v1 struct, provided by conversion from an
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg).
 pub fn onboard_asset<'a, T>(
     repository: T,
+    env: Env,
     info: MessageInfo,
     msg: OnboardAssetV1,
 ) -> EntryPointResponse
@@ -94,18 +110,10 @@ where
 {
     let asset_identifiers = msg.identifier.to_identifiers()?;
     // get asset definition config for type, or error if not present
-    let asset_definition = match repository
+    let mut asset_definition = match repository
         .use_deps(|d| load_asset_definition_v2_by_type(d.storage, &msg.asset_type))
     {
-        Ok(state) => {
-            if !state.enabled {
-                return ContractError::AssetTypeDisabled {
-                    asset_type: msg.asset_type,
-                }
-                .to_err();
-            }
-            state
-        }
+        Ok(state) => state,
         Err(_) => {
             return ContractError::UnsupportedAssetType {
                 asset_type: msg.asset_type,
@@ -114,13 +122,65 @@ where
         }
     };
 
+    // apply any scheduled verifier updates that have become effective since the last onboarding
+    // for this asset type, persisting the result so that each update is only applied once
+    let effective_updates = repository.use_deps(|d| {
+        take_effective_scheduled_verifier_updates(d.storage, &msg.asset_type, &env.block)
+    })?;
+    if !effective_updates.is_empty() {
+        asset_definition.apply_scheduled_verifier_updates(&effective_updates);
+        // Scheduled updates are applied automatically, so the change is attributed to the contract
+        repository.use_deps(|d| {
+            replace_asset_definition_v2(
+                d.storage,
+                &asset_definition,
+                env.block.height,
+                env.contract.address.as_str(),
+            )
+        })?;
+    }
+
+    // values left empty in the definition are inherited from its ancestors, and a definition is
+    // only enabled while all of its ancestors are enabled
+    let asset_definition = repository
+        .use_deps(|d| resolve_asset_definition_v2(d.storage, asset_definition.clone()))?;
+    if !asset_definition.enabled {
+        return ContractError::AssetTypeDisabled {
+            asset_type: msg.asset_type,
+        }
+        .to_err();
+    }
+
     // verify prescribed verifier is present as a verifier in asset definition
+    // any pricing tier reached by the sender and any fee discount granted to the sender are applied
+    // immediately, ensuring that the adjusted onboarding cost is both charged now and distributed
+    // during verification
     let verifier_config = match asset_definition
         .verifiers
-        .into_iter()
+        .iter()
         .find(|verifier| verifier.address == msg.verifier_address)
     {
-        Some(verifier) => verifier,
+        Some(verifier) => {
+            // Disabled verifiers can still complete verification for their pending assets, but
+            // cannot accept new ones
+            if !verifier.enabled {
+                return ContractError::VerifierDisabled {
+                    verifier_address: msg.verifier_address,
+                    asset_type: msg.asset_type,
+                }
+                .to_err();
+            }
+            repository.use_deps(|d| {
+                check_verifier_bond(&d.as_ref(), &asset_definition, &verifier.address)
+            })?;
+            let onboarding_count = repository.use_deps(|d| {
+                load_requestor_onboarding_count(d.storage, &verifier.address, info.sender.as_str())
+            })?;
+            asset_definition.get_discounted_verifier_detail(
+                &verifier.apply_pricing_tier(onboarding_count),
+                info.sender.as_str(),
+            )
+        }
         None => {
             return ContractError::UnsupportedVerifier {
                 asset_type: msg.asset_type,
@@ -130,32 +190,77 @@ where
         }
     };
 
-    // verify sent funds match what is specified in the asset state
-    if info.funds.len() != 1 {
-        return ContractError::InvalidFunds(
-            "Exactly one fund type (of nhash) should be sent".to_string(),
-        )
-        .to_err();
-    }
+    let state = repository.use_deps(|deps| config_read_v2(deps.storage).load())?;
 
-    let sent_fee = match info.funds.iter().find(|funds| funds.denom == "nhash") {
-        Some(funds) => funds,
-        None => {
-            return ContractError::InvalidFunds(format!(
-                "Improper funds supplied for onboarding (expected {}nhash)",
-                verifier_config.onboarding_cost
-            ))
-            .to_err()
+    // determine the protocol fee charged on top of the verifier's onboarding cost, if any
+    let protocol_fee = state
+        .protocol_fee
+        .as_ref()
+        .map(|fee| {
+            FeeDestinationV2::new(
+                &fee.collector_address,
+                fee.calculate_fee_amount(verifier_config.onboarding_cost),
+            )
+        })
+        .filter(|fee| !fee.fee_amount.is_zero());
+    let required_fee = verifier_config.onboarding_cost
+        + protocol_fee
+            .as_ref()
+            .map(|fee| fee.fee_amount)
+            .unwrap_or_default();
+
+    // reject the request outright if the cost has grown beyond what the requestor is willing to pay
+    if let Some(max_onboarding_cost) = msg.max_onboarding_cost {
+        if required_fee > max_onboarding_cost {
+            return ContractError::OnboardingCostExceedsMaximum {
+                required: format!("{}nhash", required_fee),
+                maximum: format!("{}nhash", max_onboarding_cost),
+            }
+            .to_err();
         }
-    };
+    }
 
-    if sent_fee.amount != verifier_config.onboarding_cost {
-        return ContractError::InvalidFunds(format!(
-            "Improper fee of {}{} provided (expected {}nhash)",
-            sent_fee.amount, sent_fee.denom, verifier_config.onboarding_cost
-        ))
-        .to_err();
-    };
+    // verify sent funds fall between the required fee and the maximum the requestor is willing to
+    // pay, which defaults to exactly the required fee.  a fully waived onboarding cost requires that
+    // no funds are sent at all unless a maximum was provided, in which case anything sent is refunded
+    let maximum_fee = msg.max_onboarding_cost.unwrap_or(required_fee);
+    let refund_amount =
+        if required_fee.is_zero() && (maximum_fee.is_zero() || info.funds.is_empty()) {
+            check_funds_are_empty(&info)?;
+            Uint128::zero()
+        } else {
+            if info.funds.len() != 1 {
+                return ContractError::InvalidFunds(
+                    "Exactly one fund type (of nhash) should be sent".to_string(),
+                )
+                .to_err();
+            }
+            let sent_fee = match info.funds.iter().find(|funds| funds.denom == NHASH) {
+                Some(funds) => funds,
+                None => {
+                    return ContractError::InvalidFunds(format!(
+                        "Improper funds supplied for onboarding (expected {}nhash)",
+                        required_fee
+                    ))
+                    .to_err()
+                }
+            };
+            if sent_fee.amount < required_fee || sent_fee.amount > maximum_fee {
+                return ContractError::InvalidFunds(if msg.max_onboarding_cost.is_some() {
+                    format!(
+                        "Improper fee of {}{} provided (expected between {}nhash and {}nhash)",
+                        sent_fee.amount, sent_fee.denom, required_fee, maximum_fee
+                    )
+                } else {
+                    format!(
+                        "Improper fee of {}{} provided (expected {}nhash)",
+                        sent_fee.amount, sent_fee.denom, required_fee
+                    )
+                })
+                .to_err();
+            }
+            sent_fee.amount - required_fee
+        };
 
     // verify asset (scope) exists
     let scope = match repository.use_deps(|d| {
@@ -170,15 +275,13 @@ where
         Ok(scope) => scope,
     };
 
-    let state = repository.use_deps(|deps| config_read_v2(deps.storage).load())?;
-
     // verify scope is of correct spec for provided asset_type
-    if scope.specification_id != asset_definition.scope_spec_address {
+    if !asset_definition.accepts_scope_spec(&scope.specification_id) {
         return ContractError::AssetSpecMismatch {
             asset_type: msg.asset_type,
             scope_address: asset_identifiers.scope_address,
             scope_spec_address: scope.specification_id,
-            expected_scope_spec_address: asset_definition.scope_spec_address,
+            expected_scope_spec_address: asset_definition.get_scope_spec_addresses().join(", "),
         }
         .to_err();
     }
@@ -214,15 +317,26 @@ where
         }
     }
 
-    let new_asset_attribute = AssetScopeAttribute::new(
-        &msg.identifier,
-        &msg.asset_type,
-        &info.sender,
-        &msg.verifier_address,
-        AssetOnboardingStatus::Pending.to_some(),
-        &verifier_config,
-        msg.access_routes,
-    )?;
+    let new_asset_attribute = AssetScopeAttribute {
+        // Recording the definition's version allows the verifiers and fees that applied to this
+        // onboarding to be found in the definition's history after it changes
+        asset_definition_version: repository
+            .use_deps(|d| load_asset_definition_version(d.storage, &asset_definition.asset_type))?
+            .to_some(),
+        // Changes to an ancestor change the values that the asset type inherits, so each
+        // ancestor's version is needed to find the values that applied to this onboarding
+        ancestor_asset_definition_versions: repository
+            .use_deps(|d| load_ancestor_asset_definition_versions(d.storage, &asset_definition))?,
+        ..AssetScopeAttribute::new(
+            &msg.identifier,
+            &msg.asset_type,
+            &info.sender,
+            &msg.verifier_address,
+            AssetOnboardingStatus::Pending.to_some(),
+            &verifier_config,
+            msg.access_routes,
+        )?
+    };
 
     // check to see if the attribute already exists, and determine if this is a fresh onboard or a subsequent one
     let is_retry = if let Some(scope_attribute) =
@@ -257,7 +371,18 @@ where
     };
 
     // store asset metadata in contract storage, with assigned verifier and provided fee (in case fee changes between onboarding and verification)
-    repository.onboard_asset(&new_asset_attribute, &verifier_config, is_retry)?;
+    repository.onboard_asset(
+        &new_asset_attribute,
+        &verifier_config,
+        protocol_fee.as_ref(),
+        is_retry,
+        env.block.height,
+    )?;
+
+    // return any amount sent beyond the required fee to the requestor
+    if !refund_amount.is_zero() {
+        repository.add_message(bank_send(&info.sender, refund_amount.u128(), NHASH));
+    }
 
     Ok(Response::new()
         .add_attributes(
@@ -275,7 +400,8 @@ where
 #[cfg(test)]
 #[cfg(feature = "enable-test-utils")]
 mod tests {
-    use cosmwasm_std::{from_binary, Coin, CosmosMsg, StdError, SubMsg, Uint128};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, coins, from_binary, Coin, CosmosMsg, StdError, SubMsg, Uint128};
     use provwasm_mocks::mock_dependencies;
     use provwasm_std::{
         AttributeMsgParams, AttributeValueType, Process, ProcessId, ProvenanceMsg,
@@ -285,14 +411,33 @@ mod tests {
     use crate::{
         core::{
             error::ContractError,
+            state::{
+                latest_verifier_detail_store_ro, load_asset_definition_v2_by_type,
+                load_requestor_onboarding_count, load_scheduled_verifier_updates,
+            },
             types::{
                 access_definition::{AccessDefinition, AccessDefinitionType},
+                asset_definition::AssetDefinitionInputV2,
+                asset_definition_version::AssetDefinitionVersionReference,
                 asset_identifier::AssetIdentifier,
                 asset_onboarding_status::AssetOnboardingStatus,
                 asset_scope_attribute::AssetScopeAttribute,
+                fee_destination::FeeDestinationV2,
+                fee_discount::FeeDiscount,
+                pricing_tier::PricingTier,
+                scheduled_verifier_update::EffectiveAt,
+                scope_spec_identifier::ScopeSpecIdentifier,
+                verifier_detail::VerifierDetailV2,
+            },
+        },
+        execute::{
+            post_verifier_bond::{post_verifier_bond, PostVerifierBondV1},
+            schedule_asset_verifier_update::{
+                schedule_asset_verifier_update, ScheduleAssetVerifierUpdateV1,
             },
+            toggle_asset_definition::{toggle_asset_definition, ToggleAssetDefinitionV1},
+            toggle_asset_verifier::{toggle_asset_verifier, ToggleAssetVerifierV1},
         },
-        execute::toggle_asset_definition::{toggle_asset_definition, ToggleAssetDefinitionV1},
         service::{
             asset_meta_repository::AssetMetaRepository, asset_meta_service::AssetMetaService,
             message_gathering_service::MessageGatheringService,
@@ -301,23 +446,25 @@ mod tests {
             onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset},
             test_constants::{
                 DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME,
-                DEFAULT_ONBOARDING_COST, DEFAULT_RECORD_SPEC_ADDRESS, DEFAULT_SCOPE_ADDRESS,
-                DEFAULT_SCOPE_SPEC_ADDRESS, DEFAULT_SENDER_ADDRESS, DEFAULT_SESSION_ADDRESS,
-                DEFAULT_VERIFIER_ADDRESS,
+                DEFAULT_FEE_ADDRESS, DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM,
+                DEFAULT_RECORD_SPEC_ADDRESS, DEFAULT_SCOPE_ADDRESS, DEFAULT_SCOPE_SPEC_ADDRESS,
+                DEFAULT_SENDER_ADDRESS, DEFAULT_SESSION_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
             },
             test_utilities::{
-                empty_mock_info, get_default_access_routes, get_default_scope, get_duped_scope,
-                mock_info_with_funds, mock_info_with_nhash, setup_test_suite,
-                test_instantiate_success, InstArgs,
+                empty_mock_info, get_bank_sends, get_default_access_routes,
+                get_default_asset_definition_input, get_default_records, get_default_scope,
+                get_default_verifier_detail, get_duped_scope, mock_info_with_funds,
+                mock_info_with_nhash, setup_test_suite, test_instantiate_success, InstArgs,
             },
             verify_asset_helpers::{test_verify_asset, TestVerifyAsset},
         },
         util::{
             constants::{
-                ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY, SCOPE_OWNER_KEY,
-                VERIFIER_ADDRESS_KEY,
+                ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY, NHASH,
+                SCOPE_OWNER_KEY, VERIFIER_ADDRESS_KEY,
             },
             functions::generate_asset_attribute_name,
+            traits::OptionExtensions,
         },
     };
 
@@ -330,12 +477,14 @@ mod tests {
 
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, 1000),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
                 asset_type: "bogus".into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.into(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .unwrap_err();
@@ -360,18 +509,21 @@ mod tests {
         setup_test_suite(&mut deps, InstArgs::default());
         toggle_asset_definition(
             deps.as_mut(),
+            mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
             ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, false),
         )
         .expect("toggling the asset definition to be disabled should succeed");
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, 1000),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.into(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .unwrap_err();
@@ -382,6 +534,116 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_onboard_asset_errors_on_disabled_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetVerifierV1::new(DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS, false),
+        )
+        .expect("toggling the verifier to be disabled should succeed");
+        let err = onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, 1000),
+            OnboardAssetV1 {
+                identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+                asset_type: DEFAULT_ASSET_TYPE.into(),
+                verifier_address: DEFAULT_VERIFIER_ADDRESS.into(),
+                access_routes: vec![],
+                max_onboarding_cost: None,
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::VerifierDisabled {
+                verifier_address,
+                asset_type,
+            } => {
+                assert_eq!(
+                    DEFAULT_VERIFIER_ADDRESS, verifier_address,
+                    "the disabled verifier's address should be included in the error",
+                );
+                assert_eq!(
+                    DEFAULT_ASSET_TYPE, asset_type,
+                    "the asset type should be included in the error",
+                );
+            }
+            _ => panic!(
+                "the request should be rejected for a disabled verifier, but got: {:?}",
+                err
+            ),
+        };
+    }
+
+    #[test]
+    fn test_onboard_asset_requires_minimum_verifier_bond() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![AssetDefinitionInputV2 {
+                    minimum_verifier_bond: coin(1000, NHASH).to_some(),
+                    ..get_default_asset_definition_input()
+                }],
+                ..Default::default()
+            },
+        );
+        for (bond, expected_bonded) in [(None, "none"), (Some(999), "999nhash")] {
+            if let Some(amount) = bond {
+                post_verifier_bond(
+                    deps.as_mut(),
+                    mock_info(DEFAULT_VERIFIER_ADDRESS, &coins(amount, NHASH)),
+                    PostVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+                )
+                .expect("posting a bond should succeed");
+            }
+            let err = onboard_asset(
+                AssetMetaService::new(deps.as_mut()),
+                mock_env(),
+                mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
+                TestOnboardAsset::default_onboard_asset(),
+            )
+            .unwrap_err();
+            match err {
+                ContractError::InsufficientVerifierBond {
+                    verifier_address,
+                    bonded,
+                    required,
+                    ..
+                } => {
+                    assert_eq!(
+                        DEFAULT_VERIFIER_ADDRESS, verifier_address,
+                        "the verifier's address should be included in the error",
+                    );
+                    assert_eq!(
+                        expected_bonded, bonded,
+                        "the verifier's current bond should be included in the error",
+                    );
+                    assert_eq!(
+                        "1000nhash", required,
+                        "the required bond should be included in the error",
+                    );
+                }
+                _ => panic!(
+                    "the request should be rejected for an insufficient bond, but got: {:?}",
+                    err
+                ),
+            };
+        }
+        post_verifier_bond(
+            deps.as_mut(),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &coins(1, NHASH)),
+            PostVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .expect("posting a bond should succeed");
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed once the verifier's bond meets the minimum");
+    }
+
     #[test]
     fn test_onboard_asset_errors_on_unsupported_verifier() {
         let mut deps = mock_dependencies(&[]);
@@ -389,12 +651,14 @@ mod tests {
 
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, 1000),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string() + "bogus".into(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .unwrap_err();
@@ -428,12 +692,14 @@ mod tests {
 
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             empty_mock_info(DEFAULT_SENDER_ADDRESS),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .unwrap_err();
@@ -459,6 +725,7 @@ mod tests {
 
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_funds(
                 DEFAULT_SENDER_ADDRESS,
                 &[
@@ -477,6 +744,7 @@ mod tests {
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .unwrap_err();
@@ -502,6 +770,7 @@ mod tests {
 
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_funds(
                 DEFAULT_SENDER_ADDRESS,
                 &[Coin {
@@ -514,6 +783,7 @@ mod tests {
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .unwrap_err();
@@ -539,12 +809,14 @@ mod tests {
 
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST + 1),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .unwrap_err();
@@ -577,12 +849,14 @@ mod tests {
 
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(bogus_scope_address),
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .unwrap_err();
@@ -610,12 +884,14 @@ mod tests {
 
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .unwrap_err();
@@ -651,6 +927,7 @@ mod tests {
         test_verify_asset(&mut deps, TestVerifyAsset::default()).unwrap();
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
             TestOnboardAsset::default_onboard_asset(),
         )
@@ -678,12 +955,14 @@ mod tests {
         deps.querier.with_scope(get_default_scope());
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .unwrap_err();
@@ -723,12 +1002,14 @@ mod tests {
         deps.querier.with_scope(get_default_scope());
         onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .expect("onboarding should succeed due to test mode being enabled");
@@ -762,12 +1043,14 @@ mod tests {
         );
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .unwrap_err();
@@ -812,12 +1095,14 @@ mod tests {
         );
         onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .expect("onboarding should succeed due to test mode being enabled");
@@ -837,12 +1122,14 @@ mod tests {
 
         let err = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: vec![],
+                max_onboarding_cost: None,
             },
         )
         .unwrap_err();
@@ -866,6 +1153,183 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_onboard_asset_inherits_from_parent_asset_type() {
+        let mut deps = mock_dependencies(&[]);
+        let child_scope_spec_address = "scopespec-child";
+        let mut child_input = get_default_asset_definition_input()
+            .with_parent_asset_type(DEFAULT_ASSET_TYPE.to_some());
+        child_input.asset_type = "child".to_string();
+        child_input.scope_spec_identifier =
+            ScopeSpecIdentifier::address(child_scope_spec_address).to_serialized_enum();
+        child_input.verifiers = vec![];
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![get_default_asset_definition_input(), child_input],
+                ..InstArgs::default()
+            },
+        );
+        let scope = get_duped_scope(
+            DEFAULT_SCOPE_ADDRESS,
+            child_scope_spec_address,
+            DEFAULT_SENDER_ADDRESS,
+        );
+        deps.querier.with_scope(scope.clone());
+        deps.querier.with_records(scope, get_default_records());
+        let child_onboard_asset = || TestOnboardAsset {
+            onboard_asset: OnboardAssetV1 {
+                asset_type: "child".to_string(),
+                ..TestOnboardAsset::default_onboard_asset()
+            },
+            ..TestOnboardAsset::default()
+        };
+        toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, false),
+        )
+        .expect("toggling the parent asset definition to be disabled should succeed");
+        let err = onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
+            child_onboard_asset().onboard_asset,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, ContractError::AssetTypeDisabled { .. }),
+            "the child asset type should be disabled while its parent is disabled, but got: {:?}",
+            err,
+        );
+        toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, true),
+        )
+        .expect("toggling the parent asset definition to be enabled should succeed");
+        test_onboard_asset(&mut deps, child_onboard_asset()).expect(
+            "the child asset type should be onboarded with the verifier inherited from its parent",
+        );
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset(DEFAULT_SCOPE_ADDRESS)
+            .expect("the default scope address should have an attribute attached to it");
+        assert_eq!(
+            "child", attribute.asset_type,
+            "the scope should be onboarded as the child asset type",
+        );
+        assert_eq!(
+            DEFAULT_VERIFIER_ADDRESS, attribute.verifier_address,
+            "the scope should be onboarded with the verifier inherited from the parent",
+        );
+        assert_eq!(
+            1.to_some(),
+            attribute.asset_definition_version,
+            "the child's own version should be recorded",
+        );
+        assert_eq!(
+            vec![AssetDefinitionVersionReference::new(DEFAULT_ASSET_TYPE, 3)],
+            attribute.ancestor_asset_definition_versions,
+            "the parent's version after being toggled twice should be recorded",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_requires_bond_for_inherited_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        let child_scope_spec_address = "scopespec-child";
+        let mut child_input = get_default_asset_definition_input()
+            .with_parent_asset_type(DEFAULT_ASSET_TYPE.to_some());
+        child_input.asset_type = "child".to_string();
+        child_input.scope_spec_identifier =
+            ScopeSpecIdentifier::address(child_scope_spec_address).to_serialized_enum();
+        child_input.verifiers = vec![];
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![
+                    AssetDefinitionInputV2 {
+                        minimum_verifier_bond: coin(1000, NHASH).to_some(),
+                        ..get_default_asset_definition_input()
+                    },
+                    child_input,
+                ],
+                ..InstArgs::default()
+            },
+        );
+        let scope = get_duped_scope(
+            DEFAULT_SCOPE_ADDRESS,
+            child_scope_spec_address,
+            DEFAULT_SENDER_ADDRESS,
+        );
+        deps.querier.with_scope(scope.clone());
+        deps.querier.with_records(scope, get_default_records());
+        let child_onboard_asset = || TestOnboardAsset {
+            onboard_asset: OnboardAssetV1 {
+                asset_type: "child".to_string(),
+                ..TestOnboardAsset::default_onboard_asset()
+            },
+            ..TestOnboardAsset::default()
+        };
+        let err = onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
+            child_onboard_asset().onboard_asset,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, ContractError::InsufficientVerifierBond { .. }),
+            "the minimum bond should be inherited from the parent asset type, but got: {:?}",
+            err,
+        );
+        post_verifier_bond(
+            deps.as_mut(),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &coins(1000, NHASH)),
+            PostVerifierBondV1::new("child"),
+        )
+        .expect("a verifier inherited from the parent should be able to post a bond for the child");
+        test_onboard_asset(&mut deps, child_onboard_asset()).expect(
+            "onboarding should succeed once the inherited verifier's bond meets the minimum",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_succeeds_for_additional_scope_spec() {
+        let mut deps = mock_dependencies(&[]);
+        let additional_scope_spec_address = "scopespec-v2";
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![get_default_asset_definition_input()
+                    .with_additional_scope_spec_identifiers(vec![ScopeSpecIdentifier::address(
+                        additional_scope_spec_address,
+                    )
+                    .to_serialized_enum()])],
+                ..InstArgs::default()
+            },
+        );
+        let scope = get_duped_scope(
+            DEFAULT_SCOPE_ADDRESS,
+            additional_scope_spec_address,
+            DEFAULT_SENDER_ADDRESS,
+        );
+        deps.querier.with_scope(scope.clone());
+        deps.querier.with_records(scope, get_default_records());
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).expect(
+            "a scope using the asset definition's additional scope spec should be onboarded",
+        );
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset(DEFAULT_SCOPE_ADDRESS)
+            .expect("the default scope address should have an attribute attached to it");
+        assert_eq!(
+            DEFAULT_ASSET_TYPE, attribute.asset_type,
+            "the scope should be onboarded as the asset type that owns the additional scope spec",
+        );
+    }
+
     #[test]
     fn test_onboard_asset_succeeds() {
         let mut deps = mock_dependencies(&[]);
@@ -873,12 +1337,14 @@ mod tests {
 
         let result = onboard_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
             OnboardAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
                 asset_type: DEFAULT_ASSET_TYPE.into(),
                 verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
                 access_routes: get_default_access_routes(),
+                max_onboarding_cost: None,
             },
         )
         .unwrap();
@@ -935,6 +1401,11 @@ mod tests {
                     deserialized.access_definitions.first().unwrap(),
                     "Proper access route should be set upon onboarding"
                 );
+                assert_eq!(
+                    1.to_some(),
+                    deserialized.asset_definition_version,
+                    "The asset definition's initial version should be recorded upon onboarding"
+                );
             }
             _ => panic!("Unexpected message from onboard_asset: {:?}", msg),
         }
@@ -978,6 +1449,367 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_onboard_asset_records_current_asset_definition_version() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        test_verify_asset(&mut deps, TestVerifyAsset::default_with_success(false)).unwrap();
+        for expected_result in [false, true] {
+            toggle_asset_definition(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, expected_result),
+            )
+            .expect("toggling the asset definition should succeed");
+        }
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset(DEFAULT_SCOPE_ADDRESS)
+            .expect("the default scope address should have an attribute attached to it");
+        assert_eq!(
+            3.to_some(),
+            attribute.asset_definition_version,
+            "the asset definition's version at the time of the retry should be recorded after it was changed twice",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_errors_when_cost_exceeds_max_onboarding_cost() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let err = onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST),
+            OnboardAssetV1 {
+                max_onboarding_cost: Uint128::new(DEFAULT_ONBOARDING_COST - 1).to_some(),
+                ..TestOnboardAsset::default_onboard_asset()
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::OnboardingCostExceedsMaximum { required, maximum } => {
+                assert_eq!(
+                    format!("{}nhash", DEFAULT_ONBOARDING_COST),
+                    required,
+                    "the required cost should be included in the error",
+                );
+                assert_eq!(
+                    format!("{}nhash", DEFAULT_ONBOARDING_COST - 1),
+                    maximum,
+                    "the provided maximum should be included in the error",
+                );
+            }
+            _ => panic!(
+                "unexpected error when the onboarding cost exceeds the maximum: {:?}",
+                err
+            ),
+        }
+    }
+
+    #[test]
+    fn test_onboard_asset_errors_when_funds_exceed_max_onboarding_cost() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let err = onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST + 11),
+            OnboardAssetV1 {
+                max_onboarding_cost: Uint128::new(DEFAULT_ONBOARDING_COST + 10).to_some(),
+                ..TestOnboardAsset::default_onboard_asset()
+            },
+        )
+        .unwrap_err();
+        match err {
+            ContractError::InvalidFunds(message) => {
+                assert_eq!(
+                    format!(
+                        "Improper fee of {}nhash provided (expected between {}nhash and {}nhash)",
+                        DEFAULT_ONBOARDING_COST + 11,
+                        DEFAULT_ONBOARDING_COST,
+                        DEFAULT_ONBOARDING_COST + 10,
+                    ),
+                    message,
+                    "the error message should include the accepted range of funds",
+                );
+            }
+            _ => panic!(
+                "unexpected error when funds exceed the maximum onboarding cost: {:?}",
+                err
+            ),
+        }
+    }
+
+    #[test]
+    fn test_onboard_asset_refunds_overpayment_up_to_max_onboarding_cost() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let response = onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST + 10),
+            OnboardAssetV1 {
+                max_onboarding_cost: Uint128::new(DEFAULT_ONBOARDING_COST + 10).to_some(),
+                ..TestOnboardAsset::default_onboard_asset()
+            },
+        )
+        .expect("onboarding with funds up to the maximum onboarding cost should succeed");
+        let refunds = get_bank_sends(&response);
+        assert_eq!(
+            vec![(
+                DEFAULT_SENDER_ADDRESS.to_string(),
+                vec![Coin::new(10, "nhash")],
+            )],
+            refunds,
+            "the amount sent beyond the onboarding cost should be refunded to the requestor",
+        );
+        let verifier_detail = latest_verifier_detail_store_ro(deps.as_ref().storage)
+            .load(DEFAULT_SCOPE_ADDRESS.as_bytes())
+            .expect("the latest verifier detail should be stored");
+        assert_eq!(
+            DEFAULT_ONBOARDING_COST,
+            verifier_detail.onboarding_cost.u128(),
+            "only the required onboarding cost should be retained for the verifier",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_pays_out_intake_fee() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![AssetDefinitionInputV2 {
+                    verifiers: vec![get_default_verifier_detail().with_intake_fee(
+                        Uint128::new(400),
+                        vec![FeeDestinationV2::new(
+                            DEFAULT_FEE_ADDRESS,
+                            Uint128::new(100),
+                        )],
+                    )],
+                    ..get_default_asset_definition_input()
+                }],
+                ..Default::default()
+            },
+        );
+        let response = test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed");
+        assert_eq!(
+            vec![
+                (
+                    DEFAULT_FEE_ADDRESS.to_string(),
+                    vec![Coin::new(100, DEFAULT_ONBOARDING_DENOM)]
+                ),
+                (
+                    DEFAULT_VERIFIER_ADDRESS.to_string(),
+                    vec![Coin::new(300, DEFAULT_ONBOARDING_DENOM)]
+                ),
+            ],
+            get_bank_sends(&response),
+            "the intake fee should be paid out when the asset is onboarded",
+        );
+        let response = test_verify_asset(&mut deps, TestVerifyAsset::default())
+            .expect("verification should succeed");
+        assert_eq!(
+            vec![(
+                DEFAULT_VERIFIER_ADDRESS.to_string(),
+                vec![Coin::new(600, DEFAULT_ONBOARDING_DENOM)]
+            )],
+            get_bank_sends(&response),
+            "only the completion fee should be paid out when the asset is verified",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_applies_fee_discounts() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![AssetDefinitionInputV2 {
+                    verifiers: vec![get_default_verifier_detail().with_fee_discounts(vec![
+                        FeeDiscount::full_waiver(DEFAULT_SENDER_ADDRESS),
+                    ])],
+                    ..get_default_asset_definition_input()
+                }
+                .with_fee_discounts(vec![
+                    FeeDiscount::new(DEFAULT_SENDER_ADDRESS, 5000),
+                    FeeDiscount::new(DEFAULT_ADMIN_ADDRESS, 5000),
+                ])],
+                ..Default::default()
+            },
+        );
+        let err = onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, DEFAULT_ONBOARDING_COST),
+            TestOnboardAsset::default_onboard_asset(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, ContractError::InvalidFunds(_)),
+            "the full onboarding cost should be rejected when an asset type discount applies, but got: {:?}",
+            err,
+        );
+        let err = onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST / 2),
+            TestOnboardAsset::default_onboard_asset(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, ContractError::InvalidFunds(_)),
+            "the verifier's full waiver should take precedence over the asset type discount, but got: {:?}",
+            err,
+        );
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset {
+                info: empty_mock_info(DEFAULT_SENDER_ADDRESS),
+                ..Default::default()
+            },
+        )
+        .expect("onboarding without funds should succeed when the onboarding cost is waived");
+    }
+
+    #[test]
+    fn test_onboard_asset_applies_pricing_tier() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![AssetDefinitionInputV2 {
+                    verifiers: vec![VerifierDetailV2::new(
+                        DEFAULT_VERIFIER_ADDRESS,
+                        Uint128::new(DEFAULT_ONBOARDING_COST),
+                        DEFAULT_ONBOARDING_DENOM,
+                        vec![FeeDestinationV2::new(
+                            DEFAULT_FEE_ADDRESS,
+                            Uint128::new(300),
+                        )],
+                        None,
+                    )
+                    .with_pricing_tiers(vec![PricingTier::new(1, Uint128::new(600))])],
+                    ..get_default_asset_definition_input()
+                }],
+                ..Default::default()
+            },
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("the first onboarding should be charged the standard onboarding cost");
+        assert_eq!(
+            1,
+            load_requestor_onboarding_count(
+                deps.as_ref().storage,
+                DEFAULT_VERIFIER_ADDRESS,
+                DEFAULT_SENDER_ADDRESS,
+            )
+            .unwrap(),
+            "the requestor's onboarding count should be incremented",
+        );
+        test_verify_asset(&mut deps, TestVerifyAsset::default_with_success(false)).unwrap();
+        let err = onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            TestOnboardAsset::default().info,
+            TestOnboardAsset::default_onboard_asset(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, ContractError::InvalidFunds(_)),
+            "the standard onboarding cost should be rejected after the tier is reached, but got: {:?}",
+            err,
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default_with_amount(600))
+            .expect("onboarding should succeed with the tier's onboarding cost");
+        assert_eq!(
+            1,
+            load_requestor_onboarding_count(
+                deps.as_ref().storage,
+                DEFAULT_VERIFIER_ADDRESS,
+                DEFAULT_SENDER_ADDRESS,
+            )
+            .unwrap(),
+            "retries should not increment the requestor's onboarding count",
+        );
+        let latest_verifier_detail = latest_verifier_detail_store_ro(deps.as_ref().storage)
+            .load(DEFAULT_SCOPE_ADDRESS.as_bytes())
+            .expect("the latest verifier detail should be stored");
+        assert_eq!(
+            Some(PricingTier::new(1, Uint128::new(600))),
+            latest_verifier_detail.applied_pricing_tier,
+            "the applied pricing tier should be recorded in the stored verifier detail",
+        );
+        assert_eq!(600, latest_verifier_detail.onboarding_cost.u128());
+        assert_eq!(
+            180,
+            latest_verifier_detail.fee_destinations[0].fee_amount.u128(),
+            "the flat fee destination should be scaled to the tier's cost",
+        );
+    }
+
+    #[test]
+    fn test_onboard_asset_applies_effective_scheduled_verifier_updates() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let mut env = mock_env();
+        let updated_verifier = VerifierDetailV2 {
+            onboarding_cost: Uint128::new(DEFAULT_ONBOARDING_COST * 2),
+            ..get_default_verifier_detail()
+        };
+        schedule_asset_verifier_update(
+            deps.as_mut(),
+            env.clone(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ScheduleAssetVerifierUpdateV1::new(
+                DEFAULT_ASSET_TYPE,
+                updated_verifier.clone(),
+                EffectiveAt::BlockHeight(env.block.height + 10),
+            ),
+        )
+        .expect("scheduling the update should succeed");
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("the current onboarding cost should apply before the update is effective");
+        test_verify_asset(&mut deps, TestVerifyAsset::default_with_success(false)).unwrap();
+        env.block.height += 10;
+        let err = onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            env.clone(),
+            TestOnboardAsset::default().info,
+            TestOnboardAsset::default_onboard_asset(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, ContractError::InvalidFunds(_)),
+            "the previous onboarding cost should be rejected once the update is effective, but got: {:?}",
+            err,
+        );
+        onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            env,
+            mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, DEFAULT_ONBOARDING_COST * 2),
+            TestOnboardAsset::default_onboard_asset(),
+        )
+        .expect("onboarding with the updated onboarding cost should succeed");
+        assert_eq!(
+            vec![updated_verifier.with_overrides_registered_fees(true)],
+            load_asset_definition_v2_by_type(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .unwrap()
+                .verifiers,
+            "the update should be persisted to the asset definition",
+        );
+        assert!(
+            load_scheduled_verifier_updates(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .unwrap()
+                .is_empty(),
+            "the applied update should be removed from the schedule",
+        );
+    }
+
     #[test]
     fn test_update_attribute_generates_appropriate_messages() {
         let mut deps = mock_dependencies(&[]);
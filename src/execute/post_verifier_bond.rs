@@ -0,0 +1,288 @@
+use cosmwasm_std::{MessageInfo, Response};
+
+use crate::core::state::{
+    delete_verifier_bond_unlock_height, deposit_verifier_bond, load_asset_definition_v2_by_type,
+    resolve_asset_definition_v2,
+};
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        event_attributes::{EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::PostVerifierBond](crate::core::msg::ExecuteMsg::PostVerifierBond)
+/// for ease of use in the underlying [post_verifier_bond](self::post_verifier_bond) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the definition for which the bond is posted.
+#[derive(Clone, PartialEq)]
+pub struct PostVerifierBondV1 {
+    pub asset_type: String,
+}
+impl PostVerifierBondV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type of the definition for which the bond is posted.
+    pub fn new<S: Into<String>>(asset_type: S) -> Self {
+        PostVerifierBondV1 {
+            asset_type: asset_type.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [PostVerifierBond](crate::core::msg::ExecuteMsg::PostVerifierBond)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<PostVerifierBondV1> {
+        match msg {
+            ExecuteMsg::PostVerifierBond { asset_type } => {
+                PostVerifierBondV1::new(asset_type).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::PostVerifierBond".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::PostVerifierBond](crate::core::msg::ExecuteMsg::PostVerifierBond)
+/// message is provided.  Adds the single coin sent by a verifier to its collateral bond for an
+/// asset type.  The funds are held by the contract until they are slashed by the admin or a
+/// verifier manager, or withdrawn after the verifier is removed from the asset definition.  Posting
+/// a bond cancels any withdrawal that the verifier has requested for it.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the post verifier bond v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn post_verifier_bond(
+    deps: DepsMutC,
+    info: MessageInfo,
+    msg: PostVerifierBondV1,
+) -> EntryPointResponse {
+    // Verifiers and the minimum bond may be inherited from a parent asset type, but the bond is
+    // always held for the requested asset type
+    let asset_definition = resolve_asset_definition_v2(
+        deps.storage,
+        load_asset_definition_v2_by_type(deps.storage, &msg.asset_type)?,
+    )?;
+    if !asset_definition
+        .verifiers
+        .iter()
+        .any(|verifier| verifier.address == info.sender.as_str())
+    {
+        return ContractError::Unauthorized {
+            explanation: format!(
+                "only verifiers within the asset definition for type {} can post a bond",
+                asset_definition.asset_type
+            ),
+        }
+        .to_err();
+    }
+    let funds = match &info.funds[..] {
+        [funds] if !funds.amount.is_zero() => funds,
+        _ => {
+            return ContractError::InvalidFunds(
+                "a bond must be posted as a single, non-zero coin".to_string(),
+            )
+            .to_err()
+        }
+    };
+    if let Some(minimum_verifier_bond) = &asset_definition.minimum_verifier_bond {
+        if minimum_verifier_bond.denom != funds.denom {
+            return ContractError::InvalidFunds(format!(
+                "bonds for asset type {} must be posted in [{}]",
+                asset_definition.asset_type, minimum_verifier_bond.denom
+            ))
+            .to_err();
+        }
+    }
+    let bond = deposit_verifier_bond(
+        deps.storage,
+        &asset_definition.asset_type,
+        info.sender.as_str(),
+        funds,
+    )?;
+    // A verifier that posts a bond intends to keep verifying, so any withdrawal that it requested
+    // earlier is abandoned
+    delete_verifier_bond_unlock_height(
+        deps.storage,
+        &asset_definition.asset_type,
+        info.sender.as_str(),
+    );
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::PostVerifierBond)
+                .set_asset_type(&asset_definition.asset_type)
+                .set_verifier(info.sender)
+                .set_new_value(&bond),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, coins};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::may_load_verifier_bond;
+    use crate::core::types::asset_definition::AssetDefinitionInputV2;
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition_input, single_attribute_for_key,
+        test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NEW_VALUE_KEY, NHASH, VERIFIER_ADDRESS_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{post_verifier_bond, PostVerifierBondV1};
+
+    #[test]
+    fn test_valid_post_verifier_bond_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &coins(500, NHASH)),
+            ExecuteMsg::PostVerifierBond {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+            },
+        )
+        .expect("posting a bond should succeed");
+        assert_eq!(
+            EventType::PostVerifierBond.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            single_attribute_for_key(&response, ASSET_TYPE_KEY),
+            "the asset type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_VERIFIER_ADDRESS,
+            single_attribute_for_key(&response, VERIFIER_ADDRESS_KEY),
+            "the verifier address should be emitted",
+        );
+        assert_eq!(
+            "500nhash",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the total bond should be emitted",
+        );
+    }
+
+    #[test]
+    fn test_valid_post_verifier_bond_adds_to_existing_bond() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        for amount in [500, 250] {
+            post_verifier_bond(
+                deps.as_mut(),
+                mock_info(DEFAULT_VERIFIER_ADDRESS, &coins(amount, NHASH)),
+                PostVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+            )
+            .expect("posting a bond should succeed");
+        }
+        assert_eq!(
+            coin(750, NHASH).to_some(),
+            may_load_verifier_bond(
+                deps.as_ref().storage,
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS
+            )
+            .unwrap(),
+            "each posted amount should be added to the bond",
+        );
+        let error = post_verifier_bond(
+            deps.as_mut(),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &coins(100, "otherdenom")),
+            PostVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected funds in a different denom than the existing bond to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_post_verifier_bond_for_non_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = post_verifier_bond(
+            deps.as_mut(),
+            mock_info(DEFAULT_SENDER_ADDRESS, &coins(500, NHASH)),
+            PostVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected an address outside the asset definition to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_post_verifier_bond_for_invalid_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![AssetDefinitionInputV2 {
+                    minimum_verifier_bond: coin(1000, NHASH).to_some(),
+                    ..get_default_asset_definition_input()
+                }],
+                ..Default::default()
+            },
+        );
+        for info in [
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &coins(0, NHASH)),
+            mock_info(
+                DEFAULT_VERIFIER_ADDRESS,
+                &[coin(500, NHASH), coin(500, "otherdenom")],
+            ),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &coins(500, "otherdenom")),
+        ] {
+            let error = post_verifier_bond(
+                deps.as_mut(),
+                info.clone(),
+                PostVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+            )
+            .unwrap_err();
+            assert!(
+                matches!(error, ContractError::InvalidFunds(_)),
+                "expected funds {:?} to be rejected, but got: {:?}",
+                info.funds,
+                error,
+            );
+        }
+    }
+}
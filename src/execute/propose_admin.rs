@@ -0,0 +1,220 @@
+use cosmwasm_std::{MessageInfo, Response};
+
+use crate::core::state::{config_read_v2, config_v2};
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_admin_only, check_funds_are_empty},
+        event_attributes::{EventAttributes, EventType},
+        scope_address_utils::bech32_string_to_addr,
+        traits::{OptionExtensions, ResultExtensions},
+    },
+};
+
+/// A transformation of [ExecuteMsg::ProposeAdmin](crate::core::msg::ExecuteMsg::ProposeAdmin)
+/// for ease of use in the underlying [propose_admin](self::propose_admin) function.
+///
+/// # Parameters
+///
+/// * `address` The bech32 address proposed to become the new admin.
+#[derive(Clone, PartialEq)]
+pub struct ProposeAdminV1 {
+    pub address: String,
+}
+impl ProposeAdminV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `address` The bech32 address proposed to become the new admin.
+    pub fn new<S: Into<String>>(address: S) -> Self {
+        ProposeAdminV1 {
+            address: address.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [ProposeAdmin](crate::core::msg::ExecuteMsg::ProposeAdmin)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<ProposeAdminV1> {
+        match msg {
+            ExecuteMsg::ProposeAdmin { address } => ProposeAdminV1::new(address).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::ProposeAdmin".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::ProposeAdmin](crate::core::msg::ExecuteMsg::ProposeAdmin)
+/// message is provided.  Stores the given address as the pending admin in the contract's
+/// [state](crate::core::state::StateV2).  The current admin retains control until the proposed
+/// address accepts via [accept_admin](crate::execute::accept_admin::accept_admin).  Proposing a new
+/// address replaces any existing proposal.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the propose admin v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn propose_admin(deps: DepsMutC, info: MessageInfo, msg: ProposeAdminV1) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let address = bech32_string_to_addr(&msg.address)?;
+    let mut state = config_read_v2(deps.storage).load()?;
+    if address == state.admin {
+        return ContractError::InvalidMessageFields {
+            message_type: "ExecuteMsg::ProposeAdmin".to_string(),
+            invalid_fields: vec!["address: must not be the current admin".to_string()],
+        }
+        .to_err();
+    }
+    state.pending_admin = address.clone().to_some();
+    config_v2(deps.storage).save(&state)?;
+    Response::new()
+        .add_attributes(EventAttributes::new(EventType::ProposeAdmin).set_new_value(address))
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::config_read_v2;
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, mock_info_with_nhash, single_attribute_for_key, test_instantiate_success,
+        InstArgs,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+
+    use super::{propose_admin, ProposeAdminV1};
+
+    #[test]
+    fn test_valid_propose_admin_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::ProposeAdmin {
+                address: DEFAULT_FEE_ADDRESS.to_string(),
+            },
+        )
+        .expect("proposing a new admin should succeed");
+        assert_eq!(
+            EventType::ProposeAdmin.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_FEE_ADDRESS,
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the proposed admin should be emitted",
+        );
+        let state = config_read_v2(deps.as_ref().storage)
+            .load()
+            .expect("state should load");
+        assert_eq!(
+            DEFAULT_ADMIN_ADDRESS,
+            state.admin.as_str(),
+            "the admin should not change until the proposal is accepted",
+        );
+        assert_eq!(
+            DEFAULT_FEE_ADDRESS,
+            state
+                .pending_admin
+                .expect("the pending admin should be set")
+                .as_str(),
+            "the proposed address should be stored as the pending admin",
+        );
+    }
+
+    #[test]
+    fn test_invalid_propose_admin_for_current_admin() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = propose_admin(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ProposeAdminV1::new(DEFAULT_ADMIN_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMessageFields { .. }),
+            "expected the invalid message fields error to be returned when proposing the current admin, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_propose_admin_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = propose_admin(
+            deps.as_mut(),
+            mock_info(DEFAULT_FEE_ADDRESS, &[]),
+            ProposeAdminV1::new(DEFAULT_FEE_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected only the admin to be able to propose a new admin, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_propose_admin_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = propose_admin(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            ProposeAdminV1::new(DEFAULT_FEE_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_propose_admin_for_invalid_address() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::ProposeAdmin {
+                address: "not-an-address".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMessageFields { .. }),
+            "expected the invalid message fields error to be returned for a bad address, but got: {:?}",
+            error,
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use cosmwasm_std::{Env, MessageInfo, Response};
+
+use crate::core::state::{config_read_v2, insert_queued_change};
+use crate::core::types::timelocked_change::TimelockedChange;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_funds_are_empty, check_timelocked_change_access},
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::QueueTimelockedChange](crate::core::msg::ExecuteMsg::QueueTimelockedChange)
+/// for ease of use in the underlying [queue_timelocked_change](self::queue_timelocked_change) function.
+///
+/// # Parameters
+///
+/// * `change` The change to queue.
+#[derive(Clone, PartialEq)]
+pub struct QueueTimelockedChangeV1 {
+    pub change: TimelockedChange,
+}
+impl QueueTimelockedChangeV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `change` The change to queue.
+    pub fn new(change: TimelockedChange) -> Self {
+        QueueTimelockedChangeV1 { change }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [QueueTimelockedChange](crate::core::msg::ExecuteMsg::QueueTimelockedChange)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<QueueTimelockedChangeV1> {
+        match msg {
+            ExecuteMsg::QueueTimelockedChange { change } => {
+                QueueTimelockedChangeV1::new(change).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::QueueTimelockedChange".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::QueueTimelockedChange](crate::core::msg::ExecuteMsg::QueueTimelockedChange)
+/// message is provided.  Stores a [QueuedChange](crate::core::types::timelocked_change::QueuedChange)
+/// that can be executed once the contract's current [timelock_delay](crate::core::state::StateV2::timelock_delay)
+/// has elapsed.  If the timelock is disabled, the change can be executed immediately.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the queue timelocked change v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn queue_timelocked_change(
+    deps: DepsMutC,
+    env: Env,
+    info: MessageInfo,
+    msg: QueueTimelockedChangeV1,
+) -> EntryPointResponse {
+    check_timelocked_change_access(&deps.as_ref(), &info, &msg.change)?;
+    check_funds_are_empty(&info)?;
+    let delay_blocks = config_read_v2(deps.storage)
+        .load()?
+        .timelock_delay
+        .unwrap_or_default();
+    let queued_change = insert_queued_change(
+        deps.storage,
+        msg.change,
+        info.sender,
+        env.block.height,
+        delay_blocks,
+    )?;
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("message_type", queued_change.change.message_type());
+    additional_metadata.add_metadata(
+        "executable_at_height",
+        queued_change.executable_at_height.to_string(),
+    );
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::QueueTimelockedChange)
+                .set_new_value(queued_change.change_id)
+                .set_additional_metadata(&additional_metadata),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::load_queued_changes;
+    use crate::core::types::timelocked_change::TimelockedChange;
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, mock_info_with_nhash, single_attribute_for_key, test_enable_timelock,
+        test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::{ADDITIONAL_METADATA_KEY, ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{queue_timelocked_change, QueueTimelockedChangeV1};
+
+    #[test]
+    fn test_valid_queue_timelocked_change_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        let env = mock_env();
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::QueueTimelockedChange {
+                change: TimelockedChange::UpdateProtocolFee { protocol_fee: None },
+            },
+        )
+        .expect("queueing a change should succeed");
+        assert_eq!(
+            EventType::QueueTimelockedChange.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            "1",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the first queued change should be assigned the first id",
+        );
+        assert_eq!(
+            format!(
+                "[executable_at_height={}], [message_type=ExecuteMsg::UpdateProtocolFee]",
+                env.block.height + 100
+            ),
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the change's type and executable height should be emitted",
+        );
+        let queued_change = load_queued_changes(deps.as_ref().storage)
+            .expect("queued changes should load")
+            .pop()
+            .expect("a queued change should be stored");
+        assert_eq!(
+            DEFAULT_ADMIN_ADDRESS,
+            queued_change.queued_by.as_str(),
+            "the sender should be recorded as the account that queued the change",
+        );
+        assert_eq!(
+            env.block.height, queued_change.queued_at_height,
+            "the current block height should be recorded",
+        );
+        assert_eq!(
+            env.block.height + 100,
+            queued_change.executable_at_height,
+            "the change should become executable after the timelock delay",
+        );
+    }
+
+    #[test]
+    fn test_queued_change_ids_increment() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        for _ in 0..2 {
+            queue_timelocked_change(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                QueueTimelockedChangeV1::new(TimelockedChange::UpdateTimelockDelay {
+                    delay_blocks: 10.to_some(),
+                }),
+            )
+            .expect("queueing a change should succeed");
+        }
+        assert_eq!(
+            vec![1, 2],
+            load_queued_changes(deps.as_ref().storage)
+                .expect("queued changes should load")
+                .into_iter()
+                .map(|change| change.change_id)
+                .collect::<Vec<u64>>(),
+            "each queued change should receive a unique, incrementing id",
+        );
+    }
+
+    #[test]
+    fn test_invalid_queue_timelocked_change_for_missing_role() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = queue_timelocked_change(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_FEE_ADDRESS, &[]),
+            QueueTimelockedChangeV1::new(TimelockedChange::UpdateProtocolFee {
+                protocol_fee: None,
+            }),
+        )
+        .unwrap_err();
+        match error {
+            ContractError::Unauthorized { explanation } => {
+                assert_eq!(
+                    "admin or treasury_manager role required", explanation,
+                    "the role governing the change should be required",
+                );
+            }
+            _ => panic!(
+                "expected an account without the role to be rejected, but got: {:?}",
+                error
+            ),
+        }
+    }
+
+    #[test]
+    fn test_invalid_queue_timelocked_change_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = queue_timelocked_change(
+            deps.as_mut(),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            QueueTimelockedChangeV1::new(TimelockedChange::UpdateProtocolFee {
+                protocol_fee: None,
+            }),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_queue_timelocked_change_for_invalid_change() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::QueueTimelockedChange {
+                change: TimelockedChange::UpdateTimelockDelay {
+                    delay_blocks: 0.to_some(),
+                },
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMessageFields { .. }),
+            "expected the queued change to be validated, but got: {:?}",
+            error,
+        );
+    }
+}
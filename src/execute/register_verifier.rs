@@ -0,0 +1,491 @@
+use cosmwasm_std::{MessageInfo, Response, Storage};
+
+use crate::core::state::{
+    insert_verifier_registration, load_asset_definitions_v2_by_verifier,
+    may_load_verifier_registration,
+};
+use crate::core::types::asset_definition::AssetDefinitionV2;
+use crate::core::types::contract_role::ContractRole;
+use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::core::types::verifier_registration::VerifierRegistration;
+use crate::util::contract_helpers::{check_funds_are_empty, check_role, check_timelock_disabled};
+use crate::util::event_attributes::EventAdditionalMetadata;
+use crate::validation::validate_init_msg::validate_verifier;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        event_attributes::{EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::RegisterVerifier](crate::core::msg::ExecuteMsg::RegisterVerifier)
+/// for ease of use in the underlying [register_verifier](self::register_verifier) function.
+///
+/// # Parameters
+///
+/// * `registration` The registration that will be stored for the verifier and resolved by each
+/// asset type that uses it.
+#[derive(Clone, PartialEq)]
+pub struct RegisterVerifierV1 {
+    pub registration: VerifierRegistration,
+}
+impl RegisterVerifierV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `registration` The registration that will be stored for the verifier and resolved by
+    /// each asset type that uses it.
+    pub fn new(registration: VerifierRegistration) -> Self {
+        RegisterVerifierV1 { registration }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [RegisterVerifier](crate::core::msg::ExecuteMsg::RegisterVerifier)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<RegisterVerifierV1> {
+        match msg {
+            ExecuteMsg::RegisterVerifier { registration } => {
+                RegisterVerifierV1::new(registration).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::RegisterVerifier".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::RegisterVerifier](crate::core::msg::ExecuteMsg::RegisterVerifier)
+/// message is provided.  Creates or replaces the global [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration)
+/// of a verifier.  While the contract's timelock is enabled, this route is rejected and the
+/// registration must be queued as a [TimelockedChange](crate::core::types::timelocked_change::TimelockedChange)
+/// instead.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the register verifier v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn register_verifier(
+    deps: DepsMutC,
+    info: MessageInfo,
+    msg: RegisterVerifierV1,
+) -> EntryPointResponse {
+    check_role(&deps.as_ref(), &info, ContractRole::VerifierManager)?;
+    check_funds_are_empty(&info)?;
+    check_timelock_disabled(&deps.as_ref(), "ExecuteMsg::RegisterVerifier")?;
+    Response::new()
+        .add_attributes(apply_register_verifier(deps.storage, msg)?)
+        .to_ok()
+}
+
+/// Stores a [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration)
+/// without any authorization checks.  Asset definitions only store the values that are specific to
+/// each asset type, and every [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
+/// with a matching address resolves the registration's values when it is loaded, so no asset
+/// definition is modified.  Used directly by [register_verifier](self::register_verifier) and by
+/// [execute_timelocked_change](crate::execute::execute_timelocked_change::execute_timelocked_change)
+/// once a queued registration's delay has elapsed.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `msg` An instance of the register verifier v1 struct.
+pub fn apply_register_verifier(
+    storage: &mut dyn Storage,
+    msg: RegisterVerifierV1,
+) -> AssetResult<EventAttributes> {
+    let registration = msg.registration;
+    let asset_definitions = load_asset_definitions_v2_by_verifier(storage, &registration.address)?;
+    for asset_definition in asset_definitions.iter() {
+        for verifier in asset_definition
+            .verifiers
+            .iter()
+            .filter(|verifier| verifier.address == registration.address)
+        {
+            let mut resolved_verifier = verifier.to_owned();
+            registration.apply_to(&mut resolved_verifier);
+            // New fees can conflict with the verifier's type-specific values, like its intake fee
+            validate_verifier(&resolved_verifier)?;
+        }
+    }
+    insert_verifier_registration(storage, &registration)?;
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("asset_type_count", asset_definitions.len().to_string());
+    EventAttributes::new(EventType::RegisterVerifier)
+        .set_verifier(&registration.address)
+        .set_additional_metadata(&additional_metadata)
+        .to_ok()
+}
+
+/// Prepares a [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2) that a
+/// route will store on a single asset definition.  A verifier without a [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration)
+/// is registered with the values of the verifier detail.  Otherwise, the registration is never
+/// modified: the verifier detail must omit the registration's entity detail or match it exactly,
+/// and a verifier detail with fees that differ from the registration is marked as
+/// [overriding the registered fees](crate::core::types::verifier_detail::VerifierDetailV2::overrides_registered_fees),
+/// so that its fees only apply to its own asset type.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `verifier` The verifier detail that will be stored.
+pub fn register_verifier_detail(
+    storage: &mut dyn Storage,
+    verifier: &mut VerifierDetailV2,
+) -> AssetResult<()> {
+    match may_load_verifier_registration(storage, &verifier.address)? {
+        Some(registration) => {
+            if verifier.entity_detail.is_some()
+                && verifier.entity_detail != registration.entity_detail
+            {
+                return ContractError::VerifierRegistrationConflict {
+                    verifier_address: verifier.address.to_owned(),
+                }
+                .to_err();
+            }
+            if !registration.has_matching_fees(verifier) {
+                verifier.overrides_registered_fees = true;
+            }
+            Ok(())
+        }
+        None => insert_verifier_registration(
+            storage,
+            &VerifierRegistration::from_verifier_detail(verifier),
+        ),
+    }
+}
+
+/// Prepares every [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2) of an
+/// asset definition that a route will store, as described by [register_verifier_detail](self::register_verifier_detail).
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `asset_definition` The asset definition that will be stored by the caller.
+pub fn register_verifier_details(
+    storage: &mut dyn Storage,
+    asset_definition: &mut AssetDefinitionV2,
+) -> AssetResult<()> {
+    for verifier in asset_definition.verifiers.iter_mut() {
+        register_verifier_detail(storage, verifier)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Uint128;
+    use provwasm_mocks::mock_dependencies;
+    use uuid::Uuid;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::{load_asset_definition_v2_by_type, load_verifier_registration};
+    use crate::core::types::asset_definition::AssetDefinitionInputV2;
+    use crate::core::types::entity_detail::EntityDetail;
+    use crate::core::types::fee_destination::FeeDestinationV2;
+    use crate::core::types::scope_spec_identifier::ScopeSpecIdentifier;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::core::types::verifier_registration::VerifierRegistration;
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_FEE_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition_input, mock_info_with_nhash,
+        single_attribute_for_key, test_enable_timelock, test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::DepsC;
+    use crate::util::constants::{
+        ADDITIONAL_METADATA_KEY, ASSET_EVENT_TYPE_KEY, NHASH, VERIFIER_ADDRESS_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{register_verifier, RegisterVerifierV1};
+
+    #[test]
+    fn test_valid_register_verifier_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let registration = get_test_registration();
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::RegisterVerifier {
+                registration: registration.clone(),
+            },
+        )
+        .expect("registering the verifier should succeed");
+        assert_eq!(
+            EventType::RegisterVerifier.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_VERIFIER_ADDRESS,
+            single_attribute_for_key(&response, VERIFIER_ADDRESS_KEY),
+            "the verifier address should be emitted",
+        );
+        assert_eq!(
+            "[asset_type_count=1]",
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the number of synced asset types should be emitted",
+        );
+        assert_eq!(
+            registration,
+            load_verifier_registration(deps.as_ref().storage, DEFAULT_VERIFIER_ADDRESS)
+                .expect("the registration should be stored"),
+            "the stored registration should match the request",
+        );
+        assert_eq!(
+            registration.to_verifier_detail(),
+            get_verifier_for_type(&deps.as_ref(), DEFAULT_ASSET_TYPE),
+            "the registration should be resolved by the default asset type",
+        );
+    }
+
+    #[test]
+    fn test_register_verifier_syncs_all_asset_types_and_retains_overrides() {
+        let mut deps = mock_dependencies(&[]);
+        let overriding_verifier = VerifierDetailV2::new(
+            DEFAULT_VERIFIER_ADDRESS,
+            Uint128::new(777),
+            NHASH,
+            vec![],
+            None,
+        )
+        .with_overrides_registered_fees(true);
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![
+                    get_default_asset_definition_input(),
+                    AssetDefinitionInputV2::new(
+                        "other_type",
+                        ScopeSpecIdentifier::uuid(Uuid::new_v4().to_string()).to_serialized_enum(),
+                        vec![overriding_verifier.clone()],
+                        true.to_some(),
+                        false.to_some(),
+                    ),
+                    AssetDefinitionInputV2::new(
+                        "unrelated_type",
+                        ScopeSpecIdentifier::uuid(Uuid::new_v4().to_string()).to_serialized_enum(),
+                        vec![VerifierDetailV2::new(
+                            DEFAULT_FEE_ADDRESS,
+                            Uint128::new(100),
+                            NHASH,
+                            vec![],
+                            None,
+                        )],
+                        true.to_some(),
+                        false.to_some(),
+                    ),
+                ],
+                ..Default::default()
+            },
+        );
+        let registration = get_test_registration();
+        let response = register_verifier(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            RegisterVerifierV1::new(registration.clone()),
+        )
+        .expect("registering the verifier should succeed");
+        assert_eq!(
+            "[asset_type_count=2]",
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "only the asset types containing the verifier should be synced",
+        );
+        assert_eq!(
+            registration.to_verifier_detail(),
+            get_verifier_for_type(&deps.as_ref(), DEFAULT_ASSET_TYPE),
+            "the default asset type should receive all registered values",
+        );
+        let other_verifier = get_verifier_for_type(&deps.as_ref(), "other_type");
+        assert_eq!(
+            registration.entity_detail, other_verifier.entity_detail,
+            "the entity detail should be resolved by an asset type that overrides the fees",
+        );
+        assert_eq!(
+            Uint128::new(777),
+            other_verifier.onboarding_cost,
+            "the overriding asset type should retain its own onboarding cost",
+        );
+        assert_eq!(
+            Uint128::new(100),
+            get_verifier_for_type(&deps.as_ref(), "unrelated_type").onboarding_cost,
+            "an asset type without the verifier should be unchanged",
+        );
+    }
+
+    #[test]
+    fn test_invalid_register_verifier_for_conflicting_intake_fee() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![AssetDefinitionInputV2::new(
+                    DEFAULT_ASSET_TYPE,
+                    ScopeSpecIdentifier::uuid(Uuid::new_v4().to_string()).to_serialized_enum(),
+                    vec![VerifierDetailV2::new(
+                        DEFAULT_VERIFIER_ADDRESS,
+                        Uint128::new(1000),
+                        NHASH,
+                        vec![],
+                        None,
+                    )
+                    .with_intake_fee(Uint128::new(500), vec![])],
+                    true.to_some(),
+                    false.to_some(),
+                )],
+                ..Default::default()
+            },
+        );
+        let error = register_verifier(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            RegisterVerifierV1::new(VerifierRegistration::new(
+                DEFAULT_VERIFIER_ADDRESS,
+                None,
+                Uint128::new(100),
+                NHASH,
+                vec![],
+            )),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMessageFields { .. }),
+            "expected a registration that invalidates an asset type's verifier to be rejected, but got: {:?}",
+            error,
+        );
+        assert_eq!(
+            Uint128::new(1000),
+            load_verifier_registration(deps.as_ref().storage, DEFAULT_VERIFIER_ADDRESS)
+                .expect("the verifier should be registered on instantiation")
+                .onboarding_cost,
+            "the rejected registration should not be stored",
+        );
+    }
+
+    #[test]
+    fn test_invalid_register_verifier_for_invalid_msg() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::RegisterVerifier {
+                registration: VerifierRegistration::new(
+                    "not-an-address",
+                    None,
+                    Uint128::new(100),
+                    "",
+                    vec![],
+                ),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMessageFields { .. }),
+            "expected the invalid message fields error to be returned for a malformed registration, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_register_verifier_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = register_verifier(
+            deps.as_mut(),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &[]),
+            RegisterVerifierV1::new(get_test_registration()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_register_verifier_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = register_verifier(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            RegisterVerifierV1::new(get_test_registration()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_register_verifier_for_enabled_timelock() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        let error = register_verifier(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            RegisterVerifierV1::new(get_test_registration()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::TimelockRequired { .. }),
+            "the timelock required error should be returned when the timelock is enabled, but got: {:?}",
+            error,
+        );
+    }
+
+    fn get_test_registration() -> VerifierRegistration {
+        VerifierRegistration::new(
+            DEFAULT_VERIFIER_ADDRESS,
+            EntityDetail::new(
+                "Registered Verifier",
+                "A globally registered verifier",
+                "https://verifier.example.com",
+                "https://github.com/verifier/verifier",
+            )
+            .to_some(),
+            Uint128::new(2000),
+            NHASH,
+            vec![FeeDestinationV2::new(
+                DEFAULT_FEE_ADDRESS,
+                Uint128::new(500),
+            )],
+        )
+    }
+
+    fn get_verifier_for_type(deps: &DepsC, asset_type: &str) -> VerifierDetailV2 {
+        load_asset_definition_v2_by_type(deps.storage, asset_type)
+            .expect("the asset definition should exist")
+            .verifiers
+            .into_iter()
+            .next()
+            .expect("the asset definition should have a verifier")
+    }
+}
@@ -0,0 +1,883 @@
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{
+    delete_scheduled_verifier_updates_for_verifier, delete_verifier_self_update_bounds,
+    load_asset_definition_v2_by_type, load_pending_verification_scope_addresses,
+    load_verifier_inheriting_asset_types, load_verifier_owning_asset_definition_v2,
+    replace_asset_definition_v2, resolve_asset_definition_v2,
+};
+use crate::core::types::contract_role::ContractRole;
+use crate::core::types::pending_asset_strategy::PendingAssetStrategy;
+use crate::service::asset_meta_repository::AssetMetaRepository;
+use crate::service::deps_manager::DepsManager;
+use crate::service::message_gathering_service::MessageGatheringService;
+use crate::util::aliases::{AssetResult, EntryPointResponse};
+use crate::util::contract_helpers::{
+    check_funds_are_empty, check_role, check_timelock_disabled, check_verifier_bond,
+};
+use crate::util::event_attributes::{EventAdditionalMetadata, EventAttributes, EventType};
+use crate::util::traits::ResultExtensions;
+use cosmwasm_std::{Env, MessageInfo, Response};
+
+/// A transformation of [ExecuteMsg::RemoveAssetVerifier](crate::core::msg::ExecuteMsg::RemoveAssetVerifier)
+/// for ease of use in the underlying [remove_asset_verifier](self::remove_asset_verifier) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the definition from which the verifier will be removed.
+/// * `verifier_address` The bech32 address of the verifier to remove.
+/// * `pending_asset_strategy` Defines how assets that are still awaiting verification by the
+/// verifier are handled.  If omitted, the request is rejected when any such assets exist.
+#[derive(Clone, PartialEq)]
+pub struct RemoveAssetVerifierV1 {
+    pub asset_type: String,
+    pub verifier_address: String,
+    pub pending_asset_strategy: Option<PendingAssetStrategy>,
+}
+impl RemoveAssetVerifierV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type of the definition from which the verifier will be removed.
+    /// * `verifier_address` The bech32 address of the verifier to remove.
+    /// * `pending_asset_strategy` Defines how assets that are still awaiting verification by the
+    /// verifier are handled.
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        asset_type: S1,
+        verifier_address: S2,
+        pending_asset_strategy: Option<PendingAssetStrategy>,
+    ) -> Self {
+        RemoveAssetVerifierV1 {
+            asset_type: asset_type.into(),
+            verifier_address: verifier_address.into(),
+            pending_asset_strategy,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [RemoveAssetVerifier](crate::core::msg::ExecuteMsg::RemoveAssetVerifier)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<RemoveAssetVerifierV1> {
+        match msg {
+            ExecuteMsg::RemoveAssetVerifier {
+                asset_type,
+                verifier_address,
+                pending_asset_strategy,
+            } => RemoveAssetVerifierV1::new(asset_type, verifier_address, pending_asset_strategy)
+                .to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::RemoveAssetVerifier".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::RemoveAssetVerifier](crate::core::msg::ExecuteMsg::RemoveAssetVerifier)
+/// message is provided.  Removes an existing [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
+/// from an existing [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2).
+/// The last verifier of an asset definition cannot be removed.  If assets are still awaiting
+/// verification by the verifier, the request is rejected unless a [PendingAssetStrategy](crate::core::types::pending_asset_strategy::PendingAssetStrategy)
+/// is provided to reassign or refund them.  If the asset type inherits its verifiers from a parent
+/// asset type, the verifier is removed from the ancestor that owns them.  Pending assets of every
+/// asset type that inherits the ancestor's verifiers are affected.  While the contract's timelock
+/// is enabled, this route is rejected and the removal must be queued as a [TimelockedChange](crate::core::types::timelocked_change::TimelockedChange)
+/// instead.
+///
+/// # Parameters
+///
+/// * `repository` A helper collection of traits that allows complex lookups of scope values and
+/// emits messages to construct the process of reassigning or refunding pending assets as a
+/// collection of messages to produce in the function's result.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the remove asset verifier v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn remove_asset_verifier<'a, T>(
+    repository: T,
+    env: Env,
+    info: MessageInfo,
+    msg: RemoveAssetVerifierV1,
+) -> EntryPointResponse
+where
+    T: AssetMetaRepository + MessageGatheringService + DepsManager<'a>,
+{
+    repository.use_deps(|deps| check_role(&deps.as_ref(), &info, ContractRole::VerifierManager))?;
+    check_funds_are_empty(&info)?;
+    repository.use_deps(|deps| {
+        check_timelock_disabled(&deps.as_ref(), "ExecuteMsg::RemoveAssetVerifier")
+    })?;
+    let attributes =
+        apply_remove_asset_verifier(&repository, msg, env.block.height, info.sender.as_str())?;
+    Response::new()
+        .add_attributes(attributes)
+        .add_messages(repository.get_messages())
+        .to_ok()
+}
+
+/// Removes an existing [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
+/// from an existing [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
+/// without any authorization checks, reassigning or refunding the verifier's pending assets.  Used
+/// directly by [remove_asset_verifier](self::remove_asset_verifier) and by [execute_timelocked_change](crate::execute::execute_timelocked_change::execute_timelocked_change)
+/// once a queued removal's delay has elapsed.  Any refund messages are gathered in the repository.
+///
+/// # Parameters
+///
+/// * `repository` A helper collection of traits that allows complex lookups of scope values and
+/// gathers the messages produced by reassigning or refunding pending assets.
+/// * `msg` An instance of the remove asset verifier v1 struct.
+/// * `block_height` The block height at which the change is applied.
+/// * `actor` The bech32 address of the account responsible for the change.
+pub fn apply_remove_asset_verifier<'a, T>(
+    repository: &T,
+    msg: RemoveAssetVerifierV1,
+    block_height: u64,
+    actor: &str,
+) -> AssetResult<EventAttributes>
+where
+    T: AssetMetaRepository + MessageGatheringService + DepsManager<'a>,
+{
+    let mut asset_definition = repository
+        .use_deps(|deps| load_verifier_owning_asset_definition_v2(deps.storage, &msg.asset_type))?;
+    if !asset_definition
+        .verifiers
+        .iter()
+        .any(|verifier| verifier.address == msg.verifier_address)
+    {
+        return ContractError::NotFound {
+            explanation: format!(
+                "verifier with address {} not found for asset definition for type {}",
+                msg.verifier_address, asset_definition.asset_type
+            ),
+        }
+        .to_err();
+    }
+    // Asset definitions are required to have at least one verifier at all times, mirroring the
+    // validation performed when they are added
+    if asset_definition.verifiers.len() == 1 {
+        return ContractError::InvalidMessageFields {
+            message_type: "ExecuteMsg::RemoveAssetVerifier".to_string(),
+            invalid_fields: vec![
+                "verifier_address: at least one verifier must remain per asset type".to_string(),
+            ],
+        }
+        .to_err();
+    }
+    // A verifier may be used by many asset types, so only the assets onboarded for this type, or
+    // for a descendant that inherits this type's verifiers, are affected by its removal
+    let affected_asset_types = repository.use_deps(|deps| {
+        load_verifier_inheriting_asset_types(deps.storage, &asset_definition.asset_type)
+    })?;
+    let mut pending_scope_addresses = vec![];
+    let mut pending_asset_types: Vec<String> = vec![];
+    for asset_type in affected_asset_types.iter() {
+        let scope_addresses = repository.use_deps(|deps| {
+            load_pending_verification_scope_addresses(
+                deps.storage,
+                asset_type,
+                &msg.verifier_address,
+            )
+        })?;
+        if !scope_addresses.is_empty() {
+            pending_asset_types.push(asset_type.to_owned());
+            pending_scope_addresses.extend(scope_addresses);
+        }
+    }
+    if !pending_scope_addresses.is_empty() {
+        match &msg.pending_asset_strategy {
+            Some(PendingAssetStrategy::Reassign { verifier_address }) => {
+                let replacement_verifier = match asset_definition
+                    .verifiers
+                    .iter()
+                    .find(|verifier| &verifier.address == verifier_address)
+                {
+                    Some(verifier) if verifier.enabled => verifier.to_owned(),
+                    Some(_) => {
+                        return ContractError::VerifierDisabled {
+                            verifier_address: verifier_address.to_owned(),
+                            asset_type: asset_definition.asset_type,
+                        }
+                        .to_err();
+                    }
+                    None => {
+                        return ContractError::NotFound {
+                            explanation: format!(
+                                "replacement verifier with address {} not found for asset definition for type {}",
+                                verifier_address, asset_definition.asset_type
+                            ),
+                        }
+                        .to_err();
+                    }
+                };
+                // The replacement must satisfy the same bond that onboarding would require for each
+                // asset type that has pending assets
+                for asset_type in pending_asset_types.iter() {
+                    repository.use_deps(|deps| {
+                        let pending_asset_definition = resolve_asset_definition_v2(
+                            deps.storage,
+                            load_asset_definition_v2_by_type(deps.storage, asset_type)?,
+                        )?;
+                        check_verifier_bond(
+                            &deps.as_ref(),
+                            &pending_asset_definition,
+                            &replacement_verifier.address,
+                        )
+                    })?;
+                }
+                for scope_address in pending_scope_addresses.iter() {
+                    repository.reassign_pending_asset(scope_address, &replacement_verifier)?;
+                }
+            }
+            Some(PendingAssetStrategy::Refund {}) => {
+                for scope_address in pending_scope_addresses.iter() {
+                    repository.refund_pending_asset(
+                        scope_address,
+                        format!(
+                            "verifier [{}] was removed before verification, and the onboarding fee was refunded",
+                            msg.verifier_address
+                        ),
+                    )?;
+                }
+            }
+            None => {
+                return ContractError::VerifierHasPendingAssets {
+                    verifier_address: msg.verifier_address,
+                    asset_type: asset_definition.asset_type,
+                    pending_asset_count: pending_scope_addresses.len(),
+                }
+                .to_err();
+            }
+        }
+    }
+    asset_definition
+        .verifiers
+        .retain(|verifier| verifier.address != msg.verifier_address);
+    repository.use_deps(|deps| {
+        replace_asset_definition_v2(deps.storage, &asset_definition, block_height, actor)?;
+        delete_verifier_self_update_bounds(
+            deps.storage,
+            &asset_definition.asset_type,
+            &msg.verifier_address,
+        );
+        delete_scheduled_verifier_updates_for_verifier(
+            deps.storage,
+            &asset_definition.asset_type,
+            &msg.verifier_address,
+        )
+    })?;
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata(
+        "pending_asset_count",
+        pending_scope_addresses.len().to_string(),
+    );
+    if let Some(strategy) = &msg.pending_asset_strategy {
+        additional_metadata.add_metadata("pending_asset_strategy", strategy.to_string());
+    }
+    EventAttributes::new(EventType::RemoveAssetVerifier)
+        .set_asset_type(&asset_definition.asset_type)
+        .set_verifier(&msg.verifier_address)
+        .set_additional_metadata(&additional_metadata)
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::{
+        insert_verifier_self_update_bounds, latest_verifier_detail_store_ro,
+        load_asset_definition_v2_by_type, load_verifier_metrics, load_verifier_self_update_bounds,
+    };
+    use crate::core::types::asset_definition::AssetDefinitionInputV2;
+    use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+    use crate::core::types::fee_destination::FeeDestinationV2;
+    use crate::core::types::pending_asset_strategy::PendingAssetStrategy;
+    use crate::core::types::scope_spec_identifier::ScopeSpecIdentifier;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::core::types::verifier_self_update_bounds::VerifierSelfUpdateBounds;
+    use crate::execute::onboard_asset::OnboardAssetV1;
+    use crate::execute::post_verifier_bond::{post_verifier_bond, PostVerifierBondV1};
+    use crate::execute::toggle_asset_verifier::{toggle_asset_verifier, ToggleAssetVerifierV1};
+    use crate::service::asset_meta_repository::AssetMetaRepository;
+    use crate::service::asset_meta_service::AssetMetaService;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_FEE_ADDRESS, DEFAULT_ONBOARDING_COST,
+        DEFAULT_ONBOARDING_DENOM, DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS,
+        DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_bank_sends, get_default_asset_definition_input, get_default_records,
+        get_default_verifier_detail, get_duped_scope, intercept_add_attribute,
+        mock_info_with_nhash, setup_test_suite, single_attribute_for_key, test_enable_timelock,
+        test_instantiate_success, InstArgs, MockOwnedDeps,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::aliases::EntryPointResponse;
+    use crate::util::constants::{
+        ADDITIONAL_METADATA_KEY, ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NHASH, VERIFIER_ADDRESS_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{remove_asset_verifier, RemoveAssetVerifierV1};
+
+    const SECOND_VERIFIER_ADDRESS: &str = "tp1g83pm46c8wxsnlra2ytruec7nuy95ttc8yy5n3";
+
+    #[test]
+    fn test_valid_remove_asset_verifier_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        setup_two_verifier_suite(&mut deps);
+        insert_verifier_self_update_bounds(
+            deps.as_mut().storage,
+            DEFAULT_ASSET_TYPE,
+            SECOND_VERIFIER_ADDRESS,
+            &VerifierSelfUpdateBounds::new(Uint128::new(5000).to_some(), vec![]),
+        )
+        .expect("storing bounds should succeed");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::RemoveAssetVerifier {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                verifier_address: SECOND_VERIFIER_ADDRESS.to_string(),
+                pending_asset_strategy: None,
+            },
+        )
+        .expect("removing a verifier without pending assets should succeed");
+        assert!(
+            response.messages.is_empty(),
+            "removing a verifier without pending assets should not produce messages",
+        );
+        assert_eq!(
+            EventType::RemoveAssetVerifier.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            single_attribute_for_key(&response, ASSET_TYPE_KEY),
+            "the asset type should be emitted",
+        );
+        assert_eq!(
+            SECOND_VERIFIER_ADDRESS,
+            single_attribute_for_key(&response, VERIFIER_ADDRESS_KEY),
+            "the removed verifier's address should be emitted",
+        );
+        assert_eq!(
+            "[pending_asset_count=0]",
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the number of pending assets should be emitted",
+        );
+        assert_eq!(
+            vec![get_default_verifier_detail()],
+            load_asset_definition_v2_by_type(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .expect("the asset definition should load")
+                .verifiers,
+            "only the remaining verifier should be stored in the asset definition",
+        );
+        assert_eq!(
+            VerifierSelfUpdateBounds::default(),
+            load_verifier_self_update_bounds(
+                deps.as_ref().storage,
+                DEFAULT_ASSET_TYPE,
+                SECOND_VERIFIER_ADDRESS,
+            )
+            .expect("the bounds should load"),
+            "the removed verifier's self update bounds should be deleted",
+        );
+    }
+
+    #[test]
+    fn test_invalid_remove_asset_verifier_with_pending_assets_and_no_strategy() {
+        let mut deps = mock_dependencies(&[]);
+        setup_two_verifier_suite(&mut deps);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed");
+        let error = test_remove_verifier(&mut deps, DEFAULT_VERIFIER_ADDRESS, None).unwrap_err();
+        match error {
+            ContractError::VerifierHasPendingAssets {
+                verifier_address,
+                asset_type,
+                pending_asset_count,
+            } => {
+                assert_eq!(DEFAULT_VERIFIER_ADDRESS, verifier_address);
+                assert_eq!(DEFAULT_ASSET_TYPE, asset_type);
+                assert_eq!(1, pending_asset_count);
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        };
+        assert_eq!(
+            2,
+            load_asset_definition_v2_by_type(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .unwrap()
+                .verifiers
+                .len(),
+            "the verifier should not be removed",
+        );
+    }
+
+    #[test]
+    fn test_valid_remove_asset_verifier_reassigns_pending_assets() {
+        let mut deps = mock_dependencies(&[]);
+        setup_two_verifier_suite(&mut deps);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed");
+        let response = test_remove_verifier(
+            &mut deps,
+            DEFAULT_VERIFIER_ADDRESS,
+            PendingAssetStrategy::Reassign {
+                verifier_address: SECOND_VERIFIER_ADDRESS.to_string(),
+            }
+            .to_some(),
+        )
+        .expect("removing the verifier with a reassign strategy should succeed");
+        assert_eq!(
+            format!(
+                "[pending_asset_count=1], [pending_asset_strategy=reassign:{}]",
+                SECOND_VERIFIER_ADDRESS
+            ),
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the pending asset handling should be emitted",
+        );
+        intercept_add_attribute(
+            &mut deps,
+            &Ok(response.clone()),
+            "the attribute should be rewritten",
+        );
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset(DEFAULT_SCOPE_ADDRESS)
+            .expect("the asset should exist");
+        assert_eq!(
+            SECOND_VERIFIER_ADDRESS,
+            attribute.verifier_address.as_str(),
+            "the asset should be assigned to the replacement verifier",
+        );
+        assert_eq!(AssetOnboardingStatus::Pending, attribute.onboarding_status);
+        let escrowed_detail = latest_verifier_detail_store_ro(deps.as_ref().storage)
+            .load(DEFAULT_SCOPE_ADDRESS.as_bytes())
+            .expect("the escrowed verifier detail should remain");
+        assert_eq!(
+            Uint128::new(DEFAULT_ONBOARDING_COST),
+            escrowed_detail.onboarding_cost,
+            "the cost paid during onboarding should be retained",
+        );
+        assert_eq!(
+            0,
+            load_verifier_metrics(deps.as_ref().storage, DEFAULT_VERIFIER_ADDRESS)
+                .unwrap()
+                .pending_assets,
+            "the removed verifier should no longer have a pending asset",
+        );
+        assert_eq!(
+            1,
+            load_verifier_metrics(deps.as_ref().storage, SECOND_VERIFIER_ADDRESS)
+                .unwrap()
+                .pending_assets,
+            "the pending asset should be counted for the replacement verifier",
+        );
+        let response = test_verify_asset(
+            &mut deps,
+            TestVerifyAsset {
+                info: mock_info(SECOND_VERIFIER_ADDRESS, &[]),
+                ..TestVerifyAsset::default()
+            },
+        )
+        .expect("the replacement verifier should be able to verify the asset");
+        assert_eq!(
+            vec![
+                (
+                    DEFAULT_FEE_ADDRESS.to_string(),
+                    vec![coin(100, DEFAULT_ONBOARDING_DENOM)]
+                ),
+                (
+                    SECOND_VERIFIER_ADDRESS.to_string(),
+                    vec![coin(
+                        DEFAULT_ONBOARDING_COST - 100,
+                        DEFAULT_ONBOARDING_DENOM
+                    )]
+                ),
+            ],
+            get_bank_sends(&response),
+            "the escrowed fee should be paid using the replacement verifier's fee destinations",
+        );
+    }
+
+    #[test]
+    fn test_valid_remove_asset_verifier_refunds_pending_assets() {
+        let mut deps = mock_dependencies(&[]);
+        setup_two_verifier_suite(&mut deps);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed");
+        let response = test_remove_verifier(
+            &mut deps,
+            DEFAULT_VERIFIER_ADDRESS,
+            PendingAssetStrategy::Refund {}.to_some(),
+        )
+        .expect("removing the verifier with a refund strategy should succeed");
+        assert_eq!(
+            "[pending_asset_count=1], [pending_asset_strategy=refund]",
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the pending asset handling should be emitted",
+        );
+        assert_eq!(
+            vec![(
+                DEFAULT_SENDER_ADDRESS.to_string(),
+                vec![coin(DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM)]
+            )],
+            get_bank_sends(&response),
+            "the escrowed fee should be returned to the requestor",
+        );
+        intercept_add_attribute(
+            &mut deps,
+            &Ok(response.clone()),
+            "the attribute should be rewritten",
+        );
+        let attribute = AssetMetaService::new(deps.as_mut())
+            .get_asset(DEFAULT_SCOPE_ADDRESS)
+            .expect("the asset should exist");
+        assert_eq!(
+            AssetOnboardingStatus::Denied,
+            attribute.onboarding_status,
+            "the refunded asset should be denied so that it can be onboarded again",
+        );
+        assert!(
+            latest_verifier_detail_store_ro(deps.as_ref().storage)
+                .may_load(DEFAULT_SCOPE_ADDRESS.as_bytes())
+                .unwrap()
+                .is_none(),
+            "the escrowed verifier detail should be removed",
+        );
+        assert_eq!(
+            0,
+            load_verifier_metrics(deps.as_ref().storage, DEFAULT_VERIFIER_ADDRESS)
+                .unwrap()
+                .pending_assets,
+            "the refunded asset should no longer be pending for the removed verifier",
+        );
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset {
+                onboard_asset: OnboardAssetV1 {
+                    verifier_address: SECOND_VERIFIER_ADDRESS.to_string(),
+                    ..TestOnboardAsset::default_onboard_asset()
+                },
+                ..TestOnboardAsset::default()
+            },
+        )
+        .expect("the refunded asset should be able to be onboarded with the remaining verifier");
+    }
+
+    #[test]
+    fn test_valid_remove_asset_verifier_refunds_pending_child_assets() {
+        let mut deps = mock_dependencies(&[]);
+        let mut child_input = get_default_asset_definition_input()
+            .with_parent_asset_type(DEFAULT_ASSET_TYPE.to_some());
+        child_input.asset_type = "child".to_string();
+        child_input.scope_spec_identifier =
+            ScopeSpecIdentifier::address("scopespec-child").to_serialized_enum();
+        child_input.verifiers = vec![];
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![get_two_verifier_asset_definition_input(), child_input],
+                ..Default::default()
+            },
+        );
+        let scope = get_duped_scope(
+            DEFAULT_SCOPE_ADDRESS,
+            "scopespec-child",
+            DEFAULT_SENDER_ADDRESS,
+        );
+        deps.querier.with_scope(scope.clone());
+        deps.querier.with_records(scope, get_default_records());
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset {
+                onboard_asset: OnboardAssetV1 {
+                    asset_type: "child".to_string(),
+                    ..TestOnboardAsset::default_onboard_asset()
+                },
+                ..TestOnboardAsset::default()
+            },
+        )
+        .expect("onboarding the child asset type should succeed");
+        let error = test_remove_verifier(&mut deps, DEFAULT_VERIFIER_ADDRESS, None).unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ContractError::VerifierHasPendingAssets {
+                    pending_asset_count: 1,
+                    ..
+                }
+            ),
+            "expected the child's pending asset to block the parent's verifier removal, but got: {:?}",
+            error,
+        );
+        // Requesting the removal through the child type removes the verifier from the parent that
+        // owns it
+        let response = remove_asset_verifier(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            RemoveAssetVerifierV1::new(
+                "child",
+                DEFAULT_VERIFIER_ADDRESS,
+                PendingAssetStrategy::Refund {}.to_some(),
+            ),
+        )
+        .expect("removing the inherited verifier with a refund strategy should succeed");
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            single_attribute_for_key(&response, ASSET_TYPE_KEY),
+            "the asset type that owns the verifier should be emitted",
+        );
+        assert_eq!(
+            "[pending_asset_count=1], [pending_asset_strategy=refund]",
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the child's pending asset should be handled",
+        );
+        assert_eq!(
+            vec![(
+                DEFAULT_SENDER_ADDRESS.to_string(),
+                vec![coin(DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM)]
+            )],
+            get_bank_sends(&response),
+            "the escrowed fee should be returned to the requestor",
+        );
+        assert_eq!(
+            1,
+            load_asset_definition_v2_by_type(deps.as_ref().storage, DEFAULT_ASSET_TYPE)
+                .unwrap()
+                .verifiers
+                .len(),
+            "the verifier should be removed from the parent asset definition",
+        );
+    }
+
+    #[test]
+    fn test_invalid_remove_asset_verifier_with_unknown_replacement() {
+        let mut deps = mock_dependencies(&[]);
+        setup_two_verifier_suite(&mut deps);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed");
+        let error = test_remove_verifier(
+            &mut deps,
+            DEFAULT_VERIFIER_ADDRESS,
+            PendingAssetStrategy::Reassign {
+                verifier_address: DEFAULT_FEE_ADDRESS.to_string(),
+            }
+            .to_some(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected a missing replacement verifier to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_remove_asset_verifier_with_disabled_replacement() {
+        let mut deps = mock_dependencies(&[]);
+        setup_two_verifier_suite(&mut deps);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed");
+        toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetVerifierV1::new(DEFAULT_ASSET_TYPE, SECOND_VERIFIER_ADDRESS, false),
+        )
+        .expect("disabling the replacement verifier should succeed");
+        let error = test_remove_verifier(
+            &mut deps,
+            DEFAULT_VERIFIER_ADDRESS,
+            PendingAssetStrategy::Reassign {
+                verifier_address: SECOND_VERIFIER_ADDRESS.to_string(),
+            }
+            .to_some(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::VerifierDisabled { .. }),
+            "expected a disabled replacement verifier to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_remove_asset_verifier_with_unbonded_replacement() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![AssetDefinitionInputV2 {
+                    minimum_verifier_bond: coin(1000, NHASH).to_some(),
+                    ..get_two_verifier_asset_definition_input()
+                }],
+                ..Default::default()
+            },
+        );
+        post_verifier_bond(
+            deps.as_mut(),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &[coin(1000, NHASH)]),
+            PostVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .expect("posting a bond should succeed");
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed");
+        let error = test_remove_verifier(
+            &mut deps,
+            DEFAULT_VERIFIER_ADDRESS,
+            PendingAssetStrategy::Reassign {
+                verifier_address: SECOND_VERIFIER_ADDRESS.to_string(),
+            }
+            .to_some(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InsufficientVerifierBond { .. }),
+            "expected a replacement verifier without the minimum bond to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_remove_asset_verifier_for_last_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = test_remove_verifier(&mut deps, DEFAULT_VERIFIER_ADDRESS, None).unwrap_err();
+        match error {
+            ContractError::InvalidMessageFields { invalid_fields, .. } => {
+                assert_eq!(
+                    vec!["verifier_address: at least one verifier must remain per asset type"],
+                    invalid_fields,
+                );
+            }
+            _ => panic!("unexpected error encountered: {:?}", error),
+        };
+    }
+
+    #[test]
+    fn test_invalid_remove_asset_verifier_for_unknown_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        setup_two_verifier_suite(&mut deps);
+        let error = test_remove_verifier(&mut deps, DEFAULT_FEE_ADDRESS, None).unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected a missing verifier to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_remove_asset_verifier_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        setup_two_verifier_suite(&mut deps);
+        let error = remove_asset_verifier(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            RemoveAssetVerifierV1::new(DEFAULT_ASSET_TYPE, SECOND_VERIFIER_ADDRESS, None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected a non-admin sender to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_remove_asset_verifier_for_enabled_timelock() {
+        let mut deps = mock_dependencies(&[]);
+        setup_two_verifier_suite(&mut deps);
+        test_enable_timelock(deps.as_mut(), 100);
+        let error = test_remove_verifier(&mut deps, SECOND_VERIFIER_ADDRESS, None).unwrap_err();
+        assert!(
+            matches!(error, ContractError::TimelockRequired { .. }),
+            "the timelock required error should be returned when the timelock is enabled, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_remove_asset_verifier_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        setup_two_verifier_suite(&mut deps);
+        let error = remove_asset_verifier(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            RemoveAssetVerifierV1::new(DEFAULT_ASSET_TYPE, SECOND_VERIFIER_ADDRESS, None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+
+    fn setup_two_verifier_suite(deps: &mut MockOwnedDeps) {
+        setup_test_suite(
+            deps,
+            InstArgs {
+                asset_definitions: vec![get_two_verifier_asset_definition_input()],
+                ..Default::default()
+            },
+        );
+    }
+
+    fn get_two_verifier_asset_definition_input() -> AssetDefinitionInputV2 {
+        AssetDefinitionInputV2 {
+            verifiers: vec![
+                get_default_verifier_detail(),
+                VerifierDetailV2::new(
+                    SECOND_VERIFIER_ADDRESS,
+                    Uint128::new(DEFAULT_ONBOARDING_COST),
+                    DEFAULT_ONBOARDING_DENOM,
+                    vec![FeeDestinationV2::new(
+                        DEFAULT_FEE_ADDRESS,
+                        Uint128::new(100),
+                    )],
+                    None,
+                ),
+            ],
+            ..get_default_asset_definition_input()
+        }
+    }
+
+    fn test_remove_verifier(
+        deps: &mut MockOwnedDeps,
+        verifier_address: &str,
+        pending_asset_strategy: Option<PendingAssetStrategy>,
+    ) -> EntryPointResponse {
+        remove_asset_verifier(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            RemoveAssetVerifierV1::new(
+                DEFAULT_ASSET_TYPE,
+                verifier_address,
+                pending_asset_strategy,
+            ),
+        )
+    }
+}
@@ -0,0 +1,204 @@
+use cosmwasm_std::{MessageInfo, Response};
+
+use crate::core::state::remove_role_member;
+use crate::core::types::contract_role::ContractRole;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_admin_only, check_funds_are_empty},
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        scope_address_utils::bech32_string_to_addr,
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::RevokeRole](crate::core::msg::ExecuteMsg::RevokeRole)
+/// for ease of use in the underlying [revoke_role](self::revoke_role) function.
+///
+/// # Parameters
+///
+/// * `role` The role to revoke.
+/// * `address` The bech32 address from which the role will be revoked.
+#[derive(Clone, PartialEq)]
+pub struct RevokeRoleV1 {
+    pub role: ContractRole,
+    pub address: String,
+}
+impl RevokeRoleV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `role` The role to revoke.
+    /// * `address` The bech32 address from which the role will be revoked.
+    pub fn new<S: Into<String>>(role: ContractRole, address: S) -> Self {
+        RevokeRoleV1 {
+            role,
+            address: address.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [RevokeRole](crate::core::msg::ExecuteMsg::RevokeRole)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<RevokeRoleV1> {
+        match msg {
+            ExecuteMsg::RevokeRole { role, address } => RevokeRoleV1::new(role, address).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::RevokeRole".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::RevokeRole](crate::core::msg::ExecuteMsg::RevokeRole)
+/// message is provided.  Revokes a [ContractRole](crate::core::types::contract_role::ContractRole)
+/// from an address.  The address loses access to every route guarded by the role, unless it is the contract's admin.  The request is rejected if the address does not hold the role.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the revoke role v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn revoke_role(deps: DepsMutC, info: MessageInfo, msg: RevokeRoleV1) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let address = bech32_string_to_addr(&msg.address)?;
+    remove_role_member(deps.storage, &msg.role, address.as_str())?;
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("address", address.as_str());
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::RevokeRole)
+                .set_new_value(msg.role)
+                .set_additional_metadata(&additional_metadata),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::{insert_role_member, load_role_members};
+    use crate::core::types::contract_role::ContractRole;
+    use crate::execute::update_protocol_fee::{update_protocol_fee, UpdateProtocolFeeV1};
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, single_attribute_for_key, test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+
+    use super::{revoke_role, RevokeRoleV1};
+
+    #[test]
+    fn test_valid_revoke_role_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        insert_role_member(
+            deps.as_mut().storage,
+            &ContractRole::TreasuryManager,
+            DEFAULT_FEE_ADDRESS,
+        )
+        .expect("granting the role should succeed");
+        update_protocol_fee(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            UpdateProtocolFeeV1::new(None),
+        )
+        .expect("a treasury manager should be able to update the protocol fee");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::RevokeRole {
+                role: ContractRole::TreasuryManager,
+                address: DEFAULT_FEE_ADDRESS.to_string(),
+            },
+        )
+        .expect("revoking the role should succeed");
+        assert_eq!(
+            EventType::RevokeRole.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            "treasury_manager",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the revoked role should be emitted",
+        );
+        assert!(
+            load_role_members(deps.as_ref().storage, &ContractRole::TreasuryManager)
+                .expect("role members should load")
+                .is_empty(),
+            "the address should no longer be a member of the role",
+        );
+        let error = update_protocol_fee(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            UpdateProtocolFeeV1::new(None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the unauthorized error to be returned after the role is revoked, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_revoke_role_for_non_member() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = revoke_role(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            RevokeRoleV1::new(ContractRole::Pauser, DEFAULT_FEE_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected the not found error to be returned when the address does not hold the role, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_revoke_role_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        insert_role_member(
+            deps.as_mut().storage,
+            &ContractRole::Pauser,
+            DEFAULT_FEE_ADDRESS,
+        )
+        .expect("granting the role should succeed");
+        let error = revoke_role(
+            deps.as_mut(),
+            mock_info(DEFAULT_FEE_ADDRESS, &[]),
+            RevokeRoleV1::new(ContractRole::Pauser, DEFAULT_FEE_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected only the admin to be able to revoke roles, but got: {:?}",
+            error,
+        );
+    }
+}
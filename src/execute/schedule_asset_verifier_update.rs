@@ -0,0 +1,351 @@
+use cosmwasm_std::{Env, MessageInfo, Response};
+
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{
+    config_read_v2, insert_scheduled_verifier_update, load_asset_definition_v2_by_type,
+};
+use crate::core::types::contract_role::ContractRole;
+use crate::core::types::scheduled_verifier_update::{EffectiveAt, ScheduledVerifierUpdate};
+use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::execute::register_verifier::register_verifier_detail;
+use crate::util::aliases::{AssetResult, DepsMutC, EntryPointResponse};
+use crate::util::contract_helpers::{check_funds_are_empty, check_role};
+use crate::util::event_attributes::{EventAttributes, EventType};
+use crate::util::traits::ResultExtensions;
+
+/// A transformation of [ExecuteMsg::ScheduleAssetVerifierUpdate](crate::core::msg::ExecuteMsg::ScheduleAssetVerifierUpdate)
+/// for ease of use in the underlying [schedule_asset_verifier_update](self::schedule_asset_verifier_update) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
+/// that contains the verifier to update.
+/// * `verifier` The verifier detail that will replace the existing verifier with a matching address.
+/// * `effective_at` The block height or time at which the update takes effect.
+#[derive(Clone, PartialEq)]
+pub struct ScheduleAssetVerifierUpdateV1 {
+    pub asset_type: String,
+    pub verifier: VerifierDetailV2,
+    pub effective_at: EffectiveAt,
+}
+impl ScheduleAssetVerifierUpdateV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type of the asset definition that contains the verifier to update.
+    /// * `verifier` The verifier detail that will replace the existing verifier with a matching address.
+    /// * `effective_at` The block height or time at which the update takes effect.
+    pub fn new<S: Into<String>>(
+        asset_type: S,
+        verifier: VerifierDetailV2,
+        effective_at: EffectiveAt,
+    ) -> Self {
+        ScheduleAssetVerifierUpdateV1 {
+            asset_type: asset_type.into(),
+            verifier,
+            effective_at,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [ScheduleAssetVerifierUpdate](crate::core::msg::ExecuteMsg::ScheduleAssetVerifierUpdate)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<ScheduleAssetVerifierUpdateV1> {
+        match msg {
+            ExecuteMsg::ScheduleAssetVerifierUpdate {
+                asset_type,
+                verifier,
+                effective_at,
+            } => ScheduleAssetVerifierUpdateV1::new(asset_type, verifier, effective_at).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::ScheduleAssetVerifierUpdate".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::ScheduleAssetVerifierUpdate](crate::core::msg::ExecuteMsg::ScheduleAssetVerifierUpdate)
+/// message is provided.  Stores a [ScheduledVerifierUpdate](crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate)
+/// that replaces an existing verifier once its effective block height or time is reached.  The
+/// update is applied during the first onboarding for the asset type that occurs after that point.
+/// While the contract's timelock is enabled, the update must be scheduled for a block height at
+/// least as far away as the timelock delay.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the schedule asset verifier update v1 struct, provided by conversion
+/// from an [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn schedule_asset_verifier_update(
+    deps: DepsMutC,
+    env: Env,
+    info: MessageInfo,
+    mut msg: ScheduleAssetVerifierUpdateV1,
+) -> EntryPointResponse {
+    check_role(&deps.as_ref(), &info, ContractRole::VerifierManager)?;
+    check_funds_are_empty(&info)?;
+    let asset_definition = load_asset_definition_v2_by_type(deps.storage, &msg.asset_type)?;
+    if !asset_definition
+        .verifiers
+        .iter()
+        .any(|verifier| verifier.address == msg.verifier.address)
+    {
+        return ContractError::NotFound {
+            explanation: format!(
+                "verifier with address {} not found for asset definition for type {}. Trying adding this verifier instead",
+                msg.verifier.address, asset_definition.asset_type
+            ),
+        }
+        .to_err();
+    }
+    // Updates that would already be effective should use the immediate update route instead, so
+    // that requestors are never surprised by a change that had no notice period
+    if msg.effective_at.has_passed(&env.block) {
+        return ContractError::InvalidMessageFields {
+            message_type: "ExecuteMsg::ScheduleAssetVerifierUpdate".to_string(),
+            invalid_fields: vec![format!(
+                "effective_at: [{}] must be after the current block",
+                msg.effective_at
+            )],
+        }
+        .to_err();
+    }
+    // While the timelock is enabled, a scheduled update must give at least as much notice as a
+    // queued change would, or it could be used to bypass the timelock
+    if let Some(delay_blocks) = config_read_v2(deps.storage).load()?.timelock_delay {
+        let earliest_height = env.block.height.saturating_add(delay_blocks);
+        match msg.effective_at {
+            EffectiveAt::BlockHeight(height) if height >= earliest_height => {}
+            _ => {
+                return ContractError::InvalidMessageFields {
+                    message_type: "ExecuteMsg::ScheduleAssetVerifierUpdate".to_string(),
+                    invalid_fields: vec![format!(
+                        "effective_at: must be a block height of at least [{}] while the timelock is enabled",
+                        earliest_height
+                    )],
+                }
+                .to_err();
+            }
+        }
+    }
+    register_verifier_detail(deps.storage, &mut msg.verifier)?;
+    let attributes = EventAttributes::new(EventType::ScheduleAssetVerifierUpdate)
+        .set_asset_type(&asset_definition.asset_type)
+        .set_verifier(&msg.verifier.address)
+        .set_new_value(&msg.effective_at);
+    insert_scheduled_verifier_update(
+        deps.storage,
+        &ScheduledVerifierUpdate::new(msg.asset_type, msg.verifier, msg.effective_at),
+    )?;
+    Response::new().add_attributes(attributes).to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Uint128;
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::load_scheduled_verifier_updates;
+    use crate::core::types::scheduled_verifier_update::{EffectiveAt, ScheduledVerifierUpdate};
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_ONBOARDING_DENOM,
+        DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_verifier_detail, single_attribute_for_key,
+        test_enable_timelock, test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY, VERIFIER_ADDRESS_KEY};
+    use crate::util::event_attributes::EventType;
+
+    use super::{schedule_asset_verifier_update, ScheduleAssetVerifierUpdateV1};
+
+    #[test]
+    fn test_valid_schedule_asset_verifier_update_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let env = mock_env();
+        let verifier = get_updated_verifier();
+        let effective_at = EffectiveAt::BlockHeight(env.block.height + 100);
+        let response = execute(
+            deps.as_mut(),
+            env,
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::ScheduleAssetVerifierUpdate {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                verifier: verifier.clone(),
+                effective_at: effective_at.clone(),
+            },
+        )
+        .expect("scheduling the update should succeed");
+        assert_eq!(
+            EventType::ScheduleAssetVerifierUpdate.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_VERIFIER_ADDRESS,
+            single_attribute_for_key(&response, VERIFIER_ADDRESS_KEY),
+            "the verifier address should be emitted",
+        );
+        assert_eq!(
+            effective_at.to_string(),
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the effective point should be emitted",
+        );
+        assert_eq!(
+            vec![ScheduledVerifierUpdate::new(
+                DEFAULT_ASSET_TYPE,
+                verifier.with_overrides_registered_fees(true),
+                effective_at
+            )],
+            load_scheduled_verifier_updates(deps.as_ref().storage, DEFAULT_ASSET_TYPE).unwrap(),
+            "the update should be stored",
+        );
+    }
+
+    #[test]
+    fn test_invalid_schedule_asset_verifier_update_for_effective_point_in_past() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let env = mock_env();
+        let effective_at = EffectiveAt::Time(env.block.time);
+        let error = schedule_asset_verifier_update(
+            deps.as_mut(),
+            env,
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ScheduleAssetVerifierUpdateV1::new(
+                DEFAULT_ASSET_TYPE,
+                get_updated_verifier(),
+                effective_at,
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMessageFields { .. }),
+            "expected an update that is already effective to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_schedule_asset_verifier_update_for_unknown_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let env = mock_env();
+        let height = env.block.height + 1;
+        let error = schedule_asset_verifier_update(
+            deps.as_mut(),
+            env,
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ScheduleAssetVerifierUpdateV1::new(
+                DEFAULT_ASSET_TYPE,
+                VerifierDetailV2::new(
+                    DEFAULT_SENDER_ADDRESS,
+                    Uint128::new(1),
+                    DEFAULT_ONBOARDING_DENOM,
+                    vec![],
+                    None,
+                ),
+                EffectiveAt::BlockHeight(height),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected a missing verifier to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_schedule_asset_verifier_update_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let env = mock_env();
+        let height = env.block.height + 1;
+        let error = schedule_asset_verifier_update(
+            deps.as_mut(),
+            env,
+            mock_info("not-the-admin", &[]),
+            ScheduleAssetVerifierUpdateV1::new(
+                DEFAULT_ASSET_TYPE,
+                get_updated_verifier(),
+                EffectiveAt::BlockHeight(height),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_schedule_asset_verifier_update_with_timelock_enabled() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        let env = mock_env();
+        for effective_at in [
+            EffectiveAt::BlockHeight(env.block.height + 99),
+            EffectiveAt::Time(env.block.time.plus_seconds(86400)),
+        ] {
+            let error = schedule_asset_verifier_update(
+                deps.as_mut(),
+                env.clone(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                ScheduleAssetVerifierUpdateV1::new(
+                    DEFAULT_ASSET_TYPE,
+                    get_updated_verifier(),
+                    effective_at.clone(),
+                ),
+            )
+            .unwrap_err();
+            assert!(
+                matches!(error, ContractError::InvalidMessageFields { .. }),
+                "expected an update effective at [{}] to be rejected while the timelock is enabled, but got: {:?}",
+                effective_at,
+                error,
+            );
+        }
+        schedule_asset_verifier_update(
+            deps.as_mut(),
+            env.clone(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ScheduleAssetVerifierUpdateV1::new(
+                DEFAULT_ASSET_TYPE,
+                get_updated_verifier(),
+                EffectiveAt::BlockHeight(env.block.height + 100),
+            ),
+        )
+        .expect("an update effective after the timelock delay should be accepted");
+    }
+
+    fn get_updated_verifier() -> VerifierDetailV2 {
+        VerifierDetailV2 {
+            onboarding_cost: Uint128::new(2000),
+            ..get_default_verifier_detail()
+        }
+    }
+}
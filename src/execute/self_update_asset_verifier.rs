@@ -0,0 +1,591 @@
+use cosmwasm_std::{Env, MessageInfo, Response};
+
+use crate::core::state::{
+    config_read_v2, load_verifier_owning_asset_definition_v2, load_verifier_self_update_bounds,
+    replace_asset_definition_v2,
+};
+use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::core::types::verifier_self_update_bounds::VerifierDetailField;
+use crate::execute::register_verifier::register_verifier_detail;
+use crate::util::functions::replace_single_matching_vec_element;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::check_funds_are_empty,
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::SelfUpdateAssetVerifier](crate::core::msg::ExecuteMsg::SelfUpdateAssetVerifier)
+/// for ease of use in the underlying [self_update_asset_verifier](self::self_update_asset_verifier) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the definition containing the verifier.
+/// * `verifier` The verifier detail that will replace the sender's existing verifier detail.
+#[derive(Clone, PartialEq)]
+pub struct SelfUpdateAssetVerifierV1 {
+    pub asset_type: String,
+    pub verifier: VerifierDetailV2,
+}
+impl SelfUpdateAssetVerifierV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type of the definition containing the verifier.
+    /// * `verifier` The verifier detail that will replace the sender's existing verifier detail.
+    pub fn new<S: Into<String>>(asset_type: S, verifier: VerifierDetailV2) -> Self {
+        SelfUpdateAssetVerifierV1 {
+            asset_type: asset_type.into(),
+            verifier,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [SelfUpdateAssetVerifier](crate::core::msg::ExecuteMsg::SelfUpdateAssetVerifier)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<SelfUpdateAssetVerifierV1> {
+        match msg {
+            ExecuteMsg::SelfUpdateAssetVerifier {
+                asset_type,
+                verifier,
+            } => SelfUpdateAssetVerifierV1::new(asset_type, verifier).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::SelfUpdateAssetVerifier".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::SelfUpdateAssetVerifier](crate::core::msg::ExecuteMsg::SelfUpdateAssetVerifier)
+/// message is provided.  Allows a verifier to replace its own [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
+/// on an existing [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2),
+/// within the [VerifierSelfUpdateBounds](crate::core::types::verifier_self_update_bounds::VerifierSelfUpdateBounds)
+/// set by the admin.  Changes to locked fields, or an onboarding cost above the admin's cap, are
+/// rejected.  While the contract's timelock is enabled, the verifier cannot raise its onboarding
+/// cost at all, so that requestors are never surprised by a higher price.  If the asset type
+/// inherits its verifiers from a parent asset type, the update is applied to the ancestor that owns
+/// them, within the bounds set for that ancestor.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the self update asset verifier v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn self_update_asset_verifier(
+    deps: DepsMutC,
+    env: Env,
+    info: MessageInfo,
+    mut msg: SelfUpdateAssetVerifierV1,
+) -> EntryPointResponse {
+    check_funds_are_empty(&info)?;
+    if info.sender != msg.verifier.address {
+        return ContractError::Unauthorized {
+            explanation: "only the verifier can update its own verifier detail".to_string(),
+        }
+        .to_err();
+    }
+    let mut asset_definition =
+        load_verifier_owning_asset_definition_v2(deps.storage, &msg.asset_type)?;
+    let current_verifier = match asset_definition
+        .verifiers
+        .iter()
+        .find(|verifier| verifier.address == msg.verifier.address)
+    {
+        Some(verifier) => verifier.clone(),
+        None => {
+            return ContractError::NotFound {
+                explanation: format!(
+                    "verifier with address {} not found for asset definition for type {}",
+                    msg.verifier.address, asset_definition.asset_type
+                ),
+            }
+            .to_err();
+        }
+    };
+    let bounds = load_verifier_self_update_bounds(
+        deps.storage,
+        &asset_definition.asset_type,
+        &msg.verifier.address,
+    )?;
+    let changed_fields = VerifierDetailField::changed_fields(&current_verifier, &msg.verifier);
+    let mut invalid_fields = changed_fields
+        .iter()
+        .filter(|field| !bounds.allows_change_to(field))
+        .map(|field| format!("verifier.{}: cannot be changed by the verifier", field))
+        .collect::<Vec<String>>();
+    if bounds.allows_change_to(&VerifierDetailField::OnboardingCost)
+        && msg.verifier.onboarding_cost > current_verifier.onboarding_cost
+    {
+        match bounds.max_onboarding_cost {
+            Some(max_cost) if msg.verifier.onboarding_cost <= max_cost => {}
+            Some(max_cost) => invalid_fields.push(format!(
+                "verifier.onboarding_cost: must not exceed [{}]",
+                max_cost
+            )),
+            None => invalid_fields
+                .push("verifier.onboarding_cost: cannot be increased by the verifier".to_string()),
+        }
+        if config_read_v2(deps.storage)
+            .load()?
+            .timelock_delay
+            .is_some()
+        {
+            invalid_fields.push(
+                "verifier.onboarding_cost: cannot be increased by the verifier while the timelock is enabled"
+                    .to_string(),
+            );
+        }
+    }
+    if !invalid_fields.is_empty() {
+        return ContractError::InvalidMessageFields {
+            message_type: "ExecuteMsg::SelfUpdateAssetVerifier".to_string(),
+            invalid_fields,
+        }
+        .to_err();
+    }
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata(
+        "changed_fields",
+        changed_fields
+            .iter()
+            .map(|field| field.name())
+            .collect::<Vec<&str>>()
+            .join(","),
+    );
+    let attributes = EventAttributes::new(EventType::SelfUpdateAssetVerifier)
+        .set_asset_type(&asset_definition.asset_type)
+        .set_verifier(&msg.verifier.address)
+        .set_additional_metadata(&additional_metadata);
+    register_verifier_detail(deps.storage, &mut msg.verifier)?;
+    let verifier_address = msg.verifier.address.clone();
+    asset_definition.verifiers =
+        replace_single_matching_vec_element(asset_definition.verifiers, msg.verifier, |v| {
+            v.address == verifier_address
+        })?;
+    replace_asset_definition_v2(
+        deps.storage,
+        &asset_definition,
+        env.block.height,
+        info.sender.as_str(),
+    )?;
+    Response::new().add_attributes(attributes).to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Uint128;
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::{
+        insert_verifier_self_update_bounds, load_asset_definition_v2_by_type,
+    };
+    use crate::core::types::entity_detail::EntityDetail;
+    use crate::core::types::fee_destination::FeeDestinationV2;
+    use crate::core::types::scope_spec_identifier::ScopeSpecIdentifier;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::core::types::verifier_self_update_bounds::{
+        VerifierDetailField, VerifierSelfUpdateBounds,
+    };
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_FEE_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition_input, get_default_verifier_detail,
+        mock_info_with_nhash, single_attribute_for_key, test_enable_timelock,
+        test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::{DepsC, DepsMutC};
+    use crate::util::constants::{
+        ADDITIONAL_METADATA_KEY, ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, VERIFIER_ADDRESS_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{self_update_asset_verifier, SelfUpdateAssetVerifierV1};
+
+    #[test]
+    fn test_valid_self_update_asset_verifier_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let mut verifier = get_default_verifier_detail();
+        verifier.fee_destinations = vec![FeeDestinationV2::new(
+            DEFAULT_FEE_ADDRESS,
+            Uint128::new(500),
+        )];
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            ExecuteMsg::SelfUpdateAssetVerifier {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                verifier: verifier.clone(),
+            },
+        )
+        .expect("the verifier should be able to update its own details");
+        assert_eq!(
+            EventType::SelfUpdateAssetVerifier.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            single_attribute_for_key(&response, ASSET_TYPE_KEY),
+            "the asset type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_VERIFIER_ADDRESS,
+            single_attribute_for_key(&response, VERIFIER_ADDRESS_KEY),
+            "the verifier address should be emitted",
+        );
+        assert_eq!(
+            "[changed_fields=fee_destinations]",
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the changed fields should be emitted",
+        );
+        // The new fees differ from the registered fees, so they only apply to this asset type
+        assert_stored_verifier(
+            &deps.as_ref(),
+            &verifier.with_overrides_registered_fees(true),
+        );
+    }
+
+    #[test]
+    fn test_valid_self_update_asset_verifier_cost_within_cap() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        set_bounds(
+            deps.as_mut(),
+            VerifierSelfUpdateBounds::new(Uint128::new(2000).to_some(), vec![]),
+        );
+        let mut verifier = get_default_verifier_detail();
+        verifier.onboarding_cost = Uint128::new(2000);
+        self_update_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            SelfUpdateAssetVerifierV1::new(DEFAULT_ASSET_TYPE, verifier.clone()),
+        )
+        .expect("raising the onboarding cost to the cap should succeed");
+        assert_stored_verifier(
+            &deps.as_ref(),
+            &verifier.with_overrides_registered_fees(true),
+        );
+    }
+
+    #[test]
+    fn test_valid_self_update_asset_verifier_cost_decrease_without_bounds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        let mut verifier = get_default_verifier_detail();
+        verifier.onboarding_cost = Uint128::new(500);
+        self_update_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            SelfUpdateAssetVerifierV1::new(DEFAULT_ASSET_TYPE, verifier.clone()),
+        )
+        .expect("lowering the onboarding cost should always be allowed");
+        assert_stored_verifier(
+            &deps.as_ref(),
+            &verifier.with_overrides_registered_fees(true),
+        );
+    }
+
+    #[test]
+    fn test_valid_self_update_asset_verifier_for_inherited_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        let mut child_input = get_default_asset_definition_input()
+            .with_parent_asset_type(DEFAULT_ASSET_TYPE.to_some());
+        child_input.asset_type = "child".to_string();
+        child_input.scope_spec_identifier =
+            ScopeSpecIdentifier::address("scopespec-child").to_serialized_enum();
+        child_input.verifiers = vec![];
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![get_default_asset_definition_input(), child_input],
+                ..InstArgs::default()
+            },
+        );
+        let mut verifier = get_default_verifier_detail();
+        verifier.onboarding_cost = Uint128::new(500);
+        let response = self_update_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            SelfUpdateAssetVerifierV1::new("child", verifier.clone()),
+        )
+        .expect("a verifier inherited by the child should be able to update itself");
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            single_attribute_for_key(&response, ASSET_TYPE_KEY),
+            "the asset type that owns the verifier should be emitted",
+        );
+        assert_stored_verifier(
+            &deps.as_ref(),
+            &verifier.with_overrides_registered_fees(true),
+        );
+    }
+
+    #[test]
+    fn test_invalid_self_update_asset_verifier_for_cost_increase() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let mut verifier = get_default_verifier_detail();
+        verifier.onboarding_cost = Uint128::new(1001);
+        assert_invalid_fields(
+            self_update_asset_verifier(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+                SelfUpdateAssetVerifierV1::new(DEFAULT_ASSET_TYPE, verifier.clone()),
+            )
+            .unwrap_err(),
+            vec!["verifier.onboarding_cost: cannot be increased by the verifier"],
+        );
+        set_bounds(
+            deps.as_mut(),
+            VerifierSelfUpdateBounds::new(Uint128::new(1000).to_some(), vec![]),
+        );
+        assert_invalid_fields(
+            self_update_asset_verifier(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+                SelfUpdateAssetVerifierV1::new(DEFAULT_ASSET_TYPE, verifier),
+            )
+            .unwrap_err(),
+            vec!["verifier.onboarding_cost: must not exceed [1000]"],
+        );
+        assert_stored_verifier(&deps.as_ref(), &get_default_verifier_detail());
+    }
+
+    #[test]
+    fn test_invalid_self_update_asset_verifier_for_cost_increase_with_timelock() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        set_bounds(
+            deps.as_mut(),
+            VerifierSelfUpdateBounds::new(Uint128::new(2000).to_some(), vec![]),
+        );
+        let mut verifier = get_default_verifier_detail();
+        verifier.onboarding_cost = Uint128::new(1500);
+        assert_invalid_fields(
+            self_update_asset_verifier(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+                SelfUpdateAssetVerifierV1::new(DEFAULT_ASSET_TYPE, verifier),
+            )
+            .unwrap_err(),
+            vec!["verifier.onboarding_cost: cannot be increased by the verifier while the timelock is enabled"],
+        );
+    }
+
+    #[test]
+    fn test_invalid_self_update_asset_verifier_for_locked_fields() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        set_bounds(
+            deps.as_mut(),
+            VerifierSelfUpdateBounds::new(None, vec![VerifierDetailField::EntityDetail]),
+        );
+        let mut verifier = get_default_verifier_detail();
+        verifier.entity_detail = None;
+        verifier.onboarding_denom = "otherdenom".to_string();
+        assert_invalid_fields(
+            self_update_asset_verifier(
+                deps.as_mut(),
+                mock_env(),
+                empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+                SelfUpdateAssetVerifierV1::new(DEFAULT_ASSET_TYPE, verifier),
+            )
+            .unwrap_err(),
+            vec![
+                "verifier.onboarding_denom: cannot be changed by the verifier",
+                "verifier.entity_detail: cannot be changed by the verifier",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_invalid_self_update_asset_verifier_for_registered_entity_detail_change() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let mut verifier = get_default_verifier_detail();
+        verifier.entity_detail = EntityDetail::new(
+            "Updated Verifier",
+            "A new description",
+            "https://home.url",
+            "https://source.url",
+        )
+        .to_some();
+        let error = self_update_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            SelfUpdateAssetVerifierV1::new(DEFAULT_ASSET_TYPE, verifier),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::VerifierRegistrationConflict { .. }),
+            "expected the registered entity detail to only be changeable through the registration, but got: {:?}",
+            error,
+        );
+        assert_stored_verifier(&deps.as_ref(), &get_default_verifier_detail());
+    }
+
+    #[test]
+    fn test_self_update_asset_verifier_does_not_alter_other_asset_types() {
+        let mut deps = mock_dependencies(&[]);
+        let mut other_input = get_default_asset_definition_input();
+        other_input.asset_type = "other_type".to_string();
+        other_input.scope_spec_identifier =
+            ScopeSpecIdentifier::address("scopespec-other").to_serialized_enum();
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![get_default_asset_definition_input(), other_input],
+                ..InstArgs::default()
+            },
+        );
+        let mut verifier = get_default_verifier_detail();
+        verifier.onboarding_cost = Uint128::new(500);
+        self_update_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            SelfUpdateAssetVerifierV1::new(DEFAULT_ASSET_TYPE, verifier.clone()),
+        )
+        .expect("lowering the onboarding cost should succeed");
+        assert_stored_verifier(
+            &deps.as_ref(),
+            &verifier.with_overrides_registered_fees(true),
+        );
+        assert_eq!(
+            vec![get_default_verifier_detail()],
+            load_asset_definition_v2_by_type(deps.as_ref().storage, "other_type")
+                .expect("the other asset definition should load")
+                .verifiers,
+            "a self update for one asset type should not alter the verifier on another asset type",
+        );
+    }
+
+    #[test]
+    fn test_invalid_self_update_asset_verifier_for_other_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        for sender in [DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS] {
+            let error = self_update_asset_verifier(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(sender, &[]),
+                SelfUpdateAssetVerifierV1::new(DEFAULT_ASSET_TYPE, get_default_verifier_detail()),
+            )
+            .unwrap_err();
+            assert!(
+                matches!(error, ContractError::Unauthorized { .. }),
+                "expected [{}] to be unable to use the self update route for another verifier, but got: {:?}",
+                sender,
+                error,
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_self_update_asset_verifier_for_unknown_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = self_update_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            SelfUpdateAssetVerifierV1::new(
+                DEFAULT_ASSET_TYPE,
+                VerifierDetailV2::new(DEFAULT_FEE_ADDRESS, Uint128::zero(), "nhash", vec![], None),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected a verifier missing from the asset definition to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_self_update_asset_verifier_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = self_update_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_VERIFIER_ADDRESS, 150),
+            SelfUpdateAssetVerifierV1::new(DEFAULT_ASSET_TYPE, get_default_verifier_detail()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+
+    fn set_bounds(deps: DepsMutC, bounds: VerifierSelfUpdateBounds) {
+        insert_verifier_self_update_bounds(
+            deps.storage,
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            &bounds,
+        )
+        .expect("the bounds should be stored");
+    }
+
+    fn assert_invalid_fields(error: ContractError, expected_fields: Vec<&str>) {
+        match error {
+            ContractError::InvalidMessageFields { invalid_fields, .. } => {
+                assert_eq!(
+                    expected_fields, invalid_fields,
+                    "the expected invalid fields should be returned",
+                );
+            }
+            _ => panic!("expected invalid message fields, but got: {:?}", error),
+        }
+    }
+
+    fn assert_stored_verifier(deps: &DepsC, expected_verifier: &VerifierDetailV2) {
+        let asset_definition = load_asset_definition_v2_by_type(deps.storage, DEFAULT_ASSET_TYPE)
+            .expect("the default asset definition should load");
+        assert_eq!(
+            expected_verifier,
+            asset_definition
+                .verifiers
+                .iter()
+                .find(|verifier| verifier.address == expected_verifier.address)
+                .expect("the verifier should exist in the asset definition"),
+            "the stored verifier should match the expected value",
+        );
+    }
+}
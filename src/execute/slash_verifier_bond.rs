@@ -0,0 +1,449 @@
+use cosmwasm_std::{Coin, MessageInfo, Response};
+
+use crate::core::state::{config_read_v2, deduct_verifier_bond, deposit_to_treasury};
+use crate::core::types::contract_role::ContractRole;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_funds_are_empty, check_role},
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        functions::bank_send,
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::SlashVerifierBond](crate::core::msg::ExecuteMsg::SlashVerifierBond)
+/// for ease of use in the underlying [slash_verifier_bond](self::slash_verifier_bond) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type for which the bond was posted.
+/// * `verifier_address` The bech32 address of the verifier whose bond is slashed.
+/// * `amount` The funds to remove from the bond.
+#[derive(Clone, PartialEq)]
+pub struct SlashVerifierBondV1 {
+    pub asset_type: String,
+    pub verifier_address: String,
+    pub amount: Coin,
+}
+impl SlashVerifierBondV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type for which the bond was posted.
+    /// * `verifier_address` The bech32 address of the verifier whose bond is slashed.
+    /// * `amount` The funds to remove from the bond.
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        asset_type: S1,
+        verifier_address: S2,
+        amount: Coin,
+    ) -> Self {
+        SlashVerifierBondV1 {
+            asset_type: asset_type.into(),
+            verifier_address: verifier_address.into(),
+            amount,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [SlashVerifierBond](crate::core::msg::ExecuteMsg::SlashVerifierBond)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<SlashVerifierBondV1> {
+        match msg {
+            ExecuteMsg::SlashVerifierBond {
+                asset_type,
+                verifier_address,
+                amount,
+            } => SlashVerifierBondV1::new(asset_type, verifier_address, amount).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::SlashVerifierBond".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::SlashVerifierBond](crate::core::msg::ExecuteMsg::SlashVerifierBond)
+/// message is provided.  Removes funds from a verifier's collateral bond for an asset type and
+/// sends them to the [slash_beneficiary](crate::core::types::verifier_bond_settings::VerifierBondSettings::slash_beneficiary)
+/// configured by the admin.  When no beneficiary is configured, the funds are retained in the
+/// contract treasury instead.  Bonds can be slashed even after the verifier has been removed
+/// from the asset definition, as long as the verifier has not yet withdrawn them.  Accessible to
+/// the admin and to holders of the [VerifierManager](crate::core::types::contract_role::ContractRole::VerifierManager)
+/// role.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the slash verifier bond v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn slash_verifier_bond(
+    deps: DepsMutC,
+    info: MessageInfo,
+    msg: SlashVerifierBondV1,
+) -> EntryPointResponse {
+    check_role(&deps.as_ref(), &info, ContractRole::VerifierManager)?;
+    check_funds_are_empty(&info)?;
+    let remaining_bond = deduct_verifier_bond(
+        deps.storage,
+        &msg.asset_type,
+        &msg.verifier_address,
+        &msg.amount,
+    )?;
+    let slash_beneficiary = config_read_v2(deps.storage)
+        .load()?
+        .verifier_bond_settings
+        .slash_beneficiary;
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("slashed_amount", msg.amount.to_string());
+    additional_metadata.add_metadata(
+        "beneficiary",
+        slash_beneficiary.as_deref().unwrap_or("treasury"),
+    );
+    let mut response = Response::new().add_attributes(
+        EventAttributes::new(EventType::SlashVerifierBond)
+            .set_asset_type(&msg.asset_type)
+            .set_verifier(&msg.verifier_address)
+            .set_new_value(&remaining_bond)
+            .set_additional_metadata(&additional_metadata),
+    );
+    match slash_beneficiary {
+        Some(beneficiary) => {
+            response = response.add_message(bank_send(
+                &beneficiary,
+                msg.amount.amount.u128(),
+                &msg.amount.denom,
+            ));
+        }
+        None => deposit_to_treasury(deps.storage, &msg.amount)?,
+    }
+    response.to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, coins, BankMsg, CosmosMsg, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::{may_load_verifier_bond, treasury_balances_store_ro};
+    use crate::core::types::contract_role::ContractRole;
+    use crate::core::types::verifier_bond_settings::VerifierBondSettings;
+    use crate::execute::grant_role::{grant_role, GrantRoleV1};
+    use crate::execute::update_verifier_bond_settings::{
+        update_verifier_bond_settings, UpdateVerifierBondSettingsV1,
+    };
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, mock_info_with_nhash, setup_bonded_verifier, single_attribute_for_key,
+        test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::{
+        ADDITIONAL_METADATA_KEY, ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NEW_VALUE_KEY, NHASH,
+        VERIFIER_ADDRESS_KEY,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{slash_verifier_bond, SlashVerifierBondV1};
+
+    #[test]
+    fn test_valid_slash_verifier_bond_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        setup_bonded_verifier(&mut deps, 1000);
+        update_verifier_bond_settings(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateVerifierBondSettingsV1::new(VerifierBondSettings::new(
+                DEFAULT_SENDER_ADDRESS.to_string().to_some(),
+            )),
+        )
+        .expect("configuring the beneficiary should succeed");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::SlashVerifierBond {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
+                amount: coin(400, NHASH),
+            },
+        )
+        .expect("slashing the bond should succeed");
+        assert_eq!(
+            EventType::SlashVerifierBond.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            single_attribute_for_key(&response, ASSET_TYPE_KEY),
+            "the asset type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_VERIFIER_ADDRESS,
+            single_attribute_for_key(&response, VERIFIER_ADDRESS_KEY),
+            "the verifier address should be emitted",
+        );
+        assert_eq!(
+            "600nhash",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the remaining bond should be emitted",
+        );
+        assert_eq!(
+            format!(
+                "[beneficiary={}], [slashed_amount=400nhash]",
+                DEFAULT_SENDER_ADDRESS
+            ),
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the slashed amount and beneficiary should be emitted",
+        );
+        assert_eq!(
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: DEFAULT_SENDER_ADDRESS.to_string(),
+                amount: coins(400, NHASH),
+            })],
+            response
+                .messages
+                .into_iter()
+                .map(|msg| msg.msg)
+                .collect::<Vec<_>>(),
+            "the slashed funds should be sent to the configured beneficiary",
+        );
+        assert_eq!(
+            Some(coin(600, NHASH)),
+            may_load_verifier_bond(
+                deps.as_ref().storage,
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS
+            )
+            .unwrap(),
+            "the bond should be reduced by the slashed amount",
+        );
+    }
+
+    #[test]
+    fn test_valid_slash_verifier_bond_without_beneficiary_retains_funds_in_treasury() {
+        let mut deps = mock_dependencies(&[]);
+        setup_bonded_verifier(&mut deps, 1000);
+        let response = slash_verifier_bond(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            SlashVerifierBondV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                coin(400, NHASH),
+            ),
+        )
+        .expect("slashing the bond should succeed");
+        assert!(
+            response.messages.is_empty(),
+            "no funds should be sent when no beneficiary is configured",
+        );
+        assert_eq!(
+            "[beneficiary=treasury], [slashed_amount=400nhash]",
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the treasury should be emitted as the beneficiary",
+        );
+        assert_eq!(
+            Some(Uint128::new(400)),
+            treasury_balances_store_ro(deps.as_ref().storage)
+                .may_load(NHASH.as_bytes())
+                .unwrap(),
+            "the slashed funds should be added to the treasury balance",
+        );
+    }
+
+    #[test]
+    fn test_valid_slash_verifier_bond_for_mixed_case_asset_type() {
+        let mut deps = mock_dependencies(&[]);
+        setup_bonded_verifier(&mut deps, 1000);
+        slash_verifier_bond(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            SlashVerifierBondV1::new(
+                DEFAULT_ASSET_TYPE.to_uppercase(),
+                DEFAULT_VERIFIER_ADDRESS,
+                coin(400, NHASH),
+            ),
+        )
+        .expect("slashing the bond with a different casing of the asset type should succeed");
+        for asset_type in [
+            DEFAULT_ASSET_TYPE.to_string(),
+            DEFAULT_ASSET_TYPE.to_uppercase(),
+        ] {
+            assert_eq!(
+                Some(coin(600, NHASH)),
+                may_load_verifier_bond(
+                    deps.as_ref().storage,
+                    &asset_type,
+                    DEFAULT_VERIFIER_ADDRESS
+                )
+                .unwrap(),
+                "the bond should be found regardless of the casing of the asset type [{}]",
+                asset_type,
+            );
+        }
+    }
+
+    #[test]
+    fn test_valid_slash_verifier_bond_removes_empty_bond() {
+        let mut deps = mock_dependencies(&[]);
+        setup_bonded_verifier(&mut deps, 1000);
+        slash_verifier_bond(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            SlashVerifierBondV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                coin(1000, NHASH),
+            ),
+        )
+        .expect("slashing the entire bond should succeed");
+        assert!(
+            may_load_verifier_bond(
+                deps.as_ref().storage,
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS
+            )
+            .unwrap()
+            .is_none(),
+            "a fully slashed bond should be removed from storage",
+        );
+    }
+
+    #[test]
+    fn test_invalid_slash_verifier_bond_for_excessive_amount() {
+        let mut deps = mock_dependencies(&[]);
+        setup_bonded_verifier(&mut deps, 1000);
+        for amount in [coin(1001, NHASH), coin(10, "otherdenom")] {
+            let error = slash_verifier_bond(
+                deps.as_mut(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                SlashVerifierBondV1::new(
+                    DEFAULT_ASSET_TYPE,
+                    DEFAULT_VERIFIER_ADDRESS,
+                    amount.clone(),
+                ),
+            )
+            .unwrap_err();
+            assert!(
+                matches!(error, ContractError::InvalidFunds(_)),
+                "expected slashing [{}] to be rejected, but got: {:?}",
+                amount,
+                error,
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_slash_verifier_bond_for_missing_bond() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = slash_verifier_bond(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            SlashVerifierBondV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                coin(100, NHASH),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected a missing bond to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_slash_verifier_bond_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        setup_bonded_verifier(&mut deps, 1000);
+        let error = slash_verifier_bond(
+            deps.as_mut(),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &[]),
+            SlashVerifierBondV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                coin(100, NHASH),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected only the admin or a verifier manager to be able to slash a bond, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_valid_slash_verifier_bond_for_verifier_manager() {
+        let mut deps = mock_dependencies(&[]);
+        setup_bonded_verifier(&mut deps, 1000);
+        grant_role(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            GrantRoleV1::new(ContractRole::VerifierManager, DEFAULT_SENDER_ADDRESS),
+        )
+        .expect("granting the role should succeed");
+        slash_verifier_bond(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_SENDER_ADDRESS),
+            SlashVerifierBondV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                coin(100, NHASH),
+            ),
+        )
+        .expect("a verifier manager should be able to slash a bond");
+        assert_eq!(
+            Some(coin(900, NHASH)),
+            may_load_verifier_bond(
+                deps.as_ref().storage,
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS
+            )
+            .unwrap(),
+            "the slashed amount should be removed from the bond",
+        );
+    }
+
+    #[test]
+    fn test_invalid_slash_verifier_bond_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        setup_bonded_verifier(&mut deps, 1000);
+        let error = slash_verifier_bond(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            SlashVerifierBondV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                coin(100, NHASH),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+}
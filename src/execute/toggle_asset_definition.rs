@@ -1,11 +1,12 @@
-use cosmwasm_std::{MessageInfo, Response};
+use cosmwasm_std::{Env, MessageInfo, Response};
 
 use crate::core::state::{load_asset_definition_v2_by_type, replace_asset_definition_v2};
+use crate::core::types::contract_role::ContractRole;
 use crate::{
     core::{error::ContractError, msg::ExecuteMsg},
     util::{
         aliases::{AssetResult, DepsMutC, EntryPointResponse},
-        contract_helpers::{check_admin_only, check_funds_are_empty},
+        contract_helpers::{check_funds_are_empty, check_role},
         event_attributes::{EventAttributes, EventType},
         traits::ResultExtensions,
     },
@@ -77,16 +78,19 @@ impl ToggleAssetDefinitionV1 {
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
 /// of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` An instance of the toggle asset definition v1 struct, provided by conversion from an
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg).
 pub fn toggle_asset_definition(
     deps: DepsMutC,
+    env: Env,
     info: MessageInfo,
     msg: ToggleAssetDefinitionV1,
 ) -> EntryPointResponse {
-    check_admin_only(&deps.as_ref(), &info)?;
+    check_role(&deps.as_ref(), &info, ContractRole::DefinitionManager)?;
     check_funds_are_empty(&info)?;
     let mut asset_definition = load_asset_definition_v2_by_type(deps.storage, &msg.asset_type)?;
     // Never toggle the state if the caller didn't expect the target result
@@ -102,7 +106,12 @@ pub fn toggle_asset_definition(
     }
     // Simply negate the current value in state to swap it
     asset_definition.enabled = !asset_definition.enabled;
-    replace_asset_definition_v2(deps.storage, &asset_definition)?;
+    replace_asset_definition_v2(
+        deps.storage,
+        &asset_definition,
+        env.block.height,
+        info.sender.as_str(),
+    )?;
     Response::new()
         .add_attributes(
             EventAttributes::new(EventType::ToggleAssetDefinition)
@@ -226,6 +235,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let error = toggle_asset_definition(
             deps.as_mut(),
+            mock_env(),
             mock_info("not-the-admin", &[]),
             ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, false),
         )
@@ -243,6 +253,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let error = toggle_asset_definition(
             deps.as_mut(),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
             ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, false),
         )
@@ -260,6 +271,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let error = toggle_asset_definition(
             deps.as_mut(),
+            mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
             ToggleAssetDefinitionV1::new("no-u", false),
         )
@@ -278,6 +290,7 @@ mod tests {
         // The asset type should be enabled by default, so trying to toggle it to enabled again should fail
         let enable_error = toggle_asset_definition(
             deps.as_mut(),
+            mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
             ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, true),
         )
@@ -299,6 +312,7 @@ mod tests {
         toggle_default_asset_definition(deps.as_mut(), false);
         let disable_error = toggle_asset_definition(
             deps.as_mut(),
+            mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
             ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, false),
         )
@@ -330,6 +344,7 @@ mod tests {
     fn toggle_default_asset_definition(deps: DepsMutC, expected_result: bool) {
         toggle_asset_definition(
             deps,
+            mock_env(),
             empty_mock_info(DEFAULT_ADMIN_ADDRESS),
             ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, expected_result),
         )
@@ -0,0 +1,405 @@
+use cosmwasm_std::{Env, MessageInfo, Response};
+
+use crate::core::state::{load_verifier_owning_asset_definition_v2, replace_asset_definition_v2};
+use crate::core::types::contract_role::ContractRole;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_funds_are_empty, check_role},
+        event_attributes::{EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::ToggleAssetVerifier](crate::core::msg::ExecuteMsg::ToggleAssetVerifier)
+/// for ease of use in the underlying [toggle_asset_verifier](self::toggle_asset_verifier) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
+/// containing the target verifier.
+/// * `verifier_address` The bech32 address of the target [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2).
+/// * `expected_result` The value of [enabled](crate::core::types::verifier_detail::VerifierDetailV2::enabled)
+/// after the toggle takes place.  This value is required to ensure that multiple toggles executed
+/// in succession (either by accident or by various unrelated callers) will only be honored if
+/// the verifier is in the intended state during the execution of the route.
+#[derive(Clone, PartialEq)]
+pub struct ToggleAssetVerifierV1 {
+    pub asset_type: String,
+    pub verifier_address: String,
+    pub expected_result: bool,
+}
+impl ToggleAssetVerifierV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type of the [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
+    /// containing the target verifier.
+    /// * `verifier_address` The bech32 address of the target [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2).
+    /// * `expected_result` The value of [enabled](crate::core::types::verifier_detail::VerifierDetailV2::enabled)
+    /// after the toggle takes place.
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        asset_type: S1,
+        verifier_address: S2,
+        expected_result: bool,
+    ) -> Self {
+        ToggleAssetVerifierV1 {
+            asset_type: asset_type.into(),
+            verifier_address: verifier_address.into(),
+            expected_result,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [ToggleAssetVerifier](crate::core::msg::ExecuteMsg::ToggleAssetVerifier)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<ToggleAssetVerifierV1> {
+        match msg {
+            ExecuteMsg::ToggleAssetVerifier {
+                asset_type,
+                verifier_address,
+                expected_result,
+            } => ToggleAssetVerifierV1::new(asset_type, verifier_address, expected_result).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::ToggleAssetVerifier".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::ToggleAssetVerifier](crate::core::msg::ExecuteMsg::ToggleAssetVerifier)
+/// message is provided.  Attempts to swap the [enabled](crate::core::types::verifier_detail::VerifierDetailV2::enabled)
+/// property of a single verifier from true to false, or false to true.  If the asset type inherits
+/// its verifiers from a parent asset type, the verifier is toggled on the ancestor that owns them.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the toggle asset verifier v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn toggle_asset_verifier(
+    deps: DepsMutC,
+    env: Env,
+    info: MessageInfo,
+    msg: ToggleAssetVerifierV1,
+) -> EntryPointResponse {
+    check_role(&deps.as_ref(), &info, ContractRole::VerifierManager)?;
+    check_funds_are_empty(&info)?;
+    let mut asset_definition =
+        load_verifier_owning_asset_definition_v2(deps.storage, &msg.asset_type)?;
+    let verifier = match asset_definition
+        .verifiers
+        .iter_mut()
+        .find(|verifier| verifier.address == msg.verifier_address)
+    {
+        Some(verifier) => verifier,
+        None => {
+            return ContractError::NotFound {
+                explanation: format!(
+                    "verifier with address {} not found for asset definition for type {}",
+                    msg.verifier_address, msg.asset_type
+                ),
+            }
+            .to_err();
+        }
+    };
+    // Never toggle the state if the caller didn't expect the target result
+    if verifier.enabled == msg.expected_result {
+        return ContractError::UnexpectedState {
+            explanation: format!(
+                "expected to toggle to [enabled = {}], but toggle would set value to [enabled = {}]",
+                msg.expected_result, !verifier.enabled
+            ),
+        }
+        .to_err();
+    }
+    verifier.enabled = !verifier.enabled;
+    let enabled = verifier.enabled;
+    replace_asset_definition_v2(
+        deps.storage,
+        &asset_definition,
+        env.block.height,
+        info.sender.as_str(),
+    )?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::ToggleAssetVerifier)
+                .set_asset_type(&asset_definition.asset_type)
+                .set_verifier(&msg.verifier_address)
+                .set_new_value(enabled),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::core::state::load_asset_definition_v2_by_type;
+    use crate::{
+        contract::execute,
+        core::{
+            error::ContractError, msg::ExecuteMsg,
+            types::scope_spec_identifier::ScopeSpecIdentifier,
+        },
+        testutil::{
+            onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset},
+            test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS},
+            test_utilities::{
+                empty_mock_info, get_default_asset_definition_input, mock_info_with_nhash,
+                setup_test_suite, single_attribute_for_key, test_instantiate_success, InstArgs,
+            },
+            verify_asset_helpers::{test_verify_asset, TestVerifyAsset},
+        },
+        util::{
+            aliases::{DepsC, DepsMutC},
+            constants::{
+                ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, NEW_VALUE_KEY, VERIFIER_ADDRESS_KEY,
+            },
+            event_attributes::EventType,
+            traits::OptionExtensions,
+        },
+    };
+
+    use super::{toggle_asset_verifier, ToggleAssetVerifierV1};
+
+    #[test]
+    fn test_valid_toggle_asset_verifier_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::ToggleAssetVerifier {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
+                expected_result: false,
+            },
+        )
+        .expect("the toggle should work correctly");
+        assert!(
+            response.messages.is_empty(),
+            "toggling a verifier should not require messages",
+        );
+        assert_eq!(
+            EventType::ToggleAssetVerifier.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            single_attribute_for_key(&response, ASSET_TYPE_KEY),
+            "the proper asset type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_VERIFIER_ADDRESS,
+            single_attribute_for_key(&response, VERIFIER_ADDRESS_KEY),
+            "the proper verifier address should be emitted",
+        );
+        assert_eq!(
+            "false",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the new value key should indicate that the verifier has been set to enabled = false",
+        );
+        test_toggle_has_successfully_occurred(&deps.as_ref(), false);
+    }
+
+    #[test]
+    fn test_toggle_off_and_back_on() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        toggle_default_verifier(deps.as_mut(), false);
+        test_toggle_has_successfully_occurred(&deps.as_ref(), false);
+        toggle_default_verifier(deps.as_mut(), true);
+        test_toggle_has_successfully_occurred(&deps.as_ref(), true);
+    }
+
+    #[test]
+    fn test_toggle_inherited_verifier_toggles_parent() {
+        let mut deps = mock_dependencies(&[]);
+        let mut child_input = get_default_asset_definition_input()
+            .with_parent_asset_type(DEFAULT_ASSET_TYPE.to_some());
+        child_input.asset_type = "child".to_string();
+        child_input.scope_spec_identifier =
+            ScopeSpecIdentifier::address("scopespec-child").to_serialized_enum();
+        child_input.verifiers = vec![];
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![get_default_asset_definition_input(), child_input],
+                ..InstArgs::default()
+            },
+        );
+        let response = toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetVerifierV1::new("child", DEFAULT_VERIFIER_ADDRESS, false),
+        )
+        .expect("toggling a verifier inherited by the child should succeed");
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            single_attribute_for_key(&response, ASSET_TYPE_KEY),
+            "the asset type that owns the verifier should be emitted",
+        );
+        test_toggle_has_successfully_occurred(&deps.as_ref(), false);
+    }
+
+    #[test]
+    fn test_disabled_verifier_can_verify_pending_assets() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding with an enabled verifier should succeed");
+        toggle_default_verifier(deps.as_mut(), false);
+        test_verify_asset(&mut deps, TestVerifyAsset::default())
+            .expect("a disabled verifier should still be able to verify its pending assets");
+    }
+
+    #[test]
+    fn test_invalid_toggle_asset_verifier_for_invalid_msg() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::ToggleAssetVerifier {
+                asset_type: String::new(),
+                verifier_address: String::new(),
+                expected_result: false,
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMessageFields { .. }),
+            "expected the invalid message fields error to be returned when the message is malformatted, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_toggle_asset_verifier_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &[]),
+            ToggleAssetVerifierV1::new(DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS, false),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_toggle_asset_verifier_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            ToggleAssetVerifierV1::new(DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS, false),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_toggle_asset_verifier_for_unknown_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetVerifierV1::new(DEFAULT_ASSET_TYPE, DEFAULT_ADMIN_ADDRESS, false),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected the not found error to be returned, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_toggle_to_incorrect_expected_state_fails() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetVerifierV1::new(DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS, true),
+        )
+        .unwrap_err();
+        match error {
+            ContractError::UnexpectedState { explanation } => {
+                assert_eq!(
+                    "expected to toggle to [enabled = true], but toggle would set value to [enabled = false]",
+                    explanation.as_str(),
+                    "incorrect error message encountered on invalid toggle",
+                );
+            }
+            _ => panic!(
+                "unexpected error encountered on invalid toggle: {:?}",
+                error
+            ),
+        };
+    }
+
+    fn test_toggle_has_successfully_occurred(deps: &DepsC, expected_enabled_value: bool) {
+        let asset_def = load_asset_definition_v2_by_type(deps.storage, DEFAULT_ASSET_TYPE)
+            .expect("the default asset definition should exist in storage");
+        assert_eq!(
+            expected_enabled_value,
+            asset_def
+                .verifiers
+                .iter()
+                .find(|verifier| verifier.address == DEFAULT_VERIFIER_ADDRESS)
+                .expect("the default verifier should exist")
+                .enabled,
+            "the verifier enabled value was not toggled to the expected value",
+        );
+    }
+
+    fn toggle_default_verifier(deps: DepsMutC, expected_result: bool) {
+        toggle_asset_verifier(
+            deps,
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetVerifierV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                expected_result,
+            ),
+        )
+        .expect("toggle should execute without fail");
+    }
+}
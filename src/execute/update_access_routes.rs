@@ -1,14 +1,14 @@
 use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
-use crate::core::state::config_read_v2;
 use crate::core::types::access_definition::AccessDefinition;
 use crate::core::types::access_route::AccessRoute;
 use crate::core::types::asset_identifier::AssetIdentifier;
+use crate::core::types::contract_role::ContractRole;
 use crate::service::asset_meta_repository::AssetMetaRepository;
 use crate::service::deps_manager::DepsManager;
 use crate::service::message_gathering_service::MessageGatheringService;
 use crate::util::aliases::{AssetResult, EntryPointResponse};
-use crate::util::contract_helpers::check_funds_are_empty;
+use crate::util::contract_helpers::{check_funds_are_empty, check_role};
 use crate::util::event_attributes::{EventAttributes, EventType};
 use crate::util::functions::filter_valid_access_routes;
 use crate::util::traits::ResultExtensions;
@@ -113,17 +113,16 @@ where
     T: AssetMetaRepository + MessageGatheringService + DepsManager<'a>,
 {
     check_funds_are_empty(&info)?;
-    // If the sender is not the specified owner address and the sender is not the admin, they are
-    // not authorized to change access routes
+    // If the sender is not the specified owner address and the sender is neither the admin nor an
+    // access route admin, they are not authorized to change access routes
     if info.sender != msg.owner_address
-        && info.sender
-            != repository
-                .use_deps(|deps| config_read_v2(deps.storage).load())?
-                .admin
+        && repository
+            .use_deps(|deps| check_role(&deps.as_ref(), &info, ContractRole::AccessRouteAdmin))
+            .is_err()
     {
         return ContractError::Unauthorized {
             explanation:
-                "only the admin or owner of the given access routes can make modifications to them"
+                "only the admin, an access route admin, or owner of the given access routes can make modifications to them"
                     .to_string(),
         }
         .to_err();
@@ -181,10 +180,11 @@ where
 mod tests {
     use super::*;
     use crate::contract::execute;
+    use crate::core::state::insert_role_member;
     use crate::service::asset_meta_service::AssetMetaService;
     use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
     use crate::testutil::test_constants::{
-        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME,
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME, DEFAULT_FEE_ADDRESS,
         DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
     };
     use crate::testutil::test_utilities::{
@@ -242,7 +242,7 @@ mod tests {
         match err {
             ContractError::Unauthorized { explanation } => {
                 assert_eq!(
-                    "only the admin or owner of the given access routes can make modifications to them",
+                    "only the admin, an access route admin, or owner of the given access routes can make modifications to them",
                     explanation,
                     "unexpected Unauthorized error message encountered",
                 );
@@ -478,6 +478,28 @@ mod tests {
         .expect("expected the update to complete successfully");
     }
 
+    #[test]
+    fn test_successful_update_access_routes_by_access_route_admin() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("expected the default asset onboarding to succeed");
+        insert_role_member(
+            deps.as_mut().storage,
+            &ContractRole::AccessRouteAdmin,
+            DEFAULT_FEE_ADDRESS,
+        )
+        .expect("granting the role should succeed");
+        test_update_access_routes(
+            &mut deps,
+            TestUpdateAccessRoutes {
+                info: empty_mock_info(DEFAULT_FEE_ADDRESS),
+                update_access_routes: get_valid_update_routes_v1(),
+            },
+        )
+        .expect("expected the update to complete successfully");
+    }
+
     #[test]
     fn test_successful_update_to_remove_access_routes() {
         let mut deps = mock_dependencies(&[]);
@@ -2,11 +2,13 @@ use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
 use crate::core::state::replace_asset_definition_v2;
 use crate::core::types::asset_definition::AssetDefinitionV2;
+use crate::core::types::contract_role::ContractRole;
+use crate::execute::register_verifier::register_verifier_details;
 use crate::util::aliases::{AssetResult, DepsMutC, EntryPointResponse};
-use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
+use crate::util::contract_helpers::{check_funds_are_empty, check_role, check_timelock_disabled};
 use crate::util::event_attributes::{EventAttributes, EventType};
 use crate::util::traits::ResultExtensions;
-use cosmwasm_std::{MessageInfo, Response};
+use cosmwasm_std::{Env, MessageInfo, Response, Storage};
 
 /// A transformation of [ExecuteMsg::UpdateAssetDefinition](crate::core::msg::ExecuteMsg::UpdateAssetDefinition)
 /// for ease of use in the underlying [update_asset_definition](self::update_asset_definition) function.
@@ -55,30 +57,61 @@ impl UpdateAssetDefinitionV1 {
 /// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateAssetDefinition](crate::core::msg::ExecuteMsg::UpdateAssetDefinition)
 /// message is provided.  Attempts to replace an existing [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
 /// value based on a matching [asset_type](crate::core::types::asset_definition::AssetDefinitionV2::asset_type)
-/// property.  If no matching type is present, the request will be rejected.
+/// property.  If no matching type is present, the request will be rejected.  While the contract's
+/// timelock is enabled, this route is rejected and the update must be queued as a [TimelockedChange](crate::core::types::timelocked_change::TimelockedChange)
+/// instead, because an update can raise verifier fees or remove verifiers.
 ///
 /// # Parameters
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
 /// of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` An instance of the update asset definition v1 struct, provided by conversion from an
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg).
 pub fn update_asset_definition(
     deps: DepsMutC,
+    env: Env,
     info: MessageInfo,
     msg: UpdateAssetDefinitionV1,
 ) -> EntryPointResponse {
-    check_admin_only(&deps.as_ref(), &info)?;
+    check_role(&deps.as_ref(), &info, ContractRole::DefinitionManager)?;
     check_funds_are_empty(&info)?;
-    // Overwrite the existing asset definition with the new one
-    replace_asset_definition_v2(deps.storage, &msg.asset_definition)?;
+    check_timelock_disabled(&deps.as_ref(), "ExecuteMsg::UpdateAssetDefinition")?;
     Response::new()
-        .add_attributes(
-            EventAttributes::new(EventType::UpdateAssetDefinition)
-                .set_asset_type(&msg.asset_definition.asset_type),
-        )
+        .add_attributes(apply_update_asset_definition(
+            deps.storage,
+            msg,
+            env.block.height,
+            info.sender.as_str(),
+        )?)
+        .to_ok()
+}
+
+/// Replaces an existing [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
+/// without any authorization checks.  Used directly by [update_asset_definition](self::update_asset_definition)
+/// and by [execute_timelocked_change](crate::execute::execute_timelocked_change::execute_timelocked_change)
+/// once a queued update's delay has elapsed.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `msg` An instance of the update asset definition v1 struct.
+/// * `block_height` The block height at which the change is applied.
+/// * `actor` The bech32 address of the account responsible for the change.
+pub fn apply_update_asset_definition(
+    storage: &mut dyn Storage,
+    mut msg: UpdateAssetDefinitionV1,
+    block_height: u64,
+    actor: &str,
+) -> AssetResult<EventAttributes> {
+    register_verifier_details(storage, &mut msg.asset_definition)?;
+    // Overwrite the existing asset definition with the new one
+    replace_asset_definition_v2(storage, &msg.asset_definition, block_height, actor)?;
+    EventAttributes::new(EventType::UpdateAssetDefinition)
+        .set_asset_type(&msg.asset_definition.asset_type)
         .to_ok()
 }
 
@@ -101,7 +134,7 @@ mod tests {
         DEFAULT_SENDER_ADDRESS,
     };
     use crate::testutil::test_utilities::{
-        empty_mock_info, get_default_entity_detail, single_attribute_for_key,
+        empty_mock_info, get_default_entity_detail, single_attribute_for_key, test_enable_timelock,
         test_instantiate_success, InstArgs,
     };
     use crate::util::aliases::DepsC;
@@ -156,6 +189,7 @@ mod tests {
         let msg = get_valid_update_asset_definition();
         update_asset_definition(
             deps.as_mut(),
+            mock_env(),
             mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
             msg.clone(),
         )
@@ -196,6 +230,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let error = update_asset_definition(
             deps.as_mut(),
+            mock_env(),
             // Send from the "sender address" which is the address of the account that does onboarding in tests
             mock_info(DEFAULT_SENDER_ADDRESS, &[]),
             get_valid_update_asset_definition(),
@@ -214,6 +249,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let error = update_asset_definition(
             deps.as_mut(),
+            mock_env(),
             mock_info(DEFAULT_ADMIN_ADDRESS, &[coin(420, "usdf")]),
             get_valid_update_asset_definition(),
         )
@@ -225,6 +261,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_update_asset_definition_for_enabled_timelock() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        let error = update_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            get_valid_update_asset_definition(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::TimelockRequired { .. }),
+            "expected the timelock required response to be returned when the timelock is enabled, but got: {:?}",
+            error,
+        );
+    }
+
     #[test]
     fn test_invalid_update_asset_definition_for_missing_loan_type() {
         let mut deps = mock_dependencies(&[]);
@@ -242,6 +297,7 @@ mod tests {
         );
         let error = update_asset_definition(
             deps.as_mut(),
+            mock_env(),
             mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
             UpdateAssetDefinitionV1::new(missing_asset_definition),
         )
@@ -1,13 +1,15 @@
 use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
 use crate::core::state::{load_asset_definition_v2_by_type, replace_asset_definition_v2};
+use crate::core::types::contract_role::ContractRole;
 use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::execute::register_verifier::register_verifier_detail;
 use crate::util::aliases::{AssetResult, DepsMutC, EntryPointResponse};
-use crate::util::contract_helpers::{check_admin_only, check_funds_are_empty};
+use crate::util::contract_helpers::{check_funds_are_empty, check_role, check_timelock_disabled};
 use crate::util::event_attributes::{EventAttributes, EventType};
 use crate::util::functions::replace_single_matching_vec_element;
 use crate::util::traits::ResultExtensions;
-use cosmwasm_std::{MessageInfo, Response};
+use cosmwasm_std::{Env, MessageInfo, Response, Storage};
 
 /// A transformation of [ExecuteMsg::UpdateAssetVerifier](crate::core::msg::ExecuteMsg::UpdateAssetVerifier)
 /// for ease of use in the underlying [update_asset_verifier](self::update_asset_verifier) function.
@@ -68,23 +70,57 @@ impl UpdateAssetVerifierV1 {
 /// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateAssetVerifier](crate::core::msg::ExecuteMsg::UpdateAssetVerifier)
 /// message is provided.  Replaces an existing [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
 /// on an existing [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2).
+/// While the contract's timelock is enabled, this route is rejected and the update must be
+/// queued as a [TimelockedChange](crate::core::types::timelocked_change::TimelockedChange) instead.
 ///
 /// # Parameters
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
 /// of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` An instance of the update asset verifier v1 struct, provided by conversion from an
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg).
 pub fn update_asset_verifier(
     deps: DepsMutC,
+    env: Env,
     info: MessageInfo,
     msg: UpdateAssetVerifierV1,
 ) -> EntryPointResponse {
-    check_admin_only(&deps.as_ref(), &info)?;
+    check_role(&deps.as_ref(), &info, ContractRole::VerifierManager)?;
     check_funds_are_empty(&info)?;
-    let mut asset_definition = load_asset_definition_v2_by_type(deps.storage, &msg.asset_type)?;
+    check_timelock_disabled(&deps.as_ref(), "ExecuteMsg::UpdateAssetVerifier")?;
+    Response::new()
+        .add_attributes(apply_update_asset_verifier(
+            deps.storage,
+            msg,
+            env.block.height,
+            info.sender.as_str(),
+        )?)
+        .to_ok()
+}
+
+/// Replaces an existing [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
+/// on an existing [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
+/// without any authorization checks.  Used directly by [update_asset_verifier](self::update_asset_verifier)
+/// and by [execute_timelocked_change](crate::execute::execute_timelocked_change::execute_timelocked_change)
+/// once a queued update's delay has elapsed.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `msg` An instance of the update asset verifier v1 struct.
+/// * `block_height` The block height at which the change is applied.
+/// * `actor` The bech32 address of the account responsible for the change.
+pub fn apply_update_asset_verifier(
+    storage: &mut dyn Storage,
+    mut msg: UpdateAssetVerifierV1,
+    block_height: u64,
+    actor: &str,
+) -> AssetResult<EventAttributes> {
+    let mut asset_definition = load_asset_definition_v2_by_type(storage, &msg.asset_type)?;
     let verifier_address = msg.verifier.address.clone();
     // If a single verifier for the given address cannot be found, data is either corrupt, or the
     // verifier does not exist.  Given validation upfront prevents multiple verifiers with the
@@ -108,13 +144,13 @@ pub fn update_asset_verifier(
         .set_asset_type(&asset_definition.asset_type)
         .set_verifier(&msg.verifier.address);
     // Replace the existing verifier and save the result to the state
+    register_verifier_detail(storage, &mut msg.verifier)?;
     asset_definition.verifiers =
         replace_single_matching_vec_element(asset_definition.verifiers, msg.verifier, |v| {
             v.address == verifier_address
         })?;
-    replace_asset_definition_v2(deps.storage, &asset_definition)?;
-    // Respond with emitted attributes
-    Response::new().add_attributes(attributes).to_ok()
+    replace_asset_definition_v2(storage, &asset_definition, block_height, actor)?;
+    attributes.to_ok()
 }
 
 #[cfg(test)]
@@ -123,15 +159,19 @@ mod tests {
     use crate::contract::execute;
     use crate::core::error::ContractError;
     use crate::core::msg::ExecuteMsg;
-    use crate::core::state::load_asset_definition_v2_by_type;
+    use crate::core::state::{load_asset_definition_v2_by_type, load_verifier_registration};
+    use crate::core::types::entity_detail::EntityDetail;
     use crate::core::types::fee_destination::FeeDestinationV2;
+    use crate::core::types::scope_spec_identifier::ScopeSpecIdentifier;
     use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::execute::register_verifier::{register_verifier, RegisterVerifierV1};
     use crate::execute::update_asset_verifier::{update_asset_verifier, UpdateAssetVerifierV1};
     use crate::testutil::test_constants::{
         DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS,
     };
     use crate::testutil::test_utilities::{
-        empty_mock_info, get_default_entity_detail, single_attribute_for_key,
+        empty_mock_info, get_default_asset_definition_input, get_default_entity_detail,
+        get_default_verifier_detail, single_attribute_for_key, test_enable_timelock,
         test_instantiate_success, InstArgs,
     };
     use crate::util::aliases::DepsC;
@@ -194,6 +234,7 @@ mod tests {
         let msg = get_valid_update_verifier_msg();
         update_asset_verifier(
             deps.as_mut(),
+            mock_env(),
             mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
             msg.clone(),
         )
@@ -201,6 +242,78 @@ mod tests {
         test_default_verifier_was_updated(&msg.verifier, &deps.as_ref());
     }
 
+    #[test]
+    fn test_update_asset_verifier_resolves_registration_across_asset_types() {
+        let mut deps = mock_dependencies(&[]);
+        let mut other_input = get_default_asset_definition_input();
+        other_input.asset_type = "other_type".to_string();
+        other_input.scope_spec_identifier =
+            ScopeSpecIdentifier::address("scopespec-other").to_serialized_enum();
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![get_default_asset_definition_input(), other_input],
+                ..InstArgs::default()
+            },
+        );
+        let new_entity_detail = EntityDetail::new(
+            "Renamed Verifier",
+            "A new description",
+            "https://home.url",
+            "https://source.url",
+        );
+        let mut renamed_verifier = get_default_verifier_detail();
+        renamed_verifier.entity_detail = new_entity_detail.clone().to_some();
+        let error = update_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAssetVerifierV1::new(DEFAULT_ASSET_TYPE, renamed_verifier.clone()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::VerifierRegistrationConflict { .. }),
+            "expected a per-type entity detail change to be rejected for a registered verifier, but got: {:?}",
+            error,
+        );
+        let mut repriced_verifier = get_default_verifier_detail();
+        repriced_verifier.onboarding_cost = Uint128::new(500);
+        update_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateAssetVerifierV1::new(DEFAULT_ASSET_TYPE, repriced_verifier.clone()),
+        )
+        .expect("updating the verifier's onboarding cost should succeed");
+        test_default_verifier_was_updated(&repriced_verifier, &deps.as_ref());
+        assert_eq!(
+            vec![get_default_verifier_detail()],
+            load_asset_definition_v2_by_type(deps.as_ref().storage, "other_type")
+                .expect("the other asset definition should load")
+                .verifiers,
+            "a new onboarding cost should not be copied into the other asset type",
+        );
+        let mut registration =
+            load_verifier_registration(deps.as_ref().storage, DEFAULT_VERIFIER_ADDRESS)
+                .expect("the verifier should be registered");
+        registration.entity_detail = new_entity_detail.clone().to_some();
+        register_verifier(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            RegisterVerifierV1::new(registration),
+        )
+        .expect("registering the new entity detail should succeed");
+        repriced_verifier.entity_detail = new_entity_detail.to_some();
+        test_default_verifier_was_updated(&repriced_verifier, &deps.as_ref());
+        assert_eq!(
+            vec![renamed_verifier],
+            load_asset_definition_v2_by_type(deps.as_ref().storage, "other_type")
+                .expect("the other asset definition should load")
+                .verifiers,
+            "the registered entity detail should be resolved for the other asset type",
+        );
+    }
+
     #[test]
     fn test_invalid_update_asset_verifier_for_invalid_asset_type() {
         let mut deps = mock_dependencies(&[]);
@@ -257,6 +370,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let error = update_asset_verifier(
             deps.as_mut(),
+            mock_env(),
             mock_info("bad-guy", &[]),
             get_valid_update_verifier_msg(),
         )
@@ -274,6 +388,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let error = update_asset_verifier(
             deps.as_mut(),
+            mock_env(),
             mock_info(DEFAULT_ADMIN_ADDRESS, &[coin(93849382, "dopehash")]),
             get_valid_update_verifier_msg(),
         )
@@ -291,6 +406,7 @@ mod tests {
         test_instantiate_success(deps.as_mut(), InstArgs::default());
         let error = update_asset_verifier(
             deps.as_mut(),
+            mock_env(),
             mock_info(DEFAULT_ADMIN_ADDRESS, &[]),
             UpdateAssetVerifierV1::new(
                 DEFAULT_ASSET_TYPE,
@@ -305,13 +421,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_update_asset_verifier_for_enabled_timelock() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        let error = update_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            get_valid_update_verifier_msg(),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::TimelockRequired { .. }),
+            "the timelock required error should be returned when the timelock is enabled, but got: {:?}",
+            error,
+        );
+    }
+
     fn test_default_verifier_was_updated(verifier: &VerifierDetailV2, deps: &DepsC) {
         let state_def = load_asset_definition_v2_by_type(deps.storage, DEFAULT_ASSET_TYPE)
             .expect("expected the default asset type to be stored in the state");
         let target_verifier = state_def.verifiers.into_iter().find(|v| v.address == verifier.address)
             .expect("expected a single verifier to be produced when searching for the updated verifier's address");
+        // The updated fees differ from the registered fees, so they only apply to this asset type
         assert_eq!(
-            verifier, &target_verifier,
+            &verifier.clone().with_overrides_registered_fees(true),
+            &target_verifier,
             "expected the verifier stored in state to equate to the updated verifier",
         );
     }
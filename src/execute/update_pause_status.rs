@@ -0,0 +1,338 @@
+use cosmwasm_std::{MessageInfo, Response};
+
+use crate::core::state::{config_read_v2, config_v2};
+use crate::core::types::contract_role::ContractRole;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_funds_are_empty, check_role},
+        event_attributes::{EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::UpdatePauseStatus](crate::core::msg::ExecuteMsg::UpdatePauseStatus)
+/// for ease of use in the underlying [update_pause_status](self::update_pause_status) function.
+///
+/// # Parameters
+///
+/// * `onboarding` If provided, pauses or resumes the onboarding process.
+/// * `verification` If provided, pauses or resumes the verification process.
+/// * `access_routes` If provided, pauses or resumes access route updates.
+#[derive(Clone, PartialEq)]
+pub struct UpdatePauseStatusV1 {
+    pub onboarding: Option<bool>,
+    pub verification: Option<bool>,
+    pub access_routes: Option<bool>,
+}
+impl UpdatePauseStatusV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `onboarding` If provided, pauses or resumes the onboarding process.
+    /// * `verification` If provided, pauses or resumes the verification process.
+    /// * `access_routes` If provided, pauses or resumes access route updates.
+    pub fn new(
+        onboarding: Option<bool>,
+        verification: Option<bool>,
+        access_routes: Option<bool>,
+    ) -> Self {
+        UpdatePauseStatusV1 {
+            onboarding,
+            verification,
+            access_routes,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [UpdatePauseStatus](crate::core::msg::ExecuteMsg::UpdatePauseStatus)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<UpdatePauseStatusV1> {
+        match msg {
+            ExecuteMsg::UpdatePauseStatus {
+                onboarding,
+                verification,
+                access_routes,
+            } => UpdatePauseStatusV1::new(onboarding, verification, access_routes).to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::UpdatePauseStatus".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdatePauseStatus](crate::core::msg::ExecuteMsg::UpdatePauseStatus)
+/// message is provided.  Toggles the flags in the contract's [PauseStatus](crate::core::types::pause_status::PauseStatus).
+/// Omitted flags keep their current value.  Changes take effect immediately, and are never
+/// subject to the timelock.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the update pause status v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn update_pause_status(
+    deps: DepsMutC,
+    info: MessageInfo,
+    msg: UpdatePauseStatusV1,
+) -> EntryPointResponse {
+    check_role(&deps.as_ref(), &info, ContractRole::Pauser)?;
+    check_funds_are_empty(&info)?;
+    let mut state = config_read_v2(deps.storage).load()?;
+    if let Some(onboarding) = msg.onboarding {
+        state.pause_status.onboarding = onboarding;
+    }
+    if let Some(verification) = msg.verification {
+        state.pause_status.verification = verification;
+    }
+    if let Some(access_routes) = msg.access_routes {
+        state.pause_status.access_routes = access_routes;
+    }
+    config_v2(deps.storage).save(&state)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::UpdatePauseStatus).set_new_value(&state.pause_status),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::config_read_v2;
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::contract_role::ContractRole;
+    use crate::core::types::pause_status::PauseStatus;
+    use crate::execute::grant_role::{grant_role, GrantRoleV1};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_FEE_ADDRESS, DEFAULT_SCOPE_ADDRESS,
+        DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_access_routes, mock_info_with_nhash, single_attribute_for_key,
+        test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::DepsC;
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{update_pause_status, UpdatePauseStatusV1};
+
+    #[test]
+    fn test_valid_update_pause_status_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::UpdatePauseStatus {
+                onboarding: true.to_some(),
+                verification: true.to_some(),
+                access_routes: None,
+            },
+        )
+        .expect("pausing onboarding and verification should succeed");
+        assert_eq!(
+            EventType::UpdatePauseStatus.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            "onboarding,verification",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the paused operations should be emitted",
+        );
+        assert_pause_status(
+            &deps.as_ref(),
+            PauseStatus {
+                onboarding: true,
+                verification: true,
+                access_routes: false,
+            },
+        );
+        let response = update_pause_status(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdatePauseStatusV1::new(false.to_some(), None, None),
+        )
+        .expect("resuming onboarding should succeed");
+        assert_eq!(
+            "verification",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "omitted flags should keep their current value",
+        );
+    }
+
+    #[test]
+    fn test_valid_update_pause_status_by_pauser() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        grant_role(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            GrantRoleV1::new(ContractRole::Pauser, DEFAULT_FEE_ADDRESS),
+        )
+        .expect("granting the role should succeed");
+        update_pause_status(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            UpdatePauseStatusV1::new(None, None, true.to_some()),
+        )
+        .expect("an account holding the pauser role should be able to pause access route updates");
+        assert_pause_status(
+            &deps.as_ref(),
+            PauseStatus {
+                onboarding: false,
+                verification: false,
+                access_routes: true,
+            },
+        );
+    }
+
+    #[test]
+    fn test_paused_operations_are_rejected_by_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        update_pause_status(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdatePauseStatusV1::new(true.to_some(), true.to_some(), true.to_some()),
+        )
+        .expect("pausing every operation should succeed");
+        for (msg, info, expected_operation) in [
+            (
+                ExecuteMsg::OnboardAsset {
+                    identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS)
+                        .to_serialized_enum(),
+                    asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                    verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
+                    access_routes: None,
+                    max_onboarding_cost: None,
+                },
+                mock_info_with_nhash(DEFAULT_SENDER_ADDRESS, 1000),
+                "onboarding",
+            ),
+            (
+                ExecuteMsg::VerifyAsset {
+                    identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS)
+                        .to_serialized_enum(),
+                    success: true,
+                    message: None,
+                    access_routes: None,
+                },
+                empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+                "verification",
+            ),
+            (
+                ExecuteMsg::UpdateAccessRoutes {
+                    identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS)
+                        .to_serialized_enum(),
+                    owner_address: DEFAULT_SENDER_ADDRESS.to_string(),
+                    access_routes: get_default_access_routes(),
+                },
+                empty_mock_info(DEFAULT_SENDER_ADDRESS),
+                "access_routes",
+            ),
+        ] {
+            let error = execute(deps.as_mut(), mock_env(), info, msg).unwrap_err();
+            match error {
+                ContractError::ContractPaused { operation } => {
+                    assert_eq!(
+                        expected_operation, operation,
+                        "the paused operation should be included in the error",
+                    );
+                }
+                _ => panic!(
+                    "expected the [{}] operation to be paused, but got: {:?}",
+                    expected_operation, error,
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_update_pause_status_for_missing_role() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = update_pause_status(
+            deps.as_mut(),
+            mock_info(DEFAULT_FEE_ADDRESS, &[]),
+            UpdatePauseStatusV1::new(true.to_some(), None, None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected an account without the pauser role to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_pause_status_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = update_pause_status(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            UpdatePauseStatusV1::new(true.to_some(), None, None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_pause_status_for_no_flags() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::UpdatePauseStatus {
+                onboarding: None,
+                verification: None,
+                access_routes: None,
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMessageFields { .. }),
+            "expected a request without any flags to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    fn assert_pause_status(deps: &DepsC, expected_status: PauseStatus) {
+        assert_eq!(
+            expected_status,
+            config_read_v2(deps.storage)
+                .load()
+                .expect("state should load")
+                .pause_status,
+            "the pause status should be stored in the contract state",
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use cosmwasm_std::{MessageInfo, Response, Storage};
+
+use crate::core::state::{config_read_v2, config_v2};
+use crate::core::types::contract_role::ContractRole;
+use crate::core::types::protocol_fee::{is_protocol_fee_increase, ProtocolFee};
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_funds_are_empty, check_role, check_timelock_disabled},
+        event_attributes::{EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::UpdateProtocolFee](crate::core::msg::ExecuteMsg::UpdateProtocolFee)
+/// for ease of use in the underlying [update_protocol_fee](self::update_protocol_fee) function.
+///
+/// # Parameters
+///
+/// * `protocol_fee` The new protocol fee, or None to remove the protocol fee.
+#[derive(Clone, PartialEq)]
+pub struct UpdateProtocolFeeV1 {
+    pub protocol_fee: Option<ProtocolFee>,
+}
+impl UpdateProtocolFeeV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `protocol_fee` The new protocol fee, or None to remove the protocol fee.
+    pub fn new(protocol_fee: Option<ProtocolFee>) -> Self {
+        UpdateProtocolFeeV1 { protocol_fee }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [UpdateProtocolFee](crate::core::msg::ExecuteMsg::UpdateProtocolFee)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<UpdateProtocolFeeV1> {
+        match msg {
+            ExecuteMsg::UpdateProtocolFee { protocol_fee } => {
+                UpdateProtocolFeeV1::new(protocol_fee).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::UpdateProtocolFee".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateProtocolFee](crate::core::msg::ExecuteMsg::UpdateProtocolFee)
+/// message is provided.  Replaces the protocol fee in the contract's [state](crate::core::state::StateV2),
+/// which changes the funds required by all future onboarding requests.
+/// While the contract's timelock is enabled, increases to the fee are rejected and must be queued
+/// as a [TimelockedChange](crate::core::types::timelocked_change::TimelockedChange) instead.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the update protocol fee v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn update_protocol_fee(
+    deps: DepsMutC,
+    info: MessageInfo,
+    msg: UpdateProtocolFeeV1,
+) -> EntryPointResponse {
+    check_role(&deps.as_ref(), &info, ContractRole::TreasuryManager)?;
+    check_funds_are_empty(&info)?;
+    // Only increases are delayed by the timelock, as lowering or removing the fee can never
+    // surprise a requestor with a higher cost
+    let current_fee = config_read_v2(deps.storage).load()?.protocol_fee;
+    if is_protocol_fee_increase(&current_fee, &msg.protocol_fee) {
+        check_timelock_disabled(&deps.as_ref(), "ExecuteMsg::UpdateProtocolFee")?;
+    }
+    Response::new()
+        .add_attributes(apply_update_protocol_fee(deps.storage, msg)?)
+        .to_ok()
+}
+
+/// Replaces the protocol fee in the contract's [state](crate::core::state::StateV2) without any
+/// authorization checks.  Used directly by [update_protocol_fee](self::update_protocol_fee) and
+/// by [execute_timelocked_change](crate::execute::execute_timelocked_change::execute_timelocked_change)
+/// once a queued fee change's delay has elapsed.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `msg` An instance of the update protocol fee v1 struct.
+pub fn apply_update_protocol_fee(
+    storage: &mut dyn Storage,
+    msg: UpdateProtocolFeeV1,
+) -> AssetResult<EventAttributes> {
+    let mut state = config_read_v2(storage).load()?;
+    state.protocol_fee = msg.protocol_fee;
+    config_v2(storage).save(&state)?;
+    EventAttributes::new(EventType::UpdateProtocolFee)
+        .set_new_value(
+            state
+                .protocol_fee
+                .map(|fee| fee.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Uint128;
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::config_read_v2;
+    use crate::core::types::protocol_fee::{ProtocolFee, ProtocolFeeAmount};
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, mock_info_with_nhash, single_attribute_for_key, test_enable_timelock,
+        test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::DepsC;
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{update_protocol_fee, UpdateProtocolFeeV1};
+
+    #[test]
+    fn test_valid_update_protocol_fee_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let protocol_fee =
+            ProtocolFee::new(DEFAULT_FEE_ADDRESS, ProtocolFeeAmount::BasisPoints(250));
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::UpdateProtocolFee {
+                protocol_fee: Some(protocol_fee.clone()),
+            },
+        )
+        .expect("the update should succeed");
+        assert_eq!(
+            EventType::UpdateProtocolFee.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            format!("{}:250bps", DEFAULT_FEE_ADDRESS),
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the new protocol fee should be emitted",
+        );
+        assert_eq!(
+            Some(protocol_fee),
+            load_protocol_fee(&deps.as_ref()),
+            "the protocol fee should be stored in the contract state",
+        );
+    }
+
+    #[test]
+    fn test_valid_remove_protocol_fee() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        update_protocol_fee(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateProtocolFeeV1::new(Some(ProtocolFee::new(
+                DEFAULT_FEE_ADDRESS,
+                ProtocolFeeAmount::Flat(Uint128::new(50)),
+            ))),
+        )
+        .expect("setting the protocol fee should succeed");
+        let response = update_protocol_fee(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateProtocolFeeV1::new(None),
+        )
+        .expect("removing the protocol fee should succeed");
+        assert_eq!(
+            "none",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the removal of the protocol fee should be emitted",
+        );
+        assert_eq!(
+            None,
+            load_protocol_fee(&deps.as_ref()),
+            "the protocol fee should be removed from the contract state",
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_protocol_fee_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = update_protocol_fee(
+            deps.as_mut(),
+            mock_info("not-the-admin", &[]),
+            UpdateProtocolFeeV1::new(None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_protocol_fee_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = update_protocol_fee(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            UpdateProtocolFeeV1::new(None),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_update_protocol_fee_with_timelock_enabled() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let protocol_fee =
+            ProtocolFee::new(DEFAULT_FEE_ADDRESS, ProtocolFeeAmount::BasisPoints(250));
+        update_protocol_fee(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateProtocolFeeV1::new(protocol_fee.clone().to_some()),
+        )
+        .expect("setting the protocol fee should succeed before the timelock is enabled");
+        test_enable_timelock(deps.as_mut(), 100);
+        let error = update_protocol_fee(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateProtocolFeeV1::new(
+                ProtocolFee::new(DEFAULT_FEE_ADDRESS, ProtocolFeeAmount::BasisPoints(300))
+                    .to_some(),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::TimelockRequired { .. }),
+            "expected a fee increase to require a timelocked change, but got: {:?}",
+            error,
+        );
+        assert_eq!(
+            protocol_fee.to_some(),
+            load_protocol_fee(&deps.as_ref()),
+            "the protocol fee should be unchanged after a rejected increase",
+        );
+        let lower_fee = ProtocolFee::new(DEFAULT_FEE_ADDRESS, ProtocolFeeAmount::BasisPoints(100));
+        update_protocol_fee(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateProtocolFeeV1::new(lower_fee.clone().to_some()),
+        )
+        .expect("a fee decrease should not require a timelocked change");
+        assert_eq!(
+            lower_fee.to_some(),
+            load_protocol_fee(&deps.as_ref()),
+            "the lowered protocol fee should be stored",
+        );
+    }
+
+    fn load_protocol_fee(deps: &DepsC) -> Option<ProtocolFee> {
+        config_read_v2(deps.storage)
+            .load()
+            .expect("the contract state should load")
+            .protocol_fee
+    }
+}
@@ -0,0 +1,264 @@
+use cosmwasm_std::{MessageInfo, Response, Storage};
+
+use crate::core::state::{config_read_v2, config_v2};
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_admin_only, check_funds_are_empty, check_timelock_disabled},
+        event_attributes::{EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::UpdateTimelockDelay](crate::core::msg::ExecuteMsg::UpdateTimelockDelay)
+/// for ease of use in the underlying [update_timelock_delay](self::update_timelock_delay) function.
+///
+/// # Parameters
+///
+/// * `delay_blocks` The new delay, in blocks, or None to disable the timelock.
+#[derive(Clone, PartialEq)]
+pub struct UpdateTimelockDelayV1 {
+    pub delay_blocks: Option<u64>,
+}
+impl UpdateTimelockDelayV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `delay_blocks` The new delay, in blocks, or None to disable the timelock.
+    pub fn new(delay_blocks: Option<u64>) -> Self {
+        UpdateTimelockDelayV1 { delay_blocks }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [UpdateTimelockDelay](crate::core::msg::ExecuteMsg::UpdateTimelockDelay)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<UpdateTimelockDelayV1> {
+        match msg {
+            ExecuteMsg::UpdateTimelockDelay { delay_blocks } => {
+                UpdateTimelockDelayV1::new(delay_blocks).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::UpdateTimelockDelay".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateTimelockDelay](crate::core::msg::ExecuteMsg::UpdateTimelockDelay)
+/// message is provided.  Replaces the timelock delay in the contract's [state](crate::core::state::StateV2).
+/// Enabling or lengthening the timelock takes effect immediately, but while the timelock is
+/// enabled, shortening or disabling it must be queued as a [TimelockedChange](crate::core::types::timelocked_change::TimelockedChange).
+/// Changes that are already queued keep the executable height calculated when they were queued.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the update timelock delay v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn update_timelock_delay(
+    deps: DepsMutC,
+    info: MessageInfo,
+    msg: UpdateTimelockDelayV1,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let current_delay = config_read_v2(deps.storage).load()?.timelock_delay;
+    let is_reduction = match (current_delay, msg.delay_blocks) {
+        (Some(current), Some(new)) => new < current,
+        (Some(_), None) => true,
+        (None, _) => false,
+    };
+    if is_reduction {
+        check_timelock_disabled(&deps.as_ref(), "ExecuteMsg::UpdateTimelockDelay")?;
+    }
+    Response::new()
+        .add_attributes(apply_update_timelock_delay(deps.storage, msg)?)
+        .to_ok()
+}
+
+/// Replaces the timelock delay in the contract's [state](crate::core::state::StateV2) without any
+/// authorization checks.  Used directly by [update_timelock_delay](self::update_timelock_delay)
+/// and by [execute_timelocked_change](crate::execute::execute_timelocked_change::execute_timelocked_change)
+/// once a queued delay change's own delay has elapsed.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `msg` An instance of the update timelock delay v1 struct.
+pub fn apply_update_timelock_delay(
+    storage: &mut dyn Storage,
+    msg: UpdateTimelockDelayV1,
+) -> AssetResult<EventAttributes> {
+    let mut state = config_read_v2(storage).load()?;
+    state.timelock_delay = msg.delay_blocks;
+    config_v2(storage).save(&state)?;
+    EventAttributes::new(EventType::UpdateTimelockDelay)
+        .set_new_value(
+            state
+                .timelock_delay
+                .map(|delay| delay.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::config_read_v2;
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, mock_info_with_nhash, single_attribute_for_key, test_enable_timelock,
+        test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::DepsC;
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{update_timelock_delay, UpdateTimelockDelayV1};
+
+    #[test]
+    fn test_valid_update_timelock_delay_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::UpdateTimelockDelay {
+                delay_blocks: 100.to_some(),
+            },
+        )
+        .expect("enabling the timelock should succeed");
+        assert_eq!(
+            EventType::UpdateTimelockDelay.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            "100",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the new delay should be emitted",
+        );
+        assert_delay(&deps.as_ref(), 100.to_some());
+    }
+
+    #[test]
+    fn test_valid_update_timelock_delay_increase_while_enabled() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        update_timelock_delay(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateTimelockDelayV1::new(200.to_some()),
+        )
+        .expect("lengthening the timelock should not require a timelocked change");
+        assert_delay(&deps.as_ref(), 200.to_some());
+    }
+
+    #[test]
+    fn test_invalid_update_timelock_delay_reduction_while_enabled() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        test_enable_timelock(deps.as_mut(), 100);
+        for delay_blocks in [50.to_some(), None] {
+            let error = update_timelock_delay(
+                deps.as_mut(),
+                empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+                UpdateTimelockDelayV1::new(delay_blocks),
+            )
+            .unwrap_err();
+            assert!(
+                matches!(error, ContractError::TimelockRequired { .. }),
+                "expected reducing the delay to {:?} to require a timelocked change, but got: {:?}",
+                delay_blocks,
+                error,
+            );
+        }
+        assert_delay(&deps.as_ref(), 100.to_some());
+    }
+
+    #[test]
+    fn test_invalid_update_timelock_delay_for_zero_delay() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::UpdateTimelockDelay {
+                delay_blocks: 0.to_some(),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMessageFields { .. }),
+            "expected a zero delay to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_timelock_delay_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = update_timelock_delay(
+            deps.as_mut(),
+            mock_info(DEFAULT_FEE_ADDRESS, &[]),
+            UpdateTimelockDelayV1::new(100.to_some()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected only the admin to be able to update the timelock delay, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_timelock_delay_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = update_timelock_delay(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            UpdateTimelockDelayV1::new(100.to_some()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+
+    fn assert_delay(deps: &DepsC, expected_delay: Option<u64>) {
+        assert_eq!(
+            expected_delay,
+            config_read_v2(deps.storage)
+                .load()
+                .expect("state should load")
+                .timelock_delay,
+            "the timelock delay should be stored in the contract state",
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use cosmwasm_std::{MessageInfo, Response};
+
+use crate::core::state::{config_read_v2, config_v2};
+use crate::core::types::verifier_bond_settings::VerifierBondSettings;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_admin_only, check_funds_are_empty},
+        event_attributes::{EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::UpdateVerifierBondSettings](crate::core::msg::ExecuteMsg::UpdateVerifierBondSettings)
+/// for ease of use in the underlying [update_verifier_bond_settings](self::update_verifier_bond_settings) function.
+///
+/// # Parameters
+///
+/// * `settings` The new settings, which replace the existing settings.
+#[derive(Clone, PartialEq)]
+pub struct UpdateVerifierBondSettingsV1 {
+    pub settings: VerifierBondSettings,
+}
+impl UpdateVerifierBondSettingsV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `settings` The new settings, which replace the existing settings.
+    pub fn new(settings: VerifierBondSettings) -> Self {
+        UpdateVerifierBondSettingsV1 { settings }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [UpdateVerifierBondSettings](crate::core::msg::ExecuteMsg::UpdateVerifierBondSettings)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<UpdateVerifierBondSettingsV1> {
+        match msg {
+            ExecuteMsg::UpdateVerifierBondSettings { settings } => {
+                UpdateVerifierBondSettingsV1::new(settings).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::UpdateVerifierBondSettings".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateVerifierBondSettings](crate::core::msg::ExecuteMsg::UpdateVerifierBondSettings)
+/// message is provided.  Replaces the [VerifierBondSettings](crate::core::types::verifier_bond_settings::VerifierBondSettings)
+/// in the contract's [state](crate::core::state::StateV2).  Only the admin can change these
+/// settings, so the accounts that slash bonds cannot choose where the slashed funds are sent.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the update verifier bond settings v1 struct, provided by conversion from
+/// an [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn update_verifier_bond_settings(
+    deps: DepsMutC,
+    info: MessageInfo,
+    msg: UpdateVerifierBondSettingsV1,
+) -> EntryPointResponse {
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let mut state = config_read_v2(deps.storage).load()?;
+    state.verifier_bond_settings = msg.settings;
+    config_v2(deps.storage).save(&state)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::UpdateVerifierBondSettings)
+                .set_new_value(&state.verifier_bond_settings),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::config_read_v2;
+    use crate::core::types::contract_role::ContractRole;
+    use crate::core::types::verifier_bond_settings::VerifierBondSettings;
+    use crate::execute::grant_role::{grant_role, GrantRoleV1};
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, mock_info_with_nhash, single_attribute_for_key, test_instantiate_success,
+        InstArgs,
+    };
+    use crate::util::aliases::DepsC;
+    use crate::util::constants::{
+        ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY, VERIFIER_BOND_UNBONDING_BLOCKS,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{update_verifier_bond_settings, UpdateVerifierBondSettingsV1};
+
+    #[test]
+    fn test_valid_update_verifier_bond_settings_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        assert_settings(&deps.as_ref(), VerifierBondSettings::default());
+        let settings = VerifierBondSettings::new(DEFAULT_FEE_ADDRESS.to_string().to_some())
+            .with_unbonding_blocks(1000);
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::UpdateVerifierBondSettings {
+                settings: settings.clone(),
+            },
+        )
+        .expect("updating the settings should succeed");
+        assert_eq!(
+            EventType::UpdateVerifierBondSettings.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            format!(
+                "slash_beneficiary={}, unbonding_blocks=1000",
+                DEFAULT_FEE_ADDRESS
+            ),
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the new settings should be emitted",
+        );
+        assert_settings(&deps.as_ref(), settings);
+        let response = update_verifier_bond_settings(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateVerifierBondSettingsV1::new(VerifierBondSettings::default()),
+        )
+        .expect("restoring the default settings should succeed");
+        assert_eq!(
+            format!(
+                "slash_beneficiary=treasury, unbonding_blocks={}",
+                VERIFIER_BOND_UNBONDING_BLOCKS
+            ),
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the default settings should be emitted",
+        );
+        assert_settings(&deps.as_ref(), VerifierBondSettings::default());
+    }
+
+    #[test]
+    fn test_invalid_update_verifier_bond_settings_for_non_admin() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        grant_role(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            GrantRoleV1::new(ContractRole::VerifierManager, DEFAULT_FEE_ADDRESS),
+        )
+        .expect("granting the role should succeed");
+        let error = update_verifier_bond_settings(
+            deps.as_mut(),
+            mock_info(DEFAULT_FEE_ADDRESS, &[]),
+            UpdateVerifierBondSettingsV1::new(VerifierBondSettings::new(
+                DEFAULT_FEE_ADDRESS.to_string().to_some(),
+            )),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected an account that can slash bonds to be unable to choose the beneficiary, but got: {:?}",
+            error,
+        );
+        assert_settings(&deps.as_ref(), VerifierBondSettings::default());
+    }
+
+    #[test]
+    fn test_invalid_update_verifier_bond_settings_for_invalid_beneficiary() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::UpdateVerifierBondSettings {
+                settings: VerifierBondSettings::new("not-an-address".to_string().to_some()),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidMessageFields { .. }),
+            "expected an invalid beneficiary to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_verifier_bond_settings_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = update_verifier_bond_settings(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            UpdateVerifierBondSettingsV1::new(VerifierBondSettings::default()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+
+    fn assert_settings(deps: &DepsC, expected_settings: VerifierBondSettings) {
+        assert_eq!(
+            expected_settings,
+            config_read_v2(deps.storage)
+                .load()
+                .expect("the contract state should load")
+                .verifier_bond_settings,
+            "the expected verifier bond settings should be stored",
+        );
+    }
+}
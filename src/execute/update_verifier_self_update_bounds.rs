@@ -0,0 +1,261 @@
+use cosmwasm_std::{MessageInfo, Response};
+
+use crate::core::state::{insert_verifier_self_update_bounds, load_asset_definition_v2_by_type};
+use crate::core::types::contract_role::ContractRole;
+use crate::core::types::verifier_self_update_bounds::VerifierSelfUpdateBounds;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_funds_are_empty, check_role},
+        event_attributes::{EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::UpdateVerifierSelfUpdateBounds](crate::core::msg::ExecuteMsg::UpdateVerifierSelfUpdateBounds)
+/// for ease of use in the underlying [update_verifier_self_update_bounds](self::update_verifier_self_update_bounds) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the definition containing the verifier.
+/// * `verifier_address` The bech32 address of the verifier to which the bounds apply.
+/// * `bounds` The limits on the changes that the verifier can make to its own detail.
+#[derive(Clone, PartialEq)]
+pub struct UpdateVerifierSelfUpdateBoundsV1 {
+    pub asset_type: String,
+    pub verifier_address: String,
+    pub bounds: VerifierSelfUpdateBounds,
+}
+impl UpdateVerifierSelfUpdateBoundsV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type of the definition containing the verifier.
+    /// * `verifier_address` The bech32 address of the verifier to which the bounds apply.
+    /// * `bounds` The limits on the changes that the verifier can make to its own detail.
+    pub fn new<S1: Into<String>, S2: Into<String>>(
+        asset_type: S1,
+        verifier_address: S2,
+        bounds: VerifierSelfUpdateBounds,
+    ) -> Self {
+        UpdateVerifierSelfUpdateBoundsV1 {
+            asset_type: asset_type.into(),
+            verifier_address: verifier_address.into(),
+            bounds,
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [UpdateVerifierSelfUpdateBounds](crate::core::msg::ExecuteMsg::UpdateVerifierSelfUpdateBounds)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<UpdateVerifierSelfUpdateBoundsV1> {
+        match msg {
+            ExecuteMsg::UpdateVerifierSelfUpdateBounds {
+                asset_type,
+                verifier_address,
+                bounds,
+            } => {
+                UpdateVerifierSelfUpdateBoundsV1::new(asset_type, verifier_address, bounds).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::UpdateVerifierSelfUpdateBounds".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::UpdateVerifierSelfUpdateBounds](crate::core::msg::ExecuteMsg::UpdateVerifierSelfUpdateBounds)
+/// message is provided.  Replaces the [VerifierSelfUpdateBounds](crate::core::types::verifier_self_update_bounds::VerifierSelfUpdateBounds)
+/// that limit the changes a verifier can make with the [SelfUpdateAssetVerifier](crate::core::msg::ExecuteMsg::SelfUpdateAssetVerifier)
+/// route.  The request will be rejected if the verifier does not exist within the asset definition.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the update verifier self update bounds v1 struct, provided by conversion
+/// from an [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn update_verifier_self_update_bounds(
+    deps: DepsMutC,
+    info: MessageInfo,
+    msg: UpdateVerifierSelfUpdateBoundsV1,
+) -> EntryPointResponse {
+    check_role(&deps.as_ref(), &info, ContractRole::VerifierManager)?;
+    check_funds_are_empty(&info)?;
+    let asset_definition = load_asset_definition_v2_by_type(deps.storage, &msg.asset_type)?;
+    if !asset_definition
+        .verifiers
+        .iter()
+        .any(|verifier| verifier.address == msg.verifier_address)
+    {
+        return ContractError::NotFound {
+            explanation: format!(
+                "verifier with address {} not found for asset definition for type {}",
+                msg.verifier_address, asset_definition.asset_type
+            ),
+        }
+        .to_err();
+    }
+    insert_verifier_self_update_bounds(
+        deps.storage,
+        &asset_definition.asset_type,
+        &msg.verifier_address,
+        &msg.bounds,
+    )?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::UpdateVerifierSelfUpdateBounds)
+                .set_asset_type(&asset_definition.asset_type)
+                .set_verifier(&msg.verifier_address),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Uint128;
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::load_verifier_self_update_bounds;
+    use crate::core::types::verifier_self_update_bounds::{
+        VerifierDetailField, VerifierSelfUpdateBounds,
+    };
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, mock_info_with_nhash, single_attribute_for_key, test_instantiate_success,
+        InstArgs,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, ASSET_TYPE_KEY, VERIFIER_ADDRESS_KEY};
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{update_verifier_self_update_bounds, UpdateVerifierSelfUpdateBoundsV1};
+
+    #[test]
+    fn test_valid_update_verifier_self_update_bounds_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let bounds = VerifierSelfUpdateBounds::new(
+            Uint128::new(5000).to_some(),
+            vec![VerifierDetailField::FeeDestinations],
+        );
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::UpdateVerifierSelfUpdateBounds {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
+                bounds: bounds.clone(),
+            },
+        )
+        .expect("updating the bounds should succeed");
+        assert_eq!(
+            EventType::UpdateVerifierSelfUpdateBounds
+                .event_name()
+                .as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_ASSET_TYPE,
+            single_attribute_for_key(&response, ASSET_TYPE_KEY),
+            "the asset type should be emitted",
+        );
+        assert_eq!(
+            DEFAULT_VERIFIER_ADDRESS,
+            single_attribute_for_key(&response, VERIFIER_ADDRESS_KEY),
+            "the verifier address should be emitted",
+        );
+        assert_eq!(
+            bounds,
+            load_verifier_self_update_bounds(
+                deps.as_ref().storage,
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+            )
+            .expect("the bounds should load"),
+            "the bounds should be stored",
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_verifier_self_update_bounds_for_unknown_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = update_verifier_self_update_bounds(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateVerifierSelfUpdateBoundsV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_SENDER_ADDRESS,
+                VerifierSelfUpdateBounds::default(),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected a missing verifier to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_verifier_self_update_bounds_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = update_verifier_self_update_bounds(
+            deps.as_mut(),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &[]),
+            UpdateVerifierSelfUpdateBoundsV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                VerifierSelfUpdateBounds::new(Uint128::new(1000000).to_some(), vec![]),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected a verifier to be unable to set its own bounds, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_update_verifier_self_update_bounds_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = update_verifier_self_update_bounds(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            UpdateVerifierSelfUpdateBoundsV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                VerifierSelfUpdateBounds::default(),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+}
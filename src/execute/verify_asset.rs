@@ -10,7 +10,7 @@ use crate::util::aliases::{AssetResult, EntryPointResponse};
 use crate::util::contract_helpers::check_funds_are_empty;
 use crate::util::event_attributes::{EventAttributes, EventType};
 use crate::util::traits::ResultExtensions;
-use cosmwasm_std::{MessageInfo, Response};
+use cosmwasm_std::{Env, MessageInfo, Response};
 
 /// A transformation of [ExecuteMsg::VerifyAsset](crate::core::msg::ExecuteMsg::VerifyAsset)
 /// for ease of use in the underlying [verify_asset](self::verify_asset) function.
@@ -82,12 +82,15 @@ impl VerifyAssetV1 {
 /// * `repository` A helper collection of traits that allows complex lookups of scope values and
 /// emits messages to construct the process of verification as a collection of messages to produce
 /// in the function's result.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
 /// of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` An instance of the verify asset v1 struct, provided by conversion from an
 /// [ExecuteMsg](crate::core::msg::ExecuteMsg).
 pub fn verify_asset<'a, T>(
     repository: T,
+    env: Env,
     info: MessageInfo,
     msg: VerifyAssetV1,
 ) -> EntryPointResponse
@@ -128,6 +131,7 @@ where
         msg.success,
         msg.message,
         msg.access_routes,
+        env.block.height,
     )?;
 
     // construct/emit verification attribute
@@ -146,22 +150,42 @@ where
 #[cfg(test)]
 #[cfg(feature = "enable-test-utils")]
 mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, CosmosMsg, Uint128};
     use provwasm_mocks::mock_dependencies;
 
     use crate::{
         core::{
             error::ContractError,
+            state::{latest_protocol_fee_store_ro, load_accrued_fees},
             types::{
-                asset_identifier::AssetIdentifier, asset_onboarding_status::AssetOnboardingStatus,
+                asset_definition::AssetDefinitionInputV2,
+                asset_identifier::AssetIdentifier,
+                asset_onboarding_status::AssetOnboardingStatus,
+                fee_destination::FeeDestinationV2,
+                fee_discount::FeeDiscount,
+                protocol_fee::{ProtocolFee, ProtocolFeeAmount},
+                verifier_detail::VerifierDetailV2,
             },
         },
+        execute::{
+            onboard_asset::onboard_asset,
+            update_protocol_fee::{update_protocol_fee, UpdateProtocolFeeV1},
+        },
         service::{
             asset_meta_repository::AssetMetaRepository, asset_meta_service::AssetMetaService,
         },
         testutil::{
             onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset},
-            test_constants::{DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS},
-            test_utilities::{empty_mock_info, mock_info_with_nhash, setup_test_suite, InstArgs},
+            test_constants::{
+                DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS, DEFAULT_ONBOARDING_COST,
+                DEFAULT_ONBOARDING_DENOM, DEFAULT_SCOPE_ADDRESS, DEFAULT_SENDER_ADDRESS,
+                DEFAULT_VERIFIER_ADDRESS,
+            },
+            test_utilities::{
+                empty_mock_info, get_bank_sends, get_default_asset_definition_input,
+                mock_info_with_nhash, setup_test_suite, InstArgs, MockOwnedDeps,
+            },
             verify_asset_helpers::{test_verify_asset, TestVerifyAsset},
         },
         util::traits::OptionExtensions,
@@ -176,6 +200,7 @@ mod tests {
         test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
         let err = verify_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             mock_info_with_nhash(DEFAULT_VERIFIER_ADDRESS, 420),
             TestVerifyAsset::default_verify_asset(),
         )
@@ -194,6 +219,7 @@ mod tests {
 
         let err = verify_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
             VerifyAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
@@ -232,6 +258,7 @@ mod tests {
         let info = empty_mock_info("tp129z88fpzthllrdzktw98cck3ypd34wv77nqfyl");
         let err = verify_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             info.clone(),
             VerifyAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
@@ -276,6 +303,7 @@ mod tests {
 
         let result = verify_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
             VerifyAssetV1 {
                 identifier: AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
@@ -301,6 +329,7 @@ mod tests {
         test_verify_asset(&mut deps, TestVerifyAsset::default()).unwrap();
         let err = verify_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
             TestVerifyAsset::default_verify_asset(),
         )
@@ -335,6 +364,7 @@ mod tests {
         test_verify_asset(&mut deps, TestVerifyAsset::default_with_success(false)).unwrap();
         let err = verify_asset(
             AssetMetaService::new(deps.as_mut()),
+            mock_env(),
             empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
             TestVerifyAsset::default_verify_asset(),
         )
@@ -392,4 +422,153 @@ mod tests {
             "the asset should be in denied status after onboarding with a status of success = false",
         );
     }
+
+    #[test]
+    fn test_verify_asset_pays_protocol_fee_charged_during_onboarding() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        set_protocol_fee(&mut deps, ProtocolFeeAmount::BasisPoints(1000));
+        let default_onboard = TestOnboardAsset::default();
+        let err = onboard_asset(
+            AssetMetaService::new(deps.as_mut()),
+            mock_env(),
+            default_onboard.info,
+            default_onboard.onboard_asset,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, ContractError::InvalidFunds(_)),
+            "onboarding should require the protocol fee in addition to the onboarding cost, but got: {:?}",
+            err,
+        );
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset::default_with_amount(DEFAULT_ONBOARDING_COST + 100),
+        )
+        .expect("onboarding with the onboarding cost plus the protocol fee should succeed");
+        // Changing the protocol fee after onboarding should not alter the fee paid out
+        set_protocol_fee(&mut deps, ProtocolFeeAmount::Flat(Uint128::new(5000)));
+        let response = test_verify_asset(&mut deps, TestVerifyAsset::default())
+            .expect("verification should succeed");
+        assert_eq!(
+            vec![
+                (
+                    DEFAULT_VERIFIER_ADDRESS.to_string(),
+                    vec![coin(DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM)]
+                ),
+                (
+                    DEFAULT_FEE_ADDRESS.to_string(),
+                    vec![coin(100, DEFAULT_ONBOARDING_DENOM)]
+                ),
+            ],
+            get_bank_sends(&response),
+            "the verifier should receive the onboarding cost and the collector should receive the protocol fee charged during onboarding",
+        );
+        assert!(
+            latest_protocol_fee_store_ro(deps.as_ref().storage)
+                .may_load(DEFAULT_SCOPE_ADDRESS.as_bytes())
+                .unwrap()
+                .is_none(),
+            "the escrowed protocol fee should be removed after verification",
+        );
+    }
+
+    #[test]
+    fn test_verify_asset_accrues_protocol_fee_for_accruing_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![AssetDefinitionInputV2 {
+                    verifiers: vec![VerifierDetailV2::new(
+                        DEFAULT_VERIFIER_ADDRESS,
+                        Uint128::new(DEFAULT_ONBOARDING_COST),
+                        DEFAULT_ONBOARDING_DENOM,
+                        vec![],
+                        None,
+                    )
+                    .with_accrue_fees(true)],
+                    ..get_default_asset_definition_input()
+                }],
+                ..Default::default()
+            },
+        );
+        set_protocol_fee(&mut deps, ProtocolFeeAmount::BasisPoints(1000));
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset::default_with_amount(DEFAULT_ONBOARDING_COST + 100),
+        )
+        .expect("onboarding with the onboarding cost plus the protocol fee should succeed");
+        let response = test_verify_asset(&mut deps, TestVerifyAsset::default())
+            .expect("verification should succeed");
+        assert!(
+            !response
+                .messages
+                .iter()
+                .any(|msg| matches!(msg.msg, CosmosMsg::Bank(_))),
+            "no bank sends should be produced when the verifier accrues fees",
+        );
+        assert_eq!(
+            vec![coin(100, DEFAULT_ONBOARDING_DENOM)],
+            load_accrued_fees(deps.as_ref().storage, DEFAULT_FEE_ADDRESS).unwrap(),
+            "the protocol fee collector should be credited the protocol fee",
+        );
+        assert_eq!(
+            vec![coin(DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM)],
+            load_accrued_fees(deps.as_ref().storage, DEFAULT_VERIFIER_ADDRESS).unwrap(),
+            "the verifier should be credited the onboarding cost",
+        );
+    }
+
+    #[test]
+    fn test_verify_asset_pays_discounted_fee_destinations() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![AssetDefinitionInputV2 {
+                    verifiers: vec![VerifierDetailV2::new(
+                        DEFAULT_VERIFIER_ADDRESS,
+                        Uint128::new(DEFAULT_ONBOARDING_COST),
+                        DEFAULT_ONBOARDING_DENOM,
+                        vec![FeeDestinationV2::new(
+                            DEFAULT_FEE_ADDRESS,
+                            Uint128::new(300),
+                        )],
+                        None,
+                    )],
+                    ..get_default_asset_definition_input()
+                }
+                .with_fee_discounts(vec![FeeDiscount::new(DEFAULT_SENDER_ADDRESS, 2500)])],
+                ..Default::default()
+            },
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default_with_amount(750))
+            .expect("onboarding with the discounted onboarding cost should succeed");
+        let response = test_verify_asset(&mut deps, TestVerifyAsset::default())
+            .expect("verification should succeed");
+        assert_eq!(
+            vec![
+                (
+                    DEFAULT_FEE_ADDRESS.to_string(),
+                    vec![coin(225, DEFAULT_ONBOARDING_DENOM)]
+                ),
+                (
+                    DEFAULT_VERIFIER_ADDRESS.to_string(),
+                    vec![coin(525, DEFAULT_ONBOARDING_DENOM)]
+                ),
+            ],
+            get_bank_sends(&response),
+            "the fee destination and verifier should each receive their discounted share of the onboarding cost",
+        );
+    }
+
+    fn set_protocol_fee(deps: &mut MockOwnedDeps, amount: ProtocolFeeAmount) {
+        update_protocol_fee(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateProtocolFeeV1::new(Some(ProtocolFee::new(DEFAULT_FEE_ADDRESS, amount))),
+        )
+        .expect("the protocol fee should be updated");
+    }
 }
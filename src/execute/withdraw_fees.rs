@@ -0,0 +1,260 @@
+use cosmwasm_std::{BankMsg, MessageInfo, Response};
+
+use crate::core::state::withdraw_accrued_fees;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::check_funds_are_empty,
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::WithdrawFees](crate::core::msg::ExecuteMsg::WithdrawFees)
+/// for ease of use in the underlying [withdraw_fees](self::withdraw_fees) function.  The
+/// message contains no fields, as the sender is always the recipient of its own fees.
+#[derive(Clone, PartialEq)]
+pub struct WithdrawFeesV1;
+impl WithdrawFeesV1 {
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [WithdrawFees](crate::core::msg::ExecuteMsg::WithdrawFees)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<WithdrawFeesV1> {
+        match msg {
+            ExecuteMsg::WithdrawFees {} => WithdrawFeesV1.to_ok(),
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::WithdrawFees".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::WithdrawFees](crate::core::msg::ExecuteMsg::WithdrawFees)
+/// message is provided.  Sends every fee balance credited to the sender's address to the sender,
+/// and clears those balances from the contract's fee ledger.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `_msg` An instance of the withdraw fees v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn withdraw_fees(
+    deps: DepsMutC,
+    info: MessageInfo,
+    _msg: WithdrawFeesV1,
+) -> EntryPointResponse {
+    check_funds_are_empty(&info)?;
+    let balances = withdraw_accrued_fees(deps.storage, info.sender.as_str())?;
+    if balances.is_empty() {
+        return ContractError::NotFound {
+            explanation: format!("no accrued fees found for address [{}]", info.sender),
+        }
+        .to_err();
+    }
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("recipient", info.sender.as_str());
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::WithdrawFees)
+                .set_new_value(
+                    balances
+                        .iter()
+                        .map(|balance| balance.to_string())
+                        .collect::<Vec<String>>()
+                        .join(","),
+                )
+                .set_additional_metadata(&additional_metadata),
+        )
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: balances,
+        })
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::{accrue_fees, load_accrued_fees};
+    use crate::core::types::asset_definition::AssetDefinitionInputV2;
+    use crate::core::types::fee_destination::FeeDestinationV2;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_FEE_ADDRESS, DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM,
+        DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition_input, get_default_entity_detail,
+        mock_info_with_nhash, setup_test_suite, single_attribute_for_key, test_instantiate_success,
+        InstArgs,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{withdraw_fees, WithdrawFeesV1};
+
+    #[test]
+    fn test_valid_withdraw_fees_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        accrue_fees(
+            deps.as_mut().storage,
+            DEFAULT_FEE_ADDRESS,
+            &coin(150, DEFAULT_ONBOARDING_DENOM),
+        )
+        .expect("accruing fees should succeed");
+        accrue_fees(
+            deps.as_mut().storage,
+            DEFAULT_FEE_ADDRESS,
+            &coin(5, "otherdenom"),
+        )
+        .expect("accruing fees in a second denom should succeed");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_FEE_ADDRESS),
+            ExecuteMsg::WithdrawFees {},
+        )
+        .expect("the withdrawal should succeed");
+        assert_eq!(
+            EventType::WithdrawFees.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            "150nhash,5otherdenom",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the withdrawn balances should be emitted",
+        );
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "a single bank send should be produced"
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!(DEFAULT_FEE_ADDRESS, to_address);
+                assert_eq!(
+                    &vec![coin(150, DEFAULT_ONBOARDING_DENOM), coin(5, "otherdenom")],
+                    amount
+                );
+            }
+            msg => panic!("unexpected message emitted: {:?}", msg),
+        };
+        assert!(
+            load_accrued_fees(deps.as_ref().storage, DEFAULT_FEE_ADDRESS)
+                .unwrap()
+                .is_empty(),
+            "the withdrawn balances should be cleared from the ledger",
+        );
+    }
+
+    #[test]
+    fn test_fees_accrue_during_verification() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![AssetDefinitionInputV2 {
+                    verifiers: vec![VerifierDetailV2::new(
+                        DEFAULT_VERIFIER_ADDRESS,
+                        Uint128::new(DEFAULT_ONBOARDING_COST),
+                        DEFAULT_ONBOARDING_DENOM,
+                        vec![FeeDestinationV2::new(
+                            DEFAULT_FEE_ADDRESS,
+                            Uint128::new(400),
+                        )],
+                        get_default_entity_detail().to_some(),
+                    )
+                    .with_accrue_fees(true)],
+                    ..get_default_asset_definition_input()
+                }],
+                ..Default::default()
+            },
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        let response = test_verify_asset(&mut deps, TestVerifyAsset::default()).unwrap();
+        assert!(
+            !response
+                .messages
+                .iter()
+                .any(|msg| matches!(msg.msg, CosmosMsg::Bank(_))),
+            "no bank sends should be produced when fees accrue",
+        );
+        assert_eq!(
+            vec![coin(400, DEFAULT_ONBOARDING_DENOM)],
+            load_accrued_fees(deps.as_ref().storage, DEFAULT_FEE_ADDRESS).unwrap(),
+            "the fee destination should be credited its fee",
+        );
+        assert_eq!(
+            vec![coin(600, DEFAULT_ONBOARDING_DENOM)],
+            load_accrued_fees(deps.as_ref().storage, DEFAULT_VERIFIER_ADDRESS).unwrap(),
+            "the verifier should be credited the remainder",
+        );
+        withdraw_fees(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            WithdrawFeesV1,
+        )
+        .expect("the verifier should be able to withdraw its fees");
+        assert_eq!(
+            vec![coin(400, DEFAULT_ONBOARDING_DENOM)],
+            load_accrued_fees(deps.as_ref().storage, DEFAULT_FEE_ADDRESS).unwrap(),
+            "a withdrawal should not affect the balances of other recipients",
+        );
+    }
+
+    #[test]
+    fn test_invalid_withdraw_fees_for_empty_balance() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = withdraw_fees(
+            deps.as_mut(),
+            mock_info(DEFAULT_FEE_ADDRESS, &[]),
+            WithdrawFeesV1,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected the not found error to be returned when no fees have accrued, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_withdraw_fees_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = withdraw_fees(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_FEE_ADDRESS, 150),
+            WithdrawFeesV1,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            error,
+        );
+    }
+}
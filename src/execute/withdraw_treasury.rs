@@ -0,0 +1,234 @@
+use cosmwasm_std::{Coin, MessageInfo, Response};
+
+use crate::core::state::withdraw_from_treasury;
+use crate::core::types::contract_role::ContractRole;
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::{check_funds_are_empty, check_role},
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        functions::bank_send,
+        traits::ResultExtensions,
+    },
+};
+
+/// A transformation of [ExecuteMsg::WithdrawTreasury](crate::core::msg::ExecuteMsg::WithdrawTreasury)
+/// for ease of use in the underlying [withdraw_treasury](self::withdraw_treasury) function.
+///
+/// # Parameters
+///
+/// * `amount` The funds to withdraw from the contract treasury.
+/// * `recipient` The bech32 address of the account that will receive the withdrawn funds.
+#[derive(Clone, PartialEq)]
+pub struct WithdrawTreasuryV1 {
+    pub amount: Coin,
+    pub recipient: String,
+}
+impl WithdrawTreasuryV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `amount` The funds to withdraw from the contract treasury.
+    /// * `recipient` The bech32 address of the account that will receive the withdrawn funds.
+    pub fn new<S: Into<String>>(amount: Coin, recipient: S) -> Self {
+        WithdrawTreasuryV1 {
+            amount,
+            recipient: recipient.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [WithdrawTreasury](crate::core::msg::ExecuteMsg::WithdrawTreasury)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<WithdrawTreasuryV1> {
+        match msg {
+            ExecuteMsg::WithdrawTreasury { amount, recipient } => {
+                WithdrawTreasuryV1::new(amount, recipient).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::WithdrawTreasury".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::WithdrawTreasury](crate::core::msg::ExecuteMsg::WithdrawTreasury)
+/// message is provided.  Sends funds retained in the contract treasury to a recipient, reducing
+/// the tracked treasury balance.  Funds held in escrow for pending verifications are never
+/// included in the treasury balance, and can therefore never be withdrawn by this route.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the withdraw treasury v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn withdraw_treasury(
+    deps: DepsMutC,
+    info: MessageInfo,
+    msg: WithdrawTreasuryV1,
+) -> EntryPointResponse {
+    check_role(&deps.as_ref(), &info, ContractRole::TreasuryManager)?;
+    check_funds_are_empty(&info)?;
+    withdraw_from_treasury(deps.storage, &msg.amount)?;
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("recipient", &msg.recipient);
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::WithdrawTreasury)
+                .set_new_value(&msg.amount)
+                .set_additional_metadata(&additional_metadata),
+        )
+        .add_message(bank_send(
+            &msg.recipient,
+            msg.amount.amount.u128(),
+            &msg.amount.denom,
+        ))
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::{deposit_to_treasury, treasury_balances_store_ro};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS, DEFAULT_ONBOARDING_DENOM,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, mock_info_with_nhash, single_attribute_for_key, test_instantiate_success,
+        InstArgs,
+    };
+    use crate::util::constants::{ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY};
+    use crate::util::event_attributes::EventType;
+
+    use super::{withdraw_treasury, WithdrawTreasuryV1};
+
+    #[test]
+    fn test_valid_withdraw_treasury_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        deposit_to_treasury(deps.as_mut().storage, &coin(500, DEFAULT_ONBOARDING_DENOM))
+            .expect("depositing to the treasury should succeed");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::WithdrawTreasury {
+                amount: coin(200, DEFAULT_ONBOARDING_DENOM),
+                recipient: DEFAULT_FEE_ADDRESS.to_string(),
+            },
+        )
+        .expect("the withdrawal should succeed");
+        assert_eq!(
+            EventType::WithdrawTreasury.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            "200nhash",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the withdrawn amount should be emitted",
+        );
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "a single bank send should be produced"
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                assert_eq!(DEFAULT_FEE_ADDRESS, to_address);
+                assert_eq!(&vec![coin(200, DEFAULT_ONBOARDING_DENOM)], amount);
+            }
+            msg => panic!("unexpected message emitted: {:?}", msg),
+        };
+        assert_eq!(
+            300,
+            treasury_balances_store_ro(deps.as_ref().storage)
+                .load(DEFAULT_ONBOARDING_DENOM.as_bytes())
+                .expect("the treasury balance should exist")
+                .u128(),
+            "the treasury balance should be reduced by the withdrawn amount",
+        );
+    }
+
+    #[test]
+    fn test_invalid_withdraw_treasury_for_insufficient_balance() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        deposit_to_treasury(deps.as_mut().storage, &coin(100, DEFAULT_ONBOARDING_DENOM))
+            .expect("depositing to the treasury should succeed");
+        let error = withdraw_treasury(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            WithdrawTreasuryV1::new(coin(101, DEFAULT_ONBOARDING_DENOM), DEFAULT_FEE_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InsufficientTreasuryBalance { .. }),
+            "expected the insufficient treasury balance error to be returned, but got: {:?}",
+            error,
+        );
+        let error = withdraw_treasury(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            WithdrawTreasuryV1::new(coin(1, "otherdenom"), DEFAULT_FEE_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InsufficientTreasuryBalance { .. }),
+            "expected a denom never held by the treasury to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_withdraw_treasury_for_invalid_sender() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = withdraw_treasury(
+            deps.as_mut(),
+            mock_info("not-the-admin", &[]),
+            WithdrawTreasuryV1::new(coin(1, DEFAULT_ONBOARDING_DENOM), DEFAULT_FEE_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected the unauthorized error to be returned when the sender is not the admin, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_withdraw_treasury_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = withdraw_treasury(
+            deps.as_mut(),
+            mock_info_with_nhash(DEFAULT_ADMIN_ADDRESS, 150),
+            WithdrawTreasuryV1::new(coin(1, DEFAULT_ONBOARDING_DENOM), DEFAULT_FEE_ADDRESS),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when the sender provides funds, but got: {:?}",
+            error,
+        );
+    }
+}
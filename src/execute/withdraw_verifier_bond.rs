@@ -0,0 +1,440 @@
+use cosmwasm_std::{Env, MessageInfo, Response};
+
+use crate::core::state::{
+    config_read_v2, deduct_verifier_bond, may_load_asset_definition_v2_by_type,
+    may_load_verifier_bond, may_load_verifier_bond_unlock_height, resolve_asset_definition_v2,
+    save_verifier_bond_unlock_height,
+};
+use crate::{
+    core::{error::ContractError, msg::ExecuteMsg},
+    util::{
+        aliases::{AssetResult, DepsMutC, EntryPointResponse},
+        contract_helpers::check_funds_are_empty,
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        functions::bank_send,
+        traits::{OptionExtensions, ResultExtensions},
+    },
+};
+
+/// A transformation of [ExecuteMsg::WithdrawVerifierBond](crate::core::msg::ExecuteMsg::WithdrawVerifierBond)
+/// for ease of use in the underlying [withdraw_verifier_bond](self::withdraw_verifier_bond) function.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type for which the bond was posted.
+#[derive(Clone, PartialEq)]
+pub struct WithdrawVerifierBondV1 {
+    pub asset_type: String,
+}
+impl WithdrawVerifierBondV1 {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_type` The asset type for which the bond was posted.
+    pub fn new<S: Into<String>>(asset_type: S) -> Self {
+        WithdrawVerifierBondV1 {
+            asset_type: asset_type.into(),
+        }
+    }
+
+    /// Attempts to create an instance of this struct from a provided execute msg.  If the provided
+    /// value is not of the [WithdrawVerifierBond](crate::core::msg::ExecuteMsg::WithdrawVerifierBond)
+    /// variant, then an [InvalidMessageType](crate::core::error::ContractError::InvalidMessageType)
+    /// error will be returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `msg` An execute msg provided by the contract's [execute](crate::contract::execute) function.
+    pub fn from_execute_msg(msg: ExecuteMsg) -> AssetResult<WithdrawVerifierBondV1> {
+        match msg {
+            ExecuteMsg::WithdrawVerifierBond { asset_type } => {
+                WithdrawVerifierBondV1::new(asset_type).to_ok()
+            }
+            _ => ContractError::InvalidMessageType {
+                expected_message_type: "ExecuteMsg::WithdrawVerifierBond".to_string(),
+            }
+            .to_err(),
+        }
+    }
+}
+
+/// The function used by [execute](crate::contract::execute) when an [ExecuteMsg::WithdrawVerifierBond](crate::core::msg::ExecuteMsg::WithdrawVerifierBond)
+/// message is provided.  Returns the sender's remaining collateral bond for an asset type.  The
+/// request will be rejected if the sender is still a verifier within the asset definition, including
+/// verifiers inherited from a parent asset type, because the bond must remain available for
+/// slashing while the verifier can still verify assets.  The first request only begins an unbonding
+/// period of the configured [unbonding_blocks](crate::core::types::verifier_bond_settings::VerifierBondSettings::unbonding_blocks),
+/// during which the bond can still be slashed.  A request made after the period has elapsed returns
+/// the bond.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg` An instance of the withdraw verifier bond v1 struct, provided by conversion from an
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).
+pub fn withdraw_verifier_bond(
+    deps: DepsMutC,
+    env: Env,
+    info: MessageInfo,
+    msg: WithdrawVerifierBondV1,
+) -> EntryPointResponse {
+    check_funds_are_empty(&info)?;
+    let asset_definition =
+        match may_load_asset_definition_v2_by_type(deps.storage, &msg.asset_type)? {
+            Some(definition) => resolve_asset_definition_v2(deps.storage, definition)?.to_some(),
+            None => None,
+        };
+    let asset_type = match asset_definition {
+        Some(definition)
+            if definition
+                .verifiers
+                .iter()
+                .any(|verifier| verifier.address == info.sender.as_str()) =>
+        {
+            return ContractError::Unauthorized {
+                explanation: format!(
+                    "verifier {} must be removed from the asset definition for type {} before its bond can be withdrawn",
+                    info.sender, definition.asset_type
+                ),
+            }
+            .to_err();
+        }
+        Some(definition) => definition.asset_type,
+        None => msg.asset_type,
+    };
+    let bond = match may_load_verifier_bond(deps.storage, &asset_type, info.sender.as_str())? {
+        Some(bond) => bond,
+        None => {
+            return ContractError::NotFound {
+                explanation: format!(
+                    "no bond exists for verifier {} for asset type {}",
+                    info.sender, asset_type
+                ),
+            }
+            .to_err()
+        }
+    };
+    match may_load_verifier_bond_unlock_height(deps.storage, &asset_type, info.sender.as_str())? {
+        Some(unlock_height) if env.block.height >= unlock_height => {}
+        Some(unlock_height) => {
+            return ContractError::VerifierBondUnbonding {
+                verifier_address: info.sender.to_string(),
+                asset_type,
+                unlock_height,
+            }
+            .to_err();
+        }
+        None => {
+            // The bond stays available for slashing until the unbonding period elapses, so a
+            // verifier cannot escape a penalty by withdrawing as soon as it is removed
+            let unbonding_blocks = config_read_v2(deps.storage)
+                .load()?
+                .verifier_bond_settings
+                .unbonding_blocks;
+            let unlock_height = env.block.height + unbonding_blocks;
+            save_verifier_bond_unlock_height(
+                deps.storage,
+                &asset_type,
+                info.sender.as_str(),
+                unlock_height,
+            )?;
+            let mut additional_metadata = EventAdditionalMetadata::new();
+            additional_metadata.add_metadata("unlock_height", unlock_height.to_string());
+            return Response::new()
+                .add_attributes(
+                    EventAttributes::new(EventType::WithdrawVerifierBond)
+                        .set_asset_type(&asset_type)
+                        .set_verifier(info.sender.as_str())
+                        .set_new_value(&bond)
+                        .set_additional_metadata(&additional_metadata),
+                )
+                .to_ok();
+        }
+    }
+    // Deducting the entire bond also removes its unbonding height
+    deduct_verifier_bond(deps.storage, &asset_type, info.sender.as_str(), &bond)?;
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::WithdrawVerifierBond)
+                .set_asset_type(&asset_type)
+                .set_verifier(info.sender.as_str())
+                .set_new_value(&bond),
+        )
+        .add_message(bank_send(&info.sender, bond.amount.u128(), &bond.denom))
+        .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, coins, BankMsg, CosmosMsg};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::error::ContractError;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::state::{delete_asset_definition_v2_by_qualifier, may_load_verifier_bond};
+    use crate::core::types::asset_qualifier::AssetQualifier;
+    use crate::core::types::scope_spec_identifier::ScopeSpecIdentifier;
+    use crate::core::types::verifier_bond_settings::VerifierBondSettings;
+    use crate::execute::post_verifier_bond::{post_verifier_bond, PostVerifierBondV1};
+    use crate::execute::update_verifier_bond_settings::{
+        update_verifier_bond_settings, UpdateVerifierBondSettingsV1,
+    };
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, env_at_height, get_default_asset_definition_input, mock_info_with_nhash,
+        setup_bonded_verifier, single_attribute_for_key, test_instantiate_success, InstArgs,
+    };
+    use crate::util::constants::{
+        ADDITIONAL_METADATA_KEY, ASSET_EVENT_TYPE_KEY, NEW_VALUE_KEY, NHASH,
+        VERIFIER_BOND_UNBONDING_BLOCKS,
+    };
+    use crate::util::event_attributes::EventType;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{withdraw_verifier_bond, WithdrawVerifierBondV1};
+
+    #[test]
+    fn test_valid_withdraw_verifier_bond_via_execute() {
+        let mut deps = mock_dependencies(&[]);
+        setup_bonded_verifier(&mut deps, 1000);
+        delete_asset_definition_v2_by_qualifier(
+            deps.as_mut().storage,
+            &AssetQualifier::asset_type(DEFAULT_ASSET_TYPE),
+            12345,
+            DEFAULT_ADMIN_ADDRESS,
+        )
+        .expect("deleting the asset definition should succeed");
+        let response = execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            ExecuteMsg::WithdrawVerifierBond {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+            },
+        )
+        .expect("requesting the withdrawal should succeed");
+        let unlock_height = mock_env().block.height + VERIFIER_BOND_UNBONDING_BLOCKS;
+        assert!(
+            response.messages.is_empty(),
+            "the bond should not be returned until the unbonding period elapses",
+        );
+        assert_eq!(
+            format!("[unlock_height={}]", unlock_height),
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the height at which the bond unlocks should be emitted",
+        );
+        let error = withdraw_verifier_bond(
+            deps.as_mut(),
+            env_at_height(unlock_height - 1),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            WithdrawVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                ContractError::VerifierBondUnbonding { unlock_height: height, .. } if height == unlock_height
+            ),
+            "expected the withdrawal to be rejected before the unbonding period elapses, but got: {:?}",
+            error,
+        );
+        let response = execute(
+            deps.as_mut(),
+            env_at_height(unlock_height),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            ExecuteMsg::WithdrawVerifierBond {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+            },
+        )
+        .expect("withdrawing the bond should succeed");
+        assert_eq!(
+            EventType::WithdrawVerifierBond.event_name().as_str(),
+            single_attribute_for_key(&response, ASSET_EVENT_TYPE_KEY),
+            "the proper event type should be emitted",
+        );
+        assert_eq!(
+            "1000nhash",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the withdrawn bond should be emitted",
+        );
+        assert_eq!(
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
+                amount: coins(1000, NHASH),
+            })],
+            response
+                .messages
+                .into_iter()
+                .map(|msg| msg.msg)
+                .collect::<Vec<_>>(),
+            "the bond should be returned to the verifier",
+        );
+        assert!(
+            may_load_verifier_bond(
+                deps.as_ref().storage,
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS
+            )
+            .unwrap()
+            .is_none(),
+            "the withdrawn bond should be removed from storage",
+        );
+    }
+
+    #[test]
+    fn test_withdraw_verifier_bond_uses_configured_unbonding_blocks() {
+        let mut deps = mock_dependencies(&[]);
+        setup_bonded_verifier(&mut deps, 1000);
+        update_verifier_bond_settings(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateVerifierBondSettingsV1::new(
+                VerifierBondSettings::default().with_unbonding_blocks(10),
+            ),
+        )
+        .expect("updating the bond settings should succeed");
+        delete_asset_definition_v2_by_qualifier(
+            deps.as_mut().storage,
+            &AssetQualifier::asset_type(DEFAULT_ASSET_TYPE),
+            12345,
+            DEFAULT_ADMIN_ADDRESS,
+        )
+        .expect("deleting the asset definition should succeed");
+        let response = withdraw_verifier_bond(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            WithdrawVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .expect("requesting the withdrawal should succeed");
+        let unlock_height = mock_env().block.height + 10;
+        assert_eq!(
+            format!("[unlock_height={}]", unlock_height),
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the configured unbonding period should be used to derive the unlock height",
+        );
+        let response = withdraw_verifier_bond(
+            deps.as_mut(),
+            env_at_height(unlock_height),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            WithdrawVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .expect("withdrawing the bond after the configured unbonding period should succeed");
+        assert_eq!(
+            "1000nhash",
+            single_attribute_for_key(&response, NEW_VALUE_KEY),
+            "the withdrawn bond should be emitted",
+        );
+    }
+
+    #[test]
+    fn test_invalid_withdraw_verifier_bond_for_active_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        setup_bonded_verifier(&mut deps, 1000);
+        let error = withdraw_verifier_bond(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            WithdrawVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected a verifier within the asset definition to be unable to withdraw, but got: {:?}",
+            error,
+        );
+        assert_eq!(
+            Some(coin(1000, NHASH)),
+            may_load_verifier_bond(
+                deps.as_ref().storage,
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS
+            )
+            .unwrap(),
+            "the bond should remain in storage",
+        );
+    }
+
+    #[test]
+    fn test_invalid_withdraw_verifier_bond_for_inherited_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        let mut child_input = get_default_asset_definition_input()
+            .with_parent_asset_type(DEFAULT_ASSET_TYPE.to_some());
+        child_input.asset_type = "child".to_string();
+        child_input.scope_spec_identifier =
+            ScopeSpecIdentifier::address("scopespec-child").to_serialized_enum();
+        child_input.verifiers = vec![];
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![get_default_asset_definition_input(), child_input],
+                ..InstArgs::default()
+            },
+        );
+        post_verifier_bond(
+            deps.as_mut(),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &coins(1000, NHASH)),
+            PostVerifierBondV1::new("child"),
+        )
+        .expect("a verifier inherited from the parent should be able to post a bond");
+        let error = withdraw_verifier_bond(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_VERIFIER_ADDRESS),
+            WithdrawVerifierBondV1::new("child"),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::Unauthorized { .. }),
+            "expected a verifier inherited from the parent to be unable to withdraw, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_withdraw_verifier_bond_for_missing_bond() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let error = withdraw_verifier_bond(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info("no-bond-address"),
+            WithdrawVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::NotFound { .. }),
+            "expected a missing bond to be rejected, but got: {:?}",
+            error,
+        );
+    }
+
+    #[test]
+    fn test_invalid_withdraw_verifier_bond_for_provided_funds() {
+        let mut deps = mock_dependencies(&[]);
+        setup_bonded_verifier(&mut deps, 1000);
+        let error = withdraw_verifier_bond(
+            deps.as_mut(),
+            mock_env(),
+            mock_info_with_nhash(DEFAULT_VERIFIER_ADDRESS, 150),
+            WithdrawVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, ContractError::InvalidFunds(_)),
+            "expected the invalid funds error to be returned when funds are provided, but got: {:?}",
+            error,
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::core::msg::InitMsg;
 use crate::core::state::{config_v2, insert_asset_definition_v2, StateV2};
+use crate::execute::register_verifier::register_verifier_details;
 use crate::migrate::version_info::migrate_version_info;
 use crate::util::aliases::{DepsMutC, EntryPointResponse};
 use crate::util::contract_helpers::check_funds_are_empty;
@@ -46,7 +47,8 @@ pub fn init_contract(
     // append new definitions. When no definitions are supplied, this contract will not be able to
     // take execution input until they are
     for input in msg.asset_definitions.iter() {
-        let asset_definition = input.as_asset_definition()?;
+        let mut asset_definition = input.as_asset_definition()?;
+        register_verifier_details(deps.storage, &mut asset_definition)?;
         // Create a new state storage for the provided asset definition
         insert_asset_definition_v2(deps.storage, &asset_definition)?;
         // Default to true for name bind if no value is specified.
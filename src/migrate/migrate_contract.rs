@@ -1,8 +1,18 @@
-use cosmwasm_std::{Response, Storage};
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Env, Order, Response, StdResult, Storage};
 use semver::Version;
 
 use crate::core::msg::MigrationOptions;
-use crate::core::state::config_v2;
+use crate::core::state::{
+    asset_definitions_v2, asset_type_statistics_store, asset_type_statistics_store_ro, config_v2,
+    insert_pending_verification_scope, insert_verifier_registration,
+    latest_verifier_detail_store_ro, may_load_verifier_registration, replace_asset_definition_v2,
+    update_verifier_metrics, verifier_metrics_store,
+};
+use crate::core::types::onboarding_statistics::OnboardingStatistics;
+use crate::core::types::verifier_registration::VerifierRegistration;
+use crate::query::query_asset_scope_attribute::query_scope_attribute_by_scope_address;
 use crate::util::event_attributes::EventAdditionalMetadata;
 use crate::util::scope_address_utils::bech32_string_to_addr;
 use crate::{
@@ -23,14 +33,29 @@ use super::version_info::{
 /// # Parameters
 ///
 /// * `deps` A DepsMutC provided by cosmwasm in the migrate entrypoint.
+/// * `env` An environment object provided by cosmwasm in the migrate entrypoint.
 /// * `options` An optional instance of [MigrationOptions](crate::core::msg::MigrationOptions) that
 /// dictates whether or not to execute optional functionality during the migration.
-pub fn migrate_contract(deps: DepsMutC, options: Option<MigrationOptions>) -> EntryPointResponse {
+pub fn migrate_contract(
+    mut deps: DepsMutC,
+    env: Env,
+    options: Option<MigrationOptions>,
+) -> EntryPointResponse {
     // Ensure the migration is not attempting to revert to an old version or something crazier
     check_valid_migration_versioning(deps.storage)?;
     // Store the new version info
     let new_version_info = migrate_version_info(deps.storage)?;
     let mut additional_metadata = EventAdditionalMetadata::new();
+    migrate_verifier_pending_asset_counts(deps.storage)?;
+    migrate_pending_verification_scopes(&mut deps)?;
+    migrate_asset_type_statistics(deps.storage)?;
+    let registered_verifier_count = migrate_verifier_registrations(deps.storage, &env)?;
+    if registered_verifier_count > 0 {
+        additional_metadata.add_metadata(
+            "registered_verifier_count",
+            registered_verifier_count.to_string(),
+        );
+    }
     if let Some(options) = options {
         // Only load and update the state if any options have actually been specified
         if options.has_changes() {
@@ -39,6 +64,8 @@ pub fn migrate_contract(deps: DepsMutC, options: Option<MigrationOptions>) -> En
             if let Some(new_admin_address) = options.new_admin_address {
                 // Only set a new specified admin if it is a legitimate bech32 Provenance Blockchain address
                 state.admin = bech32_string_to_addr(&new_admin_address)?;
+                // Any outstanding admin proposal was made by the previous admin and is discarded
+                state.pending_admin = None;
                 additional_metadata.add_metadata("new_admin_address", &new_admin_address);
             }
             // Persist all changes to the state
@@ -85,14 +112,197 @@ fn check_valid_migration_versioning(storage: &mut dyn Storage) -> AssetResult<()
     Ok(())
 }
 
+/// Populates the global verifier registry from the asset definitions stored before it existed.
+/// Each unregistered verifier is registered with the values from the first asset type, in
+/// ascending order, that contains it.  Verifier details in other asset types with different fees
+/// are marked as [overriding the registered fees](crate::core::types::verifier_detail::VerifierDetailV2::overrides_registered_fees),
+/// ensuring that the migration never changes the fees charged for an asset type.  Entity details
+/// are only kept in the registry, so they are removed from the stored asset definitions.  Returns
+/// the number of verifiers that were registered.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract's internal storage for data manipulation.
+/// * `env` An environment object provided by cosmwasm in the migrate entrypoint.  Changed asset
+/// definitions are attributed to the contract's own address.
+fn migrate_verifier_registrations(storage: &mut dyn Storage, env: &Env) -> AssetResult<usize> {
+    let asset_definitions = asset_definitions_v2()
+        .range(storage, None, None, Order::Ascending)
+        .map(|result| result.map(|(_, definition)| definition))
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut registered_verifier_count = 0;
+    for mut asset_definition in asset_definitions {
+        let mut definition_changed = false;
+        for verifier in asset_definition.verifiers.iter_mut() {
+            match may_load_verifier_registration(storage, &verifier.address)? {
+                Some(registration) => {
+                    if !verifier.overrides_registered_fees
+                        && !registration.has_matching_fees(verifier)
+                    {
+                        verifier.overrides_registered_fees = true;
+                        definition_changed = true;
+                    }
+                }
+                None => {
+                    insert_verifier_registration(
+                        storage,
+                        &VerifierRegistration::from_verifier_detail(verifier),
+                    )?;
+                    registered_verifier_count += 1;
+                }
+            }
+        }
+        // Registered entity details are resolved from the registry, so stored copies are removed
+        definition_changed |= asset_definition
+            .verifiers
+            .iter()
+            .any(|verifier| verifier.entity_detail.is_some());
+        if definition_changed {
+            replace_asset_definition_v2(
+                storage,
+                &asset_definition,
+                env.block.height,
+                env.contract.address.as_str(),
+            )?;
+        }
+    }
+    registered_verifier_count.to_ok()
+}
+
+/// Recounts the [pending_assets](crate::core::types::verifier_metrics::VerifierMetrics::pending_assets)
+/// of every verifier from the assets that are currently awaiting verification.  Assets onboarded
+/// before verifier metrics existed were never counted, so this ensures that every verifier's
+/// pending asset count is accurate after the migration.  The remaining metrics are left unchanged.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract's internal storage for data manipulation.
+fn migrate_verifier_pending_asset_counts(storage: &mut dyn Storage) -> AssetResult<()> {
+    let mut pending_asset_counts: BTreeMap<String, u64> = BTreeMap::new();
+    for result in latest_verifier_detail_store_ro(storage).range(None, None, Order::Ascending) {
+        let (_, verifier_detail) = result?;
+        *pending_asset_counts
+            .entry(verifier_detail.address)
+            .or_default() += 1;
+    }
+    let verifier_addresses = verifier_metrics_store(storage)
+        .range(None, None, Order::Ascending)
+        .map(|result| result.map(|(address, _)| address))
+        .collect::<StdResult<Vec<_>>>()?;
+    // Verifiers with stored metrics but no pending assets must also be recounted, to zero
+    for address in verifier_addresses {
+        let address =
+            String::from_utf8(address).map_err(|e| ContractError::generic(e.to_string()))?;
+        pending_asset_counts.entry(address).or_default();
+    }
+    for (address, pending_assets) in pending_asset_counts {
+        update_verifier_metrics(storage, &address, |metrics| {
+            metrics.pending_assets = pending_assets
+        })?;
+    }
+    Ok(())
+}
+
+/// Indexes every asset that is currently awaiting verification by its asset type and verifier.
+/// Assets onboarded before the [pending verification index](crate::core::state::pending_verification_scopes_store)
+/// existed are otherwise invisible to the routes that look up a verifier's pending assets.  Indexing
+/// an asset that is already indexed has no effect.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework, used to look up the scope
+/// attribute of each pending asset.
+fn migrate_pending_verification_scopes(deps: &mut DepsMutC) -> AssetResult<()> {
+    let pending_verifications = latest_verifier_detail_store_ro(deps.storage)
+        .range(None, None, Order::Ascending)
+        .map(|result| {
+            let (scope_address, verifier_detail) = result?;
+            let scope_address = String::from_utf8(scope_address)
+                .map_err(|e| ContractError::generic(e.to_string()))?;
+            Ok((scope_address, verifier_detail.address))
+        })
+        .collect::<AssetResult<Vec<_>>>()?;
+    for (scope_address, verifier_address) in pending_verifications {
+        let attribute = query_scope_attribute_by_scope_address(&deps.as_ref(), &scope_address)?;
+        insert_pending_verification_scope(
+            deps.storage,
+            &attribute.asset_type,
+            &verifier_address,
+            &scope_address,
+        )?;
+    }
+    Ok(())
+}
+
+/// Combines the [OnboardingStatistics](crate::core::types::onboarding_statistics::OnboardingStatistics)
+/// that were recorded under a non-lowercase asset type into the statistics of the lowercase asset
+/// type.  Counters were previously keyed on the casing provided by each requestor, which split the
+/// counters of a single asset type across multiple entries.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract's internal storage for data manipulation.
+fn migrate_asset_type_statistics(storage: &mut dyn Storage) -> AssetResult<()> {
+    let mixed_case_statistics = asset_type_statistics_store_ro(storage)
+        .range(None, None, Order::Ascending)
+        .map(|result| {
+            let (asset_type, statistics) = result?;
+            let asset_type =
+                String::from_utf8(asset_type).map_err(|e| ContractError::generic(e.to_string()))?;
+            Ok((asset_type, statistics))
+        })
+        .collect::<AssetResult<Vec<_>>>()?
+        .into_iter()
+        .filter(|(asset_type, _)| asset_type.to_lowercase() != *asset_type)
+        .collect::<Vec<_>>();
+    let mut store = asset_type_statistics_store(storage);
+    for (asset_type, statistics) in mixed_case_statistics {
+        store.remove(asset_type.as_bytes());
+        store.update(
+            asset_type.to_lowercase().as_bytes(),
+            |existing| -> AssetResult<OnboardingStatistics> {
+                let mut existing = existing.unwrap_or_default();
+                existing.merge(&statistics);
+                existing.to_ok()
+            },
+        )?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 #[cfg(feature = "enable-test-utils")]
 mod tests {
+    use cosmwasm_std::testing::mock_env;
     use provwasm_mocks::mock_dependencies;
 
-    use crate::core::state::config_read_v2;
-    use crate::testutil::test_utilities::{test_instantiate_success, InstArgs};
+    use cosmwasm_std::{Order, StdResult, Uint128};
+    use uuid::Uuid;
+
+    use crate::core::state::{
+        asset_type_statistics_store, asset_type_statistics_store_ro, config_read_v2,
+        delete_pending_verification_scope, insert_asset_definition_v2,
+        load_asset_definition_v2_by_type, load_pending_verification_scope_addresses,
+        load_verifier_metrics, load_verifier_registration, verifier_registrations_store,
+    };
+    use crate::core::types::asset_definition::AssetDefinitionInputV2;
+    use crate::core::types::onboarding_statistics::OnboardingStatistics;
+    use crate::core::types::scope_spec_identifier::ScopeSpecIdentifier;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::core::types::verifier_metrics::VerifierMetrics;
+    use crate::execute::propose_admin::{propose_admin, ProposeAdminV1};
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_FEE_ADDRESS, DEFAULT_SCOPE_ADDRESS,
+        DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition_input, get_default_verifier_detail,
+        setup_test_suite, test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::DepsC;
     use crate::util::constants::ADDITIONAL_METADATA_KEY;
+    use crate::util::constants::NHASH;
     use crate::util::traits::OptionExtensions;
     use crate::{
         migrate::version_info::{set_version_info, VersionInfoV1},
@@ -113,7 +323,7 @@ mod tests {
             },
         )
         .expect("setting the initial version info should not fail");
-        let response = migrate_contract(deps.as_mut(), None).expect(
+        let response = migrate_contract(deps.as_mut(), mock_env(), None).expect(
             "a migration should be successful when the contract is migrating to a new version",
         );
         assert!(
@@ -152,6 +362,7 @@ mod tests {
         let new_admin_address = "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n";
         let response = migrate_contract(
             deps.as_mut(),
+            mock_env(),
             MigrationOptions {
                 // Address randomly generated externally
                 new_admin_address: new_admin_address.to_string().to_some(),
@@ -193,6 +404,259 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_migration_with_admin_change_clears_pending_admin() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        set_version_info(
+            deps.as_mut().storage,
+            &VersionInfoV1 {
+                contract: CONTRACT_NAME.to_string(),
+                version: "0.0.0".to_string(),
+            },
+        )
+        .expect("setting the initial version info should not fail");
+        propose_admin(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ProposeAdminV1::new(DEFAULT_FEE_ADDRESS),
+        )
+        .expect("proposing a new admin should succeed");
+        migrate_contract(
+            deps.as_mut(),
+            mock_env(),
+            MigrationOptions {
+                new_admin_address: "tp1hsqtppgy7mgd64q0uvk7q39qa7h8hp30urqs6n"
+                    .to_string()
+                    .to_some(),
+            }
+            .to_some(),
+        )
+        .expect("the migration should succeed");
+        assert!(
+            config_read_v2(deps.as_ref().storage)
+                .load()
+                .expect("expected the contract config to load without issue")
+                .pending_admin
+                .is_none(),
+            "a migration that changes the admin should discard the pending admin proposal",
+        );
+    }
+
+    #[test]
+    fn test_migration_recounts_verifier_pending_assets() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed");
+        // Simulate an asset that was onboarded before pending assets were counted, and a verifier
+        // with a stale pending count
+        verifier_metrics_store(deps.as_mut().storage)
+            .save(
+                DEFAULT_VERIFIER_ADDRESS.as_bytes(),
+                &VerifierMetrics::default(),
+            )
+            .expect("the default verifier's metrics should be reset");
+        verifier_metrics_store(deps.as_mut().storage)
+            .save(
+                DEFAULT_FEE_ADDRESS.as_bytes(),
+                &VerifierMetrics {
+                    approvals: 3,
+                    pending_assets: 5,
+                    ..VerifierMetrics::default()
+                },
+            )
+            .expect("the stale verifier's metrics should be stored");
+        set_version_info(
+            deps.as_mut().storage,
+            &VersionInfoV1 {
+                contract: CONTRACT_NAME.to_string(),
+                version: "0.0.0".to_string(),
+            },
+        )
+        .expect("setting the initial version info should not fail");
+        migrate_contract(deps.as_mut(), mock_env(), None).expect("the migration should succeed");
+        assert_eq!(
+            1,
+            load_verifier_metrics(deps.as_ref().storage, DEFAULT_VERIFIER_ADDRESS)
+                .unwrap()
+                .pending_assets,
+            "the asset awaiting verification should be counted for its verifier",
+        );
+        assert_eq!(
+            VerifierMetrics {
+                approvals: 3,
+                ..VerifierMetrics::default()
+            },
+            load_verifier_metrics(deps.as_ref().storage, DEFAULT_FEE_ADDRESS).unwrap(),
+            "the stale pending count should be reset without changing the other metrics",
+        );
+    }
+
+    #[test]
+    fn test_migration_indexes_pending_verification_scopes() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should succeed");
+        // Simulate an asset that was onboarded before pending assets were indexed
+        delete_pending_verification_scope(
+            deps.as_mut().storage,
+            DEFAULT_ASSET_TYPE,
+            DEFAULT_VERIFIER_ADDRESS,
+            DEFAULT_SCOPE_ADDRESS,
+        );
+        set_version_info(
+            deps.as_mut().storage,
+            &VersionInfoV1 {
+                contract: CONTRACT_NAME.to_string(),
+                version: "0.0.0".to_string(),
+            },
+        )
+        .expect("setting the initial version info should not fail");
+        migrate_contract(deps.as_mut(), mock_env(), None).expect("the migration should succeed");
+        assert_eq!(
+            vec![DEFAULT_SCOPE_ADDRESS.to_string()],
+            load_pending_verification_scope_addresses(
+                deps.as_ref().storage,
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+            )
+            .expect("the pending scope addresses should load"),
+            "the asset awaiting verification should be indexed for its verifier",
+        );
+    }
+
+    #[test]
+    fn test_migration_combines_mixed_case_asset_type_statistics() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let statistics = OnboardingStatistics {
+            onboardings: 2,
+            retries: 1,
+            approvals: 1,
+            denials: 0,
+        };
+        for asset_type in [
+            DEFAULT_ASSET_TYPE.to_string(),
+            DEFAULT_ASSET_TYPE.to_uppercase(),
+        ] {
+            asset_type_statistics_store(deps.as_mut().storage)
+                .save(asset_type.as_bytes(), &statistics)
+                .expect("the statistics should be stored");
+        }
+        set_version_info(
+            deps.as_mut().storage,
+            &VersionInfoV1 {
+                contract: CONTRACT_NAME.to_string(),
+                version: "0.0.0".to_string(),
+            },
+        )
+        .expect("setting the initial version info should not fail");
+        migrate_contract(deps.as_mut(), mock_env(), None).expect("the migration should succeed");
+        let stored_statistics = asset_type_statistics_store_ro(deps.as_ref().storage)
+            .range(None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()
+            .expect("the statistics should load");
+        assert_eq!(
+            vec![(
+                DEFAULT_ASSET_TYPE.as_bytes().to_vec(),
+                OnboardingStatistics {
+                    onboardings: 4,
+                    retries: 2,
+                    approvals: 2,
+                    denials: 0,
+                },
+            )],
+            stored_statistics,
+            "the statistics of each casing should be combined under the lowercase asset type",
+        );
+    }
+
+    #[test]
+    fn test_migration_populates_verifier_registrations() {
+        let mut deps = mock_dependencies(&[]);
+        let overriding_verifier = VerifierDetailV2::new(
+            DEFAULT_VERIFIER_ADDRESS,
+            Uint128::new(777),
+            NHASH,
+            vec![],
+            None,
+        );
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        // Verifiers are registered on instantiation, so the registry is cleared and the other asset
+        // type is stored directly to reproduce a contract created before the registry existed
+        verifier_registrations_store(deps.as_mut().storage)
+            .remove(DEFAULT_VERIFIER_ADDRESS.as_bytes());
+        replace_asset_definition_v2(
+            deps.as_mut().storage,
+            &get_default_asset_definition_input()
+                .into_asset_definition()
+                .expect("the default asset definition should be valid"),
+            mock_env().block.height,
+            DEFAULT_ADMIN_ADDRESS,
+        )
+        .expect("the default asset definition should be restored with its entity detail");
+        insert_asset_definition_v2(
+            deps.as_mut().storage,
+            &AssetDefinitionInputV2::new(
+                "zzz_other_type",
+                ScopeSpecIdentifier::uuid(Uuid::new_v4().to_string()).to_serialized_enum(),
+                vec![overriding_verifier],
+                true.to_some(),
+                false.to_some(),
+            )
+            .into_asset_definition()
+            .expect("the other asset definition should be valid"),
+        )
+        .expect("the other asset definition should be stored");
+        set_version_info(
+            deps.as_mut().storage,
+            &VersionInfoV1 {
+                contract: CONTRACT_NAME.to_string(),
+                version: "0.0.0".to_string(),
+            },
+        )
+        .expect("setting the initial version info should not fail");
+        let response = migrate_contract(deps.as_mut(), mock_env(), None)
+            .expect("the migration should populate the verifier registry");
+        assert_eq!(
+            "[registered_verifier_count=1]",
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "the single verifier should be registered once",
+        );
+        assert_eq!(
+            VerifierRegistration::from_verifier_detail(&get_default_verifier_detail()),
+            load_verifier_registration(deps.as_ref().storage, DEFAULT_VERIFIER_ADDRESS)
+                .expect("the verifier should be registered"),
+            "the registration should use the values from the first asset type",
+        );
+        assert_eq!(
+            get_default_verifier_detail(),
+            load_default_verifier(&deps.as_ref(), DEFAULT_ASSET_TYPE),
+            "the verifier in the first asset type should use the registered values",
+        );
+        let other_verifier = load_default_verifier(&deps.as_ref(), "zzz_other_type");
+        assert!(
+            other_verifier.overrides_registered_fees,
+            "the verifier with different fees should override the registered fees",
+        );
+        assert_eq!(
+            Uint128::new(777),
+            other_verifier.onboarding_cost,
+            "the migration should not change the fees of any asset type",
+        );
+        let response = migrate_contract(deps.as_mut(), mock_env(), None)
+            .expect("running the migration again should succeed");
+        assert!(
+            response
+                .attributes
+                .iter()
+                .all(|attribute| attribute.key != ADDITIONAL_METADATA_KEY),
+            "no verifiers should be registered when the migration runs again",
+        );
+    }
+
     #[test]
     fn test_failed_migration_for_incorrect_name() {
         let mut deps = mock_dependencies(&[]);
@@ -204,7 +668,7 @@ mod tests {
             },
         )
         .unwrap();
-        let error = migrate_contract(deps.as_mut(), None).unwrap_err();
+        let error = migrate_contract(deps.as_mut(), mock_env(), None).unwrap_err();
         match error {
             ContractError::InvalidContractName {
                 current_contract,
@@ -236,7 +700,7 @@ mod tests {
             },
         )
         .unwrap();
-        let error = migrate_contract(deps.as_mut(), None).unwrap_err();
+        let error = migrate_contract(deps.as_mut(), mock_env(), None).unwrap_err();
         match error {
             ContractError::InvalidContractVersion {
                 current_version,
@@ -271,6 +735,7 @@ mod tests {
         .expect("overriding version info should not fail");
         let error = migrate_contract(
             deps.as_mut(),
+            mock_env(),
             MigrationOptions {
                 new_admin_address: "not a bech32 thing that's for sure".to_string().to_some(),
             }
@@ -284,4 +749,13 @@ mod tests {
             "expected a bech32 error to occur when an invalid bech32 address was provided as the new admin",
         );
     }
+
+    fn load_default_verifier(deps: &DepsC, asset_type: &str) -> VerifierDetailV2 {
+        load_asset_definition_v2_by_type(deps.storage, asset_type)
+            .expect("the asset definition should exist")
+            .verifiers
+            .into_iter()
+            .find(|verifier| verifier.address == DEFAULT_VERIFIER_ADDRESS)
+            .expect("the default verifier should exist in the asset definition")
+    }
 }
@@ -1,16 +1,50 @@
 //! Contains the functionality used in the [contract file](crate::contract) to perform a contract query.
 
+/// A query that fetches the fees credited to an address that have not yet been withdrawn.
+pub mod query_accrued_fees;
 /// A query that fetches a target [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
 /// from the contract's internal storage.
 pub mod query_asset_definition;
+/// A query that fetches an asset definition along with the definitions of its descendant asset
+/// types.
+pub mod query_asset_definition_family;
+/// A query that fetches every version of an asset definition.
+pub mod query_asset_definition_history;
+/// A query that fetches a single version of an asset definition.
+pub mod query_asset_definition_version;
 /// A query that fetches all [AssetDefinitionV2s](crate::core::types::asset_definition::AssetDefinitionV2)
 /// from the contract's internal storage.
 pub mod query_asset_definitions;
 /// A query that attempts to find an [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
 /// on a Provenance Blockchain Metadata Scope that was added by this contract.
 pub mod query_asset_scope_attribute;
+/// A query that runs all onboarding checks without making any changes, reporting every reason that
+/// an asset could not currently be onboarded.
+pub mod query_can_onboard;
+/// A query that fetches the flags denoting which of the contract's processes are paused.
+pub mod query_pause_status;
+/// A query that fetches the addresses that have been granted each [ContractRole](crate::core::types::contract_role::ContractRole).
+pub mod query_roles;
+/// A query that fetches every scheduled verifier update that has not yet been applied.
+pub mod query_scheduled_verifier_updates;
 /// A query that directly returns the contract's stored [StateV2](crate::core::state::StateV2) value.
 pub mod query_state;
+/// A query that fetches all onboarding and verification counters tracked by the contract.
+pub mod query_statistics;
+/// A query that fetches every queued [TimelockedChange](crate::core::types::timelocked_change::TimelockedChange)
+/// that has not yet been executed or cancelled.
+pub mod query_timelocked_changes;
+/// A query that fetches the funds currently held in the contract treasury.
+pub mod query_treasury_balance;
+/// A query that locates every asset type for which a verifier address is registered, alongside its
+/// [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2) for each.
+pub mod query_verifier;
+/// A query that fetches the collateral bond posted by a verifier for an asset type.
+pub mod query_verifier_bond;
+/// A query that fetches the global registration of a verifier.
+pub mod query_verifier_registration;
+/// A query that fetches the limits on the changes a verifier can make to its own verifier detail.
+pub mod query_verifier_self_update_bounds;
 /// A query that directly returns the contract's stored [VersionInfoV1](crate::migrate::version_info::VersionInfoV1)
 /// value.
 pub mod query_version;
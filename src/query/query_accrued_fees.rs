@@ -0,0 +1,84 @@
+use cosmwasm_std::{to_binary, Binary, Coin};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::state::load_accrued_fees;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// The response produced by the [query_accrued_fees](self::query_accrued_fees) function.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryAccruedFeesResponse {
+    /// The bech32 address that was requested.
+    pub address: String,
+    /// All fees credited to the address that have not yet been withdrawn, with one entry per denom.
+    pub balances: Vec<Coin>,
+}
+
+/// A query that fetches the fees credited to an address that can be collected using the
+/// [WithdrawFees](crate::core::msg::ExecuteMsg::WithdrawFees) route.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `address` The bech32 address for which to fetch accrued fees.
+pub fn query_accrued_fees<S: Into<String>>(deps: &DepsC, address: S) -> AssetResult<Binary> {
+    let address = address.into();
+    let balances = load_accrued_fees(deps.storage, &address)?;
+    to_binary(&QueryAccruedFeesResponse { address, balances })?.to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::{coin, from_binary};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::core::state::accrue_fees;
+    use crate::testutil::test_constants::{DEFAULT_FEE_ADDRESS, DEFAULT_ONBOARDING_DENOM};
+    use crate::testutil::test_utilities::{test_instantiate_success, InstArgs};
+
+    use super::{query_accrued_fees, QueryAccruedFeesResponse};
+
+    #[test]
+    fn test_query_accrued_fees() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let response = from_binary::<QueryAccruedFeesResponse>(
+            &query_accrued_fees(&deps.as_ref(), DEFAULT_FEE_ADDRESS)
+                .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert!(
+            response.balances.is_empty(),
+            "no balances should be returned before fees accrue",
+        );
+        accrue_fees(
+            deps.as_mut().storage,
+            DEFAULT_FEE_ADDRESS,
+            &coin(100, DEFAULT_ONBOARDING_DENOM),
+        )
+        .unwrap();
+        accrue_fees(
+            deps.as_mut().storage,
+            DEFAULT_FEE_ADDRESS,
+            &coin(50, DEFAULT_ONBOARDING_DENOM),
+        )
+        .unwrap();
+        let response = from_binary::<QueryAccruedFeesResponse>(
+            &query_accrued_fees(&deps.as_ref(), DEFAULT_FEE_ADDRESS)
+                .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            QueryAccruedFeesResponse {
+                address: DEFAULT_FEE_ADDRESS.to_string(),
+                balances: vec![coin(150, DEFAULT_ONBOARDING_DENOM)],
+            },
+            response,
+            "accrued fees should be summed per denom",
+        );
+    }
+}
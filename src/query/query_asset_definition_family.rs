@@ -0,0 +1,122 @@
+use cosmwasm_std::{to_binary, Binary};
+
+use crate::core::state::load_asset_definition_v2_family;
+use crate::query::query_asset_definitions::QueryAssetDefinitionsResponse;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// A query that fetches the [AssetDefinitionV2](crate::core::types::asset_definition::AssetDefinitionV2)
+/// of an asset type, followed by the definitions of all asset types that descend from it.  Each
+/// returned definition includes the values that it inherits from its ancestors.  If no definition
+/// exists for the asset type, an empty list is returned.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `asset_type` The asset type at the top of the family.
+pub fn query_asset_definition_family<S: Into<String>>(
+    deps: &DepsC,
+    asset_type: S,
+) -> AssetResult<Binary> {
+    to_binary(&QueryAssetDefinitionsResponse::new(
+        load_asset_definition_v2_family(deps.storage, &asset_type.into())?,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::from_binary;
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::contract::execute;
+    use crate::core::msg::ExecuteMsg;
+    use crate::core::types::asset_definition::AssetDefinitionV2;
+    use crate::core::types::scope_spec_identifier::ScopeSpecIdentifier;
+    use crate::query::query_asset_definitions::QueryAssetDefinitionsResponse;
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition, get_default_asset_definition_input,
+        test_instantiate_success, InstArgs, MockOwnedDeps,
+    };
+    use crate::util::aliases::DepsC;
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_asset_definition_family;
+
+    #[test]
+    fn test_query_asset_definition_family() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        add_child_definition(&mut deps, "heloc", DEFAULT_ASSET_TYPE, "scopespec-heloc");
+        add_child_definition(
+            &mut deps,
+            "first_lien",
+            DEFAULT_ASSET_TYPE,
+            "scopespec-lien",
+        );
+        add_child_definition(&mut deps, "heloc_v2", "heloc", "scopespec-heloc-v2");
+        let family = query_family(&deps.as_ref(), DEFAULT_ASSET_TYPE);
+        assert_eq!(
+            vec![DEFAULT_ASSET_TYPE, "first_lien", "heloc", "heloc_v2"],
+            family
+                .iter()
+                .map(|definition| definition.asset_type.as_str())
+                .collect::<Vec<&str>>(),
+            "the family should include the asset type followed by each generation of its descendants",
+        );
+        assert!(
+            family
+                .iter()
+                .all(|definition| definition.verifiers == get_default_asset_definition().verifiers),
+            "each descendant should inherit the verifiers of the root asset type",
+        );
+        assert_eq!(
+            vec!["heloc", "heloc_v2"],
+            query_family(&deps.as_ref(), "HELOC")
+                .iter()
+                .map(|definition| definition.asset_type.as_str())
+                .collect::<Vec<&str>>(),
+            "a family should be able to be fetched from the middle of the hierarchy, regardless of casing",
+        );
+        assert!(
+            query_family(&deps.as_ref(), "unknown-type").is_empty(),
+            "an asset type without a definition should have no family",
+        );
+    }
+
+    fn add_child_definition(
+        deps: &mut MockOwnedDeps,
+        asset_type: &str,
+        parent_asset_type: &str,
+        scope_spec_address: &str,
+    ) {
+        let mut input = get_default_asset_definition_input()
+            .with_parent_asset_type(parent_asset_type.to_some());
+        input.asset_type = asset_type.to_string();
+        input.scope_spec_identifier =
+            ScopeSpecIdentifier::address(scope_spec_address).to_serialized_enum();
+        input.verifiers = vec![];
+        input.bind_name = false.to_some();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ExecuteMsg::AddAssetDefinition {
+                asset_definition: input,
+            },
+        )
+        .expect("adding the child asset definition should succeed");
+    }
+
+    fn query_family(deps: &DepsC, asset_type: &str) -> Vec<AssetDefinitionV2> {
+        from_binary::<QueryAssetDefinitionsResponse>(
+            &query_asset_definition_family(deps, asset_type).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize")
+        .asset_definitions
+    }
+}
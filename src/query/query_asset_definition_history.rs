@@ -0,0 +1,98 @@
+use cosmwasm_std::{to_binary, Binary};
+
+use crate::core::state::load_asset_definition_history;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// A query that fetches every version of an asset definition, in ascending version order.  The
+/// definition's current values, if it still exists, are included as the final version.  If the
+/// asset type has never been defined, an empty list is returned.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `asset_type` The asset type of the definition.
+pub fn query_asset_definition_history<S: Into<String>>(
+    deps: &DepsC,
+    asset_type: S,
+) -> AssetResult<Binary> {
+    to_binary(&load_asset_definition_history(
+        deps.storage,
+        &asset_type.into(),
+    )?)?
+    .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::from_binary;
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::core::types::asset_definition::AssetDefinitionV2;
+    use crate::core::types::asset_definition_version::AssetDefinitionVersion;
+    use crate::execute::toggle_asset_definition::{
+        toggle_asset_definition, ToggleAssetDefinitionV1,
+    };
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition, test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::DepsC;
+
+    use super::query_asset_definition_history;
+
+    #[test]
+    fn test_query_asset_definition_history() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        assert_eq!(
+            vec![AssetDefinitionVersion::current(
+                1,
+                get_default_asset_definition()
+            )],
+            query_history(&deps.as_ref(), DEFAULT_ASSET_TYPE),
+            "an unchanged asset definition should only include its current version",
+        );
+        toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, false),
+        )
+        .expect("toggling the asset definition should succeed");
+        let history = query_history(&deps.as_ref(), DEFAULT_ASSET_TYPE.to_uppercase());
+        assert_eq!(
+            vec![
+                AssetDefinitionVersion::replaced(
+                    1,
+                    get_default_asset_definition(),
+                    mock_env().block.height,
+                    DEFAULT_ADMIN_ADDRESS,
+                ),
+                AssetDefinitionVersion::current(
+                    2,
+                    AssetDefinitionV2 {
+                        enabled: false,
+                        ..get_default_asset_definition()
+                    },
+                ),
+            ],
+            history,
+            "the replaced version should be returned before the current version, regardless of the casing of the asset type",
+        );
+        assert!(
+            query_history(&deps.as_ref(), "unknown-type").is_empty(),
+            "an asset type that was never defined should have no history",
+        );
+    }
+
+    fn query_history<S: Into<String>>(deps: &DepsC, asset_type: S) -> Vec<AssetDefinitionVersion> {
+        from_binary(
+            &query_asset_definition_history(deps, asset_type).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize")
+    }
+}
@@ -0,0 +1,97 @@
+use cosmwasm_std::{to_binary, Binary};
+
+use crate::core::state::may_load_asset_definition_version;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// A query that fetches a single version of an asset definition.  If the version does not exist
+/// for the asset type, an empty response is returned.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `asset_type` The asset type of the definition.
+/// * `version` The version number to fetch.
+pub fn query_asset_definition_version<S: Into<String>>(
+    deps: &DepsC,
+    asset_type: S,
+    version: u64,
+) -> AssetResult<Binary> {
+    to_binary(&may_load_asset_definition_version(
+        deps.storage,
+        &asset_type.into(),
+        version,
+    )?)?
+    .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::from_binary;
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::core::types::asset_definition::AssetDefinitionV2;
+    use crate::core::types::asset_definition_version::AssetDefinitionVersion;
+    use crate::execute::toggle_asset_definition::{
+        toggle_asset_definition, ToggleAssetDefinitionV1,
+    };
+    use crate::testutil::test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE};
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition, test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::DepsC;
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_asset_definition_version;
+
+    #[test]
+    fn test_query_asset_definition_version() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, false),
+        )
+        .expect("toggling the asset definition should succeed");
+        assert_eq!(
+            AssetDefinitionVersion::replaced(
+                1,
+                get_default_asset_definition(),
+                mock_env().block.height,
+                DEFAULT_ADMIN_ADDRESS,
+            )
+            .to_some(),
+            query_version(&deps.as_ref(), 1),
+            "the replaced version should be returned with the block height and actor that replaced it",
+        );
+        assert_eq!(
+            AssetDefinitionVersion::current(
+                2,
+                AssetDefinitionV2 {
+                    enabled: false,
+                    ..get_default_asset_definition()
+                },
+            )
+            .to_some(),
+            query_version(&deps.as_ref(), 2),
+            "the current version should be returned",
+        );
+        assert!(
+            query_version(&deps.as_ref(), 3).is_none(),
+            "a version that does not yet exist should not be returned",
+        );
+    }
+
+    fn query_version(deps: &DepsC, version: u64) -> Option<AssetDefinitionVersion> {
+        from_binary(
+            &query_asset_definition_version(deps, DEFAULT_ASSET_TYPE, version)
+                .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize")
+    }
+}
@@ -2,7 +2,7 @@ use cosmwasm_std::{to_binary, Binary};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::core::state::asset_definitions_v2;
+use crate::core::state::{apply_verifier_registrations, asset_definitions_v2};
 use crate::core::types::asset_definition::AssetDefinitionV2;
 use crate::util::{
     aliases::{AssetResult, DepsC},
@@ -43,7 +43,8 @@ pub fn query_asset_definitions(deps: &DepsC) -> AssetResult<Binary> {
         .into_iter()
         .filter(|result| result.is_ok())
         .map(|result| result.unwrap().1)
-        .collect::<Vec<AssetDefinitionV2>>();
+        .map(|definition| apply_verifier_registrations(deps.storage, definition))
+        .collect::<AssetResult<Vec<AssetDefinitionV2>>>()?;
     to_binary(&QueryAssetDefinitionsResponse::new(asset_definitions))?.to_ok()
 }
 
@@ -0,0 +1,657 @@
+use cosmwasm_std::{to_binary, Binary, Coin, Env};
+use provwasm_std::ProvenanceQuerier;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::ContractError;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{
+    config_read_v2, load_requestor_onboarding_count, load_scheduled_verifier_updates,
+    may_load_asset_definition_v2_by_type, resolve_asset_definition_v2,
+};
+use crate::core::types::asset_identifier::AssetIdentifier;
+use crate::core::types::asset_onboarding_status::AssetOnboardingStatus;
+use crate::query::query_asset_scope_attribute::may_query_scope_attribute_by_scope_address;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::constants::NHASH;
+use crate::util::contract_helpers::{check_execute_msg_not_paused, check_verifier_bond};
+use crate::util::traits::{OptionExtensions, ResultExtensions};
+
+/// The response produced by the [query_can_onboard](self::query_can_onboard) function.  Describes
+/// every reason that an [OnboardAsset](crate::core::msg::ExecuteMsg::OnboardAsset) request with the
+/// same parameters would currently be rejected.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryCanOnboardResponse {
+    /// Indicates whether or not all onboarding checks passed.  When `true`, the [failures](self::QueryCanOnboardResponse::failures)
+    /// vector will always be empty.
+    pub can_onboard: bool,
+    /// The exact funds that must be sent alongside the onboarding request, in nhash, including the
+    /// [protocol_fee](self::QueryCanOnboardResponse::protocol_fee).  This value is only populated
+    /// when the requested verifier could be located on the requested asset type.
+    pub required_funds: Option<Coin>,
+    /// The portion of the [required_funds](self::QueryCanOnboardResponse::required_funds) that is
+    /// charged as the contract's [protocol fee](crate::core::types::protocol_fee::ProtocolFee).
+    /// This value is only populated when the requested verifier could be located and a nonzero
+    /// protocol fee applies.
+    pub protocol_fee: Option<Coin>,
+    /// A description of each check that failed, in the order in which the onboarding process
+    /// performs them.  Each value matches the error message that onboarding would produce.
+    pub failures: Vec<String>,
+}
+impl QueryCanOnboardResponse {
+    /// Constructs a new instance of this struct, deriving the [can_onboard](self::QueryCanOnboardResponse::can_onboard)
+    /// value from the presence of failures.
+    ///
+    /// # Parameters
+    ///
+    /// * `required_funds` The exact funds that must be sent alongside the onboarding request.
+    /// * `protocol_fee` The portion of the required funds charged as the protocol fee.
+    /// * `failures` A description of each check that failed.
+    pub fn new(
+        required_funds: Option<Coin>,
+        protocol_fee: Option<Coin>,
+        failures: Vec<String>,
+    ) -> Self {
+        Self {
+            can_onboard: failures.is_empty(),
+            required_funds,
+            protocol_fee,
+            failures,
+        }
+    }
+}
+
+/// A query that runs every check performed by the [onboard_asset](crate::execute::onboard_asset::onboard_asset)
+/// execution route without making any changes, collecting all failures instead of stopping at the
+/// first one.  This allows requestors to discover all onboarding problems before paying any
+/// transaction fees.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Used to determine which
+/// [scheduled verifier updates](crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate)
+/// the onboarding route would apply.
+/// * `identifier` Helps derive the scope address of the asset to check.
+/// * `asset_type` The [asset_type](crate::core::types::asset_definition::AssetDefinitionV2::asset_type)
+/// that the asset would be onboarded as.
+/// * `verifier_address` The bech32 address of the verifier that would be requested.
+/// * `sender` The bech32 address of the account that would send the onboarding request.
+pub fn query_can_onboard<S1: Into<String>, S2: Into<String>, S3: Into<String>>(
+    deps: &DepsC,
+    env: &Env,
+    identifier: AssetIdentifier,
+    asset_type: S1,
+    verifier_address: S2,
+    sender: S3,
+) -> AssetResult<Binary> {
+    let asset_type = asset_type.into();
+    let verifier_address = verifier_address.into();
+    let sender = sender.into();
+    let scope_address = identifier.to_identifiers()?.scope_address;
+    let mut failures: Vec<ContractError> = vec![];
+    // The execute entrypoint rejects paused routes before any other checks are made, so the same
+    // check is run against an equivalent onboarding message
+    if let Err(e) = check_execute_msg_not_paused(
+        deps,
+        &ExecuteMsg::OnboardAsset {
+            identifier: identifier.to_serialized_enum(),
+            asset_type: asset_type.clone(),
+            verifier_address: verifier_address.clone(),
+            access_routes: None,
+            max_onboarding_cost: None,
+        },
+    ) {
+        failures.push(e);
+    }
+    let mut asset_definition = may_load_asset_definition_v2_by_type(deps.storage, &asset_type)?;
+    // Scheduled verifier updates that have become effective are applied in memory, mirroring the
+    // onboarding route, which applies them before charging fees
+    if let Some(definition) = asset_definition.as_mut() {
+        let effective_updates = load_scheduled_verifier_updates(deps.storage, &asset_type)?
+            .into_iter()
+            .filter(|update| update.effective_at.has_passed(&env.block))
+            .collect::<Vec<_>>();
+        definition.apply_scheduled_verifier_updates(&effective_updates);
+    }
+    let asset_definition = asset_definition
+        .map(|definition| resolve_asset_definition_v2(deps.storage, definition))
+        .transpose()?;
+    match &asset_definition {
+        Some(definition) if !definition.enabled => {
+            failures.push(ContractError::AssetTypeDisabled {
+                asset_type: asset_type.clone(),
+            });
+        }
+        None => {
+            failures.push(ContractError::UnsupportedAssetType {
+                asset_type: asset_type.clone(),
+            });
+        }
+        _ => (),
+    };
+    // Any pricing tier reached by the sender and any fee discount granted to the sender are applied
+    // up front, ensuring that the reported funds match what the onboarding route will require
+    let verifier = match asset_definition.as_ref().and_then(|definition| {
+        definition
+            .verifiers
+            .iter()
+            .find(|verifier| verifier.address == verifier_address)
+            .map(|verifier| (definition, verifier))
+    }) {
+        Some((definition, verifier)) => {
+            let onboarding_count =
+                load_requestor_onboarding_count(deps.storage, &verifier.address, &sender)?;
+            definition
+                .get_discounted_verifier_detail(
+                    &verifier.apply_pricing_tier(onboarding_count),
+                    &sender,
+                )
+                .to_some()
+        }
+        None => None,
+    };
+    // A missing asset definition has already been reported, so only report the missing verifier
+    // when it was actually possible to find it
+    if asset_definition.is_some() && verifier.is_none() {
+        failures.push(ContractError::UnsupportedVerifier {
+            asset_type: asset_type.clone(),
+            verifier_address: verifier_address.clone(),
+        });
+    }
+    if matches!(&verifier, Some(verifier) if !verifier.enabled) {
+        failures.push(ContractError::VerifierDisabled {
+            verifier_address: verifier_address.clone(),
+            asset_type: asset_type.clone(),
+        });
+    }
+    if let (Some(definition), Some(_)) = (&asset_definition, &verifier) {
+        if let Err(e) = check_verifier_bond(deps, definition, &verifier_address) {
+            failures.push(e);
+        }
+    }
+    let state = config_read_v2(deps.storage).load()?;
+    let querier = ProvenanceQuerier::new(&deps.querier);
+    match querier.get_scope(&scope_address) {
+        Ok(scope) => {
+            let mut scope_spec_matches = false;
+            if let Some(definition) = &asset_definition {
+                scope_spec_matches = definition.accepts_scope_spec(&scope.specification_id);
+                if !scope_spec_matches {
+                    failures.push(ContractError::AssetSpecMismatch {
+                        asset_type: asset_type.clone(),
+                        scope_address: scope_address.clone(),
+                        scope_spec_address: scope.specification_id.clone(),
+                        expected_scope_spec_address: definition
+                            .get_scope_spec_addresses()
+                            .join(", "),
+                    });
+                }
+            }
+            if !scope.owners.iter().any(|owner| owner.address == sender) {
+                failures.push(ContractError::Unauthorized {
+                    explanation: "sender address does not own the scope".to_string(),
+                });
+            }
+            if !state.is_test {
+                match querier.get_records(&scope.scope_id) {
+                    Ok(records) => {
+                        if !records
+                            .records
+                            .into_iter()
+                            .any(|record| !record.outputs.is_empty())
+                        {
+                            failures.push(ContractError::InvalidScope {
+                                explanation: format!(
+                                    "cannot onboard scope [{}]. scope must have at least one non-empty record",
+                                    scope.scope_id,
+                                ),
+                            });
+                        }
+                    }
+                    Err(e) => failures.push(ContractError::Std(e)),
+                };
+            }
+            match may_query_scope_attribute_by_scope_address(deps, &scope_address) {
+                Ok(Some(attribute)) => match attribute.onboarding_status {
+                    AssetOnboardingStatus::Approved => {
+                        failures.push(ContractError::AssetAlreadyOnboarded {
+                            scope_address: scope_address.clone(),
+                        });
+                    }
+                    AssetOnboardingStatus::Pending => {
+                        failures.push(ContractError::AssetPendingVerification {
+                            scope_address: scope_address.clone(),
+                            verifier_address: attribute.verifier_address.into_string(),
+                        });
+                    }
+                    // Denied assets are eligible for a retry
+                    AssetOnboardingStatus::Denied => (),
+                },
+                Ok(None) => (),
+                // The onboarding route fails on any error looking up the attribute of a scope
+                // that matches the asset definition
+                Err(e) if scope_spec_matches => failures.push(e),
+                // Missing asset definitions and spec mismatches prevent the attribute from being
+                // located, and have already been reported above
+                Err(_) => (),
+            };
+        }
+        Err(_) => {
+            failures.push(ContractError::AssetNotFound {
+                scope_address: scope_address.clone(),
+            });
+        }
+    };
+    // The onboarding route only accepts nhash, regardless of the verifier's onboarding denom
+    let protocol_fee = verifier
+        .as_ref()
+        .zip(state.protocol_fee.as_ref())
+        .map(|(verifier, protocol_fee)| Coin {
+            denom: NHASH.to_string(),
+            amount: protocol_fee.calculate_fee_amount(verifier.onboarding_cost),
+        })
+        .filter(|fee| !fee.amount.is_zero());
+    let required_funds = verifier.as_ref().map(|verifier| Coin {
+        denom: NHASH.to_string(),
+        amount: verifier.onboarding_cost
+            + protocol_fee
+                .as_ref()
+                .map(|fee| fee.amount)
+                .unwrap_or_default(),
+    });
+    to_binary(&QueryCanOnboardResponse::new(
+        required_funds,
+        protocol_fee,
+        failures.into_iter().map(|e| e.to_string()).collect(),
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, coins, from_binary, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use serde_json_wasm::to_string;
+
+    use crate::core::types::asset_definition::AssetDefinitionInputV2;
+    use crate::core::types::asset_identifier::AssetIdentifier;
+    use crate::core::types::fee_discount::FeeDiscount;
+    use crate::core::types::protocol_fee::{ProtocolFee, ProtocolFeeAmount};
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::execute::post_verifier_bond::{post_verifier_bond, PostVerifierBondV1};
+    use crate::execute::toggle_asset_definition::{
+        toggle_asset_definition, ToggleAssetDefinitionV1,
+    };
+    use crate::execute::toggle_asset_verifier::{toggle_asset_verifier, ToggleAssetVerifierV1};
+    use crate::execute::update_pause_status::{update_pause_status, UpdatePauseStatusV1};
+    use crate::execute::update_protocol_fee::{update_protocol_fee, UpdateProtocolFeeV1};
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME, DEFAULT_FEE_ADDRESS,
+        DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM, DEFAULT_SCOPE_ADDRESS,
+        DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition_input, get_default_asset_scope_attribute,
+        get_default_entity_detail, get_duped_scope, setup_test_suite, test_instantiate_success,
+        InstArgs,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::aliases::DepsC;
+    use crate::util::constants::NHASH;
+    use crate::util::functions::generate_asset_attribute_name;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{query_can_onboard, QueryCanOnboardResponse};
+
+    #[test]
+    fn test_can_onboard_with_default_values() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert_eq!(
+            QueryCanOnboardResponse {
+                can_onboard: true,
+                required_funds: coin(DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM).to_some(),
+                protocol_fee: None,
+                failures: vec![],
+            },
+            response,
+            "expected the default values to be eligible for onboarding",
+        );
+    }
+
+    #[test]
+    fn test_can_onboard_reports_unsupported_asset_type_and_missing_scope() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let response = from_binary::<QueryCanOnboardResponse>(
+            &query_can_onboard(
+                &deps.as_ref(),
+                &mock_env(),
+                AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+                "bogus",
+                DEFAULT_VERIFIER_ADDRESS,
+                DEFAULT_SENDER_ADDRESS,
+            )
+            .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert!(!response.can_onboard, "the asset should not be eligible");
+        assert!(
+            response.required_funds.is_none(),
+            "no required funds should be reported when the verifier cannot be found",
+        );
+        assert_eq!(
+            vec![
+                "Unsupported asset type [bogus]".to_string(),
+                format!("Asset {} not found", DEFAULT_SCOPE_ADDRESS),
+            ],
+            response.failures,
+            "expected both the asset type and the missing scope to be reported",
+        );
+    }
+
+    #[test]
+    fn test_can_onboard_reports_all_failures_at_once() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        toggle_asset_definition(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetDefinitionV1::new(DEFAULT_ASSET_TYPE, false),
+        )
+        .expect("toggling the asset definition to be disabled should succeed");
+        // Mock a scope with the wrong spec, owned by a different account, with no records
+        deps.querier.with_scope(get_duped_scope(
+            DEFAULT_SCOPE_ADDRESS,
+            "bogus-spec",
+            DEFAULT_ADMIN_ADDRESS,
+        ));
+        let response = from_binary::<QueryCanOnboardResponse>(
+            &query_can_onboard(
+                &deps.as_ref(),
+                &mock_env(),
+                AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+                DEFAULT_ASSET_TYPE,
+                "bogus-verifier",
+                DEFAULT_SENDER_ADDRESS,
+            )
+            .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert!(!response.can_onboard, "the asset should not be eligible");
+        assert_eq!(
+            5,
+            response.failures.len(),
+            "expected the disabled type, unsupported verifier, spec mismatch, ownership and records failures, but got: {:?}",
+            response.failures,
+        );
+        assert!(response.failures[0].contains("is currently disabled"));
+        assert!(response.failures[1].contains("Unsupported verifier [bogus-verifier]"));
+        assert!(response.failures[2].contains("does not conform to the spec"));
+        assert!(response.failures[3].contains("sender address does not own the scope"));
+    }
+
+    #[test]
+    fn test_can_onboard_reports_disabled_verifier() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        toggle_asset_verifier(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            ToggleAssetVerifierV1::new(DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS, false),
+        )
+        .expect("toggling the verifier to be disabled should succeed");
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert!(!response.can_onboard, "the asset should not be eligible");
+        assert_eq!(
+            vec![format!(
+                "Verifier [{}] is currently disabled for asset type [{}]",
+                DEFAULT_VERIFIER_ADDRESS, DEFAULT_ASSET_TYPE
+            )],
+            response.failures,
+            "expected only the disabled verifier to be reported",
+        );
+    }
+
+    #[test]
+    fn test_can_onboard_reports_paused_onboarding() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        update_pause_status(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdatePauseStatusV1::new(true.to_some(), None, None),
+        )
+        .expect("pausing onboarding should succeed");
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert!(
+            !response.can_onboard,
+            "the asset should not be eligible while onboarding is paused",
+        );
+        assert_eq!(
+            vec!["Contract operation [onboarding] is currently paused".to_string()],
+            response.failures,
+            "expected only the paused onboarding process to be reported",
+        );
+        update_pause_status(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdatePauseStatusV1::new(false.to_some(), None, None),
+        )
+        .expect("resuming onboarding should succeed");
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert!(
+            response.can_onboard,
+            "the asset should become eligible after onboarding is resumed",
+        );
+    }
+
+    #[test]
+    fn test_can_onboard_reports_insufficient_verifier_bond() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![get_default_asset_definition_input()
+                    .with_minimum_verifier_bond(coin(1000, NHASH).to_some())],
+                ..Default::default()
+            },
+        );
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert_eq!(
+            vec![format!(
+                "Verifier [{}] has a bond of [none] for asset type [{}], but a bond of [1000nhash] is required",
+                DEFAULT_VERIFIER_ADDRESS, DEFAULT_ASSET_TYPE
+            )],
+            response.failures,
+            "expected only the missing bond to be reported",
+        );
+        post_verifier_bond(
+            deps.as_mut(),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &coins(1000, NHASH)),
+            PostVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .expect("posting a bond should succeed");
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert!(
+            response.can_onboard,
+            "the asset should be eligible once the verifier is bonded, but got failures: {:?}",
+            response.failures,
+        );
+    }
+
+    #[test]
+    fn test_can_onboard_reports_current_status() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert_eq!(
+            vec![format!(
+                "Asset {} is currently awaiting verification from address {}",
+                DEFAULT_SCOPE_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+            )],
+            response.failures,
+            "expected a pending asset to be reported",
+        );
+        test_verify_asset(&mut deps, TestVerifyAsset::default_with_success(false)).unwrap();
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert!(
+            response.can_onboard,
+            "a denied asset should be eligible for a retry, but got failures: {:?}",
+            response.failures,
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        test_verify_asset(&mut deps, TestVerifyAsset::default()).unwrap();
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert_eq!(
+            vec![format!(
+                "Asset {} has already been fully onboarded",
+                DEFAULT_SCOPE_ADDRESS
+            )],
+            response.failures,
+            "expected an approved asset to be reported",
+        );
+    }
+
+    #[test]
+    fn test_can_onboard_includes_protocol_fee() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        update_protocol_fee(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateProtocolFeeV1::new(Some(ProtocolFee::new(
+                DEFAULT_FEE_ADDRESS,
+                ProtocolFeeAmount::BasisPoints(250),
+            ))),
+        )
+        .expect("the protocol fee should be updated");
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert_eq!(
+            coin(DEFAULT_ONBOARDING_COST + 25, DEFAULT_ONBOARDING_DENOM).to_some(),
+            response.required_funds,
+            "the required funds should include the protocol fee",
+        );
+        assert_eq!(
+            coin(25, DEFAULT_ONBOARDING_DENOM).to_some(),
+            response.protocol_fee,
+            "the protocol fee should be reported separately",
+        );
+    }
+
+    #[test]
+    fn test_can_onboard_reports_required_funds_in_nhash() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![AssetDefinitionInputV2 {
+                    verifiers: vec![VerifierDetailV2::new(
+                        DEFAULT_VERIFIER_ADDRESS,
+                        Uint128::new(DEFAULT_ONBOARDING_COST),
+                        "otherdenom",
+                        vec![],
+                        get_default_entity_detail().to_some(),
+                    )],
+                    ..get_default_asset_definition_input()
+                }],
+                ..Default::default()
+            },
+        );
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert_eq!(
+            coin(DEFAULT_ONBOARDING_COST, NHASH).to_some(),
+            response.required_funds,
+            "the required funds should be reported in the only denom that onboarding accepts",
+        );
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset::default_with_coin(DEFAULT_ONBOARDING_COST, NHASH),
+        )
+        .expect("onboarding with the reported required funds should succeed");
+    }
+
+    #[test]
+    fn test_can_onboard_reports_attribute_lookup_failures() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        // Duplicate attributes cause the attribute lookup performed by onboarding to fail
+        let attribute_json = to_string(&get_default_asset_scope_attribute())
+            .expect("the attribute should serialize");
+        let attribute_name =
+            generate_asset_attribute_name(DEFAULT_ASSET_TYPE, DEFAULT_CONTRACT_BASE_NAME);
+        deps.querier.with_attributes(
+            DEFAULT_SCOPE_ADDRESS,
+            &[
+                (attribute_name.as_str(), attribute_json.as_str(), "json"),
+                (attribute_name.as_str(), attribute_json.as_str(), "json"),
+            ],
+        );
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert!(!response.can_onboard, "the asset should not be eligible");
+        assert_eq!(
+            1,
+            response.failures.len(),
+            "expected only the attribute lookup failure, but got: {:?}",
+            response.failures,
+        );
+        assert!(
+            response.failures[0].contains("more than one asset scope attribute exists"),
+            "expected the attribute lookup failure to be reported, but got: {:?}",
+            response.failures,
+        );
+    }
+
+    #[test]
+    fn test_can_onboard_applies_fee_discount() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![get_default_asset_definition_input()
+                    .with_fee_discounts(vec![FeeDiscount::new(DEFAULT_SENDER_ADDRESS, 2000)])],
+                ..Default::default()
+            },
+        );
+        let response = query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        assert_eq!(
+            coin(DEFAULT_ONBOARDING_COST * 8 / 10, DEFAULT_ONBOARDING_DENOM).to_some(),
+            response.required_funds,
+            "the required funds should reflect the sender's discount",
+        );
+    }
+
+    #[test]
+    fn test_can_onboard_does_not_alter_storage() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        query_default_can_onboard(&deps.as_ref(), DEFAULT_SENDER_ADDRESS);
+        test_onboard_asset(&mut deps, TestOnboardAsset::default())
+            .expect("onboarding should still succeed after the query runs");
+    }
+
+    fn query_default_can_onboard(deps: &DepsC, sender: &str) -> QueryCanOnboardResponse {
+        from_binary(
+            &query_can_onboard(
+                deps,
+                &mock_env(),
+                AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                sender,
+            )
+            .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize")
+    }
+}
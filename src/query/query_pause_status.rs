@@ -0,0 +1,61 @@
+use cosmwasm_std::{to_binary, Binary};
+
+use crate::core::state::config_read_v2;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// A query that fetches the contract's current [PauseStatus](crate::core::types::pause_status::PauseStatus),
+/// indicating which of its state-changing processes are halted.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_pause_status(deps: &DepsC) -> AssetResult<Binary> {
+    to_binary(&config_read_v2(deps.storage).load()?.pause_status)?.to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::from_binary;
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::core::types::pause_status::PauseStatus;
+    use crate::execute::update_pause_status::{update_pause_status, UpdatePauseStatusV1};
+    use crate::testutil::test_constants::DEFAULT_ADMIN_ADDRESS;
+    use crate::testutil::test_utilities::{empty_mock_info, test_instantiate_success, InstArgs};
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_pause_status;
+
+    #[test]
+    fn test_query_pause_status() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let status = from_binary::<PauseStatus>(
+            &query_pause_status(&deps.as_ref()).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            PauseStatus::default(),
+            status,
+            "nothing should be paused after instantiation",
+        );
+        update_pause_status(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdatePauseStatusV1::new(None, true.to_some(), None),
+        )
+        .expect("pausing verification should succeed");
+        let status = from_binary::<PauseStatus>(
+            &query_pause_status(&deps.as_ref()).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert!(
+            status.verification && !status.onboarding && !status.access_routes,
+            "only verification should be paused, but got: {:?}",
+            status,
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use cosmwasm_std::{to_binary, Binary};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::state::load_role_members;
+use crate::core::types::contract_role::ContractRole;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// The addresses that have been granted a single [ContractRole](crate::core::types::contract_role::ContractRole).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct RoleMembers {
+    /// The role held by each member.
+    pub role: ContractRole,
+    /// The bech32 addresses that hold the role, sorted alphabetically.  The contract's admin
+    /// implicitly holds every role, and is not included.
+    pub members: Vec<String>,
+}
+
+/// The response produced by the [query_roles](self::query_roles) function.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryRolesResponse {
+    /// The members of each requested role.
+    pub roles: Vec<RoleMembers>,
+}
+
+/// A query that fetches the addresses that have been granted each [ContractRole](crate::core::types::contract_role::ContractRole).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `role` If provided, only the members of this role are returned.  Otherwise, the members of
+/// every role are returned.
+pub fn query_roles(deps: &DepsC, role: Option<ContractRole>) -> AssetResult<Binary> {
+    let roles = role
+        .map(|role| vec![role])
+        .unwrap_or_else(ContractRole::all)
+        .into_iter()
+        .map(|role| {
+            RoleMembers {
+                members: load_role_members(deps.storage, &role)?,
+                role,
+            }
+            .to_ok()
+        })
+        .collect::<AssetResult<Vec<RoleMembers>>>()?;
+    to_binary(&QueryRolesResponse { roles })?.to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::from_binary;
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::core::state::insert_role_member;
+    use crate::core::types::contract_role::ContractRole;
+    use crate::testutil::test_constants::{DEFAULT_FEE_ADDRESS, DEFAULT_SENDER_ADDRESS};
+    use crate::testutil::test_utilities::{test_instantiate_success, InstArgs};
+
+    use super::{query_roles, QueryRolesResponse, RoleMembers};
+
+    #[test]
+    fn test_query_roles() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        insert_role_member(
+            deps.as_mut().storage,
+            &ContractRole::Pauser,
+            DEFAULT_SENDER_ADDRESS,
+        )
+        .expect("granting the role should succeed");
+        insert_role_member(
+            deps.as_mut().storage,
+            &ContractRole::Pauser,
+            DEFAULT_FEE_ADDRESS,
+        )
+        .expect("granting the role should succeed");
+        let response = from_binary::<QueryRolesResponse>(
+            &query_roles(&deps.as_ref(), Some(ContractRole::Pauser))
+                .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            vec![RoleMembers {
+                role: ContractRole::Pauser,
+                members: vec![
+                    DEFAULT_SENDER_ADDRESS.to_string(),
+                    DEFAULT_FEE_ADDRESS.to_string(),
+                ],
+            }],
+            response.roles,
+            "the members of the requested role should be returned in sorted order",
+        );
+        let response = from_binary::<QueryRolesResponse>(
+            &query_roles(&deps.as_ref(), None).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            ContractRole::all(),
+            response
+                .roles
+                .iter()
+                .map(|role_members| role_members.role)
+                .collect::<Vec<ContractRole>>(),
+            "every role should be returned when no role is requested",
+        );
+        assert!(
+            response
+                .roles
+                .iter()
+                .filter(|role_members| role_members.role != ContractRole::Pauser)
+                .all(|role_members| role_members.members.is_empty()),
+            "roles that have not been granted should have no members",
+        );
+    }
+}
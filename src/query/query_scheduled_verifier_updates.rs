@@ -0,0 +1,101 @@
+use cosmwasm_std::{to_binary, Binary, Order};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::state::{load_scheduled_verifier_updates, scheduled_verifier_updates_store_ro};
+use crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// The response produced by the [query_scheduled_verifier_updates](self::query_scheduled_verifier_updates)
+/// function.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryScheduledVerifierUpdatesResponse {
+    /// Every scheduled update that has not yet been applied, ordered by asset type and then by the
+    /// order in which the updates were scheduled.  Updates that have become effective remain in
+    /// this list until the next onboarding for their asset type applies them.
+    pub updates: Vec<ScheduledVerifierUpdate>,
+}
+
+/// A query that fetches every [ScheduledVerifierUpdate](crate::core::types::scheduled_verifier_update::ScheduledVerifierUpdate)
+/// that has not yet been applied, allowing integrators to prepare for upcoming fee changes.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `asset_type` If provided, only updates for this asset type are returned.
+pub fn query_scheduled_verifier_updates(
+    deps: &DepsC,
+    asset_type: Option<String>,
+) -> AssetResult<Binary> {
+    let updates = match asset_type {
+        Some(asset_type) => load_scheduled_verifier_updates(deps.storage, &asset_type)?,
+        None => scheduled_verifier_updates_store_ro(deps.storage)
+            .range(None, None, Order::Ascending)
+            .filter_map(|result| result.ok())
+            .flat_map(|(_, updates)| updates)
+            .collect(),
+    };
+    to_binary(&QueryScheduledVerifierUpdatesResponse { updates })?.to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::from_binary;
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::core::state::insert_scheduled_verifier_update;
+    use crate::core::types::scheduled_verifier_update::{EffectiveAt, ScheduledVerifierUpdate};
+    use crate::testutil::test_constants::DEFAULT_ASSET_TYPE;
+    use crate::testutil::test_utilities::{
+        get_default_verifier_detail, test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::DepsC;
+
+    use super::{query_scheduled_verifier_updates, QueryScheduledVerifierUpdatesResponse};
+
+    #[test]
+    fn test_query_scheduled_verifier_updates() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        assert!(
+            query_updates(&deps.as_ref(), None).updates.is_empty(),
+            "no updates should be returned before any are scheduled",
+        );
+        let first_update = ScheduledVerifierUpdate::new(
+            DEFAULT_ASSET_TYPE,
+            get_default_verifier_detail(),
+            EffectiveAt::BlockHeight(50000),
+        );
+        let second_update = ScheduledVerifierUpdate::new(
+            "other_type",
+            get_default_verifier_detail(),
+            EffectiveAt::BlockHeight(100),
+        );
+        insert_scheduled_verifier_update(deps.as_mut().storage, &second_update).unwrap();
+        insert_scheduled_verifier_update(deps.as_mut().storage, &first_update).unwrap();
+        assert_eq!(
+            vec![second_update, first_update.clone()],
+            query_updates(&deps.as_ref(), None).updates,
+            "all updates should be returned, ordered by asset type",
+        );
+        assert_eq!(
+            vec![first_update],
+            query_updates(&deps.as_ref(), Some(DEFAULT_ASSET_TYPE.to_string())).updates,
+            "only updates for the requested asset type should be returned",
+        );
+    }
+
+    fn query_updates(
+        deps: &DepsC,
+        asset_type: Option<String>,
+    ) -> QueryScheduledVerifierUpdatesResponse {
+        from_binary(
+            &query_scheduled_verifier_updates(deps, asset_type).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize")
+    }
+}
@@ -26,7 +26,7 @@ mod tests {
     use provwasm_mocks::mock_dependencies;
 
     use crate::{
-        core::state::StateV2,
+        core::{state::StateV2, types::pause_status::PauseStatus},
         testutil::{
             test_constants::{DEFAULT_ADMIN_ADDRESS, DEFAULT_CONTRACT_BASE_NAME},
             test_utilities::{test_instantiate_success, InstArgs},
@@ -53,5 +53,14 @@ mod tests {
             "the default info name should be tagged as the admin address after default instantiation",
         );
         assert!(!state.is_test, "the default is_test value should be false");
+        assert!(
+            state.pending_admin.is_none(),
+            "no pending admin should exist after default instantiation",
+        );
+        assert_eq!(
+            PauseStatus::default(),
+            state.pause_status,
+            "no processes should be paused after default instantiation",
+        );
     }
 }
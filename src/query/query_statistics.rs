@@ -0,0 +1,206 @@
+use cosmwasm_std::{to_binary, Binary, Coin, Order, Uint128};
+use cosmwasm_storage::ReadonlyBucket;
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::core::state::{
+    asset_type_statistics_store_ro, contract_statistics_read, fees_paid_store_ro,
+    verifier_statistics_store_ro,
+};
+use crate::core::types::onboarding_statistics::OnboardingStatistics;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// Links [OnboardingStatistics](crate::core::types::onboarding_statistics::OnboardingStatistics)
+/// to the asset type to which they pertain.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AssetTypeStatistics {
+    /// The asset type for which the statistics were recorded.
+    pub asset_type: String,
+    /// All counters recorded for the asset type.
+    pub statistics: OnboardingStatistics,
+}
+
+/// Links [OnboardingStatistics](crate::core::types::onboarding_statistics::OnboardingStatistics)
+/// to the verifier to which they pertain.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VerifierStatistics {
+    /// The bech32 address of the verifier for which the statistics were recorded.
+    pub verifier_address: String,
+    /// All counters recorded for the verifier.
+    pub statistics: OnboardingStatistics,
+}
+
+/// The response produced by the [query_statistics](self::query_statistics) function.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryStatisticsResponse {
+    /// Counters that encompass every onboarding and verification performed by the contract.
+    pub totals: OnboardingStatistics,
+    /// Counters for each asset type that has been onboarded at least once.
+    pub asset_types: Vec<AssetTypeStatistics>,
+    /// Counters for each verifier that has been selected during onboarding at least once.
+    pub verifiers: Vec<VerifierStatistics>,
+    /// The total amount of fees paid out by the contract after verifications, per denom.
+    pub fees_paid: Vec<Coin>,
+}
+
+/// A query that fetches all onboarding and verification counters tracked by the contract.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_statistics(deps: &DepsC) -> AssetResult<Binary> {
+    let totals = contract_statistics_read(deps.storage)
+        .may_load()?
+        .unwrap_or_default();
+    let asset_types = load_keyed_values(asset_type_statistics_store_ro(deps.storage))
+        .into_iter()
+        .map(|(asset_type, statistics)| AssetTypeStatistics {
+            asset_type,
+            statistics,
+        })
+        .collect();
+    let verifiers = load_keyed_values(verifier_statistics_store_ro(deps.storage))
+        .into_iter()
+        .map(|(verifier_address, statistics)| VerifierStatistics {
+            verifier_address,
+            statistics,
+        })
+        .collect();
+    let fees_paid = load_keyed_values::<Uint128>(fees_paid_store_ro(deps.storage))
+        .into_iter()
+        .map(|(denom, amount)| Coin { denom, amount })
+        .collect();
+    to_binary(&QueryStatisticsResponse {
+        totals,
+        asset_types,
+        verifiers,
+        fees_paid,
+    })?
+    .to_ok()
+}
+
+fn load_keyed_values<T: Serialize + DeserializeOwned>(
+    bucket: ReadonlyBucket<T>,
+) -> Vec<(String, T)> {
+    bucket
+        .range(None, None, Order::Ascending)
+        .filter_map(|result| result.ok())
+        .filter_map(|(key, value)| String::from_utf8(key).ok().map(|key| (key, value)))
+        .collect()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::{coin, from_binary};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::core::types::onboarding_statistics::OnboardingStatistics;
+    use crate::execute::onboard_asset::OnboardAssetV1;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM,
+        DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{setup_test_suite, InstArgs};
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::aliases::DepsC;
+
+    use super::{query_statistics, AssetTypeStatistics, QueryStatisticsResponse};
+
+    #[test]
+    fn test_empty_statistics() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        let response = query_statistics_response(&deps.as_ref());
+        assert_eq!(
+            QueryStatisticsResponse {
+                totals: OnboardingStatistics::default(),
+                asset_types: vec![],
+                verifiers: vec![],
+                fees_paid: vec![],
+            },
+            response,
+            "no statistics should be recorded before any onboarding occurs",
+        );
+    }
+
+    #[test]
+    fn test_statistics_are_recorded_through_onboarding_lifecycle() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        test_verify_asset(&mut deps, TestVerifyAsset::default_with_success(false)).unwrap();
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        test_verify_asset(&mut deps, TestVerifyAsset::default()).unwrap();
+        let response = query_statistics_response(&deps.as_ref());
+        let expected_statistics = OnboardingStatistics {
+            onboardings: 2,
+            retries: 1,
+            approvals: 1,
+            denials: 1,
+        };
+        assert_eq!(
+            expected_statistics, response.totals,
+            "the contract-wide totals should reflect every action taken",
+        );
+        assert_eq!(1, response.asset_types.len());
+        assert_eq!(DEFAULT_ASSET_TYPE, response.asset_types[0].asset_type);
+        assert_eq!(expected_statistics, response.asset_types[0].statistics);
+        assert_eq!(1, response.verifiers.len());
+        assert_eq!(
+            DEFAULT_VERIFIER_ADDRESS,
+            response.verifiers[0].verifier_address
+        );
+        assert_eq!(expected_statistics, response.verifiers[0].statistics);
+        assert_eq!(
+            vec![coin(DEFAULT_ONBOARDING_COST * 2, DEFAULT_ONBOARDING_DENOM)],
+            response.fees_paid,
+            "the fees for both verifications should be tracked",
+        );
+    }
+
+    #[test]
+    fn test_asset_type_statistics_ignore_requestor_casing() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset {
+                onboard_asset: OnboardAssetV1 {
+                    asset_type: DEFAULT_ASSET_TYPE.to_uppercase(),
+                    ..TestOnboardAsset::default_onboard_asset()
+                },
+                ..TestOnboardAsset::default()
+            },
+        )
+        .expect("onboarding with an uppercase asset type should succeed");
+        test_verify_asset(&mut deps, TestVerifyAsset::default_with_success(false)).unwrap();
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        test_verify_asset(&mut deps, TestVerifyAsset::default()).unwrap();
+        let response = query_statistics_response(&deps.as_ref());
+        assert_eq!(
+            vec![AssetTypeStatistics {
+                asset_type: DEFAULT_ASSET_TYPE.to_string(),
+                statistics: OnboardingStatistics {
+                    onboardings: 2,
+                    retries: 1,
+                    approvals: 1,
+                    denials: 1,
+                },
+            }],
+            response.asset_types,
+            "the counters for every casing of the asset type should be combined under its lowercase form",
+        );
+    }
+
+    fn query_statistics_response(deps: &DepsC) -> QueryStatisticsResponse {
+        from_binary(&query_statistics(deps).expect("the query should succeed"))
+            .expect("the response should deserialize")
+    }
+}
@@ -0,0 +1,96 @@
+use cosmwasm_std::{to_binary, Binary};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::state::{config_read_v2, load_queued_changes};
+use crate::core::types::timelocked_change::QueuedChange;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// The response produced by the [query_timelocked_changes](self::query_timelocked_changes) function.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryTimelockedChangesResponse {
+    /// The contract's current [timelock_delay](crate::core::state::StateV2::timelock_delay), in
+    /// blocks.  A None value indicates that the timelock is disabled.
+    pub timelock_delay: Option<u64>,
+    /// Every change that has been queued and not yet executed or cancelled, in the order in which
+    /// they were queued.
+    pub changes: Vec<QueuedChange>,
+}
+
+/// A query that fetches every [QueuedChange](crate::core::types::timelocked_change::QueuedChange)
+/// that has not yet been executed or cancelled, alongside the contract's current timelock delay.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_timelocked_changes(deps: &DepsC) -> AssetResult<Binary> {
+    to_binary(&QueryTimelockedChangesResponse {
+        timelock_delay: config_read_v2(deps.storage).load()?.timelock_delay,
+        changes: load_queued_changes(deps.storage)?,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::from_binary;
+    use cosmwasm_std::testing::mock_env;
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::core::types::timelocked_change::TimelockedChange;
+    use crate::execute::queue_timelocked_change::{
+        queue_timelocked_change, QueueTimelockedChangeV1,
+    };
+    use crate::testutil::test_constants::DEFAULT_ADMIN_ADDRESS;
+    use crate::testutil::test_utilities::{
+        empty_mock_info, test_enable_timelock, test_instantiate_success, InstArgs,
+    };
+    use crate::util::traits::OptionExtensions;
+
+    use super::{query_timelocked_changes, QueryTimelockedChangesResponse};
+
+    #[test]
+    fn test_query_timelocked_changes() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let response = from_binary::<QueryTimelockedChangesResponse>(
+            &query_timelocked_changes(&deps.as_ref()).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert!(
+            response.timelock_delay.is_none() && response.changes.is_empty(),
+            "the timelock should be disabled with no queued changes after instantiation",
+        );
+        test_enable_timelock(deps.as_mut(), 100);
+        let change = TimelockedChange::UpdateProtocolFee { protocol_fee: None };
+        queue_timelocked_change(
+            deps.as_mut(),
+            mock_env(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            QueueTimelockedChangeV1::new(change.clone()),
+        )
+        .expect("queueing a change should succeed");
+        let response = from_binary::<QueryTimelockedChangesResponse>(
+            &query_timelocked_changes(&deps.as_ref()).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize");
+        assert_eq!(
+            100.to_some(),
+            response.timelock_delay,
+            "the current timelock delay should be returned",
+        );
+        assert_eq!(
+            1,
+            response.changes.len(),
+            "the queued change should be returned"
+        );
+        assert_eq!(
+            change, response.changes[0].change,
+            "the queued change's contents should be returned",
+        );
+    }
+}
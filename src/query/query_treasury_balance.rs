@@ -0,0 +1,129 @@
+use cosmwasm_std::{to_binary, Binary, Coin, Order};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::state::treasury_balances_store_ro;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// The response produced by the [query_treasury_balance](self::query_treasury_balance) function.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryTreasuryBalanceResponse {
+    /// All funds held in the contract treasury, with one entry per denom.  Denoms that have been
+    /// fully withdrawn are omitted.
+    pub balances: Vec<Coin>,
+}
+
+/// A query that fetches the funds currently held in the contract treasury.  These funds do not
+/// include onboarding fees held in escrow for pending verifications.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_treasury_balance(deps: &DepsC) -> AssetResult<Binary> {
+    let balances = treasury_balances_store_ro(deps.storage)
+        .range(None, None, Order::Ascending)
+        .filter_map(|result| result.ok())
+        .filter(|(_, amount)| !amount.is_zero())
+        .filter_map(|(denom, amount)| {
+            String::from_utf8(denom)
+                .ok()
+                .map(|denom| Coin { denom, amount })
+        })
+        .collect::<Vec<Coin>>();
+    to_binary(&QueryTreasuryBalanceResponse { balances })?.to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::{coin, from_binary, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::core::types::fee_destination::FeeDestinationV2;
+    use crate::core::types::fee_remainder_destination::FeeRemainderDestination;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::execute::withdraw_treasury::{withdraw_treasury, WithdrawTreasuryV1};
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_FEE_ADDRESS, DEFAULT_ONBOARDING_COST,
+        DEFAULT_ONBOARDING_DENOM, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_asset_definition_input, get_default_entity_detail,
+        setup_test_suite, InstArgs,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::aliases::DepsC;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{query_treasury_balance, QueryTreasuryBalanceResponse};
+
+    #[test]
+    fn test_empty_treasury() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        assert!(
+            query_treasury_balance_response(&deps.as_ref())
+                .balances
+                .is_empty(),
+            "the treasury should be empty before any verifications occur",
+        );
+    }
+
+    #[test]
+    fn test_treasury_accrues_remainders_and_reflects_withdrawals() {
+        let mut deps = mock_dependencies(&[]);
+        let mut asset_definition = get_default_asset_definition_input();
+        asset_definition.verifiers = vec![VerifierDetailV2::new(
+            DEFAULT_VERIFIER_ADDRESS,
+            Uint128::new(DEFAULT_ONBOARDING_COST),
+            DEFAULT_ONBOARDING_DENOM,
+            vec![FeeDestinationV2::new(
+                DEFAULT_FEE_ADDRESS,
+                Uint128::new(400),
+            )],
+            get_default_entity_detail().to_some(),
+        )
+        .with_remainder_destination(FeeRemainderDestination::ContractTreasury)];
+        setup_test_suite(
+            &mut deps,
+            InstArgs {
+                asset_definitions: vec![asset_definition],
+                ..Default::default()
+            },
+        );
+        test_onboard_asset(&mut deps, TestOnboardAsset::default()).unwrap();
+        test_verify_asset(&mut deps, TestVerifyAsset::default()).unwrap();
+        assert_eq!(
+            vec![coin(
+                DEFAULT_ONBOARDING_COST - 400,
+                DEFAULT_ONBOARDING_DENOM
+            )],
+            query_treasury_balance_response(&deps.as_ref()).balances,
+            "the unclaimed portion of the onboarding cost should be retained in the treasury",
+        );
+        withdraw_treasury(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            WithdrawTreasuryV1::new(
+                coin(DEFAULT_ONBOARDING_COST - 400, DEFAULT_ONBOARDING_DENOM),
+                DEFAULT_ADMIN_ADDRESS,
+            ),
+        )
+        .expect("withdrawing the entire treasury balance should succeed");
+        assert!(
+            query_treasury_balance_response(&deps.as_ref())
+                .balances
+                .is_empty(),
+            "fully withdrawn denoms should be omitted from the response",
+        );
+    }
+
+    fn query_treasury_balance_response(deps: &DepsC) -> QueryTreasuryBalanceResponse {
+        from_binary(&query_treasury_balance(deps).expect("the query should succeed"))
+            .expect("the response should deserialize")
+    }
+}
@@ -0,0 +1,327 @@
+use cosmwasm_std::{to_binary, Binary, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::state::{
+    asset_definitions_v2, load_verifier_metrics, resolve_asset_definition_v2,
+};
+use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::core::types::verifier_metrics::VerifierMetrics;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// Links a single [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2) to the
+/// asset type for which it is configured.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VerifierAssetTypeDetail {
+    /// The [asset_type](crate::core::types::asset_definition::AssetDefinitionV2::asset_type) of
+    /// the asset definition in which the verifier is registered.
+    pub asset_type: String,
+    /// Indicates whether or not the asset definition is currently enabled for onboarding.
+    pub asset_definition_enabled: bool,
+    /// The verifier's configuration within the asset definition.
+    pub verifier_detail: VerifierDetailV2,
+}
+
+/// The response produced by the [query_verifier](self::query_verifier) function.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryVerifierResponse {
+    /// The bech32 address of the verifier that was requested.
+    pub verifier_address: String,
+    /// Every asset type for which the verifier is registered, alongside its configuration for
+    /// that type.  This vector will be empty if the verifier is not registered anywhere.
+    pub asset_types: Vec<VerifierAssetTypeDetail>,
+    /// The verifier's performance across all asset types.  This value contains only zeroes if no
+    /// asset has ever been onboarded to the verifier.
+    pub metrics: VerifierMetrics,
+}
+impl QueryVerifierResponse {
+    /// Constructs a new instance of this struct.
+    ///
+    /// # Parameters
+    ///
+    /// * `verifier_address` The bech32 address of the verifier that was requested.
+    /// * `asset_types` Every asset type for which the verifier is registered.
+    /// * `metrics` The verifier's performance across all asset types.
+    pub fn new<S: Into<String>>(
+        verifier_address: S,
+        asset_types: Vec<VerifierAssetTypeDetail>,
+        metrics: VerifierMetrics,
+    ) -> Self {
+        Self {
+            verifier_address: verifier_address.into(),
+            asset_types,
+            metrics,
+        }
+    }
+}
+
+/// A query that locates every [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
+/// registered to a verifier address across all stored [AssetDefinitionV2s](crate::core::types::asset_definition::AssetDefinitionV2),
+/// alongside the verifier's [VerifierMetrics](crate::core::types::verifier_metrics::VerifierMetrics).
+/// Asset types that inherit the verifier from a parent asset type are included.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `verifier_address` The bech32 address of the verifier to search for.
+pub fn query_verifier<S: Into<String>>(deps: &DepsC, verifier_address: S) -> AssetResult<Binary> {
+    let verifier_address = verifier_address.into();
+    let asset_types = asset_definitions_v2()
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|result| result.map(|(_, definition)| definition))
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+        .map(|definition| resolve_asset_definition_v2(deps.storage, definition))
+        .collect::<AssetResult<Vec<_>>>()?
+        .into_iter()
+        .filter_map(|definition| {
+            let asset_type = definition.asset_type;
+            let asset_definition_enabled = definition.enabled;
+            definition
+                .verifiers
+                .into_iter()
+                .find(|verifier| verifier.address == verifier_address)
+                .map(|verifier_detail| VerifierAssetTypeDetail {
+                    asset_type,
+                    asset_definition_enabled,
+                    verifier_detail,
+                })
+        })
+        .collect::<Vec<VerifierAssetTypeDetail>>();
+    let metrics = load_verifier_metrics(deps.storage, &verifier_address)?;
+    to_binary(&QueryVerifierResponse::new(
+        verifier_address,
+        asset_types,
+        metrics,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::{from_binary, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use uuid::Uuid;
+
+    use crate::core::types::asset_definition::AssetDefinitionInputV2;
+    use crate::core::types::scope_spec_identifier::ScopeSpecIdentifier;
+    use crate::core::types::verifier_detail::VerifierDetailV2;
+    use crate::core::types::verifier_metrics::VerifierMetrics;
+    use crate::testutil::onboard_asset_helpers::{test_onboard_asset, TestOnboardAsset};
+    use crate::testutil::test_constants::{
+        DEFAULT_ASSET_TYPE, DEFAULT_FEE_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        env_at_height, get_default_asset_definition_input, get_default_entity_detail,
+        get_default_verifier_detail, setup_test_suite, test_instantiate_success, InstArgs,
+    };
+    use crate::testutil::verify_asset_helpers::{test_verify_asset, TestVerifyAsset};
+    use crate::util::aliases::DepsC;
+    use crate::util::traits::OptionExtensions;
+
+    use super::{query_verifier, QueryVerifierResponse};
+
+    #[test]
+    fn test_unknown_verifier_returns_empty_result() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let response = query_verifier_response(&deps.as_ref(), "unknown-verifier");
+        assert_eq!(
+            "unknown-verifier", response.verifier_address,
+            "the requested address should be echoed in the response",
+        );
+        assert!(
+            response.asset_types.is_empty(),
+            "no asset types should be found for an unregistered verifier",
+        );
+    }
+
+    #[test]
+    fn test_default_verifier_is_found() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        let response = query_verifier_response(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS);
+        assert_eq!(
+            1,
+            response.asset_types.len(),
+            "expected the verifier to be found for the default asset type only",
+        );
+        let detail = response.asset_types.first().unwrap();
+        assert_eq!(DEFAULT_ASSET_TYPE, detail.asset_type);
+        assert!(detail.asset_definition_enabled);
+        assert_eq!(get_default_verifier_detail(), detail.verifier_detail);
+    }
+
+    #[test]
+    fn test_verifier_is_found_across_many_asset_types() {
+        let mut deps = mock_dependencies(&[]);
+        let other_verifier = VerifierDetailV2::new(
+            DEFAULT_VERIFIER_ADDRESS,
+            Uint128::new(777),
+            "nhash",
+            vec![],
+            get_default_entity_detail().to_some(),
+        )
+        .with_overrides_registered_fees(true);
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![
+                    get_default_asset_definition_input(),
+                    AssetDefinitionInputV2::new(
+                        "other_type",
+                        ScopeSpecIdentifier::uuid(Uuid::new_v4().to_string()).to_serialized_enum(),
+                        vec![other_verifier.clone()],
+                        false.to_some(),
+                        false.to_some(),
+                    ),
+                    AssetDefinitionInputV2::new(
+                        "unrelated_type",
+                        ScopeSpecIdentifier::uuid(Uuid::new_v4().to_string()).to_serialized_enum(),
+                        vec![VerifierDetailV2::new(
+                            DEFAULT_FEE_ADDRESS,
+                            Uint128::new(100),
+                            "nhash",
+                            vec![],
+                            None,
+                        )],
+                        true.to_some(),
+                        false.to_some(),
+                    ),
+                ],
+                ..Default::default()
+            },
+        );
+        let response = query_verifier_response(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS);
+        assert_eq!(
+            2,
+            response.asset_types.len(),
+            "expected the verifier to be found in both asset types in which it is registered",
+        );
+        let other_detail = response
+            .asset_types
+            .iter()
+            .find(|detail| detail.asset_type == "other_type")
+            .expect("the other asset type should be included");
+        assert!(
+            !other_detail.asset_definition_enabled,
+            "the disabled state of the other asset definition should be reflected",
+        );
+        assert_eq!(other_verifier, other_detail.verifier_detail);
+        assert!(
+            response
+                .asset_types
+                .iter()
+                .any(|detail| detail.asset_type == DEFAULT_ASSET_TYPE),
+            "the default asset type should be included",
+        );
+    }
+
+    #[test]
+    fn test_verifier_is_found_for_inheriting_asset_types() {
+        let mut deps = mock_dependencies(&[]);
+        let mut child_input = get_default_asset_definition_input()
+            .with_parent_asset_type(DEFAULT_ASSET_TYPE.to_some());
+        child_input.asset_type = "child".to_string();
+        child_input.scope_spec_identifier =
+            ScopeSpecIdentifier::uuid(Uuid::new_v4().to_string()).to_serialized_enum();
+        child_input.verifiers = vec![];
+        test_instantiate_success(
+            deps.as_mut(),
+            InstArgs {
+                asset_definitions: vec![get_default_asset_definition_input(), child_input],
+                ..Default::default()
+            },
+        );
+        let response = query_verifier_response(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS);
+        assert_eq!(
+            2,
+            response.asset_types.len(),
+            "expected the verifier to be found in the parent and the child asset types",
+        );
+        let child_detail = response
+            .asset_types
+            .iter()
+            .find(|detail| detail.asset_type == "child")
+            .expect("the child asset type that inherits the verifier should be included");
+        assert_eq!(
+            get_default_verifier_detail(),
+            child_detail.verifier_detail,
+            "the inherited verifier detail should be included for the child asset type",
+        );
+    }
+
+    #[test]
+    fn test_verifier_metrics_are_included() {
+        let mut deps = mock_dependencies(&[]);
+        setup_test_suite(&mut deps, InstArgs::default());
+        assert_eq!(
+            VerifierMetrics::default(),
+            query_verifier_response(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS).metrics,
+            "a verifier without any onboarded assets should have empty metrics",
+        );
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset {
+                env: env_at_height(100),
+                ..TestOnboardAsset::default()
+            },
+        )
+        .expect("onboarding should succeed");
+        assert_eq!(
+            VerifierMetrics {
+                pending_assets: 1,
+                ..VerifierMetrics::default()
+            },
+            query_verifier_response(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS).metrics,
+            "the onboarded asset should be pending",
+        );
+        test_verify_asset(
+            &mut deps,
+            TestVerifyAsset {
+                env: env_at_height(150),
+                ..TestVerifyAsset::default_with_success(false)
+            },
+        )
+        .expect("verification should succeed");
+        test_onboard_asset(
+            &mut deps,
+            TestOnboardAsset {
+                env: env_at_height(200),
+                ..TestOnboardAsset::default()
+            },
+        )
+        .expect("the denied asset should be onboarded again");
+        test_verify_asset(
+            &mut deps,
+            TestVerifyAsset {
+                env: env_at_height(225),
+                ..TestVerifyAsset::default()
+            },
+        )
+        .expect("the retried verification should succeed");
+        assert_eq!(
+            VerifierMetrics {
+                approvals: 1,
+                denials: 1,
+                timed_verifications: 2,
+                total_verification_blocks: 75,
+                average_verification_blocks: 37,
+                max_verification_blocks: 50,
+                pending_assets: 0,
+            },
+            query_verifier_response(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS).metrics,
+            "each verification should be measured from its own onboarding",
+        );
+    }
+
+    fn query_verifier_response(deps: &DepsC, address: &str) -> QueryVerifierResponse {
+        from_binary(&query_verifier(deps, address).expect("the query should succeed"))
+            .expect("the response should deserialize")
+    }
+}
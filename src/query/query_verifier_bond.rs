@@ -0,0 +1,84 @@
+use cosmwasm_std::{to_binary, Binary};
+
+use crate::core::state::{may_load_asset_definition_v2_by_type, may_load_verifier_bond};
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// A query that fetches the collateral bond posted by a verifier for an asset type.  If the verifier
+/// has no bond for the asset type, an empty response is returned.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `asset_type` The asset type for which the bond was posted.
+/// * `verifier_address` The bech32 address of the verifier.
+pub fn query_verifier_bond<S1: Into<String>, S2: Into<String>>(
+    deps: &DepsC,
+    asset_type: S1,
+    verifier_address: S2,
+) -> AssetResult<Binary> {
+    let asset_type = asset_type.into();
+    // Bonds are stored under the asset type's original casing, so prefer the casing of the asset
+    // definition when it still exists
+    let asset_type = may_load_asset_definition_v2_by_type(deps.storage, &asset_type)?
+        .map(|definition| definition.asset_type)
+        .unwrap_or(asset_type);
+    to_binary(&may_load_verifier_bond(
+        deps.storage,
+        &asset_type,
+        &verifier_address.into(),
+    )?)?
+    .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{coin, coins, from_binary, Coin};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::execute::post_verifier_bond::{post_verifier_bond, PostVerifierBondV1};
+    use crate::testutil::test_constants::{DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS};
+    use crate::testutil::test_utilities::{test_instantiate_success, InstArgs};
+    use crate::util::aliases::DepsC;
+    use crate::util::constants::NHASH;
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_verifier_bond;
+
+    #[test]
+    fn test_query_verifier_bond() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        assert!(
+            query_bond(&deps.as_ref(), DEFAULT_ASSET_TYPE).is_none(),
+            "no bond should be returned for a verifier that has not posted one",
+        );
+        post_verifier_bond(
+            deps.as_mut(),
+            mock_info(DEFAULT_VERIFIER_ADDRESS, &coins(1000, NHASH)),
+            PostVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+        )
+        .expect("posting a bond should succeed");
+        assert_eq!(
+            coin(1000, NHASH).to_some(),
+            query_bond(&deps.as_ref(), DEFAULT_ASSET_TYPE),
+            "the posted bond should be returned",
+        );
+        assert_eq!(
+            coin(1000, NHASH).to_some(),
+            query_bond(&deps.as_ref(), DEFAULT_ASSET_TYPE.to_uppercase()),
+            "the posted bond should be returned regardless of the casing of the asset type",
+        );
+    }
+
+    fn query_bond<S: Into<String>>(deps: &DepsC, asset_type: S) -> Option<Coin> {
+        from_binary(
+            &query_verifier_bond(deps, asset_type, DEFAULT_VERIFIER_ADDRESS)
+                .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize")
+    }
+}
@@ -0,0 +1,86 @@
+use cosmwasm_std::{to_binary, Binary};
+
+use crate::core::state::may_load_verifier_registration;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// A query that fetches the global [VerifierRegistration](crate::core::types::verifier_registration::VerifierRegistration)
+/// of a verifier.  If the verifier has never been registered, an empty response is returned.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `verifier_address` The bech32 address of the verifier.
+pub fn query_verifier_registration<S: Into<String>>(
+    deps: &DepsC,
+    verifier_address: S,
+) -> AssetResult<Binary> {
+    to_binary(&may_load_verifier_registration(
+        deps.storage,
+        &verifier_address.into(),
+    )?)?
+    .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::{from_binary, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::core::types::verifier_registration::VerifierRegistration;
+    use crate::execute::register_verifier::{register_verifier, RegisterVerifierV1};
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_SENDER_ADDRESS, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{
+        empty_mock_info, get_default_entity_detail, get_default_verifier_detail,
+        test_instantiate_success, InstArgs,
+    };
+    use crate::util::aliases::DepsC;
+    use crate::util::constants::NHASH;
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_verifier_registration;
+
+    #[test]
+    fn test_query_verifier_registration() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        assert!(
+            query_registration(&deps.as_ref(), DEFAULT_SENDER_ADDRESS).is_none(),
+            "no registration should be returned for an unregistered verifier",
+        );
+        assert_eq!(
+            VerifierRegistration::from_verifier_detail(&get_default_verifier_detail()).to_some(),
+            query_registration(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS),
+            "the verifier should be registered when its asset definition is instantiated",
+        );
+        let registration = VerifierRegistration::new(
+            DEFAULT_VERIFIER_ADDRESS,
+            get_default_entity_detail().to_some(),
+            Uint128::new(1000),
+            NHASH,
+            vec![],
+        );
+        register_verifier(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            RegisterVerifierV1::new(registration.clone()),
+        )
+        .expect("registering the verifier should succeed");
+        assert_eq!(
+            registration.to_some(),
+            query_registration(&deps.as_ref(), DEFAULT_VERIFIER_ADDRESS),
+            "the stored registration should be returned",
+        );
+    }
+
+    fn query_registration(deps: &DepsC, verifier_address: &str) -> Option<VerifierRegistration> {
+        from_binary(
+            &query_verifier_registration(deps, verifier_address).expect("the query should succeed"),
+        )
+        .expect("the response should deserialize")
+    }
+}
@@ -0,0 +1,88 @@
+use cosmwasm_std::{to_binary, Binary};
+
+use crate::core::state::load_verifier_self_update_bounds;
+use crate::util::aliases::{AssetResult, DepsC};
+use crate::util::traits::ResultExtensions;
+
+/// A query that fetches the [VerifierSelfUpdateBounds](crate::core::types::verifier_self_update_bounds::VerifierSelfUpdateBounds)
+/// for a verifier within an asset type.  If the admin has never set bounds for the verifier, the
+/// default bounds are returned.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `asset_type` The asset type of the definition containing the verifier.
+/// * `verifier_address` The bech32 address of the verifier.
+pub fn query_verifier_self_update_bounds<S1: Into<String>, S2: Into<String>>(
+    deps: &DepsC,
+    asset_type: S1,
+    verifier_address: S2,
+) -> AssetResult<Binary> {
+    to_binary(&load_verifier_self_update_bounds(
+        deps.storage,
+        &asset_type.into(),
+        &verifier_address.into(),
+    )?)?
+    .to_ok()
+}
+
+#[cfg(test)]
+#[cfg(feature = "enable-test-utils")]
+mod tests {
+    use cosmwasm_std::{from_binary, Uint128};
+    use provwasm_mocks::mock_dependencies;
+
+    use crate::core::types::verifier_self_update_bounds::{
+        VerifierDetailField, VerifierSelfUpdateBounds,
+    };
+    use crate::execute::update_verifier_self_update_bounds::{
+        update_verifier_self_update_bounds, UpdateVerifierSelfUpdateBoundsV1,
+    };
+    use crate::testutil::test_constants::{
+        DEFAULT_ADMIN_ADDRESS, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS,
+    };
+    use crate::testutil::test_utilities::{empty_mock_info, test_instantiate_success, InstArgs};
+    use crate::util::aliases::DepsC;
+    use crate::util::traits::OptionExtensions;
+
+    use super::query_verifier_self_update_bounds;
+
+    #[test]
+    fn test_query_verifier_self_update_bounds() {
+        let mut deps = mock_dependencies(&[]);
+        test_instantiate_success(deps.as_mut(), InstArgs::default());
+        assert_eq!(
+            VerifierSelfUpdateBounds::default(),
+            query_bounds(&deps.as_ref()),
+            "the default bounds should be returned when none have been set",
+        );
+        let bounds = VerifierSelfUpdateBounds::new(
+            Uint128::new(1500).to_some(),
+            vec![VerifierDetailField::AccrueFees],
+        );
+        update_verifier_self_update_bounds(
+            deps.as_mut(),
+            empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+            UpdateVerifierSelfUpdateBoundsV1::new(
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_VERIFIER_ADDRESS,
+                bounds.clone(),
+            ),
+        )
+        .expect("setting the bounds should succeed");
+        assert_eq!(
+            bounds,
+            query_bounds(&deps.as_ref()),
+            "the stored bounds should be returned",
+        );
+    }
+
+    fn query_bounds(deps: &DepsC) -> VerifierSelfUpdateBounds {
+        from_binary(
+            &query_verifier_self_update_bounds(deps, DEFAULT_ASSET_TYPE, DEFAULT_VERIFIER_ADDRESS)
+                .expect("the query should succeed"),
+        )
+        .expect("the response should deserialize")
+    }
+}
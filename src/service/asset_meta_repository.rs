@@ -1,3 +1,4 @@
+use crate::core::types::fee_destination::FeeDestinationV2;
 use crate::core::types::verifier_detail::VerifierDetailV2;
 use crate::{
     core::types::{access_route::AccessRoute, asset_scope_attribute::AssetScopeAttribute},
@@ -25,13 +26,21 @@ pub trait AssetMetaRepository {
     /// * `latest_verifier_detail` The verifier detail currently in storage when this scope is
     /// onboarded.  Stored in contract storage until a verification has been completed to ensure that
     /// the proper fee distribution is made when verification completes.
+    /// * `protocol_fee` The [protocol fee](crate::core::types::protocol_fee::ProtocolFee) charged
+    /// during onboarding, if any, expressed as the collector and amount charged.  Stored in contract
+    /// storage until a verification has been completed, at which point it is paid to the collector.
     /// * `is_retry` Indicates that this onboarding action was attempted before, and the scope has
     /// an existing scope attribute with a failed verification on it.
+    /// * `block_height` The height of the block in which the onboarding occurs.  Stored in contract
+    /// storage until a verification has been completed, in order to measure the time that the
+    /// verifier takes to process the asset.
     fn onboard_asset(
         &self,
         attribute: &AssetScopeAttribute,
         latest_verifier_detail: &VerifierDetailV2,
+        protocol_fee: Option<&FeeDestinationV2>,
         is_retry: bool,
+        block_height: u64,
     ) -> AssetResult<()>;
 
     /// Alters the internal values of the [AssetScopeAttribute](crate::core::types::asset_scope_attribute::AssetScopeAttribute)
@@ -77,11 +86,42 @@ pub trait AssetMetaRepository {
     /// * `access_routes` Additional access routes that the verifier provides for external consumers
     /// to retrieve the underlying asset data from the scope, potentially without access an object
     /// store.
+    /// * `block_height` The height of the block in which the verification occurs.
     fn verify_asset<S1: Into<String>, S2: Into<String>>(
         &self,
         scope_address: S1,
         success: bool,
         verification_message: Option<S2>,
         access_routes: Vec<AccessRoute>,
+        block_height: u64,
+    ) -> AssetResult<()>;
+
+    /// Assigns an asset that is awaiting verification to a different verifier.  The fees held in
+    /// escrow for the asset are retained, and are distributed using the new verifier's fee
+    /// destinations when verification completes.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope_address` A Provenance Blockchain bech32 address with an hrp of "scope".  Links to
+    /// the pending scope to reassign.
+    /// * `verifier` The verifier detail of the verifier that will take over the asset.
+    fn reassign_pending_asset<S1: Into<String>>(
+        &self,
+        scope_address: S1,
+        verifier: &VerifierDetailV2,
+    ) -> AssetResult<()>;
+
+    /// Returns the fees held in escrow for an asset that is awaiting verification to its requestor,
+    /// and marks the asset as denied so that it can be onboarded again.
+    ///
+    /// # Parameters
+    ///
+    /// * `scope_address` A Provenance Blockchain bech32 address with an hrp of "scope".  Links to
+    /// the pending scope to refund.
+    /// * `message` The message to record as the asset's latest verification result.
+    fn refund_pending_asset<S1: Into<String>, S2: Into<String>>(
+        &self,
+        scope_address: S1,
+        message: S2,
     ) -> AssetResult<()>;
 }
@@ -1,9 +1,18 @@
 use std::collections::HashSet;
 
-use cosmwasm_std::CosmosMsg;
+use cosmwasm_std::{coin, Coin, CosmosMsg};
 use provwasm_std::{delete_attributes, ProvenanceMsg};
 
-use crate::core::state::{delete_latest_verifier_detail, insert_latest_verifier_detail};
+use crate::core::state::{
+    accrue_fees, delete_latest_protocol_fee, delete_latest_verifier_detail,
+    delete_pending_verification_scope, deposit_to_treasury, increment_requestor_onboarding_count,
+    insert_latest_protocol_fee, insert_latest_verifier_detail, insert_pending_verification_scope,
+    latest_onboarding_height_store, latest_protocol_fee_store_ro, record_fees_paid,
+    record_onboarding_statistics, record_verification_statistics, take_latest_onboarding_height,
+    update_verifier_metrics,
+};
+use crate::core::types::fee_destination::FeeDestinationV2;
+use crate::core::types::fee_remainder_destination::FeeRemainderDestination;
 use crate::core::types::verifier_detail::VerifierDetailV2;
 use crate::{
     core::{
@@ -23,7 +32,10 @@ use crate::{
     util::aliases::{AssetResult, DepsMutC},
     util::deps_container::DepsContainer,
     util::vec_container::VecContainer,
-    util::{fees::calculate_verifier_cost_messages, functions::generate_asset_attribute_name},
+    util::{
+        fees::{calculate_verifier_fee_payouts, calculate_verifier_intake_fee_payouts},
+        functions::{bank_send, generate_asset_attribute_name},
+    },
     util::{functions::filter_valid_access_routes, traits::ResultExtensions},
     util::{
         provenance_util::get_add_attribute_to_scope_msg, scope_address_utils::bech32_string_to_addr,
@@ -57,6 +69,82 @@ impl<'a> AssetMetaService<'a> {
             messages: VecContainer::new(),
         }
     }
+
+    /// Distributes fee payouts derived from a verifier detail, returning the funds that were paid.
+    /// Payouts are sent immediately, or credited to each recipient's fee ledger balance if the
+    /// verifier has elected to [accrue fees](crate::core::types::verifier_detail::VerifierDetailV2::accrue_fees).
+    /// Any remainder directed to the contract treasury is added to its tracked balance.
+    ///
+    /// # Parameters
+    ///
+    /// * `verifier_detail` The verifier detail from which the payouts were derived.
+    /// * `fee_payouts` Each recipient address and the funds it is owed.
+    /// * `remainder` The portion of the distributed cost not claimed by any fee destination.
+    fn pay_out_fees(
+        &self,
+        verifier_detail: &VerifierDetailV2,
+        fee_payouts: &[(String, Coin)],
+        remainder: u128,
+    ) -> AssetResult<Vec<Coin>> {
+        if verifier_detail.accrue_fees {
+            // credit each recipient's balance, to be collected later via the withdraw fees route
+            self.use_deps(|deps| {
+                fee_payouts
+                    .iter()
+                    .try_for_each(|(address, fee)| accrue_fees(deps.storage, address, fee))
+            })?;
+        } else {
+            fee_payouts.iter().for_each(|(address, fee)| {
+                self.add_message(bank_send(address, fee.amount.u128(), &fee.denom))
+            });
+        }
+        // The contract retains the unclaimed portion of the cost when the verifier elects to leave
+        // it in the treasury, so its tracked balance must grow accordingly
+        if verifier_detail.remainder_destination == FeeRemainderDestination::ContractTreasury
+            && remainder > 0
+        {
+            self.use_deps(|deps| {
+                deposit_to_treasury(
+                    deps.storage,
+                    &coin(remainder, &verifier_detail.onboarding_denom),
+                )
+            })?;
+        }
+        fee_payouts
+            .iter()
+            .map(|(_, fee)| fee.to_owned())
+            .collect::<Vec<Coin>>()
+            .to_ok()
+    }
+
+    /// Fetches the verifier detail held in storage for an asset that is awaiting verification,
+    /// returning an error if the asset is not awaiting verification.
+    ///
+    /// # Parameters
+    ///
+    /// * `attribute` The scope attribute of the pending asset.
+    fn get_escrowed_verifier_detail(
+        &self,
+        attribute: &AssetScopeAttribute,
+    ) -> AssetResult<VerifierDetailV2> {
+        if attribute.onboarding_status != AssetOnboardingStatus::Pending {
+            return ContractError::AssetAlreadyVerified {
+                scope_address: attribute.scope_address.clone(),
+                status: attribute.onboarding_status.clone(),
+            }
+            .to_err();
+        }
+        match self.use_deps(|deps| attribute.get_latest_verifier_detail(deps.storage)) {
+            Some(verifier_detail) => verifier_detail.to_ok(),
+            None => ContractError::UnexpectedState {
+                explanation: format!(
+                    "Verifier detail not present on pending asset [{}]",
+                    attribute.scope_address
+                ),
+            }
+            .to_err(),
+        }
+    }
 }
 impl<'a> AssetMetaRepository for AssetMetaService<'a> {
     fn has_asset<S1: Into<String>>(&self, scope_address: S1) -> AssetResult<bool> {
@@ -73,7 +161,9 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
         &self,
         attribute: &AssetScopeAttribute,
         latest_verifier_detail: &VerifierDetailV2,
+        protocol_fee: Option<&FeeDestinationV2>,
         is_retry: bool,
+        block_height: u64,
     ) -> AssetResult<()> {
         // Verify that the attribute does or does not exist.  This check verifies that the value equivalent to is_retry:
         // If the asset exists, this should be a retry, because a subsequent onboard should only occur for that purpose
@@ -111,8 +201,61 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
                 deps.storage,
                 &attribute.scope_address,
                 latest_verifier_detail,
+            )?;
+            insert_pending_verification_scope(
+                deps.storage,
+                &attribute.asset_type,
+                &latest_verifier_detail.address,
+                &attribute.scope_address,
+            )
+        })?;
+
+        // Store the protocol fee held in escrow, if one was charged, so that it can be paid to its
+        // collector when verification occurs
+        if let Some(protocol_fee) = protocol_fee {
+            self.use_deps(|deps| {
+                insert_latest_protocol_fee(deps.storage, &attribute.scope_address, protocol_fee)
+            })?;
+        }
+
+        // The intake fee is paid out immediately, so its recipients keep it regardless of whether
+        // the scope is ever verified
+        if !latest_verifier_detail.intake_fee.is_zero() {
+            let fees_paid = self.pay_out_fees(
+                latest_verifier_detail,
+                &calculate_verifier_intake_fee_payouts(latest_verifier_detail)?,
+                latest_verifier_detail.get_intake_fee_remainder(),
+            )?;
+            self.use_deps(|deps| record_fees_paid(deps.storage, &fees_paid))?;
+        }
+
+        self.use_deps(|deps| {
+            record_onboarding_statistics(
+                deps.storage,
+                &attribute.asset_type,
+                attribute.verifier_address.as_str(),
+                is_retry,
+            )?;
+            latest_onboarding_height_store(deps.storage)
+                .save(attribute.scope_address.as_bytes(), &block_height)?;
+            update_verifier_metrics(
+                deps.storage,
+                attribute.verifier_address.as_str(),
+                |metrics| metrics.record_onboarding(),
             )
         })?;
+
+        // Retries re-submit an asset that was already counted, so only new onboardings advance the
+        // requestor towards the verifier's pricing tiers
+        if !is_retry {
+            self.use_deps(|deps| {
+                increment_requestor_onboarding_count(
+                    deps.storage,
+                    attribute.verifier_address.as_str(),
+                    attribute.requestor_address.as_str(),
+                )
+            })?;
+        }
         Ok(())
     }
 
@@ -157,6 +300,7 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
         success: bool,
         verification_message: Option<S2>,
         access_routes: Vec<AccessRoute>,
+        block_height: u64,
     ) -> AssetResult<()> {
         // set verification result on asset (add messages to message service)
         let scope_address_str = scope_address.into();
@@ -228,12 +372,58 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
             // with the changes made to the attribute
             self.update_attribute(&attribute)?;
 
-            // distribute fees now that verification has happened
-            self.append_messages(&calculate_verifier_cost_messages(&verifier_detail)?);
+            // distribute the completion fee now that verification has happened
+            let mut fee_payouts = calculate_verifier_fee_payouts(&verifier_detail)?;
+            // the protocol fee charged during onboarding, if one was charged, is distributed in
+            // the same way as the completion fee, so that it also accrues if the verifier elected
+            // to accrue fees
+            if let Some(protocol_fee) = self.use_deps(|deps| {
+                latest_protocol_fee_store_ro(deps.storage).may_load(scope_address_str.as_bytes())
+            })? {
+                fee_payouts.push((
+                    protocol_fee.address,
+                    coin(
+                        protocol_fee.fee_amount.u128(),
+                        &verifier_detail.onboarding_denom,
+                    ),
+                ));
+                self.use_deps(|deps| delete_latest_protocol_fee(deps.storage, &scope_address_str))?;
+            }
+            let fees_paid = self.pay_out_fees(
+                &verifier_detail,
+                &fee_payouts,
+                verifier_detail.get_fee_remainder(),
+            )?;
+
+            self.use_deps(|deps| {
+                record_verification_statistics(
+                    deps.storage,
+                    &attribute.asset_type,
+                    attribute.verifier_address.as_str(),
+                    success,
+                )?;
+                let elapsed_blocks =
+                    take_latest_onboarding_height(deps.storage, &scope_address_str)?
+                        .map(|onboarding_height| block_height.saturating_sub(onboarding_height));
+                update_verifier_metrics(
+                    deps.storage,
+                    attribute.verifier_address.as_str(),
+                    |metrics| metrics.record_verification(success, elapsed_blocks),
+                )?;
+                record_fees_paid(deps.storage, &fees_paid)
+            })?;
 
             // Remove the latest verifier detail from storage - it's only needed for discovering
             // fees, so its existence is no longer relevant after verification completes.
-            self.use_deps(|deps| delete_latest_verifier_detail(deps.storage, &scope_address_str))?;
+            self.use_deps(|deps| {
+                delete_pending_verification_scope(
+                    deps.storage,
+                    &attribute.asset_type,
+                    attribute.verifier_address.as_str(),
+                    &scope_address_str,
+                );
+                delete_latest_verifier_detail(deps.storage, &scope_address_str)
+            })?;
         } else {
             return ContractError::UnexpectedState {
                 explanation: format!(
@@ -246,6 +436,109 @@ impl<'a> AssetMetaRepository for AssetMetaService<'a> {
 
         Ok(())
     }
+
+    fn reassign_pending_asset<S1: Into<String>>(
+        &self,
+        scope_address: S1,
+        verifier: &VerifierDetailV2,
+    ) -> AssetResult<()> {
+        let scope_address_str = scope_address.into();
+        let mut attribute = self.get_asset(&scope_address_str)?;
+        let escrowed_verifier_detail = self.get_escrowed_verifier_detail(&attribute)?;
+        // The requestor has already paid the escrowed cost, so it is retained as-is, and only the
+        // new verifier's payout configuration is adopted
+        let reassigned_verifier_detail = VerifierDetailV2 {
+            onboarding_cost: escrowed_verifier_detail.onboarding_cost,
+            onboarding_denom: escrowed_verifier_detail.onboarding_denom,
+            applied_pricing_tier: escrowed_verifier_detail.applied_pricing_tier,
+            intake_fee: escrowed_verifier_detail.intake_fee,
+            intake_fee_destinations: escrowed_verifier_detail.intake_fee_destinations,
+            ..verifier.to_owned()
+        };
+        // Ensure that the new verifier's fee destinations can be paid from the escrowed completion
+        // fee, otherwise the asset could never be verified
+        calculate_verifier_fee_payouts(&reassigned_verifier_detail)?;
+        let previous_verifier_address = attribute.verifier_address.to_string();
+        attribute.verifier_address = bech32_string_to_addr(&reassigned_verifier_detail.address)?;
+        self.update_attribute(&attribute)?;
+        self.use_deps(|deps| {
+            // The asset keeps its original onboarding height, so only its pending count moves to
+            // the new verifier
+            update_verifier_metrics(deps.storage, &previous_verifier_address, |metrics| {
+                metrics.release_pending_asset()
+            })?;
+            update_verifier_metrics(
+                deps.storage,
+                &reassigned_verifier_detail.address,
+                |metrics| metrics.record_onboarding(),
+            )?;
+            delete_pending_verification_scope(
+                deps.storage,
+                &attribute.asset_type,
+                &previous_verifier_address,
+                &scope_address_str,
+            );
+            insert_pending_verification_scope(
+                deps.storage,
+                &attribute.asset_type,
+                &reassigned_verifier_detail.address,
+                &scope_address_str,
+            )?;
+            insert_latest_verifier_detail(
+                deps.storage,
+                &scope_address_str,
+                &reassigned_verifier_detail,
+            )
+        })
+    }
+
+    fn refund_pending_asset<S1: Into<String>, S2: Into<String>>(
+        &self,
+        scope_address: S1,
+        message: S2,
+    ) -> AssetResult<()> {
+        let scope_address_str = scope_address.into();
+        let mut attribute = self.get_asset(&scope_address_str)?;
+        let escrowed_verifier_detail = self.get_escrowed_verifier_detail(&attribute)?;
+        // The intake fee was paid out during onboarding, so only the completion fee and any
+        // protocol fee remain in escrow
+        let mut refund_amount = escrowed_verifier_detail.get_completion_fee().u128();
+        if let Some(protocol_fee) = self.use_deps(|deps| {
+            latest_protocol_fee_store_ro(deps.storage).may_load(scope_address_str.as_bytes())
+        })? {
+            refund_amount += protocol_fee.fee_amount.u128();
+            self.use_deps(|deps| delete_latest_protocol_fee(deps.storage, &scope_address_str))?;
+        }
+        if refund_amount > 0 {
+            self.add_message(bank_send(
+                &attribute.requestor_address,
+                refund_amount,
+                &escrowed_verifier_detail.onboarding_denom,
+            ));
+        }
+        // Denying the asset allows the requestor to onboard it again with another verifier
+        attribute.onboarding_status = AssetOnboardingStatus::Denied;
+        attribute.latest_verification_result = Some(AssetVerificationResult {
+            message: message.into(),
+            success: false,
+        });
+        self.update_attribute(&attribute)?;
+        self.use_deps(|deps| {
+            take_latest_onboarding_height(deps.storage, &scope_address_str)?;
+            update_verifier_metrics(
+                deps.storage,
+                attribute.verifier_address.as_str(),
+                |metrics| metrics.release_pending_asset(),
+            )?;
+            delete_pending_verification_scope(
+                deps.storage,
+                &attribute.asset_type,
+                attribute.verifier_address.as_str(),
+                &scope_address_str,
+            );
+            delete_latest_verifier_detail(deps.storage, &scope_address_str)
+        })
+    }
 }
 impl<'a> DepsManager<'a> for AssetMetaService<'a> {
     fn use_deps<T, F>(&self, deps_fn: F) -> T
@@ -368,7 +661,9 @@ mod tests {
             .onboard_asset(
                 &get_default_test_attribute(),
                 &get_default_verifier_detail(),
+                None,
                 false,
+                12345,
             )
             .unwrap_err();
 
@@ -396,7 +691,13 @@ mod tests {
 
         let verifier_detail = get_default_verifier_detail();
         repository
-            .onboard_asset(&get_default_test_attribute(), &verifier_detail, false)
+            .onboard_asset(
+                &get_default_test_attribute(),
+                &verifier_detail,
+                None,
+                false,
+                12345,
+            )
             .unwrap();
 
         let messages = repository.get_messages();
@@ -531,7 +832,7 @@ mod tests {
         let repository = AssetMetaService::new(deps.as_mut());
 
         let err = repository
-            .verify_asset::<&str, &str>(DEFAULT_SCOPE_ADDRESS, true, None, vec![])
+            .verify_asset::<&str, &str>(DEFAULT_SCOPE_ADDRESS, true, None, vec![], 12345)
             .unwrap_err();
 
         match err {
@@ -601,6 +902,8 @@ mod tests {
                     onboarding_status: AssetOnboardingStatus::Pending,
                     latest_verifier_detail: None,
                     latest_verification_result: None,
+                    asset_definition_version: None,
+                    ancestor_asset_definition_versions: vec![],
                     access_definitions: vec![
                         AccessDefinition {
                             owner_address: DEFAULT_SENDER_ADDRESS.to_string(),
@@ -623,13 +926,13 @@ mod tests {
         insert_latest_verifier_detail(
             deps.as_mut().storage,
             DEFAULT_SCOPE_ADDRESS,
-            &VerifierDetailV2 {
-                address: DEFAULT_VERIFIER_ADDRESS.to_string(),
-                onboarding_cost: Uint128::new(DEFAULT_ONBOARDING_COST),
-                onboarding_denom: DEFAULT_ONBOARDING_DENOM.to_string(),
-                fee_destinations: vec![],
-                entity_detail: get_default_entity_detail().to_some(),
-            },
+            &VerifierDetailV2::new(
+                DEFAULT_VERIFIER_ADDRESS,
+                Uint128::new(DEFAULT_ONBOARDING_COST),
+                DEFAULT_ONBOARDING_DENOM,
+                vec![],
+                get_default_entity_detail().to_some(),
+            ),
         )
         .expect("expected the latest verifier detail to be properly stored");
 
@@ -642,6 +945,7 @@ mod tests {
                 true,
                 "Great jaerb there Hamstar".to_some(),
                 vec![AccessRoute::route_only("newroute")],
+                12345,
             )
             .unwrap();
 
@@ -1017,7 +1321,7 @@ mod tests {
 
         let repository = AssetMetaService::new(deps.as_mut());
         repository
-            .verify_asset::<&str, &str>(DEFAULT_SCOPE_ADDRESS, result, message, vec![])
+            .verify_asset::<&str, &str>(DEFAULT_SCOPE_ADDRESS, result, message, vec![], 12345)
             .unwrap();
 
         let messages = repository.get_messages();
@@ -1125,15 +1429,18 @@ mod tests {
     }
 
     fn get_default_test_attribute() -> AssetScopeAttribute {
-        AssetScopeAttribute::new(
-            &AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
-            DEFAULT_ASSET_TYPE,
-            DEFAULT_SENDER_ADDRESS,
-            DEFAULT_VERIFIER_ADDRESS,
-            AssetOnboardingStatus::Pending.to_some(),
-            &get_default_verifier_detail(),
-            get_default_access_routes(),
-        )
-        .expect("failed to instantiate default asset scope attribute")
+        AssetScopeAttribute {
+            asset_definition_version: 1.to_some(),
+            ..AssetScopeAttribute::new(
+                &AssetIdentifier::scope_address(DEFAULT_SCOPE_ADDRESS),
+                DEFAULT_ASSET_TYPE,
+                DEFAULT_SENDER_ADDRESS,
+                DEFAULT_VERIFIER_ADDRESS,
+                AssetOnboardingStatus::Pending.to_some(),
+                &get_default_verifier_detail(),
+                get_default_access_routes(),
+            )
+            .expect("failed to instantiate default asset scope attribute")
+        }
     }
 }
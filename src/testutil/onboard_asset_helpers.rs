@@ -3,8 +3,8 @@ use crate::execute::onboard_asset::{onboard_asset, OnboardAssetV1};
 use crate::service::asset_meta_service::AssetMetaService;
 use crate::testutil::test_utilities::MockOwnedDeps;
 use crate::util::aliases::EntryPointResponse;
-use cosmwasm_std::testing::mock_info;
-use cosmwasm_std::{coin, MessageInfo};
+use cosmwasm_std::testing::{mock_env, mock_info};
+use cosmwasm_std::{coin, Env, MessageInfo};
 
 use super::test_constants::{
     DEFAULT_ASSET_TYPE, DEFAULT_ONBOARDING_COST, DEFAULT_ONBOARDING_DENOM, DEFAULT_SCOPE_ADDRESS,
@@ -13,6 +13,7 @@ use super::test_constants::{
 use super::test_utilities::{get_default_access_routes, intercept_add_attribute};
 
 pub struct TestOnboardAsset {
+    pub env: Env,
     pub info: MessageInfo,
     pub onboard_asset: OnboardAssetV1,
 }
@@ -23,6 +24,7 @@ impl TestOnboardAsset {
             asset_type: DEFAULT_ASSET_TYPE.to_string(),
             verifier_address: DEFAULT_VERIFIER_ADDRESS.to_string(),
             access_routes: get_default_access_routes(),
+            max_onboarding_cost: None,
         }
     }
 
@@ -52,6 +54,7 @@ impl TestOnboardAsset {
 impl Default for TestOnboardAsset {
     fn default() -> Self {
         TestOnboardAsset {
+            env: mock_env(),
             info: mock_info(
                 DEFAULT_SENDER_ADDRESS,
                 &[coin(
@@ -67,6 +70,7 @@ impl Default for TestOnboardAsset {
 pub fn test_onboard_asset(deps: &mut MockOwnedDeps, msg: TestOnboardAsset) -> EntryPointResponse {
     let response = onboard_asset(
         AssetMetaService::new(deps.as_mut()),
+        msg.env,
         msg.info,
         msg.onboard_asset,
     );
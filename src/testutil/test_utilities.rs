@@ -1,7 +1,7 @@
 use cosmwasm_std::{
-    from_binary,
+    coins, from_binary,
     testing::{mock_env, mock_info, MockApi, MockStorage},
-    Addr, Coin, CosmosMsg, Env, MessageInfo, OwnedDeps, Response, Uint128,
+    Addr, BankMsg, Coin, CosmosMsg, Env, MessageInfo, OwnedDeps, Response, Uint128,
 };
 use provwasm_mocks::ProvenanceMockQuerier;
 use provwasm_std::{
@@ -13,6 +13,8 @@ use serde_json_wasm::to_string;
 
 use crate::core::types::asset_definition::{AssetDefinitionInputV2, AssetDefinitionV2};
 use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::execute::post_verifier_bond::{post_verifier_bond, PostVerifierBondV1};
+use crate::execute::update_timelock_delay::{update_timelock_delay, UpdateTimelockDelayV1};
 use crate::{
     contract::instantiate,
     core::{
@@ -25,7 +27,7 @@ use crate::{
             scope_spec_identifier::ScopeSpecIdentifier,
         },
     },
-    util::{functions::generate_asset_attribute_name, traits::OptionExtensions},
+    util::{constants::NHASH, functions::generate_asset_attribute_name, traits::OptionExtensions},
 };
 use crate::{
     core::types::access_route::AccessRoute,
@@ -56,6 +58,10 @@ pub fn get_default_asset_definition_input() -> AssetDefinitionInputV2 {
         enabled: None,
         // Specifying None will cause the underlying code to always choose bind_name: true
         bind_name: None,
+        fee_discounts: vec![],
+        minimum_verifier_bond: None,
+        additional_scope_spec_identifiers: vec![],
+        parent_asset_type: None,
     }
 }
 
@@ -69,13 +75,13 @@ pub fn get_default_entity_detail() -> EntityDetail {
 }
 
 pub fn get_default_verifier_detail() -> VerifierDetailV2 {
-    VerifierDetailV2 {
-        address: DEFAULT_VERIFIER_ADDRESS.into(),
-        onboarding_cost: Uint128::from(DEFAULT_ONBOARDING_COST),
-        onboarding_denom: DEFAULT_ONBOARDING_DENOM.into(),
-        fee_destinations: vec![],
-        entity_detail: get_default_entity_detail().to_some(),
-    }
+    VerifierDetailV2::new(
+        DEFAULT_VERIFIER_ADDRESS,
+        Uint128::from(DEFAULT_ONBOARDING_COST),
+        DEFAULT_ONBOARDING_DENOM,
+        vec![],
+        get_default_entity_detail().to_some(),
+    )
 }
 
 pub fn get_default_asset_definition() -> AssetDefinitionV2 {
@@ -136,6 +142,8 @@ pub fn get_default_asset_scope_attribute_and_detail(
             access_routes: get_default_access_routes(),
             definition_type: AccessDefinitionType::Requestor,
         }],
+        asset_definition_version: 1.to_some(),
+        ancestor_asset_definition_versions: vec![],
     }
 }
 
@@ -189,6 +197,44 @@ pub fn test_instantiate_success(deps: DepsMutC, args: InstArgs) -> Response<Prov
     test_instantiate(deps, args).expect("expected instantiation to succeed")
 }
 
+pub fn test_enable_timelock(deps: DepsMutC, delay_blocks: u64) {
+    update_timelock_delay(
+        deps,
+        empty_mock_info(DEFAULT_ADMIN_ADDRESS),
+        UpdateTimelockDelayV1::new(delay_blocks.to_some()),
+    )
+    .expect("expected enabling the timelock to succeed");
+}
+
+pub fn env_at_height(height: u64) -> Env {
+    let mut env = mock_env();
+    env.block.height = height;
+    env
+}
+
+pub fn setup_bonded_verifier(deps: &mut MockOwnedDeps, amount: u128) {
+    test_instantiate_success(deps.as_mut(), InstArgs::default());
+    post_verifier_bond(
+        deps.as_mut(),
+        mock_info(DEFAULT_VERIFIER_ADDRESS, &coins(amount, NHASH)),
+        PostVerifierBondV1::new(DEFAULT_ASSET_TYPE),
+    )
+    .expect("posting a bond should succeed");
+}
+
+pub fn get_bank_sends(response: &Response<ProvenanceMsg>) -> Vec<(String, Vec<Coin>)> {
+    response
+        .messages
+        .iter()
+        .filter_map(|msg| match &msg.msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+                (to_address.to_owned(), amount.to_owned()).to_some()
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 pub fn empty_mock_info<S: Into<String>>(sender: S) -> MessageInfo {
     mock_info(&sender.into(), &[])
 }
@@ -1,4 +1,7 @@
-use cosmwasm_std::{testing::mock_info, MessageInfo};
+use cosmwasm_std::{
+    testing::{mock_env, mock_info},
+    Env, MessageInfo,
+};
 
 use crate::{
     core::types::asset_identifier::AssetIdentifier,
@@ -13,6 +16,7 @@ use super::{
 };
 
 pub struct TestVerifyAsset {
+    pub env: Env,
     pub info: MessageInfo,
     pub contract_base_name: String,
     pub verify_asset: VerifyAssetV1,
@@ -40,6 +44,7 @@ impl TestVerifyAsset {
 impl Default for TestVerifyAsset {
     fn default() -> Self {
         Self {
+            env: mock_env(),
             info: mock_info(DEFAULT_VERIFIER_ADDRESS, &[]),
             contract_base_name: DEFAULT_CONTRACT_BASE_NAME.to_string(),
             verify_asset: TestVerifyAsset::default_verify_asset(),
@@ -50,6 +55,7 @@ impl Default for TestVerifyAsset {
 pub fn test_verify_asset(deps: &mut MockOwnedDeps, msg: TestVerifyAsset) -> EntryPointResponse {
     let response = verify_asset(
         AssetMetaService::new(deps.as_mut()),
+        msg.env,
         msg.info,
         msg.verify_asset,
     );
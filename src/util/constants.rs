@@ -35,3 +35,11 @@ pub const ADDITIONAL_METADATA_KEY: &str = "asset_additional_metadata";
 /// A constant declaration to ensure the word "nhash" does not have typos when used throughout the
 /// contract's source.
 pub const NHASH: &str = "nhash";
+/// The amount of basis points that represent the entirety of a value.  Used when calculating fees
+/// that are expressed as a fraction of a total.
+pub const BASIS_POINTS_DENOMINATOR: u128 = 10_000;
+/// The default number of blocks that a verifier's bond remains locked after the verifier requests
+/// its withdrawal, used until the admin configures the [unbonding_blocks](crate::core::types::verifier_bond_settings::VerifierBondSettings::unbonding_blocks)
+/// of the contract's verifier bond settings.  This keeps the bond available for slashing after the verifier is removed from an
+/// asset definition.  At roughly five seconds per block, this is about one week.
+pub const VERIFIER_BOND_UNBONDING_BLOCKS: u64 = 120_960;
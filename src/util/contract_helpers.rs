@@ -1,5 +1,9 @@
 use crate::core::error::ContractError;
-use crate::core::state::config_read_v2;
+use crate::core::msg::ExecuteMsg;
+use crate::core::state::{config_read_v2, has_role, may_load_verifier_bond};
+use crate::core::types::asset_definition::AssetDefinitionV2;
+use crate::core::types::contract_role::ContractRole;
+use crate::core::types::timelocked_change::TimelockedChange;
 use crate::util::aliases::{AssetResult, DepsC};
 use crate::util::traits::ResultExtensions;
 use cosmwasm_std::MessageInfo;
@@ -21,9 +25,11 @@ use cosmwasm_std::MessageInfo;
 /// use cosmwasm_std::testing::mock_info;
 /// use provwasm_mocks::mock_dependencies;
 /// use asset_classification_smart_contract::core::state::{config_v2, StateV2};
+/// use asset_classification_smart_contract::core::types::pause_status::PauseStatus;
+/// use asset_classification_smart_contract::core::types::verifier_bond_settings::VerifierBondSettings;
 ///
 /// let mut deps = mock_dependencies(&[]);
-/// config_v2(deps.as_mut().storage).save(&StateV2 { base_contract_name: "contract-name".to_string(), admin: Addr::unchecked("admin-name"), is_test: false })
+/// config_v2(deps.as_mut().storage).save(&StateV2 { base_contract_name: "contract-name".to_string(), admin: Addr::unchecked("admin-name"), is_test: false, protocol_fee: None, pending_admin: None, timelock_delay: None, pause_status: PauseStatus::default(), verifier_bond_settings: VerifierBondSettings::default() })
 ///     .expect("expected state to save successfully");
 /// let info = mock_info("admin-name", &[]);
 /// check_admin_only(&deps.as_ref(), &info).expect("admin-name was used as the admin and should return a success");
@@ -40,6 +46,95 @@ pub fn check_admin_only(deps: &DepsC, info: &MessageInfo) -> AssetResult<()> {
     }
 }
 
+/// Ensures that only the admin of the contract, or an address that has been granted the given
+/// [ContractRole](crate::core::types::contract_role::ContractRole), can call into a route.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `role` The role that grants access to the route.
+pub fn check_role(deps: &DepsC, info: &MessageInfo, role: ContractRole) -> AssetResult<()> {
+    let state = config_read_v2(deps.storage).load()?;
+    if info.sender != state.admin && !has_role(deps.storage, &role, info.sender.as_str())? {
+        ContractError::Unauthorized {
+            explanation: format!("admin or {} role required", role),
+        }
+        .to_err()
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensures that only the admin of the contract, or an address holding the [ContractRole](crate::core::types::contract_role::ContractRole)
+/// that governs the given [TimelockedChange](crate::core::types::timelocked_change::TimelockedChange),
+/// can queue, execute or cancel it.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `change` The change being managed.
+pub fn check_timelocked_change_access(
+    deps: &DepsC,
+    info: &MessageInfo,
+    change: &TimelockedChange,
+) -> AssetResult<()> {
+    match change.required_role() {
+        Some(role) => check_role(deps, info, role),
+        None => check_admin_only(deps, info),
+    }
+}
+
+/// Ensures that the contract's timelock is disabled, allowing a sensitive configuration change to
+/// be made directly.  While the timelock is enabled, a [TimelockRequired](crate::core::error::ContractError::TimelockRequired)
+/// error is returned, and the change must instead be queued as a [TimelockedChange](crate::core::types::timelocked_change::TimelockedChange).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `message_type` The name of the message variant requesting the change.
+pub fn check_timelock_disabled(deps: &DepsC, message_type: &str) -> AssetResult<()> {
+    let state = config_read_v2(deps.storage).load()?;
+    if state.timelock_delay.is_some() {
+        ContractError::TimelockRequired {
+            message_type: message_type.to_string(),
+        }
+        .to_err()
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensures that the process requested by an execute message has not been halted via the contract's
+/// [PauseStatus](crate::core::types::pause_status::PauseStatus).  A [ContractPaused](crate::core::error::ContractError::ContractPaused)
+/// error is returned for onboarding, verification and access route update requests while their
+/// respective flag is set.  All other messages are always allowed.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `msg` The execute message provided to the contract's [execute](crate::contract::execute) function.
+pub fn check_execute_msg_not_paused(deps: &DepsC, msg: &ExecuteMsg) -> AssetResult<()> {
+    let pause_status = config_read_v2(deps.storage).load()?.pause_status;
+    let paused_operation = match msg {
+        ExecuteMsg::OnboardAsset { .. } if pause_status.onboarding => "onboarding",
+        ExecuteMsg::VerifyAsset { .. } if pause_status.verification => "verification",
+        ExecuteMsg::UpdateAccessRoutes { .. } if pause_status.access_routes => "access_routes",
+        _ => return Ok(()),
+    };
+    ContractError::ContractPaused {
+        operation: paused_operation.to_string(),
+    }
+    .to_err()
+}
+
 /// Ensures that the info provided to the route does not include any funds.
 ///
 /// # Parameters
@@ -62,3 +157,40 @@ pub fn check_funds_are_empty(info: &MessageInfo) -> AssetResult<()> {
         Ok(())
     }
 }
+
+/// Ensures that a verifier has posted the [minimum_verifier_bond](crate::core::types::asset_definition::AssetDefinitionV2::minimum_verifier_bond)
+/// required by an asset definition, if one is required.  The bond must be held in the same denom as
+/// the requirement.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `asset_definition` The asset definition for which the verifier was selected.
+/// * `verifier_address` The bech32 address of the selected verifier.
+pub fn check_verifier_bond(
+    deps: &DepsC,
+    asset_definition: &AssetDefinitionV2,
+    verifier_address: &str,
+) -> AssetResult<()> {
+    let required_bond = match &asset_definition.minimum_verifier_bond {
+        Some(required_bond) => required_bond,
+        None => return Ok(()),
+    };
+    let bond =
+        may_load_verifier_bond(deps.storage, &asset_definition.asset_type, verifier_address)?;
+    match bond {
+        Some(bond) if bond.denom == required_bond.denom && bond.amount >= required_bond.amount => {
+            Ok(())
+        }
+        _ => ContractError::InsufficientVerifierBond {
+            verifier_address: verifier_address.to_string(),
+            asset_type: asset_definition.asset_type.clone(),
+            bonded: bond
+                .map(|bond| bond.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+            required: required_bond.to_string(),
+        }
+        .to_err(),
+    }
+}
@@ -32,6 +32,52 @@ pub enum EventType {
     BindContractAlias,
     /// Occurs when the contract is [executed](crate::contract::execute) to [delete an asset definition](crate::execute::delete_asset_definition).
     DeleteAssetDefinition,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [withdraw funds from the treasury](crate::execute::withdraw_treasury).
+    WithdrawTreasury,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [update the protocol fee](crate::execute::update_protocol_fee).
+    UpdateProtocolFee,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [withdraw accrued fees](crate::execute::withdraw_fees).
+    WithdrawFees,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [schedule an asset verifier update](crate::execute::schedule_asset_verifier_update).
+    ScheduleAssetVerifierUpdate,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [grant a role](crate::execute::grant_role).
+    GrantRole,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [revoke a role](crate::execute::revoke_role).
+    RevokeRole,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [propose a new admin](crate::execute::propose_admin).
+    ProposeAdmin,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [accept a proposed admin transfer](crate::execute::accept_admin).
+    AcceptAdmin,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [cancel a proposed admin transfer](crate::execute::cancel_admin_proposal).
+    CancelAdminProposal,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [update the timelock delay](crate::execute::update_timelock_delay).
+    UpdateTimelockDelay,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [queue a timelocked change](crate::execute::queue_timelocked_change).
+    QueueTimelockedChange,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [cancel a timelocked change](crate::execute::cancel_timelocked_change).
+    CancelTimelockedChange,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [update the pause status](crate::execute::update_pause_status).
+    UpdatePauseStatus,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [update the bounds of verifier self-service updates](crate::execute::update_verifier_self_update_bounds).
+    UpdateVerifierSelfUpdateBounds,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [update its own verifier detail as a verifier](crate::execute::self_update_asset_verifier).
+    SelfUpdateAssetVerifier,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [remove a verifier from an asset definition](crate::execute::remove_asset_verifier).
+    RemoveAssetVerifier,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [toggle an asset verifier](crate::execute::toggle_asset_verifier).
+    ToggleAssetVerifier,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [register a verifier](crate::execute::register_verifier).
+    RegisterVerifier,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [add a registered asset verifier](crate::execute::add_registered_asset_verifier).
+    AddRegisteredAssetVerifier,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [post a verifier bond](crate::execute::post_verifier_bond).
+    PostVerifierBond,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [slash a verifier bond](crate::execute::slash_verifier_bond).
+    SlashVerifierBond,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [withdraw a verifier bond](crate::execute::withdraw_verifier_bond).
+    WithdrawVerifierBond,
+    /// Occurs when the contract is [executed](crate::contract::execute) to [update the verifier bond settings](crate::execute::update_verifier_bond_settings).
+    UpdateVerifierBondSettings,
 }
 #[allow(clippy::from_over_into)]
 impl Into<String> for EventType {
@@ -49,6 +95,29 @@ impl Into<String> for EventType {
             EventType::UpdateAccessRoutes => "update_access_routes",
             EventType::BindContractAlias => "bind_contract_alias",
             EventType::DeleteAssetDefinition => "delete_asset_definition",
+            EventType::WithdrawTreasury => "withdraw_treasury",
+            EventType::UpdateProtocolFee => "update_protocol_fee",
+            EventType::WithdrawFees => "withdraw_fees",
+            EventType::ScheduleAssetVerifierUpdate => "schedule_asset_verifier_update",
+            EventType::GrantRole => "grant_role",
+            EventType::RevokeRole => "revoke_role",
+            EventType::ProposeAdmin => "propose_admin",
+            EventType::AcceptAdmin => "accept_admin",
+            EventType::CancelAdminProposal => "cancel_admin_proposal",
+            EventType::UpdateTimelockDelay => "update_timelock_delay",
+            EventType::QueueTimelockedChange => "queue_timelocked_change",
+            EventType::CancelTimelockedChange => "cancel_timelocked_change",
+            EventType::UpdatePauseStatus => "update_pause_status",
+            EventType::UpdateVerifierSelfUpdateBounds => "update_verifier_self_update_bounds",
+            EventType::SelfUpdateAssetVerifier => "self_update_asset_verifier",
+            EventType::RemoveAssetVerifier => "remove_asset_verifier",
+            EventType::ToggleAssetVerifier => "toggle_asset_verifier",
+            EventType::RegisterVerifier => "register_verifier",
+            EventType::AddRegisteredAssetVerifier => "add_registered_asset_verifier",
+            EventType::PostVerifierBond => "post_verifier_bond",
+            EventType::SlashVerifierBond => "slash_verifier_bond",
+            EventType::WithdrawVerifierBond => "withdraw_verifier_bond",
+            EventType::UpdateVerifierBondSettings => "update_verifier_bond_settings",
         }
         .into()
     }
@@ -172,7 +241,8 @@ impl EventAttributes {
     /// Appends a dynamic set of additional metadata to an existing [EventAttributes](self::EventAttributes)
     /// and returns the same instance to create a functional chain for further attribute addition.
     /// Note: If the metadata provided is empty, this key will be skipped to prevent strange value
-    /// displays to external consumers.
+    /// displays to external consumers.  If additional metadata has already been set, the new
+    /// metadata is appended to the existing value rather than emitting the key twice.
     ///
     /// # Parameters
     ///
@@ -184,10 +254,19 @@ impl EventAttributes {
     ) -> Self {
         // Only append additional metadata if it actually has keys
         if additional_metadata.has_metadata() {
-            self.attributes.push((
-                ADDITIONAL_METADATA_KEY.into(),
-                additional_metadata.get_meta_string(),
-            ));
+            if let Some((_, existing_metadata)) = self
+                .attributes
+                .iter_mut()
+                .find(|(key, _)| key == ADDITIONAL_METADATA_KEY)
+            {
+                existing_metadata.push_str(", ");
+                existing_metadata.push_str(&additional_metadata.get_meta_string());
+            } else {
+                self.attributes.push((
+                    ADDITIONAL_METADATA_KEY.into(),
+                    additional_metadata.get_meta_string(),
+                ));
+            }
         }
         self
     }
@@ -263,8 +342,8 @@ mod tests {
     use crate::{
         testutil::test_utilities::single_attribute_for_key,
         util::constants::{
-            ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY, NEW_VALUE_KEY,
-            VERIFIER_ADDRESS_KEY,
+            ADDITIONAL_METADATA_KEY, ASSET_EVENT_TYPE_KEY, ASSET_SCOPE_ADDRESS_KEY, ASSET_TYPE_KEY,
+            NEW_VALUE_KEY, VERIFIER_ADDRESS_KEY,
         },
     };
 
@@ -332,4 +411,22 @@ mod tests {
             "expected the third key/value addition to also display alongside the first two, alphabetically sorted",
         );
     }
+
+    #[test]
+    fn test_additional_metadata_is_appended_to_existing_metadata() {
+        let mut first_metadata = EventAdditionalMetadata::new();
+        first_metadata.add_metadata("b", "b_value");
+        let mut second_metadata = EventAdditionalMetadata::new();
+        second_metadata.add_metadata("a", "a_value");
+        let attributes = EventAttributes::new(EventType::OnboardAsset)
+            .set_additional_metadata(&first_metadata)
+            .set_additional_metadata(&EventAdditionalMetadata::new())
+            .set_additional_metadata(&second_metadata);
+        let response: Response<String> = Response::new().add_attributes(attributes);
+        assert_eq!(
+            "[b=b_value], [a=a_value]",
+            single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY),
+            "expected later metadata to be appended to the existing additional metadata attribute",
+        );
+    }
 }
@@ -1,14 +1,16 @@
-use cosmwasm_std::CosmosMsg;
+use cosmwasm_std::{coin, Coin, CosmosMsg};
 use provwasm_std::ProvenanceMsg;
 
 use crate::core::error::ContractError;
+use crate::core::types::fee_destination::FeeDestinationV2;
+use crate::core::types::fee_remainder_destination::FeeRemainderDestination;
 use crate::core::types::verifier_detail::VerifierDetailV2;
 
 use super::{aliases::AssetResult, functions::bank_send, traits::ResultExtensions};
 
 /// This function distributes funds from the sender address to the targets defined by a [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2).
-/// It breaks down all percentages defined in the verifier detail's fee destinations and core onboarding
-/// cost to derive a variable sized vector of destination messages.
+/// It breaks down all flat amounts and basis point fractions defined in the verifier detail's fee
+/// destinations and core onboarding cost to derive a variable sized vector of destination messages.
 /// Important: The response type is of [ProvenanceMsg](provwasm_std::ProvenanceMsg), which allows
 /// these bank send messages to match the type used for contract execution routes.
 ///
@@ -18,47 +20,107 @@ use super::{aliases::AssetResult, functions::bank_send, traits::ResultExtensions
 pub fn calculate_verifier_cost_messages(
     verifier: &VerifierDetailV2,
 ) -> AssetResult<Vec<CosmosMsg<ProvenanceMsg>>> {
-    let mut cost_messages: Vec<CosmosMsg<ProvenanceMsg>> = vec![];
+    calculate_verifier_fee_payouts(verifier)?
+        .into_iter()
+        .map(|(address, fee)| bank_send(&address, fee.amount.u128(), &fee.denom))
+        .collect::<Vec<CosmosMsg<ProvenanceMsg>>>()
+        .to_ok()
+}
+
+/// Derives each recipient address and the funds it is owed from a [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2)
+/// when a verification completes.  Destinations owed nothing are omitted, as is the portion of the
+/// completion fee retained by the contract treasury.
+///
+/// # Parameters
+///
+/// * `verifier` The verifier detail from which to extract fee information.
+pub fn calculate_verifier_fee_payouts(
+    verifier: &VerifierDetailV2,
+) -> AssetResult<Vec<(String, Coin)>> {
+    calculate_payouts(
+        verifier,
+        verifier.get_fee_destination_amounts(),
+        verifier.get_completion_fee().u128(),
+        "completion fee",
+    )
+}
+
+/// Derives each recipient address and the funds it is owed from the [intake_fee](crate::core::types::verifier_detail::VerifierDetailV2::intake_fee)
+/// of a [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2) when a scope is
+/// onboarded.  Destinations owed nothing are omitted, as is the portion of the intake fee retained
+/// by the contract treasury.
+///
+/// # Parameters
+///
+/// * `verifier` The verifier detail from which to extract fee information.
+pub fn calculate_verifier_intake_fee_payouts(
+    verifier: &VerifierDetailV2,
+) -> AssetResult<Vec<(String, Coin)>> {
+    calculate_payouts(
+        verifier,
+        verifier.get_intake_fee_destination_amounts(),
+        verifier.intake_fee.u128(),
+        "intake fee",
+    )
+}
+
+fn calculate_payouts(
+    verifier: &VerifierDetailV2,
+    destination_amounts: Vec<(&FeeDestinationV2, u128)>,
+    cost: u128,
+    cost_name: &str,
+) -> AssetResult<Vec<(String, Coin)>> {
+    let mut payouts: Vec<(String, Coin)> = vec![];
     let denom = &verifier.onboarding_denom;
     let mut fee_total: u128 = 0;
-    // Append a message for each destination
-    verifier.fee_destinations.iter().for_each(|destination| {
-        cost_messages.push(bank_send(
-            &destination.address,
-            destination.fee_amount.u128(),
-            denom,
-        ));
-        fee_total += destination.fee_amount.u128();
-    });
-    // Fee distribution can, at most, be equal to the onboarding cost.  The onboarding cost should
-    // always reflect the exact total that is taken from the requestor address when onboarding a new
-    // scope.
-    if fee_total > verifier.onboarding_cost.u128() {
+    // Append a payout for each destination, skipping any basis point fees that rounded down to zero
+    destination_amounts
+        .into_iter()
+        .for_each(|(destination, fee_amount)| {
+            if fee_amount > 0 {
+                payouts.push((destination.address.to_owned(), coin(fee_amount, denom)));
+            }
+            fee_total += fee_amount;
+        });
+    // Fee distribution can, at most, be equal to the cost being distributed.  The onboarding cost
+    // should always reflect the exact total that is taken from the requestor address when
+    // onboarding a new scope.
+    if fee_total > cost {
         return ContractError::generic(
-            format!("misconfigured fee destinations! fee total ({}{}) was greater than the specified onboarding cost ({}{})",
+            format!("misconfigured fee destinations! fee total ({}{}) was greater than the specified {} ({}{})",
                 fee_total,
                 denom,
-                verifier.onboarding_cost.u128(),
+                cost_name,
+                cost,
                 denom,
             )
         ).to_err();
     }
-    // The total funds disbursed to the verifier itself is the remainder from subtracting the fee cost from the onboarding cost
-    let verifier_cost = verifier.onboarding_cost.u128() - fee_total;
-    // Append a bank send message from the contract to the verifier for the cost if the verifier receives funds
-    if verifier_cost > 0 {
-        cost_messages.push(bank_send(&verifier.address, verifier_cost, denom));
+    // The remainder of the cost is sent to the verifier's chosen destination.  Funds destined for
+    // the contract treasury are already held by the contract, so no payout is needed
+    let remainder = cost - fee_total;
+    if remainder > 0 {
+        match &verifier.remainder_destination {
+            FeeRemainderDestination::Verifier => {
+                payouts.push((verifier.address.to_owned(), coin(remainder, denom)));
+            }
+            FeeRemainderDestination::Address(address) => {
+                payouts.push((address.to_owned(), coin(remainder, denom)));
+            }
+            FeeRemainderDestination::ContractTreasury => (),
+        };
     }
-    cost_messages.to_ok()
+    payouts.to_ok()
 }
 
 #[cfg(test)]
 #[cfg(feature = "enable-test-utils")]
 mod tests {
-    use cosmwasm_std::{BankMsg, CosmosMsg, Uint128};
+    use cosmwasm_std::{coin, BankMsg, CosmosMsg, Uint128};
     use provwasm_std::ProvenanceMsg;
 
     use crate::core::types::fee_destination::FeeDestinationV2;
+    use crate::core::types::fee_remainder_destination::FeeRemainderDestination;
     use crate::core::types::verifier_detail::VerifierDetailV2;
     use crate::{
         core::error::ContractError,
@@ -66,7 +128,7 @@ mod tests {
         util::{constants::NHASH, traits::OptionExtensions},
     };
 
-    use super::calculate_verifier_cost_messages;
+    use super::{calculate_verifier_cost_messages, calculate_verifier_intake_fee_payouts};
 
     #[test]
     fn test_invalid_verifier_greater_fee_than_onboarding_cost() {
@@ -82,7 +144,7 @@ mod tests {
         match error {
             ContractError::GenericError { msg } => {
                 assert_eq!(
-                    "misconfigured fee destinations! fee total (101nhash) was greater than the specified onboarding cost (100nhash)",
+                    "misconfigured fee destinations! fee total (101nhash) was greater than the specified completion fee (100nhash)",
                     msg.as_str(),
                     "unexpected error message generated",
                 );
@@ -228,6 +290,197 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_basis_point_fee_destinations_with_rounding_remainder() {
+        let verifier = VerifierDetailV2::new(
+            "verifier",
+            Uint128::new(103),
+            NHASH,
+            vec![
+                FeeDestinationV2::new_basis_points("first", 2500).with_rounding_remainder(),
+                FeeDestinationV2::new_basis_points("second", 2500),
+                FeeDestinationV2::new("third", Uint128::new(10)),
+            ],
+            None,
+        );
+        let messages = calculate_verifier_cost_messages(&verifier)
+            .expect("validation should pass and messages should be returned");
+        assert_eq!(4, messages.len(), "expected four messages to be sent");
+        test_messages_contains_send_for_address(
+            &messages,
+            "first",
+            26,
+            NHASH,
+            "expected the first fee destination to receive its rounded share plus the rounding remainder",
+        );
+        test_messages_contains_send_for_address(
+            &messages,
+            "second",
+            25,
+            NHASH,
+            "expected the second fee destination to receive its share rounded down",
+        );
+        test_messages_contains_send_for_address(
+            &messages,
+            "third",
+            10,
+            NHASH,
+            "expected the flat fee destination to receive its exact amount",
+        );
+        test_messages_contains_send_for_address(
+            &messages,
+            "verifier",
+            42,
+            NHASH,
+            "expected the verifier to receive the remaining funds",
+        );
+    }
+
+    #[test]
+    fn test_basis_point_fee_destination_rounded_to_zero_is_skipped() {
+        let verifier = VerifierDetailV2::new(
+            "verifier",
+            Uint128::new(5),
+            NHASH,
+            vec![FeeDestinationV2::new_basis_points("first", 1)],
+            None,
+        );
+        let messages = calculate_verifier_cost_messages(&verifier)
+            .expect("validation should pass and messages should be returned");
+        assert_eq!(
+            1,
+            messages.len(),
+            "expected only the verifier to receive funds when the fee rounds down to zero",
+        );
+        test_messages_contains_send_for_address(
+            &messages,
+            "verifier",
+            5,
+            NHASH,
+            "expected the verifier to receive the full onboarding cost",
+        );
+    }
+
+    #[test]
+    fn test_remainder_sent_to_chosen_address() {
+        let verifier = VerifierDetailV2::new(
+            "verifier",
+            Uint128::new(100),
+            NHASH,
+            vec![FeeDestinationV2::new("fee", Uint128::new(25))],
+            None,
+        )
+        .with_remainder_destination(FeeRemainderDestination::Address("remainder".to_string()));
+        let messages = calculate_verifier_cost_messages(&verifier)
+            .expect("validation should pass and messages should be returned");
+        assert_eq!(2, messages.len(), "expected two messages to be sent");
+        test_messages_contains_send_for_address(
+            &messages,
+            "fee",
+            25,
+            NHASH,
+            "expected the fee destination to receive its fee",
+        );
+        test_messages_contains_send_for_address(
+            &messages,
+            "remainder",
+            75,
+            NHASH,
+            "expected the chosen remainder address to receive the remaining funds",
+        );
+    }
+
+    #[test]
+    fn test_remainder_retained_in_contract_treasury() {
+        let verifier = VerifierDetailV2::new(
+            "verifier",
+            Uint128::new(100),
+            NHASH,
+            vec![FeeDestinationV2::new("fee", Uint128::new(25))],
+            None,
+        )
+        .with_remainder_destination(FeeRemainderDestination::ContractTreasury);
+        let messages = calculate_verifier_cost_messages(&verifier)
+            .expect("validation should pass and messages should be returned");
+        assert_eq!(
+            1,
+            messages.len(),
+            "expected only the fee destination to receive funds when the remainder is retained",
+        );
+        test_messages_contains_send_for_address(
+            &messages,
+            "fee",
+            25,
+            NHASH,
+            "expected the fee destination to receive its fee",
+        );
+    }
+
+    #[test]
+    fn test_intake_fee_split_from_completion_fee() {
+        let verifier = VerifierDetailV2::new(
+            "verifier",
+            Uint128::new(100),
+            NHASH,
+            vec![FeeDestinationV2::new("fee", Uint128::new(25))],
+            None,
+        )
+        .with_intake_fee(
+            Uint128::new(40),
+            vec![FeeDestinationV2::new("intake", Uint128::new(10))],
+        );
+        let intake_payouts = calculate_verifier_intake_fee_payouts(&verifier)
+            .expect("intake fee payouts should be calculated");
+        assert_eq!(
+            vec![
+                ("intake".to_string(), coin(10, NHASH)),
+                ("verifier".to_string(), coin(30, NHASH)),
+            ],
+            intake_payouts,
+            "expected the intake fee to be split between its destinations and the verifier",
+        );
+        let messages = calculate_verifier_cost_messages(&verifier)
+            .expect("validation should pass and messages should be returned");
+        assert_eq!(2, messages.len(), "expected two messages to be sent");
+        test_messages_contains_send_for_address(
+            &messages,
+            "fee",
+            25,
+            NHASH,
+            "expected the fee destination to receive its fee",
+        );
+        test_messages_contains_send_for_address(
+            &messages,
+            "verifier",
+            35,
+            NHASH,
+            "expected the verifier to receive the remainder of the completion fee only",
+        );
+    }
+
+    #[test]
+    fn test_invalid_verifier_greater_intake_fee_destinations_than_intake_fee() {
+        let verifier = VerifierDetailV2::new("verifier", Uint128::new(100), NHASH, vec![], None)
+            .with_intake_fee(
+                Uint128::new(40),
+                vec![FeeDestinationV2::new("intake", Uint128::new(41))],
+            );
+        let error = calculate_verifier_intake_fee_payouts(&verifier).unwrap_err();
+        match error {
+            ContractError::GenericError { msg } => {
+                assert_eq!(
+                    "misconfigured fee destinations! fee total (41nhash) was greater than the specified intake fee (40nhash)",
+                    msg.as_str(),
+                    "unexpected error message generated",
+                );
+            }
+            _ => panic!(
+                "unexpected error encountered when providing a bad verifier: {:?}",
+                error
+            ),
+        }
+    }
+
     /// Loops through all messages contained in the input slice until it finds a message with the given address,
     /// ensuring that the expected amount was sent in the expected denom to that address.  All output errors are
     /// prefixed with the input error_message string.
@@ -1,13 +1,22 @@
+use cosmwasm_std::Coin;
+
 use crate::core::error::ContractError;
 use crate::core::msg::ExecuteMsg;
 use crate::core::types::asset_identifier::AssetIdentifier;
 use crate::core::types::asset_qualifier::AssetQualifier;
+use crate::core::types::pending_asset_strategy::PendingAssetStrategy;
+use crate::core::types::protocol_fee::{ProtocolFee, ProtocolFeeAmount};
 use crate::core::types::serialized_enum::SerializedEnum;
+use crate::core::types::timelocked_change::TimelockedChange;
+use crate::core::types::verifier_bond_settings::VerifierBondSettings;
 use crate::core::types::verifier_detail::VerifierDetailV2;
+use crate::core::types::verifier_registration::VerifierRegistration;
 use crate::util::aliases::AssetResult;
+use crate::util::constants::BASIS_POINTS_DENOMINATOR;
+use crate::util::scope_address_utils::bech32_string_to_addr;
 use crate::util::traits::{OptionExtensions, ResultExtensions};
 use crate::validation::validate_init_msg::{
-    validate_asset_definition, validate_verifier_with_provided_errors,
+    validate_asset_definition, validate_verifier, validate_verifier_with_provided_errors,
 };
 
 /// The main branch of validation for an execute msg.  Funnels the intercepted value based on variant
@@ -42,6 +51,11 @@ pub fn validate_execute_msg(msg: &ExecuteMsg) -> AssetResult<()> {
             asset_type,
             verifier,
         } => validate_asset_verifier_msg(asset_type, verifier),
+        ExecuteMsg::ScheduleAssetVerifierUpdate {
+            asset_type,
+            verifier,
+            ..
+        } => validate_asset_verifier_msg(asset_type, verifier),
         ExecuteMsg::UpdateAccessRoutes {
             identifier,
             owner_address,
@@ -51,6 +65,74 @@ pub fn validate_execute_msg(msg: &ExecuteMsg) -> AssetResult<()> {
         ExecuteMsg::DeleteAssetDefinition { qualifier } => {
             validate_delete_asset_definition(qualifier)
         }
+        ExecuteMsg::WithdrawTreasury { amount, recipient } => {
+            validate_withdraw_treasury(amount, recipient)
+        }
+        ExecuteMsg::UpdateProtocolFee { protocol_fee } => {
+            validate_update_protocol_fee(protocol_fee)
+        }
+        // This message has no fields, so there is nothing to validate
+        ExecuteMsg::WithdrawFees {} => Ok(()),
+        ExecuteMsg::GrantRole { address, .. } => {
+            validate_role_address("ExecuteMsg::GrantRole", address)
+        }
+        ExecuteMsg::RevokeRole { address, .. } => {
+            validate_role_address("ExecuteMsg::RevokeRole", address)
+        }
+        ExecuteMsg::ProposeAdmin { address } => validate_propose_admin(address),
+        // These messages have no fields, so there is nothing to validate
+        ExecuteMsg::AcceptAdmin {} => Ok(()),
+        ExecuteMsg::CancelAdminProposal {} => Ok(()),
+        ExecuteMsg::UpdateTimelockDelay { delay_blocks } => {
+            validate_update_timelock_delay(delay_blocks)
+        }
+        ExecuteMsg::QueueTimelockedChange { change } => validate_timelocked_change(change),
+        // Change ids are only ever checked against the queue, so there is nothing to validate
+        ExecuteMsg::ExecuteTimelockedChange { .. } => Ok(()),
+        ExecuteMsg::CancelTimelockedChange { .. } => Ok(()),
+        ExecuteMsg::UpdatePauseStatus {
+            onboarding,
+            verification,
+            access_routes,
+        } => validate_update_pause_status(onboarding, verification, access_routes),
+        ExecuteMsg::UpdateVerifierSelfUpdateBounds {
+            asset_type,
+            verifier_address,
+            ..
+        } => validate_update_verifier_self_update_bounds(asset_type, verifier_address),
+        ExecuteMsg::SelfUpdateAssetVerifier {
+            asset_type,
+            verifier,
+        } => validate_asset_verifier_msg(asset_type, verifier),
+        ExecuteMsg::RemoveAssetVerifier {
+            asset_type,
+            verifier_address,
+            pending_asset_strategy,
+        } => validate_remove_asset_verifier(asset_type, verifier_address, pending_asset_strategy),
+        ExecuteMsg::ToggleAssetVerifier {
+            asset_type,
+            verifier_address,
+            ..
+        } => validate_toggle_asset_verifier(asset_type, verifier_address),
+        ExecuteMsg::RegisterVerifier { registration } => validate_register_verifier(registration),
+        ExecuteMsg::AddRegisteredAssetVerifier {
+            asset_type,
+            verifier_address,
+        } => validate_add_registered_asset_verifier(asset_type, verifier_address),
+        ExecuteMsg::PostVerifierBond { asset_type } => {
+            validate_verifier_bond_asset_type("ExecuteMsg::PostVerifierBond", asset_type)
+        }
+        ExecuteMsg::SlashVerifierBond {
+            asset_type,
+            verifier_address,
+            amount,
+        } => validate_slash_verifier_bond(asset_type, verifier_address, amount),
+        ExecuteMsg::WithdrawVerifierBond { asset_type } => {
+            validate_verifier_bond_asset_type("ExecuteMsg::WithdrawVerifierBond", asset_type)
+        }
+        ExecuteMsg::UpdateVerifierBondSettings { settings } => {
+            validate_update_verifier_bond_settings(settings)
+        }
     }
 }
 
@@ -120,7 +202,8 @@ fn validate_toggle_asset_definition(asset_type: &str) -> AssetResult<()> {
     gen_validation_response("ExecuteMsg::ToggleAssetDefinition", invalid_fields)
 }
 
-/// Validates the [AddAssetVerifier](crate::core::msg::ExecuteMsg::AddAssetVerifier) or [UpdateAssetVerifier](crate::core::msg::ExecuteMsg::UpdateAssetVerifier)
+/// Validates the [AddAssetVerifier](crate::core::msg::ExecuteMsg::AddAssetVerifier), [UpdateAssetVerifier](crate::core::msg::ExecuteMsg::UpdateAssetVerifier)
+/// or [ScheduleAssetVerifierUpdate](crate::core::msg::ExecuteMsg::ScheduleAssetVerifierUpdate)
 /// variants of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
 /// success, or an  [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
 /// error when invalid fields are found.
@@ -197,6 +280,365 @@ fn validate_delete_asset_definition(qualifier: &SerializedEnum) -> AssetResult<(
     gen_validation_response("ExecuteMsg::DeleteAssetDefinition", invalid_fields)
 }
 
+/// Validates the [WithdrawTreasury](crate::core::msg::ExecuteMsg::WithdrawTreasury) variant of the
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `amount` The funds to withdraw from the contract treasury.
+/// * `recipient` The bech32 address of the account that will receive the withdrawn funds.
+fn validate_withdraw_treasury(amount: &Coin, recipient: &str) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if amount.amount.is_zero() {
+        invalid_fields.push("amount:amount: must not be zero".to_string());
+    }
+    if amount.denom.is_empty() {
+        invalid_fields.push("amount:denom: must not be blank".to_string());
+    }
+    if bech32_string_to_addr(recipient).is_err() {
+        invalid_fields.push("recipient: must be a valid address".to_string());
+    }
+    gen_validation_response("ExecuteMsg::WithdrawTreasury", invalid_fields)
+}
+
+/// Validates the [GrantRole](crate::core::msg::ExecuteMsg::GrantRole) and [RevokeRole](crate::core::msg::ExecuteMsg::RevokeRole)
+/// variants of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `message_type` The name of the message variant being validated.
+/// * `address` The bech32 address to which the role applies.
+fn validate_role_address(message_type: &str, address: &str) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if bech32_string_to_addr(address).is_err() {
+        invalid_fields.push("address: must be a valid address".to_string());
+    }
+    gen_validation_response(message_type, invalid_fields)
+}
+
+/// Validates the [ProposeAdmin](crate::core::msg::ExecuteMsg::ProposeAdmin) variant of the
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `address` The bech32 address proposed to become the new admin.
+fn validate_propose_admin(address: &str) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if bech32_string_to_addr(address).is_err() {
+        invalid_fields.push("address: must be a valid address".to_string());
+    }
+    gen_validation_response("ExecuteMsg::ProposeAdmin", invalid_fields)
+}
+
+/// Validates the [UpdateTimelockDelay](crate::core::msg::ExecuteMsg::UpdateTimelockDelay) variant
+/// of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or
+/// an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `delay_blocks` The new delay, or None if the timelock is being disabled.
+fn validate_update_timelock_delay(delay_blocks: &Option<u64>) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if delay_blocks == &Some(0) {
+        invalid_fields.push(
+            "delay_blocks: must be greater than zero, or omitted to disable the timelock"
+                .to_string(),
+        );
+    }
+    gen_validation_response("ExecuteMsg::UpdateTimelockDelay", invalid_fields)
+}
+
+/// Validates the [UpdatePauseStatus](crate::core::msg::ExecuteMsg::UpdatePauseStatus) variant
+/// of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or
+/// an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `onboarding` The optional new onboarding pause flag.
+/// * `verification` The optional new verification pause flag.
+/// * `access_routes` The optional new access route update pause flag.
+fn validate_update_pause_status(
+    onboarding: &Option<bool>,
+    verification: &Option<bool>,
+    access_routes: &Option<bool>,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if onboarding.is_none() && verification.is_none() && access_routes.is_none() {
+        invalid_fields.push(
+            "onboarding, verification, access_routes: at least one flag must be provided"
+                .to_string(),
+        );
+    }
+    gen_validation_response("ExecuteMsg::UpdatePauseStatus", invalid_fields)
+}
+
+/// Validates the [UpdateVerifierSelfUpdateBounds](crate::core::msg::ExecuteMsg::UpdateVerifierSelfUpdateBounds)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the definition containing the verifier.
+/// * `verifier_address` The bech32 address of the verifier to which the bounds apply.
+fn validate_update_verifier_self_update_bounds(
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if bech32_string_to_addr(verifier_address).is_err() {
+        invalid_fields.push("verifier_address: must be a valid address".to_string());
+    }
+    gen_validation_response("ExecuteMsg::UpdateVerifierSelfUpdateBounds", invalid_fields)
+}
+
+/// Validates the [RemoveAssetVerifier](crate::core::msg::ExecuteMsg::RemoveAssetVerifier)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the definition from which the verifier will be removed.
+/// * `verifier_address` The bech32 address of the verifier to remove.
+/// * `pending_asset_strategy` Defines how assets that are still awaiting verification by the
+/// verifier are handled.
+fn validate_remove_asset_verifier(
+    asset_type: &str,
+    verifier_address: &str,
+    pending_asset_strategy: &Option<PendingAssetStrategy>,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if bech32_string_to_addr(verifier_address).is_err() {
+        invalid_fields.push("verifier_address: must be a valid address".to_string());
+    }
+    if let Some(PendingAssetStrategy::Reassign {
+        verifier_address: replacement_address,
+    }) = pending_asset_strategy
+    {
+        if bech32_string_to_addr(replacement_address).is_err() {
+            invalid_fields.push(
+                "pending_asset_strategy.verifier_address: must be a valid address".to_string(),
+            );
+        }
+        if replacement_address == verifier_address {
+            invalid_fields.push(
+                "pending_asset_strategy.verifier_address: must not be the verifier being removed"
+                    .to_string(),
+            );
+        }
+    }
+    gen_validation_response("ExecuteMsg::RemoveAssetVerifier", invalid_fields)
+}
+
+/// Validates the [ToggleAssetVerifier](crate::core::msg::ExecuteMsg::ToggleAssetVerifier)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the definition containing the verifier.
+/// * `verifier_address` The bech32 address of the verifier to toggle.
+fn validate_toggle_asset_verifier(asset_type: &str, verifier_address: &str) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if bech32_string_to_addr(verifier_address).is_err() {
+        invalid_fields.push("verifier_address: must be a valid address".to_string());
+    }
+    gen_validation_response("ExecuteMsg::ToggleAssetVerifier", invalid_fields)
+}
+
+/// Validates the [RegisterVerifier](crate::core::msg::ExecuteMsg::RegisterVerifier) variant of
+/// the [ExecuteMsg](crate::core::msg::ExecuteMsg), using the same rules as a [VerifierDetailV2](crate::core::types::verifier_detail::VerifierDetailV2).
+/// Returning an empty response on success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `registration` The registration to store for the verifier.
+fn validate_register_verifier(registration: &VerifierRegistration) -> AssetResult<()> {
+    validate_verifier(&registration.to_verifier_detail())
+}
+
+/// Validates the [AddRegisteredAssetVerifier](crate::core::msg::ExecuteMsg::AddRegisteredAssetVerifier)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type of the definition to which the verifier will be added.
+/// * `verifier_address` The bech32 address of the registered verifier.
+fn validate_add_registered_asset_verifier(
+    asset_type: &str,
+    verifier_address: &str,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if bech32_string_to_addr(verifier_address).is_err() {
+        invalid_fields.push("verifier_address: must be a valid address".to_string());
+    }
+    gen_validation_response("ExecuteMsg::AddRegisteredAssetVerifier", invalid_fields)
+}
+
+/// Validates the [PostVerifierBond](crate::core::msg::ExecuteMsg::PostVerifierBond) and [WithdrawVerifierBond](crate::core::msg::ExecuteMsg::WithdrawVerifierBond)
+/// variants of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `message_type` The name of the message variant being validated.
+/// * `asset_type` The asset type for which the bond is held.
+fn validate_verifier_bond_asset_type(message_type: &str, asset_type: &str) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    gen_validation_response(message_type, invalid_fields)
+}
+
+/// Validates the [SlashVerifierBond](crate::core::msg::ExecuteMsg::SlashVerifierBond) variant of
+/// the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `asset_type` The asset type for which the bond is held.
+/// * `verifier_address` The bech32 address of the verifier whose bond is slashed.
+/// * `amount` The amount to remove from the bond.
+fn validate_slash_verifier_bond(
+    asset_type: &str,
+    verifier_address: &str,
+    amount: &Coin,
+) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if asset_type.is_empty() {
+        invalid_fields.push("asset_type: must not be blank".to_string());
+    }
+    if bech32_string_to_addr(verifier_address).is_err() {
+        invalid_fields.push("verifier_address: must be a valid address".to_string());
+    }
+    if amount.amount.is_zero() {
+        invalid_fields.push("amount:amount: must not be zero".to_string());
+    }
+    if amount.denom.is_empty() {
+        invalid_fields.push("amount:denom: must not be blank".to_string());
+    }
+    gen_validation_response("ExecuteMsg::SlashVerifierBond", invalid_fields)
+}
+
+/// Validates the [UpdateVerifierBondSettings](crate::core::msg::ExecuteMsg::UpdateVerifierBondSettings)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on
+/// success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `settings` The new verifier bond settings.
+fn validate_update_verifier_bond_settings(settings: &VerifierBondSettings) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if let Some(slash_beneficiary) = &settings.slash_beneficiary {
+        if bech32_string_to_addr(slash_beneficiary).is_err() {
+            invalid_fields.push("settings:slash_beneficiary: must be a valid address".to_string());
+        }
+    }
+    if settings.unbonding_blocks == 0 {
+        invalid_fields.push("settings:unbonding_blocks: must be greater than zero".to_string());
+    }
+    gen_validation_response("ExecuteMsg::UpdateVerifierBondSettings", invalid_fields)
+}
+
+/// Validates the change held by the [QueueTimelockedChange](crate::core::msg::ExecuteMsg::QueueTimelockedChange)
+/// variant of the [ExecuteMsg](crate::core::msg::ExecuteMsg), using the same rules as the route
+/// that the change delays.  Returning an empty response on success, or an [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields)
+/// error when invalid fields are found.
+///
+/// # Parameters
+///
+/// * `change` The change being queued.
+fn validate_timelocked_change(change: &TimelockedChange) -> AssetResult<()> {
+    match change {
+        TimelockedChange::UpdateAssetVerifier {
+            asset_type,
+            verifier,
+        } => validate_asset_verifier_msg(asset_type, verifier),
+        TimelockedChange::DeleteAssetDefinition { qualifier } => {
+            validate_delete_asset_definition(qualifier)
+        }
+        TimelockedChange::UpdateProtocolFee { protocol_fee } => {
+            validate_update_protocol_fee(protocol_fee)
+        }
+        TimelockedChange::UpdateTimelockDelay { delay_blocks } => {
+            validate_update_timelock_delay(delay_blocks)
+        }
+        TimelockedChange::RegisterVerifier { registration } => {
+            validate_register_verifier(registration)
+        }
+        TimelockedChange::UpdateAssetDefinition { asset_definition } => {
+            validate_asset_definition(&asset_definition.as_asset_definition()?)
+        }
+        TimelockedChange::RemoveAssetVerifier {
+            asset_type,
+            verifier_address,
+            pending_asset_strategy,
+        } => validate_remove_asset_verifier(asset_type, verifier_address, pending_asset_strategy),
+    }
+}
+
+/// Validates the [UpdateProtocolFee](crate::core::msg::ExecuteMsg::UpdateProtocolFee) variant of the
+/// [ExecuteMsg](crate::core::msg::ExecuteMsg).  Returning an empty response on success, or an
+/// [InvalidMessageFields](crate::core::error::ContractError::InvalidMessageFields) error when
+/// invalid fields are found.
+///
+/// # Parameters
+///
+/// * `protocol_fee` The new protocol fee, or None if the protocol fee is being removed.
+fn validate_update_protocol_fee(protocol_fee: &Option<ProtocolFee>) -> AssetResult<()> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if let Some(protocol_fee) = protocol_fee {
+        if bech32_string_to_addr(&protocol_fee.collector_address).is_err() {
+            invalid_fields
+                .push("protocol_fee:collector_address: must be a valid address".to_string());
+        }
+        match protocol_fee.amount {
+            ProtocolFeeAmount::Flat(amount) => {
+                if amount.is_zero() {
+                    invalid_fields.push("protocol_fee:amount:flat: must not be zero".to_string());
+                }
+            }
+            ProtocolFeeAmount::BasisPoints(basis_points) => {
+                if basis_points == 0 || basis_points as u128 > BASIS_POINTS_DENOMINATOR {
+                    invalid_fields.push(
+                        "protocol_fee:amount:basis_points: must be between 1 and 10000".to_string(),
+                    );
+                }
+            }
+        }
+    }
+    gen_validation_response("ExecuteMsg::UpdateProtocolFee", invalid_fields)
+}
+
 /// Validates a serialized enum to ensure that it can convert to a valid [AssetIdentifier](crate::core::types::asset_identifier::AssetIdentifier),
 /// returning an optional string that is only populated if an error is present.
 ///
@@ -308,11 +750,17 @@ fn gen_validation_response<S: Into<String>>(
 
 #[cfg(test)]
 mod tests {
+    use cosmwasm_std::{coin, Uint128};
+
     use crate::core::types::asset_qualifier::AssetQualifier;
+    use crate::core::types::protocol_fee::{ProtocolFee, ProtocolFeeAmount};
     use crate::core::types::serialized_enum::SerializedEnum;
+    use crate::core::types::verifier_bond_settings::VerifierBondSettings;
+    use crate::util::traits::OptionExtensions;
     use crate::validation::validate_execute_msg::{
         validate_bind_contract_alias, validate_delete_asset_definition,
-        validate_update_access_routes,
+        validate_slash_verifier_bond, validate_update_access_routes, validate_update_protocol_fee,
+        validate_update_verifier_bond_settings, validate_withdraw_treasury,
     };
     use crate::{
         core::{error::ContractError, types::asset_identifier::AssetIdentifier},
@@ -729,6 +1177,149 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_validate_withdraw_treasury_success() {
+        validate_withdraw_treasury(
+            &coin(100, "nhash"),
+            "tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5",
+        )
+        .expect("a valid withdrawal should pass validation");
+    }
+
+    #[test]
+    fn test_validate_withdraw_treasury_invalid_fields() {
+        let result = validate_withdraw_treasury(&coin(0, ""), "not-an-address");
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::WithdrawTreasury", message_type,
+                "incorrect message type for error",
+            );
+            assert_eq!(
+                vec![
+                    "amount:amount: must not be zero".to_string(),
+                    "amount:denom: must not be blank".to_string(),
+                    "recipient: must be a valid address".to_string(),
+                ],
+                invalid_fields,
+                "expected all invalid fields to be reported",
+            );
+        });
+    }
+
+    #[test]
+    fn test_validate_slash_verifier_bond_success() {
+        validate_slash_verifier_bond(
+            "heloc",
+            "tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5",
+            &coin(100, "nhash"),
+        )
+        .expect("a valid slash should pass validation");
+    }
+
+    #[test]
+    fn test_validate_slash_verifier_bond_invalid_fields() {
+        let result = validate_slash_verifier_bond("", "not-an-address", &coin(0, ""));
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::SlashVerifierBond", message_type,
+                "incorrect message type for error",
+            );
+            assert_eq!(
+                vec![
+                    "asset_type: must not be blank".to_string(),
+                    "verifier_address: must be a valid address".to_string(),
+                    "amount:amount: must not be zero".to_string(),
+                    "amount:denom: must not be blank".to_string(),
+                ],
+                invalid_fields,
+                "expected all invalid fields to be reported",
+            );
+        });
+    }
+
+    #[test]
+    fn test_validate_update_verifier_bond_settings() {
+        validate_update_verifier_bond_settings(&VerifierBondSettings::default())
+            .expect("settings without a beneficiary should pass validation");
+        validate_update_verifier_bond_settings(&VerifierBondSettings::new(
+            "tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5"
+                .to_string()
+                .to_some(),
+        ))
+        .expect("settings with a valid beneficiary should pass validation");
+        let result = validate_update_verifier_bond_settings(
+            &VerifierBondSettings::new("not-an-address".to_string().to_some())
+                .with_unbonding_blocks(0),
+        );
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::UpdateVerifierBondSettings", message_type,
+                "incorrect message type for error",
+            );
+            assert_eq!(
+                vec![
+                    "settings:slash_beneficiary: must be a valid address".to_string(),
+                    "settings:unbonding_blocks: must be greater than zero".to_string(),
+                ],
+                invalid_fields,
+                "expected the invalid beneficiary and unbonding period to be reported",
+            );
+        });
+    }
+
+    #[test]
+    fn test_validate_update_protocol_fee_success() {
+        validate_update_protocol_fee(&None)
+            .expect("removing the protocol fee should pass validation");
+        validate_update_protocol_fee(&Some(ProtocolFee::new(
+            "tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5",
+            ProtocolFeeAmount::BasisPoints(10000),
+        )))
+        .expect("a valid basis point protocol fee should pass validation");
+        validate_update_protocol_fee(&Some(ProtocolFee::new(
+            "tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5",
+            ProtocolFeeAmount::Flat(Uint128::new(1)),
+        )))
+        .expect("a valid flat protocol fee should pass validation");
+    }
+
+    #[test]
+    fn test_validate_update_protocol_fee_invalid_fields() {
+        let result = validate_update_protocol_fee(&Some(ProtocolFee::new(
+            "not-an-address",
+            ProtocolFeeAmount::Flat(Uint128::zero()),
+        )));
+        test_invalid_message_fields(result, |message_type, invalid_fields| {
+            assert_eq!(
+                "ExecuteMsg::UpdateProtocolFee", message_type,
+                "incorrect message type for error",
+            );
+            assert_eq!(
+                vec![
+                    "protocol_fee:collector_address: must be a valid address".to_string(),
+                    "protocol_fee:amount:flat: must not be zero".to_string(),
+                ],
+                invalid_fields,
+                "expected all invalid fields to be reported",
+            );
+        });
+        for basis_points in [0, 10001] {
+            let result = validate_update_protocol_fee(&Some(ProtocolFee::new(
+                "tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5",
+                ProtocolFeeAmount::BasisPoints(basis_points),
+            )));
+            test_invalid_message_fields(result, |_, invalid_fields| {
+                assert_eq!(
+                    vec![
+                        "protocol_fee:amount:basis_points: must be between 1 and 10000".to_string()
+                    ],
+                    invalid_fields,
+                    "expected the basis points to be rejected",
+                );
+            });
+        }
+    }
+
     // Extracts the InvalidMessageFunds error data from a response from one of the functions
     // in this file, allowing a unit test to target the relevant information without as much
     // boilerplate nonsense.
@@ -2,8 +2,11 @@ use crate::core::error::ContractError;
 use crate::core::msg::InitMsg;
 use crate::core::types::asset_definition::{AssetDefinitionInputV2, AssetDefinitionV2};
 use crate::core::types::fee_destination::FeeDestinationV2;
+use crate::core::types::fee_discount::FeeDiscount;
+use crate::core::types::fee_remainder_destination::FeeRemainderDestination;
 use crate::core::types::verifier_detail::VerifierDetailV2;
 use crate::util::aliases::AssetResult;
+use crate::util::constants::BASIS_POINTS_DENOMINATOR;
 use crate::util::functions::distinct_count_by_property;
 use crate::util::scope_address_utils::bech32_string_to_addr;
 use crate::util::traits::ResultExtensions;
@@ -129,7 +132,43 @@ fn validate_asset_definition_internal(asset_definition: &AssetDefinitionV2) -> V
     if asset_definition.scope_spec_address.is_empty() {
         invalid_fields.push("asset_definition:scope_spec_address: must not be blank".to_string());
     }
-    if asset_definition.verifiers.is_empty() {
+    if asset_definition
+        .additional_scope_spec_addresses
+        .iter()
+        .any(|address| address.is_empty())
+    {
+        invalid_fields.push(
+            "asset_definition:additional_scope_spec_addresses: must not contain blank values"
+                .to_string(),
+        );
+    }
+    let scope_spec_addresses = asset_definition
+        .get_scope_spec_addresses()
+        .into_iter()
+        .map(|address| address.to_lowercase())
+        .collect::<Vec<String>>();
+    if scope_spec_addresses
+        .iter()
+        .enumerate()
+        .any(|(index, address)| scope_spec_addresses[..index].contains(address))
+    {
+        invalid_fields.push(
+            "asset_definition:additional_scope_spec_addresses: each scope spec address must be unique".to_string(),
+        );
+    }
+    if let Some(parent_asset_type) = &asset_definition.parent_asset_type {
+        if parent_asset_type.is_empty() {
+            invalid_fields
+                .push("asset_definition:parent_asset_type: must not be blank".to_string());
+        } else if parent_asset_type.to_lowercase() == asset_definition.asset_type.to_lowercase() {
+            invalid_fields.push(
+                "asset_definition:parent_asset_type: must not be the definition's own asset type"
+                    .to_string(),
+            );
+        }
+    }
+    // Definitions with a parent inherit its verifiers when they do not define their own
+    if asset_definition.verifiers.is_empty() && asset_definition.parent_asset_type.is_none() {
         invalid_fields.push(
             "asset_definition:verifiers: at least one verifier must be supplied per asset type"
                 .to_string(),
@@ -141,6 +180,22 @@ fn validate_asset_definition_internal(asset_definition: &AssetDefinitionV2) -> V
         .flat_map(validate_verifier_internal)
         .collect::<Vec<String>>();
     invalid_fields.append(&mut verifier_messages);
+    invalid_fields.append(&mut validate_fee_discounts_internal(
+        "asset_definition",
+        &asset_definition.fee_discounts,
+    ));
+    if let Some(minimum_verifier_bond) = &asset_definition.minimum_verifier_bond {
+        if minimum_verifier_bond.amount.is_zero() {
+            invalid_fields.push(
+                "asset_definition:minimum_verifier_bond:amount: must not be zero".to_string(),
+            );
+        }
+        if minimum_verifier_bond.denom.is_empty() {
+            invalid_fields.push(
+                "asset_definition:minimum_verifier_bond:denom: must not be blank".to_string(),
+            );
+        }
+    }
     invalid_fields
 }
 
@@ -153,12 +208,41 @@ fn validate_verifier_internal(verifier: &VerifierDetailV2) -> Vec<String> {
         invalid_fields.push("verifier:onboarding_denom: must not be blank".to_string());
     }
     if !verifier.fee_destinations.is_empty()
-        && verifier.get_fee_total() > verifier.onboarding_cost.u128()
+        && verifier.get_fee_total() > verifier.get_completion_fee().u128()
     {
         invalid_fields.push(
-            "verifier:fee_destinations:fee_amounts must sum to be less than or equal to the onboarding cost".to_string(),
+            "verifier:fee_destinations:fee_amounts must sum to be less than or equal to the onboarding cost minus the intake fee".to_string(),
         );
     }
+    if verifier
+        .fee_destinations
+        .iter()
+        .filter_map(|dest| dest.fee_basis_points)
+        .map(u128::from)
+        .sum::<u128>()
+        > BASIS_POINTS_DENOMINATOR
+    {
+        invalid_fields.push(format!(
+            "verifier:fee_destinations:fee_basis_points must sum to be less than or equal to {}",
+            BASIS_POINTS_DENOMINATOR,
+        ));
+    }
+    if verifier
+        .fee_destinations
+        .iter()
+        .filter(|dest| dest.receives_rounding_remainder)
+        .count()
+        > 1
+    {
+        invalid_fields.push("verifier:fee_destinations: at most one fee destination may receive the rounding remainder".to_string());
+    }
+    if let FeeRemainderDestination::Address(address) = &verifier.remainder_destination {
+        if bech32_string_to_addr(address).is_err() {
+            invalid_fields.push(
+                "verifier:remainder_destination:address: must be a valid address".to_string(),
+            );
+        }
+    }
     if distinct_count_by_property(&verifier.fee_destinations, |dest| &dest.address)
         != verifier.fee_destinations.len()
     {
@@ -170,6 +254,110 @@ fn validate_verifier_internal(verifier: &VerifierDetailV2) -> Vec<String> {
         .flat_map(validate_destination_internal)
         .collect::<Vec<String>>();
     invalid_fields.append(&mut fee_destination_messages);
+    invalid_fields.append(&mut validate_fee_discounts_internal(
+        "verifier",
+        &verifier.fee_discounts,
+    ));
+    if verifier.intake_fee > verifier.onboarding_cost {
+        invalid_fields.push(
+            "verifier:intake_fee: must be less than or equal to the onboarding cost".to_string(),
+        );
+    }
+    if verifier.intake_fee.is_zero() && !verifier.intake_fee_destinations.is_empty() {
+        invalid_fields.push(
+            "verifier:intake_fee_destinations: must be empty when no intake fee is charged"
+                .to_string(),
+        );
+    }
+    if !verifier.intake_fee_destinations.is_empty()
+        && verifier.get_intake_fee_total() > verifier.intake_fee.u128()
+    {
+        invalid_fields.push(
+            "verifier:intake_fee_destinations:fee_amounts must sum to be less than or equal to the intake fee".to_string(),
+        );
+    }
+    if verifier
+        .intake_fee_destinations
+        .iter()
+        .filter_map(|dest| dest.fee_basis_points)
+        .map(u128::from)
+        .sum::<u128>()
+        > BASIS_POINTS_DENOMINATOR
+    {
+        invalid_fields.push(format!(
+            "verifier:intake_fee_destinations:fee_basis_points must sum to be less than or equal to {}",
+            BASIS_POINTS_DENOMINATOR,
+        ));
+    }
+    if verifier
+        .intake_fee_destinations
+        .iter()
+        .filter(|dest| dest.receives_rounding_remainder)
+        .count()
+        > 1
+    {
+        invalid_fields.push("verifier:intake_fee_destinations: at most one fee destination may receive the rounding remainder".to_string());
+    }
+    if distinct_count_by_property(&verifier.intake_fee_destinations, |dest| &dest.address)
+        != verifier.intake_fee_destinations.len()
+    {
+        invalid_fields.push("verifier:intake_fee_destinations: all intake fee destinations within a verifier must have unique addresses".to_string());
+    }
+    let mut intake_fee_destination_messages = verifier
+        .intake_fee_destinations
+        .iter()
+        .flat_map(validate_destination_internal)
+        .collect::<Vec<String>>();
+    invalid_fields.append(&mut intake_fee_destination_messages);
+    if verifier
+        .pricing_tiers
+        .iter()
+        .any(|tier| tier.minimum_onboardings == 0)
+    {
+        invalid_fields.push(
+            "verifier:pricing_tiers:minimum_onboardings: must be greater than zero".to_string(),
+        );
+    }
+    if distinct_count_by_property(&verifier.pricing_tiers, |tier| &tier.minimum_onboardings)
+        != verifier.pricing_tiers.len()
+    {
+        invalid_fields.push("verifier:pricing_tiers: all pricing tiers within a verifier must have unique minimum_onboardings".to_string());
+    }
+    if verifier.applied_pricing_tier.is_some() {
+        invalid_fields.push(
+            "verifier:applied_pricing_tier: must not be provided in an asset definition"
+                .to_string(),
+        );
+    }
+    invalid_fields
+}
+
+fn validate_fee_discounts_internal(prefix: &str, fee_discounts: &[FeeDiscount]) -> Vec<String> {
+    let mut invalid_fields: Vec<String> = vec![];
+    if distinct_count_by_property(fee_discounts, |discount| &discount.requestor_address)
+        != fee_discounts.len()
+    {
+        invalid_fields.push(format!(
+            "{}:fee_discounts: all fee discounts must have unique requestor addresses",
+            prefix,
+        ));
+    }
+    for discount in fee_discounts {
+        if bech32_string_to_addr(&discount.requestor_address).is_err() {
+            invalid_fields.push(format!(
+                "{}:fee_discounts:requestor_address: must be a valid address",
+                prefix,
+            ));
+        }
+        if discount.discount_basis_points == 0
+            || u128::from(discount.discount_basis_points) > BASIS_POINTS_DENOMINATOR
+        {
+            invalid_fields.push(format!(
+                "{}:fee_discounts:discount_basis_points: must be between 1 and {}",
+                prefix, BASIS_POINTS_DENOMINATOR,
+            ));
+        }
+    }
     invalid_fields
 }
 
@@ -178,8 +366,26 @@ fn validate_destination_internal(destination: &FeeDestinationV2) -> Vec<String>
     if bech32_string_to_addr(&destination.address).is_err() {
         invalid_fields.push("fee_destination:address: must be a valid address".to_string());
     }
-    if destination.fee_amount == Uint128::zero() {
-        invalid_fields.push("fee_destination:fee_amount: must not be zero".to_string());
+    match destination.fee_basis_points {
+        Some(basis_points) => {
+            if basis_points == 0 || u128::from(basis_points) > BASIS_POINTS_DENOMINATOR {
+                invalid_fields.push(format!(
+                    "fee_destination:fee_basis_points: must be between 1 and {}",
+                    BASIS_POINTS_DENOMINATOR,
+                ));
+            }
+            if destination.fee_amount != Uint128::zero() {
+                invalid_fields.push(
+                    "fee_destination:fee_amount: must be zero when fee_basis_points are provided"
+                        .to_string(),
+                );
+            }
+        }
+        None => {
+            if destination.fee_amount == Uint128::zero() {
+                invalid_fields.push("fee_destination:fee_amount: must not be zero".to_string());
+            }
+        }
     }
     invalid_fields
 }
@@ -192,16 +398,22 @@ pub mod tests {
     use crate::core::types::asset_definition::{AssetDefinitionInputV2, AssetDefinitionV2};
     use crate::core::types::entity_detail::EntityDetail;
     use crate::core::types::fee_destination::FeeDestinationV2;
+    use crate::core::types::fee_discount::FeeDiscount;
+    use crate::core::types::fee_remainder_destination::FeeRemainderDestination;
+    use crate::core::types::pricing_tier::PricingTier;
     use crate::core::types::scope_spec_identifier::ScopeSpecIdentifier;
     use crate::core::types::verifier_detail::VerifierDetailV2;
-    use crate::testutil::test_utilities::get_default_entity_detail;
+    use crate::testutil::test_utilities::{
+        get_default_asset_definition_input, get_default_entity_detail, get_default_verifier_detail,
+    };
     use crate::util::constants::NHASH;
     use crate::util::traits::OptionExtensions;
     use crate::validation::validate_init_msg::{
-        validate_asset_definition_input_internal, validate_asset_definition_internal,
-        validate_destination_internal, validate_init_msg, validate_verifier_internal,
+        validate_asset_definition, validate_asset_definition_input_internal,
+        validate_asset_definition_internal, validate_destination_internal, validate_init_msg,
+        validate_verifier_internal,
     };
-    use cosmwasm_std::Uint128;
+    use cosmwasm_std::{coin, Uint128};
 
     #[test]
     fn test_valid_init_msg_no_definitions() {
@@ -468,6 +680,36 @@ pub mod tests {
         )
     }
 
+    #[test]
+    fn test_invalid_asset_definition_blank_additional_scope_spec_address() {
+        test_invalid_asset_definition(
+            &AssetDefinitionV2 {
+                additional_scope_spec_addresses: vec!["".to_string()],
+                ..AssetDefinitionV2::new(
+                    "heloc",
+                    "scope-spec-address",
+                    vec![get_default_verifier_detail()],
+                )
+            },
+            "asset_definition:additional_scope_spec_addresses: must not contain blank values",
+        )
+    }
+
+    #[test]
+    fn test_invalid_asset_definition_duplicate_additional_scope_spec_address() {
+        test_invalid_asset_definition(
+            &AssetDefinitionV2 {
+                additional_scope_spec_addresses: vec!["SCOPE-SPEC-ADDRESS".to_string()],
+                ..AssetDefinitionV2::new(
+                    "heloc",
+                    "scope-spec-address",
+                    vec![get_default_verifier_detail()],
+                )
+            },
+            "asset_definition:additional_scope_spec_addresses: each scope spec address must be unique",
+        )
+    }
+
     #[test]
     fn test_invalid_asset_definition_empty_verifiers() {
         test_invalid_asset_definition(
@@ -476,6 +718,37 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_valid_asset_definition_with_parent_and_no_verifiers() {
+        validate_asset_definition(&AssetDefinitionV2 {
+            parent_asset_type: "mortgage".to_string().to_some(),
+            ..AssetDefinitionV2::new("heloc", "scope-spec-address", vec![])
+        })
+        .expect("a definition with a parent should be able to inherit its verifiers");
+    }
+
+    #[test]
+    fn test_invalid_asset_definition_blank_parent_asset_type() {
+        test_invalid_asset_definition(
+            &AssetDefinitionV2 {
+                parent_asset_type: "".to_string().to_some(),
+                ..AssetDefinitionV2::new("heloc", "scope-spec-address", vec![])
+            },
+            "asset_definition:parent_asset_type: must not be blank",
+        );
+    }
+
+    #[test]
+    fn test_invalid_asset_definition_parent_asset_type_is_own_asset_type() {
+        test_invalid_asset_definition(
+            &AssetDefinitionV2 {
+                parent_asset_type: "HELOC".to_string().to_some(),
+                ..AssetDefinitionV2::new("heloc", "scope-spec-address", vec![])
+            },
+            "asset_definition:parent_asset_type: must not be the definition's own asset type",
+        );
+    }
+
     #[test]
     fn test_invalid_asset_definition_picks_up_invalid_verifier_scenarios() {
         test_invalid_asset_definition(
@@ -604,7 +877,24 @@ pub mod tests {
                 vec![FeeDestinationV2::new("fee", Uint128::new(1011))],
                 get_default_entity_detail().to_some(),
             ),
-            "verifier:fee_destinations:fee_amounts must sum to be less than or equal to the onboarding cost",
+            "verifier:fee_destinations:fee_amounts must sum to be less than or equal to the onboarding cost minus the intake fee",
+        );
+    }
+
+    #[test]
+    fn test_invalid_verifier_remainder_destination_address() {
+        test_invalid_verifier(
+            &VerifierDetailV2::new(
+                "address",
+                Uint128::new(100),
+                NHASH,
+                vec![],
+                get_default_entity_detail().to_some(),
+            )
+            .with_remainder_destination(FeeRemainderDestination::Address(
+                "not-an-address".to_string(),
+            )),
+            "verifier:remainder_destination:address: must be a valid address",
         );
     }
 
@@ -667,6 +957,263 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_valid_basis_point_destination() {
+        let destination =
+            FeeDestinationV2::new_basis_points("tp1362ax9s0gxr5yy636q2p9uuefeg8lhguvu6np5", 10000);
+        assert!(
+            validate_destination_internal(&destination).is_empty(),
+            "a valid basis point fee destination should pass validation and return no error messages",
+        );
+    }
+
+    #[test]
+    fn test_invalid_destination_basis_points_out_of_range() {
+        test_invalid_destination(
+            &FeeDestinationV2::new_basis_points("good-address", 0),
+            "fee_destination:fee_basis_points: must be between 1 and 10000",
+        );
+        test_invalid_destination(
+            &FeeDestinationV2::new_basis_points("good-address", 10001),
+            "fee_destination:fee_basis_points: must be between 1 and 10000",
+        );
+    }
+
+    #[test]
+    fn test_invalid_destination_basis_points_with_fee_amount() {
+        let mut destination = FeeDestinationV2::new_basis_points("good-address", 100);
+        destination.fee_amount = Uint128::new(1);
+        test_invalid_destination(
+            &destination,
+            "fee_destination:fee_amount: must be zero when fee_basis_points are provided",
+        );
+    }
+
+    #[test]
+    fn test_invalid_verifier_basis_points_too_high() {
+        test_invalid_verifier(
+            &VerifierDetailV2::new(
+                "address",
+                Uint128::new(100),
+                NHASH,
+                vec![
+                    FeeDestinationV2::new_basis_points("fee-1", 5000),
+                    FeeDestinationV2::new_basis_points("fee-2", 5001),
+                ],
+                get_default_entity_detail().to_some(),
+            ),
+            "verifier:fee_destinations:fee_basis_points must sum to be less than or equal to 10000",
+        );
+    }
+
+    #[test]
+    fn test_invalid_verifier_multiple_rounding_remainder_destinations() {
+        test_invalid_verifier(
+            &VerifierDetailV2::new(
+                "address",
+                Uint128::new(100),
+                NHASH,
+                vec![
+                    FeeDestinationV2::new_basis_points("fee-1", 2500).with_rounding_remainder(),
+                    FeeDestinationV2::new_basis_points("fee-2", 2500).with_rounding_remainder(),
+                ],
+                get_default_entity_detail().to_some(),
+            ),
+            "verifier:fee_destinations: at most one fee destination may receive the rounding remainder",
+        );
+    }
+
+    #[test]
+    fn test_valid_verifier_with_fee_discounts() {
+        let verifier = VerifierDetailV2::new(
+            "tp1z28j4v88vz3jyzz286a8627lfsclemk294essy",
+            Uint128::new(100),
+            NHASH,
+            vec![],
+            get_default_entity_detail().to_some(),
+        )
+        .with_fee_discounts(vec![
+            FeeDiscount::new("tp143p2m575fqre9rmaf9tpqwp9ux0mrzv83tdfh6", 2500),
+            FeeDiscount::full_waiver("tp1dv7562fvlvf74904t222ze362m036ugtmg45ll"),
+        ]);
+        let response = validate_verifier_internal(&verifier);
+        assert!(
+            response.is_empty(),
+            "a verifier with valid fee discounts should pass validation, but got messages: {:?}",
+            response,
+        );
+    }
+
+    #[test]
+    fn test_invalid_verifier_fee_discount_scenarios() {
+        let verifier = VerifierDetailV2::new(
+            "address",
+            Uint128::new(100),
+            NHASH,
+            vec![],
+            get_default_entity_detail().to_some(),
+        );
+        test_invalid_verifier(
+            &verifier
+                .clone()
+                .with_fee_discounts(vec![FeeDiscount::new("not-an-address", 100)]),
+            "verifier:fee_discounts:requestor_address: must be a valid address",
+        );
+        test_invalid_verifier(
+            &verifier
+                .clone()
+                .with_fee_discounts(vec![FeeDiscount::new("discounted", 0)]),
+            "verifier:fee_discounts:discount_basis_points: must be between 1 and 10000",
+        );
+        test_invalid_verifier(
+            &verifier
+                .clone()
+                .with_fee_discounts(vec![FeeDiscount::new("discounted", 10001)]),
+            "verifier:fee_discounts:discount_basis_points: must be between 1 and 10000",
+        );
+        test_invalid_verifier(
+            &verifier.with_fee_discounts(vec![
+                FeeDiscount::new("discounted", 100),
+                FeeDiscount::full_waiver("discounted"),
+            ]),
+            "verifier:fee_discounts: all fee discounts must have unique requestor addresses",
+        );
+    }
+
+    #[test]
+    fn test_invalid_verifier_pricing_tier_scenarios() {
+        let verifier = VerifierDetailV2::new(
+            "address",
+            Uint128::new(100),
+            NHASH,
+            vec![],
+            get_default_entity_detail().to_some(),
+        );
+        test_invalid_verifier(
+            &verifier
+                .clone()
+                .with_pricing_tiers(vec![PricingTier::new(0, Uint128::new(50))]),
+            "verifier:pricing_tiers:minimum_onboardings: must be greater than zero",
+        );
+        test_invalid_verifier(
+            &verifier.clone().with_pricing_tiers(vec![
+                PricingTier::new(10, Uint128::new(50)),
+                PricingTier::new(10, Uint128::new(25)),
+            ]),
+            "verifier:pricing_tiers: all pricing tiers within a verifier must have unique minimum_onboardings",
+        );
+        test_invalid_verifier(
+            &verifier
+                .with_pricing_tiers(vec![PricingTier::new(5, Uint128::new(50))])
+                .apply_pricing_tier(10),
+            "verifier:applied_pricing_tier: must not be provided in an asset definition",
+        );
+    }
+
+    #[test]
+    fn test_invalid_verifier_intake_fee_scenarios() {
+        let verifier = VerifierDetailV2::new(
+            "address",
+            Uint128::new(100),
+            NHASH,
+            vec![],
+            get_default_entity_detail().to_some(),
+        );
+        test_invalid_verifier(
+            &verifier.clone().with_intake_fee(Uint128::new(101), vec![]),
+            "verifier:intake_fee: must be less than or equal to the onboarding cost",
+        );
+        test_invalid_verifier(
+            &verifier.clone().with_intake_fee(
+                Uint128::zero(),
+                vec![FeeDestinationV2::new(
+                    "tp1z28j4v88vz3jyzz286a8627lfsclemk294essy",
+                    Uint128::new(10),
+                )],
+            ),
+            "verifier:intake_fee_destinations: must be empty when no intake fee is charged",
+        );
+        test_invalid_verifier(
+            &verifier.clone().with_intake_fee(
+                Uint128::new(40),
+                vec![FeeDestinationV2::new(
+                    "tp1z28j4v88vz3jyzz286a8627lfsclemk294essy",
+                    Uint128::new(41),
+                )],
+            ),
+            "verifier:intake_fee_destinations:fee_amounts must sum to be less than or equal to the intake fee",
+        );
+        test_invalid_verifier(
+            &verifier.clone().with_intake_fee(
+                Uint128::new(40),
+                vec![
+                    FeeDestinationV2::new(
+                        "tp1z28j4v88vz3jyzz286a8627lfsclemk294essy",
+                        Uint128::new(10),
+                    ),
+                    FeeDestinationV2::new(
+                        "tp1z28j4v88vz3jyzz286a8627lfsclemk294essy",
+                        Uint128::new(10),
+                    ),
+                ],
+            ),
+            "verifier:intake_fee_destinations: all intake fee destinations within a verifier must have unique addresses",
+        );
+        test_invalid_verifier(
+            &VerifierDetailV2::new(
+                "address",
+                Uint128::new(100),
+                NHASH,
+                vec![FeeDestinationV2::new(
+                    "tp1z28j4v88vz3jyzz286a8627lfsclemk294essy",
+                    Uint128::new(70),
+                )],
+                get_default_entity_detail().to_some(),
+            )
+            .with_intake_fee(Uint128::new(40), vec![]),
+            "verifier:fee_destinations:fee_amounts must sum to be less than or equal to the onboarding cost minus the intake fee",
+        );
+    }
+
+    #[test]
+    fn test_invalid_asset_definition_fee_discount_scenarios() {
+        let mut definition = get_default_asset_definition_input()
+            .as_asset_definition()
+            .unwrap();
+        definition.fee_discounts = vec![
+            FeeDiscount::new("not-an-address", 100),
+            FeeDiscount::new("not-an-address", 0),
+        ];
+        test_invalid_asset_definition(
+            &definition,
+            "asset_definition:fee_discounts:requestor_address: must be a valid address",
+        );
+        test_invalid_asset_definition(
+            &definition,
+            "asset_definition:fee_discounts:discount_basis_points: must be between 1 and 10000",
+        );
+        test_invalid_asset_definition(
+            &definition,
+            "asset_definition:fee_discounts: all fee discounts must have unique requestor addresses",
+        );
+    }
+
+    #[test]
+    fn test_invalid_asset_definition_minimum_verifier_bond_scenarios() {
+        let mut definition = get_default_asset_definition_input()
+            .as_asset_definition()
+            .unwrap();
+        definition.minimum_verifier_bond = coin(0, "").to_some();
+        test_invalid_asset_definition(
+            &definition,
+            "asset_definition:minimum_verifier_bond:amount: must not be zero",
+        );
+        test_invalid_asset_definition(
+            &definition,
+            "asset_definition:minimum_verifier_bond:denom: must not be blank",
+        );
+    }
+
     #[test]
     fn test_validate_asset_definition_input_internal_bad_scope_spec_identifier() {
         let error_strings = validate_asset_definition_input_internal(&AssetDefinitionInputV2::new(